@@ -1,10 +1,10 @@
 use crate::rendering::renderer::Renderer;
-use crate::wad::{LumpStore, By};
+use crate::wad::{LumpStore, By, WadError};
 use crate::rendering::patch::Patch;
 
 // Do we even need this?
 pub trait Drawer {
-    fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore);
+    fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError>;
 }
 
 pub struct PageDrawer {
@@ -12,8 +12,8 @@ pub struct PageDrawer {
 }
 
 impl PageDrawer {
-    fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) {
-        let patch = lumps.get_lump(By::Name(self.page_name.as_ref().expect("Page name not set"))).into();
-        renderer.draw_patch(0, 0, 0, &patch);
+    fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError> {
+        let patch = Patch::load(lumps, By::Name(self.page_name.as_ref().expect("Page name not set")))?;
+        renderer.draw_patch(0, 0, 0, &patch)
     }
 }
\ No newline at end of file
@@ -1,11 +1,13 @@
 use crate::menu::MenuComponent;
 use crate::level_component::LevelComponent;
-use crate::game_context::GameState::Level;
+use crate::play::spec::LevelExit;
 use crate::events::EventSystem;
 use crate::rendering::renderer::Renderer;
 use crate::system::System;
-use crate::wad::{LumpStore, By};
+use crate::wad::{LumpStore, WadError};
 use crate::page_component::PageComponent;
+use crate::rendering::RenderData;
+use crate::tic_cmd::{TicCmd, BT_SPECIAL, BT_SPECIALMASK, BTS_PAUSE};
 
 const MAX_NODES:usize = 8;
 const BACKUPTICKS:i32 = 12;
@@ -21,6 +23,22 @@ pub struct GameContext {
     pub(crate) game_time: i32,
     pub(crate) skip_tics: i32,
     pub(crate) make_tic: i32,
+    pub(crate) local_cmds: [TicCmd; BACKUPTICKS as usize],
+    pub(crate) paused: bool,
+
+    pub(crate) skill: Skill,
+    pub(crate) episode: u32,
+    pub(crate) map: u32,
+
+    // The new game G_DeferedInitNew asked for, d_skill, d_episode and d_map.
+    pub(crate) defered_skill: Skill,
+    pub(crate) defered_episode: u32,
+    pub(crate) defered_map: u32,
+
+    // Set by G_SecretExitLevel, and the 0 based map after the intermission,
+    //  wminfo.next.
+    pub(crate) secret_exit: bool,
+    pub(crate) next_map: u32,
 
     pub(crate) menu: MenuComponent,
     pub(crate) level: LevelComponent,
@@ -35,42 +53,52 @@ impl GameContext {
             action: GameAction::Nothing,
             old_enter_tics: 0,
             net_tics: [0i32; MAX_NODES],
-            node_in_game: [false; MAX_NODES],
+            // D_CheckNetGame, the console player is node 0
+            node_in_game: [true, false, false, false, false, false, false, false],
             game_tic: 0,
             game_time: 0,
             skip_tics: 0,
             make_tic: 0,
+            local_cmds: [TicCmd::default(); BACKUPTICKS as usize],
+            paused: false,
+            skill: Skill::Medium,
+            episode: 1,
+            map: 1,
+            defered_skill: Skill::Medium,
+            defered_episode: 1,
+            defered_map: 1,
+            secret_exit: false,
+            next_map: 0,
             menu: MenuComponent::new(),
             level: LevelComponent::new(),
             page: PageComponent::new(),
         }
     }
 
-    pub fn game_loop(&mut self, events: &mut EventSystem, renderer: &mut dyn Renderer, system: &System, lumps: &LumpStore) {
-        let map = 1;
-        /*let map_lump_num = lumps.get_lump_number(&format!("map{:02}", map)).unwrap();
-
-        let blockmap = crate::level::blockmap::load(lumps, map_lump_num);
-        let vertices = crate::level::vertices::load(lumps, map_lump_num);
-    */
-
-        self.try_run_tics(system);
+    pub fn game_loop(&mut self, events: &mut EventSystem, renderer: &mut dyn Renderer, system: &System, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        self.try_run_tics(system, lumps, render_data)?;
 
         // Below are contents of D_Display
+        if !matches!(self.state, GameState::Level) {
+            self.level.restore_palette(renderer, render_data);
+        }
+
         match self.state {
             GameState::ForceWipe => {}
-            GameState::Level => {}
+            GameState::Level => {
+                self.level.draw(renderer, lumps, render_data)?;
+            }
             GameState::Intermission => {}
             GameState::Finale => {}
             GameState::DemoScreen => {
                 // D_PageDrawer
-                self.page.draw(renderer, lumps);
+                self.page.draw(renderer, lumps)?;
             }
         }
 
         // TODO S_UpdateSounds(players[consoleplayer].mo);// move positional sounds
 
-        self.menu.draw(renderer, lumps);
+        self.menu.draw(renderer, lumps)?;
 
         events.process_events(&mut [
             &mut self.menu,
@@ -78,13 +106,19 @@ impl GameContext {
             &mut self.level
         ]);
 
-        if self.page.open_menu_requested() {
+        // any other key pops up menu if in demos
+        if self.page.open_menu_requested() && matches!(self.state, GameState::DemoScreen) {
             self.menu.show();
         }
 
+        if let Some((skill, episode)) = self.menu.new_game_requested() {
+            self.defered_init_new(skill, episode, 1);
+        }
+
+        Ok(())
     }
 
-    fn try_run_tics(&mut self, system: &System) {
+    fn try_run_tics(&mut self, system: &System, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
         let ticdup = 1; // TODO: ticdup comes from "doomcom"
         let enter_tic = system.calculate_tics() as i32 / ticdup;
         let real_tics = enter_tic - self.old_enter_tics;
@@ -139,7 +173,7 @@ impl GameContext {
             // don't stay in here forever -- give the menu a chance to work
             if system.calculate_tics() as i32 / ticdup - enter_tic >= 20 {
                 // TODO M_Ticker
-                return;
+                return Ok(());
             }
         }
 
@@ -156,10 +190,8 @@ impl GameContext {
                     self.page.advance_demo(&self.mode)
                 }
 
-                // TODO:
-                // M_Ticker
-                // TODO G_Ticker is required to get any gameplay on screen
-                //game_ticker( demo_state);
+                // TODO M_Ticker
+                self.ticker(lumps, render_data)?;
                 self.game_tic += 1;
 
                 // modify command for duplicated tics
@@ -169,6 +201,8 @@ impl GameContext {
             }
             self.net_update(system);
         }
+
+        Ok(())
     }
 
     fn net_update(&mut self, system: &System) {
@@ -195,17 +229,21 @@ impl GameContext {
 
         // build new ticcmds for console player
         let gameticdiv = self.game_tic / ticdup;
-        for i in 0..newtics {
+        for _ in 0..newtics {
             // I_StartTic
             // D_ProcessEvents
-            if self.game_tic - gameticdiv >= BACKUPTICKS / 2 - 1 {
+            if self.make_tic - gameticdiv >= BACKUPTICKS / 2 - 1 {
                 break; // can't hold any more
             }
 
-            // G_BuildTiccmd(&localcmds[maketic % BACKUPTICS]);
+            self.local_cmds[(self.make_tic % BACKUPTICKS) as usize] = self.level.build_tic_cmd();
             self.make_tic += 1;
         }
 
+        // TODO The local commands reach nettics through the packets
+        //  sent to ourselves in HSendPacket and GetPackets.
+        self.net_tics[0] = self.make_tic;
+
         /*
         TODO
         if singletics
@@ -217,6 +255,220 @@ impl GameContext {
 
         // TODO: GetPackets
     }
+
+    // G_Ticker
+    // Make ticcmd_ts for the players.
+    fn ticker(&mut self, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        let ticdup = 1;
+
+        // TODO do player reborns if needed
+
+        // do things to change the game state
+        while self.action != GameAction::Nothing {
+            match self.action {
+                GameAction::LoadLevel => self.do_load_level(lumps, render_data)?,
+                GameAction::NewGame => self.do_new_game(lumps, render_data)?,
+                GameAction::Completed => self.do_completed(),
+                GameAction::WorldDone => self.do_world_done(lumps, render_data)?,
+                // TODO Loading, saving and playing demos, the victory finale
+                //  and screenshots
+                _ => self.action = GameAction::Nothing,
+            }
+        }
+
+        // get commands, check consistancy,
+        // and build new consistancy check
+        let buf = (self.game_tic / ticdup % BACKUPTICKS) as usize;
+        let cmd = self.local_cmds[buf];
+        self.level.set_player_cmd(cmd);
+
+        // check for special buttons
+        // TODO BTS_SAVEGAME
+        if cmd.buttons & BT_SPECIAL != 0 && cmd.buttons & BT_SPECIALMASK == BTS_PAUSE {
+            // TODO S_PauseSound and S_ResumeSound
+            self.paused = !self.paused;
+        }
+
+        // do main actions
+        match self.state {
+            GameState::Level => {
+                // P_Ticker
+                if !self.paused {
+                    self.level.ticker();
+                }
+
+                match self.level.take_exit() {
+                    Some(LevelExit::Normal) => self.exit_level(),
+                    Some(LevelExit::Secret) => self.secret_exit_level(),
+                    None => {}
+                }
+                // TODO ST_Ticker, AM_Ticker and HU_Ticker
+            }
+            GameState::Intermission => {
+                // TODO WI_Ticker, the intermission goes straight on for now
+                self.world_done();
+            }
+            GameState::Finale => {
+                // TODO F_Ticker
+            }
+            GameState::DemoScreen => {
+                self.page.ticker();
+            }
+            GameState::ForceWipe => {}
+        }
+
+        Ok(())
+    }
+
+    // G_DoLoadLevel
+    fn do_load_level(&mut self, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        // TODO Set the sky map, force a wipe and reborn dead players
+        self.state = GameState::Level;
+
+        self.level.setup_level(lumps, render_data, &self.mode, self.episode, self.map, self.skill)?;
+        self.action = GameAction::Nothing;
+
+        // clear cmd building stuff
+        self.level.clear_input();
+        self.paused = false;
+
+        Ok(())
+    }
+
+    // G_DeferedInitNew
+    // Can be called by the startup code or the menu task,
+    // consoleplayer, displayplayer, playeringame[] should be set.
+    pub fn defered_init_new(&mut self, skill: Skill, episode: u32, map: u32) {
+        self.defered_skill = skill;
+        self.defered_episode = episode;
+        self.defered_map = map;
+        self.action = GameAction::NewGame;
+    }
+
+    // G_DoNewGame
+    fn do_new_game(&mut self, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        // TODO Reset demo playback, netgame and deathmatch settings
+        self.init_new(self.defered_skill, self.defered_episode, self.defered_map, lumps, render_data)?;
+        self.action = GameAction::Nothing;
+
+        Ok(())
+    }
+
+    // G_InitNew
+    fn init_new(&mut self, skill: Skill, mut episode: u32, mut map: u32, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        self.paused = false;
+
+        // This was quite messy with SPECIAL and commented parts.
+        // Supposedly hacks to make the latest edition work.
+        // It might not work properly.
+        if episode < 1 {
+            episode = 1;
+        }
+
+        match self.mode {
+            GameMode::Retail => episode = episode.min(4),
+            GameMode::Shareware => episode = episode.min(1),
+            _ => episode = episode.min(3),
+        }
+
+        if map < 1 {
+            map = 1;
+        }
+
+        if map > 9 && !matches!(self.mode, GameMode::Commercial) {
+            map = 9;
+        }
+
+        self.level.clear_random();
+
+        // TODO respawnmonsters and the fast monster states for nightmare,
+        //  and automapactive/viewactive
+
+        self.episode = episode;
+        self.map = map;
+        self.skill = skill;
+
+        self.do_load_level(lumps, render_data)
+    }
+
+    // G_ExitLevel
+    pub fn exit_level(&mut self) {
+        self.secret_exit = false;
+        self.action = GameAction::Completed;
+    }
+
+    // G_SecretExitLevel
+    // Here's for the german edition.
+    pub fn secret_exit_level(&mut self) {
+        // IF NO WOLF3D LEVELS, NO SECRET EXIT!
+        // TODO Check for MAP31 in commercial games
+        self.secret_exit = true;
+        self.action = GameAction::Completed;
+    }
+
+    // G_DoCompleted
+    fn do_completed(&mut self) {
+        self.action = GameAction::Nothing;
+
+        // TODO G_PlayerFinishLevel for each player and AM_Stop
+
+        if !matches!(self.mode, GameMode::Commercial) {
+            if self.map == 8 {
+                self.action = GameAction::Victory;
+                return;
+            }
+            // TODO Mark the players as having been in the secret level on map 9
+        }
+
+        // wminfo.next is 0 biased, unlike gamemap
+        self.next_map = if matches!(self.mode, GameMode::Commercial) {
+            if self.secret_exit {
+                match self.map {
+                    15 => 30,
+                    31 => 31,
+                    _ => self.map,
+                }
+            } else {
+                match self.map {
+                    31 | 32 => 15,
+                    _ => self.map,
+                }
+            }
+        } else if self.secret_exit {
+            8 // go to secret level
+        } else if self.map == 9 {
+            // returning from secret level
+            match self.episode {
+                1 => 3,
+                2 => 5,
+                3 => 6,
+                _ => 2,
+            }
+        } else {
+            self.map // go to next level
+        };
+
+        // TODO Fill in the rest of wminfo and WI_Start
+        self.state = GameState::Intermission;
+    }
+
+    // G_WorldDone
+    fn world_done(&mut self) {
+        self.action = GameAction::WorldDone;
+
+        // TODO Mark the secret as found, and F_StartFinale
+        //  for the commercial text screens
+    }
+
+    // G_DoWorldDone
+    fn do_world_done(&mut self, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        self.state = GameState::Level;
+        self.map = self.next_map + 1;
+        self.do_load_level(lumps, render_data)?;
+        self.action = GameAction::Nothing;
+
+        Ok(())
+    }
 }
 
 pub enum GameMode {
@@ -227,6 +479,16 @@ pub enum GameMode {
     Indetermined,
 }
 
+// skill_t
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Skill {
+    Baby,
+    Easy,
+    Medium,
+    Hard,
+    Nightmare,
+}
+
 pub enum GameState {
     ForceWipe,
     Level,
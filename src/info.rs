@@ -0,0 +1,5947 @@
+// Thing frame/state and sprite tables, see info.c.
+
+use crate::types::{DoomRealNum, FRAC_BITS, from_fixed_bits};
+use crate::sounds::*;
+use crate::play::mobj::{MF_COUNTITEM, MF_COUNTKILL, MF_DROPOFF, MF_FLOAT, MF_MISSILE, MF_NOBLOCKMAP, MF_NOBLOOD, MF_NOCLIP, MF_NOGRAVITY, MF_NOSECTOR, MF_NOTDMATCH, MF_PICKUP, MF_SHADOW, MF_SHOOTABLE, MF_SOLID, MF_SPAWNCEILING, MF_SPECIAL};
+
+pub const NUM_SPRITES: usize = 138;
+
+// The four letter names of the sprite lumps, indexed by spritenum_t.
+pub const SPRITE_NAMES: [&str; NUM_SPRITES] = [
+    "TROO", "SHTG", "PUNG", "PISG", "PISF", "SHTF", "SHT2", "CHGG", "CHGF", "MISG",
+    "MISF", "SAWG", "PLSG", "PLSF", "BFGG", "BFGF", "BLUD", "PUFF", "BAL1", "BAL2",
+    "PLSS", "PLSE", "MISL", "BFS1", "BFE1", "BFE2", "TFOG", "IFOG", "PLAY", "POSS",
+    "SPOS", "VILE", "FIRE", "FATB", "FBXP", "SKEL", "MANF", "FATT", "CPOS", "SARG",
+    "HEAD", "BAL7", "BOSS", "BOS2", "SKUL", "SPID", "BSPI", "APLS", "APBX", "CYBR",
+    "PAIN", "SSWV", "KEEN", "BBRN", "BOSF", "ARM1", "ARM2", "BAR1", "BEXP", "FCAN",
+    "BON1", "BON2", "BKEY", "RKEY", "YKEY", "BSKU", "RSKU", "YSKU", "STIM", "MEDI",
+    "SOUL", "PINV", "PSTR", "PINS", "MEGA", "SUIT", "PMAP", "PVIS", "CLIP", "AMMO",
+    "ROCK", "BROK", "CELL", "CELP", "SHEL", "SBOX", "BPAK", "BFUG", "MGUN", "CSAW",
+    "LAUN", "PLAS", "SHOT", "SGN2", "COLU", "SMT2", "GOR1", "POL2", "POL5", "POL4",
+    "POL3", "POL1", "POL6", "GOR2", "GOR3", "GOR4", "GOR5", "SMIT", "COL1", "COL2",
+    "COL3", "COL4", "CAND", "CBRA", "COL6", "TRE1", "TRE2", "ELEC", "CEYE", "FSKU",
+    "COL5", "TBLU", "TGRN", "TRED", "SMBT", "SMGT", "SMRT", "HDB1", "HDB2", "HDB3",
+    "HDB4", "HDB5", "HDB6", "POB1", "POB2", "BRS1", "TLMP", "TLP2",
+];
+
+pub type SpriteNum = usize;
+
+// spritenum_t
+pub const SPR_TROO: SpriteNum = 0;
+pub const SPR_SHTG: SpriteNum = 1;
+pub const SPR_PUNG: SpriteNum = 2;
+pub const SPR_PISG: SpriteNum = 3;
+pub const SPR_PISF: SpriteNum = 4;
+pub const SPR_SHTF: SpriteNum = 5;
+pub const SPR_SHT2: SpriteNum = 6;
+pub const SPR_CHGG: SpriteNum = 7;
+pub const SPR_CHGF: SpriteNum = 8;
+pub const SPR_MISG: SpriteNum = 9;
+pub const SPR_MISF: SpriteNum = 10;
+pub const SPR_SAWG: SpriteNum = 11;
+pub const SPR_PLSG: SpriteNum = 12;
+pub const SPR_PLSF: SpriteNum = 13;
+pub const SPR_BFGG: SpriteNum = 14;
+pub const SPR_BFGF: SpriteNum = 15;
+pub const SPR_BLUD: SpriteNum = 16;
+pub const SPR_PUFF: SpriteNum = 17;
+pub const SPR_BAL1: SpriteNum = 18;
+pub const SPR_BAL2: SpriteNum = 19;
+pub const SPR_PLSS: SpriteNum = 20;
+pub const SPR_PLSE: SpriteNum = 21;
+pub const SPR_MISL: SpriteNum = 22;
+pub const SPR_BFS1: SpriteNum = 23;
+pub const SPR_BFE1: SpriteNum = 24;
+pub const SPR_BFE2: SpriteNum = 25;
+pub const SPR_TFOG: SpriteNum = 26;
+pub const SPR_IFOG: SpriteNum = 27;
+pub const SPR_PLAY: SpriteNum = 28;
+pub const SPR_POSS: SpriteNum = 29;
+pub const SPR_SPOS: SpriteNum = 30;
+pub const SPR_VILE: SpriteNum = 31;
+pub const SPR_FIRE: SpriteNum = 32;
+pub const SPR_FATB: SpriteNum = 33;
+pub const SPR_FBXP: SpriteNum = 34;
+pub const SPR_SKEL: SpriteNum = 35;
+pub const SPR_MANF: SpriteNum = 36;
+pub const SPR_FATT: SpriteNum = 37;
+pub const SPR_CPOS: SpriteNum = 38;
+pub const SPR_SARG: SpriteNum = 39;
+pub const SPR_HEAD: SpriteNum = 40;
+pub const SPR_BAL7: SpriteNum = 41;
+pub const SPR_BOSS: SpriteNum = 42;
+pub const SPR_BOS2: SpriteNum = 43;
+pub const SPR_SKUL: SpriteNum = 44;
+pub const SPR_SPID: SpriteNum = 45;
+pub const SPR_BSPI: SpriteNum = 46;
+pub const SPR_APLS: SpriteNum = 47;
+pub const SPR_APBX: SpriteNum = 48;
+pub const SPR_CYBR: SpriteNum = 49;
+pub const SPR_PAIN: SpriteNum = 50;
+pub const SPR_SSWV: SpriteNum = 51;
+pub const SPR_KEEN: SpriteNum = 52;
+pub const SPR_BBRN: SpriteNum = 53;
+pub const SPR_BOSF: SpriteNum = 54;
+pub const SPR_ARM1: SpriteNum = 55;
+pub const SPR_ARM2: SpriteNum = 56;
+pub const SPR_BAR1: SpriteNum = 57;
+pub const SPR_BEXP: SpriteNum = 58;
+pub const SPR_FCAN: SpriteNum = 59;
+pub const SPR_BON1: SpriteNum = 60;
+pub const SPR_BON2: SpriteNum = 61;
+pub const SPR_BKEY: SpriteNum = 62;
+pub const SPR_RKEY: SpriteNum = 63;
+pub const SPR_YKEY: SpriteNum = 64;
+pub const SPR_BSKU: SpriteNum = 65;
+pub const SPR_RSKU: SpriteNum = 66;
+pub const SPR_YSKU: SpriteNum = 67;
+pub const SPR_STIM: SpriteNum = 68;
+pub const SPR_MEDI: SpriteNum = 69;
+pub const SPR_SOUL: SpriteNum = 70;
+pub const SPR_PINV: SpriteNum = 71;
+pub const SPR_PSTR: SpriteNum = 72;
+pub const SPR_PINS: SpriteNum = 73;
+pub const SPR_MEGA: SpriteNum = 74;
+pub const SPR_SUIT: SpriteNum = 75;
+pub const SPR_PMAP: SpriteNum = 76;
+pub const SPR_PVIS: SpriteNum = 77;
+pub const SPR_CLIP: SpriteNum = 78;
+pub const SPR_AMMO: SpriteNum = 79;
+pub const SPR_ROCK: SpriteNum = 80;
+pub const SPR_BROK: SpriteNum = 81;
+pub const SPR_CELL: SpriteNum = 82;
+pub const SPR_CELP: SpriteNum = 83;
+pub const SPR_SHEL: SpriteNum = 84;
+pub const SPR_SBOX: SpriteNum = 85;
+pub const SPR_BPAK: SpriteNum = 86;
+pub const SPR_BFUG: SpriteNum = 87;
+pub const SPR_MGUN: SpriteNum = 88;
+pub const SPR_CSAW: SpriteNum = 89;
+pub const SPR_LAUN: SpriteNum = 90;
+pub const SPR_PLAS: SpriteNum = 91;
+pub const SPR_SHOT: SpriteNum = 92;
+pub const SPR_SGN2: SpriteNum = 93;
+pub const SPR_COLU: SpriteNum = 94;
+pub const SPR_SMT2: SpriteNum = 95;
+pub const SPR_GOR1: SpriteNum = 96;
+pub const SPR_POL2: SpriteNum = 97;
+pub const SPR_POL5: SpriteNum = 98;
+pub const SPR_POL4: SpriteNum = 99;
+pub const SPR_POL3: SpriteNum = 100;
+pub const SPR_POL1: SpriteNum = 101;
+pub const SPR_POL6: SpriteNum = 102;
+pub const SPR_GOR2: SpriteNum = 103;
+pub const SPR_GOR3: SpriteNum = 104;
+pub const SPR_GOR4: SpriteNum = 105;
+pub const SPR_GOR5: SpriteNum = 106;
+pub const SPR_SMIT: SpriteNum = 107;
+pub const SPR_COL1: SpriteNum = 108;
+pub const SPR_COL2: SpriteNum = 109;
+pub const SPR_COL3: SpriteNum = 110;
+pub const SPR_COL4: SpriteNum = 111;
+pub const SPR_CAND: SpriteNum = 112;
+pub const SPR_CBRA: SpriteNum = 113;
+pub const SPR_COL6: SpriteNum = 114;
+pub const SPR_TRE1: SpriteNum = 115;
+pub const SPR_TRE2: SpriteNum = 116;
+pub const SPR_ELEC: SpriteNum = 117;
+pub const SPR_CEYE: SpriteNum = 118;
+pub const SPR_FSKU: SpriteNum = 119;
+pub const SPR_COL5: SpriteNum = 120;
+pub const SPR_TBLU: SpriteNum = 121;
+pub const SPR_TGRN: SpriteNum = 122;
+pub const SPR_TRED: SpriteNum = 123;
+pub const SPR_SMBT: SpriteNum = 124;
+pub const SPR_SMGT: SpriteNum = 125;
+pub const SPR_SMRT: SpriteNum = 126;
+pub const SPR_HDB1: SpriteNum = 127;
+pub const SPR_HDB2: SpriteNum = 128;
+pub const SPR_HDB3: SpriteNum = 129;
+pub const SPR_HDB4: SpriteNum = 130;
+pub const SPR_HDB5: SpriteNum = 131;
+pub const SPR_HDB6: SpriteNum = 132;
+pub const SPR_POB1: SpriteNum = 133;
+pub const SPR_POB2: SpriteNum = 134;
+pub const SPR_BRS1: SpriteNum = 135;
+pub const SPR_TLMP: SpriteNum = 136;
+pub const SPR_TLP2: SpriteNum = 137;
+
+/// Codepointers run on entering a state, the A_ functions of
+/// p_enemy.c and p_pspr.c.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Action {
+    Light0,
+    WeaponReady,
+    Lower,
+    Raise,
+    Punch,
+    ReFire,
+    FirePistol,
+    Light1,
+    FireShotgun,
+    Light2,
+    FireShotgun2,
+    CheckReload,
+    OpenShotgun2,
+    LoadShotgun2,
+    CloseShotgun2,
+    FireCGun,
+    GunFlash,
+    FireMissile,
+    Saw,
+    FirePlasma,
+    BfgSound,
+    FireBfg,
+    BfgSpray,
+    Explode,
+    Pain,
+    PlayerScream,
+    Fall,
+    XScream,
+    Look,
+    Chase,
+    FaceTarget,
+    PosAttack,
+    Scream,
+    SPosAttack,
+    VileChase,
+    VileStart,
+    VileTarget,
+    VileAttack,
+    StartFire,
+    Fire,
+    FireCrackle,
+    Tracer,
+    SkelWhoosh,
+    SkelFist,
+    SkelMissile,
+    FatRaise,
+    FatAttack1,
+    FatAttack2,
+    FatAttack3,
+    BossDeath,
+    CPosAttack,
+    CPosRefire,
+    TroopAttack,
+    SargAttack,
+    HeadAttack,
+    BruisAttack,
+    SkullAttack,
+    Metal,
+    SpidRefire,
+    BabyMetal,
+    BspiAttack,
+    Hoof,
+    CyberAttack,
+    PainAttack,
+    PainDie,
+    KeenDie,
+    BrainPain,
+    BrainScream,
+    BrainDie,
+    BrainAwake,
+    BrainSpit,
+    SpawnSound,
+    SpawnFly,
+    BrainExplode,
+}
+
+pub type StateNum = usize;
+
+// statenum_t
+pub const S_NULL: StateNum = 0;
+pub const S_LIGHTDONE: StateNum = 1;
+pub const S_PUNCH: StateNum = 2;
+pub const S_PUNCHDOWN: StateNum = 3;
+pub const S_PUNCHUP: StateNum = 4;
+pub const S_PUNCH1: StateNum = 5;
+pub const S_PUNCH2: StateNum = 6;
+pub const S_PUNCH3: StateNum = 7;
+pub const S_PUNCH4: StateNum = 8;
+pub const S_PUNCH5: StateNum = 9;
+pub const S_PISTOL: StateNum = 10;
+pub const S_PISTOLDOWN: StateNum = 11;
+pub const S_PISTOLUP: StateNum = 12;
+pub const S_PISTOL1: StateNum = 13;
+pub const S_PISTOL2: StateNum = 14;
+pub const S_PISTOL3: StateNum = 15;
+pub const S_PISTOL4: StateNum = 16;
+pub const S_PISTOLFLASH: StateNum = 17;
+pub const S_SGUN: StateNum = 18;
+pub const S_SGUNDOWN: StateNum = 19;
+pub const S_SGUNUP: StateNum = 20;
+pub const S_SGUN1: StateNum = 21;
+pub const S_SGUN2: StateNum = 22;
+pub const S_SGUN3: StateNum = 23;
+pub const S_SGUN4: StateNum = 24;
+pub const S_SGUN5: StateNum = 25;
+pub const S_SGUN6: StateNum = 26;
+pub const S_SGUN7: StateNum = 27;
+pub const S_SGUN8: StateNum = 28;
+pub const S_SGUN9: StateNum = 29;
+pub const S_SGUNFLASH1: StateNum = 30;
+pub const S_SGUNFLASH2: StateNum = 31;
+pub const S_DSGUN: StateNum = 32;
+pub const S_DSGUNDOWN: StateNum = 33;
+pub const S_DSGUNUP: StateNum = 34;
+pub const S_DSGUN1: StateNum = 35;
+pub const S_DSGUN2: StateNum = 36;
+pub const S_DSGUN3: StateNum = 37;
+pub const S_DSGUN4: StateNum = 38;
+pub const S_DSGUN5: StateNum = 39;
+pub const S_DSGUN6: StateNum = 40;
+pub const S_DSGUN7: StateNum = 41;
+pub const S_DSGUN8: StateNum = 42;
+pub const S_DSGUN9: StateNum = 43;
+pub const S_DSGUN10: StateNum = 44;
+pub const S_DSNR1: StateNum = 45;
+pub const S_DSNR2: StateNum = 46;
+pub const S_DSGUNFLASH1: StateNum = 47;
+pub const S_DSGUNFLASH2: StateNum = 48;
+pub const S_CHAIN: StateNum = 49;
+pub const S_CHAINDOWN: StateNum = 50;
+pub const S_CHAINUP: StateNum = 51;
+pub const S_CHAIN1: StateNum = 52;
+pub const S_CHAIN2: StateNum = 53;
+pub const S_CHAIN3: StateNum = 54;
+pub const S_CHAINFLASH1: StateNum = 55;
+pub const S_CHAINFLASH2: StateNum = 56;
+pub const S_MISSILE: StateNum = 57;
+pub const S_MISSILEDOWN: StateNum = 58;
+pub const S_MISSILEUP: StateNum = 59;
+pub const S_MISSILE1: StateNum = 60;
+pub const S_MISSILE2: StateNum = 61;
+pub const S_MISSILE3: StateNum = 62;
+pub const S_MISSILEFLASH1: StateNum = 63;
+pub const S_MISSILEFLASH2: StateNum = 64;
+pub const S_MISSILEFLASH3: StateNum = 65;
+pub const S_MISSILEFLASH4: StateNum = 66;
+pub const S_SAW: StateNum = 67;
+pub const S_SAWB: StateNum = 68;
+pub const S_SAWDOWN: StateNum = 69;
+pub const S_SAWUP: StateNum = 70;
+pub const S_SAW1: StateNum = 71;
+pub const S_SAW2: StateNum = 72;
+pub const S_SAW3: StateNum = 73;
+pub const S_PLASMA: StateNum = 74;
+pub const S_PLASMADOWN: StateNum = 75;
+pub const S_PLASMAUP: StateNum = 76;
+pub const S_PLASMA1: StateNum = 77;
+pub const S_PLASMA2: StateNum = 78;
+pub const S_PLASMAFLASH1: StateNum = 79;
+pub const S_PLASMAFLASH2: StateNum = 80;
+pub const S_BFG: StateNum = 81;
+pub const S_BFGDOWN: StateNum = 82;
+pub const S_BFGUP: StateNum = 83;
+pub const S_BFG1: StateNum = 84;
+pub const S_BFG2: StateNum = 85;
+pub const S_BFG3: StateNum = 86;
+pub const S_BFG4: StateNum = 87;
+pub const S_BFGFLASH1: StateNum = 88;
+pub const S_BFGFLASH2: StateNum = 89;
+pub const S_BLOOD1: StateNum = 90;
+pub const S_BLOOD2: StateNum = 91;
+pub const S_BLOOD3: StateNum = 92;
+pub const S_PUFF1: StateNum = 93;
+pub const S_PUFF2: StateNum = 94;
+pub const S_PUFF3: StateNum = 95;
+pub const S_PUFF4: StateNum = 96;
+pub const S_TBALL1: StateNum = 97;
+pub const S_TBALL2: StateNum = 98;
+pub const S_TBALLX1: StateNum = 99;
+pub const S_TBALLX2: StateNum = 100;
+pub const S_TBALLX3: StateNum = 101;
+pub const S_RBALL1: StateNum = 102;
+pub const S_RBALL2: StateNum = 103;
+pub const S_RBALLX1: StateNum = 104;
+pub const S_RBALLX2: StateNum = 105;
+pub const S_RBALLX3: StateNum = 106;
+pub const S_PLASBALL: StateNum = 107;
+pub const S_PLASBALL2: StateNum = 108;
+pub const S_PLASEXP: StateNum = 109;
+pub const S_PLASEXP2: StateNum = 110;
+pub const S_PLASEXP3: StateNum = 111;
+pub const S_PLASEXP4: StateNum = 112;
+pub const S_PLASEXP5: StateNum = 113;
+pub const S_ROCKET: StateNum = 114;
+pub const S_BFGSHOT: StateNum = 115;
+pub const S_BFGSHOT2: StateNum = 116;
+pub const S_BFGLAND: StateNum = 117;
+pub const S_BFGLAND2: StateNum = 118;
+pub const S_BFGLAND3: StateNum = 119;
+pub const S_BFGLAND4: StateNum = 120;
+pub const S_BFGLAND5: StateNum = 121;
+pub const S_BFGLAND6: StateNum = 122;
+pub const S_BFGEXP: StateNum = 123;
+pub const S_BFGEXP2: StateNum = 124;
+pub const S_BFGEXP3: StateNum = 125;
+pub const S_BFGEXP4: StateNum = 126;
+pub const S_EXPLODE1: StateNum = 127;
+pub const S_EXPLODE2: StateNum = 128;
+pub const S_EXPLODE3: StateNum = 129;
+pub const S_TFOG: StateNum = 130;
+pub const S_TFOG01: StateNum = 131;
+pub const S_TFOG02: StateNum = 132;
+pub const S_TFOG2: StateNum = 133;
+pub const S_TFOG3: StateNum = 134;
+pub const S_TFOG4: StateNum = 135;
+pub const S_TFOG5: StateNum = 136;
+pub const S_TFOG6: StateNum = 137;
+pub const S_TFOG7: StateNum = 138;
+pub const S_TFOG8: StateNum = 139;
+pub const S_TFOG9: StateNum = 140;
+pub const S_TFOG10: StateNum = 141;
+pub const S_IFOG: StateNum = 142;
+pub const S_IFOG01: StateNum = 143;
+pub const S_IFOG02: StateNum = 144;
+pub const S_IFOG2: StateNum = 145;
+pub const S_IFOG3: StateNum = 146;
+pub const S_IFOG4: StateNum = 147;
+pub const S_IFOG5: StateNum = 148;
+pub const S_PLAY: StateNum = 149;
+pub const S_PLAY_RUN1: StateNum = 150;
+pub const S_PLAY_RUN2: StateNum = 151;
+pub const S_PLAY_RUN3: StateNum = 152;
+pub const S_PLAY_RUN4: StateNum = 153;
+pub const S_PLAY_ATK1: StateNum = 154;
+pub const S_PLAY_ATK2: StateNum = 155;
+pub const S_PLAY_PAIN: StateNum = 156;
+pub const S_PLAY_PAIN2: StateNum = 157;
+pub const S_PLAY_DIE1: StateNum = 158;
+pub const S_PLAY_DIE2: StateNum = 159;
+pub const S_PLAY_DIE3: StateNum = 160;
+pub const S_PLAY_DIE4: StateNum = 161;
+pub const S_PLAY_DIE5: StateNum = 162;
+pub const S_PLAY_DIE6: StateNum = 163;
+pub const S_PLAY_DIE7: StateNum = 164;
+pub const S_PLAY_XDIE1: StateNum = 165;
+pub const S_PLAY_XDIE2: StateNum = 166;
+pub const S_PLAY_XDIE3: StateNum = 167;
+pub const S_PLAY_XDIE4: StateNum = 168;
+pub const S_PLAY_XDIE5: StateNum = 169;
+pub const S_PLAY_XDIE6: StateNum = 170;
+pub const S_PLAY_XDIE7: StateNum = 171;
+pub const S_PLAY_XDIE8: StateNum = 172;
+pub const S_PLAY_XDIE9: StateNum = 173;
+pub const S_POSS_STND: StateNum = 174;
+pub const S_POSS_STND2: StateNum = 175;
+pub const S_POSS_RUN1: StateNum = 176;
+pub const S_POSS_RUN2: StateNum = 177;
+pub const S_POSS_RUN3: StateNum = 178;
+pub const S_POSS_RUN4: StateNum = 179;
+pub const S_POSS_RUN5: StateNum = 180;
+pub const S_POSS_RUN6: StateNum = 181;
+pub const S_POSS_RUN7: StateNum = 182;
+pub const S_POSS_RUN8: StateNum = 183;
+pub const S_POSS_ATK1: StateNum = 184;
+pub const S_POSS_ATK2: StateNum = 185;
+pub const S_POSS_ATK3: StateNum = 186;
+pub const S_POSS_PAIN: StateNum = 187;
+pub const S_POSS_PAIN2: StateNum = 188;
+pub const S_POSS_DIE1: StateNum = 189;
+pub const S_POSS_DIE2: StateNum = 190;
+pub const S_POSS_DIE3: StateNum = 191;
+pub const S_POSS_DIE4: StateNum = 192;
+pub const S_POSS_DIE5: StateNum = 193;
+pub const S_POSS_XDIE1: StateNum = 194;
+pub const S_POSS_XDIE2: StateNum = 195;
+pub const S_POSS_XDIE3: StateNum = 196;
+pub const S_POSS_XDIE4: StateNum = 197;
+pub const S_POSS_XDIE5: StateNum = 198;
+pub const S_POSS_XDIE6: StateNum = 199;
+pub const S_POSS_XDIE7: StateNum = 200;
+pub const S_POSS_XDIE8: StateNum = 201;
+pub const S_POSS_XDIE9: StateNum = 202;
+pub const S_POSS_RAISE1: StateNum = 203;
+pub const S_POSS_RAISE2: StateNum = 204;
+pub const S_POSS_RAISE3: StateNum = 205;
+pub const S_POSS_RAISE4: StateNum = 206;
+pub const S_SPOS_STND: StateNum = 207;
+pub const S_SPOS_STND2: StateNum = 208;
+pub const S_SPOS_RUN1: StateNum = 209;
+pub const S_SPOS_RUN2: StateNum = 210;
+pub const S_SPOS_RUN3: StateNum = 211;
+pub const S_SPOS_RUN4: StateNum = 212;
+pub const S_SPOS_RUN5: StateNum = 213;
+pub const S_SPOS_RUN6: StateNum = 214;
+pub const S_SPOS_RUN7: StateNum = 215;
+pub const S_SPOS_RUN8: StateNum = 216;
+pub const S_SPOS_ATK1: StateNum = 217;
+pub const S_SPOS_ATK2: StateNum = 218;
+pub const S_SPOS_ATK3: StateNum = 219;
+pub const S_SPOS_PAIN: StateNum = 220;
+pub const S_SPOS_PAIN2: StateNum = 221;
+pub const S_SPOS_DIE1: StateNum = 222;
+pub const S_SPOS_DIE2: StateNum = 223;
+pub const S_SPOS_DIE3: StateNum = 224;
+pub const S_SPOS_DIE4: StateNum = 225;
+pub const S_SPOS_DIE5: StateNum = 226;
+pub const S_SPOS_XDIE1: StateNum = 227;
+pub const S_SPOS_XDIE2: StateNum = 228;
+pub const S_SPOS_XDIE3: StateNum = 229;
+pub const S_SPOS_XDIE4: StateNum = 230;
+pub const S_SPOS_XDIE5: StateNum = 231;
+pub const S_SPOS_XDIE6: StateNum = 232;
+pub const S_SPOS_XDIE7: StateNum = 233;
+pub const S_SPOS_XDIE8: StateNum = 234;
+pub const S_SPOS_XDIE9: StateNum = 235;
+pub const S_SPOS_RAISE1: StateNum = 236;
+pub const S_SPOS_RAISE2: StateNum = 237;
+pub const S_SPOS_RAISE3: StateNum = 238;
+pub const S_SPOS_RAISE4: StateNum = 239;
+pub const S_SPOS_RAISE5: StateNum = 240;
+pub const S_VILE_STND: StateNum = 241;
+pub const S_VILE_STND2: StateNum = 242;
+pub const S_VILE_RUN1: StateNum = 243;
+pub const S_VILE_RUN2: StateNum = 244;
+pub const S_VILE_RUN3: StateNum = 245;
+pub const S_VILE_RUN4: StateNum = 246;
+pub const S_VILE_RUN5: StateNum = 247;
+pub const S_VILE_RUN6: StateNum = 248;
+pub const S_VILE_RUN7: StateNum = 249;
+pub const S_VILE_RUN8: StateNum = 250;
+pub const S_VILE_RUN9: StateNum = 251;
+pub const S_VILE_RUN10: StateNum = 252;
+pub const S_VILE_RUN11: StateNum = 253;
+pub const S_VILE_RUN12: StateNum = 254;
+pub const S_VILE_ATK1: StateNum = 255;
+pub const S_VILE_ATK2: StateNum = 256;
+pub const S_VILE_ATK3: StateNum = 257;
+pub const S_VILE_ATK4: StateNum = 258;
+pub const S_VILE_ATK5: StateNum = 259;
+pub const S_VILE_ATK6: StateNum = 260;
+pub const S_VILE_ATK7: StateNum = 261;
+pub const S_VILE_ATK8: StateNum = 262;
+pub const S_VILE_ATK9: StateNum = 263;
+pub const S_VILE_ATK10: StateNum = 264;
+pub const S_VILE_ATK11: StateNum = 265;
+pub const S_VILE_HEAL1: StateNum = 266;
+pub const S_VILE_HEAL2: StateNum = 267;
+pub const S_VILE_HEAL3: StateNum = 268;
+pub const S_VILE_PAIN: StateNum = 269;
+pub const S_VILE_PAIN2: StateNum = 270;
+pub const S_VILE_DIE1: StateNum = 271;
+pub const S_VILE_DIE2: StateNum = 272;
+pub const S_VILE_DIE3: StateNum = 273;
+pub const S_VILE_DIE4: StateNum = 274;
+pub const S_VILE_DIE5: StateNum = 275;
+pub const S_VILE_DIE6: StateNum = 276;
+pub const S_VILE_DIE7: StateNum = 277;
+pub const S_VILE_DIE8: StateNum = 278;
+pub const S_VILE_DIE9: StateNum = 279;
+pub const S_VILE_DIE10: StateNum = 280;
+pub const S_FIRE1: StateNum = 281;
+pub const S_FIRE2: StateNum = 282;
+pub const S_FIRE3: StateNum = 283;
+pub const S_FIRE4: StateNum = 284;
+pub const S_FIRE5: StateNum = 285;
+pub const S_FIRE6: StateNum = 286;
+pub const S_FIRE7: StateNum = 287;
+pub const S_FIRE8: StateNum = 288;
+pub const S_FIRE9: StateNum = 289;
+pub const S_FIRE10: StateNum = 290;
+pub const S_FIRE11: StateNum = 291;
+pub const S_FIRE12: StateNum = 292;
+pub const S_FIRE13: StateNum = 293;
+pub const S_FIRE14: StateNum = 294;
+pub const S_FIRE15: StateNum = 295;
+pub const S_FIRE16: StateNum = 296;
+pub const S_FIRE17: StateNum = 297;
+pub const S_FIRE18: StateNum = 298;
+pub const S_FIRE19: StateNum = 299;
+pub const S_FIRE20: StateNum = 300;
+pub const S_FIRE21: StateNum = 301;
+pub const S_FIRE22: StateNum = 302;
+pub const S_FIRE23: StateNum = 303;
+pub const S_FIRE24: StateNum = 304;
+pub const S_FIRE25: StateNum = 305;
+pub const S_FIRE26: StateNum = 306;
+pub const S_FIRE27: StateNum = 307;
+pub const S_FIRE28: StateNum = 308;
+pub const S_FIRE29: StateNum = 309;
+pub const S_FIRE30: StateNum = 310;
+pub const S_SMOKE1: StateNum = 311;
+pub const S_SMOKE2: StateNum = 312;
+pub const S_SMOKE3: StateNum = 313;
+pub const S_SMOKE4: StateNum = 314;
+pub const S_SMOKE5: StateNum = 315;
+pub const S_TRACER: StateNum = 316;
+pub const S_TRACER2: StateNum = 317;
+pub const S_TRACEEXP1: StateNum = 318;
+pub const S_TRACEEXP2: StateNum = 319;
+pub const S_TRACEEXP3: StateNum = 320;
+pub const S_SKEL_STND: StateNum = 321;
+pub const S_SKEL_STND2: StateNum = 322;
+pub const S_SKEL_RUN1: StateNum = 323;
+pub const S_SKEL_RUN2: StateNum = 324;
+pub const S_SKEL_RUN3: StateNum = 325;
+pub const S_SKEL_RUN4: StateNum = 326;
+pub const S_SKEL_RUN5: StateNum = 327;
+pub const S_SKEL_RUN6: StateNum = 328;
+pub const S_SKEL_RUN7: StateNum = 329;
+pub const S_SKEL_RUN8: StateNum = 330;
+pub const S_SKEL_RUN9: StateNum = 331;
+pub const S_SKEL_RUN10: StateNum = 332;
+pub const S_SKEL_RUN11: StateNum = 333;
+pub const S_SKEL_RUN12: StateNum = 334;
+pub const S_SKEL_FIST1: StateNum = 335;
+pub const S_SKEL_FIST2: StateNum = 336;
+pub const S_SKEL_FIST3: StateNum = 337;
+pub const S_SKEL_FIST4: StateNum = 338;
+pub const S_SKEL_MISS1: StateNum = 339;
+pub const S_SKEL_MISS2: StateNum = 340;
+pub const S_SKEL_MISS3: StateNum = 341;
+pub const S_SKEL_MISS4: StateNum = 342;
+pub const S_SKEL_PAIN: StateNum = 343;
+pub const S_SKEL_PAIN2: StateNum = 344;
+pub const S_SKEL_DIE1: StateNum = 345;
+pub const S_SKEL_DIE2: StateNum = 346;
+pub const S_SKEL_DIE3: StateNum = 347;
+pub const S_SKEL_DIE4: StateNum = 348;
+pub const S_SKEL_DIE5: StateNum = 349;
+pub const S_SKEL_DIE6: StateNum = 350;
+pub const S_SKEL_RAISE1: StateNum = 351;
+pub const S_SKEL_RAISE2: StateNum = 352;
+pub const S_SKEL_RAISE3: StateNum = 353;
+pub const S_SKEL_RAISE4: StateNum = 354;
+pub const S_SKEL_RAISE5: StateNum = 355;
+pub const S_SKEL_RAISE6: StateNum = 356;
+pub const S_FATSHOT1: StateNum = 357;
+pub const S_FATSHOT2: StateNum = 358;
+pub const S_FATSHOTX1: StateNum = 359;
+pub const S_FATSHOTX2: StateNum = 360;
+pub const S_FATSHOTX3: StateNum = 361;
+pub const S_FATT_STND: StateNum = 362;
+pub const S_FATT_STND2: StateNum = 363;
+pub const S_FATT_RUN1: StateNum = 364;
+pub const S_FATT_RUN2: StateNum = 365;
+pub const S_FATT_RUN3: StateNum = 366;
+pub const S_FATT_RUN4: StateNum = 367;
+pub const S_FATT_RUN5: StateNum = 368;
+pub const S_FATT_RUN6: StateNum = 369;
+pub const S_FATT_RUN7: StateNum = 370;
+pub const S_FATT_RUN8: StateNum = 371;
+pub const S_FATT_RUN9: StateNum = 372;
+pub const S_FATT_RUN10: StateNum = 373;
+pub const S_FATT_RUN11: StateNum = 374;
+pub const S_FATT_RUN12: StateNum = 375;
+pub const S_FATT_ATK1: StateNum = 376;
+pub const S_FATT_ATK2: StateNum = 377;
+pub const S_FATT_ATK3: StateNum = 378;
+pub const S_FATT_ATK4: StateNum = 379;
+pub const S_FATT_ATK5: StateNum = 380;
+pub const S_FATT_ATK6: StateNum = 381;
+pub const S_FATT_ATK7: StateNum = 382;
+pub const S_FATT_ATK8: StateNum = 383;
+pub const S_FATT_ATK9: StateNum = 384;
+pub const S_FATT_ATK10: StateNum = 385;
+pub const S_FATT_PAIN: StateNum = 386;
+pub const S_FATT_PAIN2: StateNum = 387;
+pub const S_FATT_DIE1: StateNum = 388;
+pub const S_FATT_DIE2: StateNum = 389;
+pub const S_FATT_DIE3: StateNum = 390;
+pub const S_FATT_DIE4: StateNum = 391;
+pub const S_FATT_DIE5: StateNum = 392;
+pub const S_FATT_DIE6: StateNum = 393;
+pub const S_FATT_DIE7: StateNum = 394;
+pub const S_FATT_DIE8: StateNum = 395;
+pub const S_FATT_DIE9: StateNum = 396;
+pub const S_FATT_DIE10: StateNum = 397;
+pub const S_FATT_RAISE1: StateNum = 398;
+pub const S_FATT_RAISE2: StateNum = 399;
+pub const S_FATT_RAISE3: StateNum = 400;
+pub const S_FATT_RAISE4: StateNum = 401;
+pub const S_FATT_RAISE5: StateNum = 402;
+pub const S_FATT_RAISE6: StateNum = 403;
+pub const S_FATT_RAISE7: StateNum = 404;
+pub const S_FATT_RAISE8: StateNum = 405;
+pub const S_CPOS_STND: StateNum = 406;
+pub const S_CPOS_STND2: StateNum = 407;
+pub const S_CPOS_RUN1: StateNum = 408;
+pub const S_CPOS_RUN2: StateNum = 409;
+pub const S_CPOS_RUN3: StateNum = 410;
+pub const S_CPOS_RUN4: StateNum = 411;
+pub const S_CPOS_RUN5: StateNum = 412;
+pub const S_CPOS_RUN6: StateNum = 413;
+pub const S_CPOS_RUN7: StateNum = 414;
+pub const S_CPOS_RUN8: StateNum = 415;
+pub const S_CPOS_ATK1: StateNum = 416;
+pub const S_CPOS_ATK2: StateNum = 417;
+pub const S_CPOS_ATK3: StateNum = 418;
+pub const S_CPOS_ATK4: StateNum = 419;
+pub const S_CPOS_PAIN: StateNum = 420;
+pub const S_CPOS_PAIN2: StateNum = 421;
+pub const S_CPOS_DIE1: StateNum = 422;
+pub const S_CPOS_DIE2: StateNum = 423;
+pub const S_CPOS_DIE3: StateNum = 424;
+pub const S_CPOS_DIE4: StateNum = 425;
+pub const S_CPOS_DIE5: StateNum = 426;
+pub const S_CPOS_DIE6: StateNum = 427;
+pub const S_CPOS_DIE7: StateNum = 428;
+pub const S_CPOS_XDIE1: StateNum = 429;
+pub const S_CPOS_XDIE2: StateNum = 430;
+pub const S_CPOS_XDIE3: StateNum = 431;
+pub const S_CPOS_XDIE4: StateNum = 432;
+pub const S_CPOS_XDIE5: StateNum = 433;
+pub const S_CPOS_XDIE6: StateNum = 434;
+pub const S_CPOS_RAISE1: StateNum = 435;
+pub const S_CPOS_RAISE2: StateNum = 436;
+pub const S_CPOS_RAISE3: StateNum = 437;
+pub const S_CPOS_RAISE4: StateNum = 438;
+pub const S_CPOS_RAISE5: StateNum = 439;
+pub const S_CPOS_RAISE6: StateNum = 440;
+pub const S_CPOS_RAISE7: StateNum = 441;
+pub const S_TROO_STND: StateNum = 442;
+pub const S_TROO_STND2: StateNum = 443;
+pub const S_TROO_RUN1: StateNum = 444;
+pub const S_TROO_RUN2: StateNum = 445;
+pub const S_TROO_RUN3: StateNum = 446;
+pub const S_TROO_RUN4: StateNum = 447;
+pub const S_TROO_RUN5: StateNum = 448;
+pub const S_TROO_RUN6: StateNum = 449;
+pub const S_TROO_RUN7: StateNum = 450;
+pub const S_TROO_RUN8: StateNum = 451;
+pub const S_TROO_ATK1: StateNum = 452;
+pub const S_TROO_ATK2: StateNum = 453;
+pub const S_TROO_ATK3: StateNum = 454;
+pub const S_TROO_PAIN: StateNum = 455;
+pub const S_TROO_PAIN2: StateNum = 456;
+pub const S_TROO_DIE1: StateNum = 457;
+pub const S_TROO_DIE2: StateNum = 458;
+pub const S_TROO_DIE3: StateNum = 459;
+pub const S_TROO_DIE4: StateNum = 460;
+pub const S_TROO_DIE5: StateNum = 461;
+pub const S_TROO_XDIE1: StateNum = 462;
+pub const S_TROO_XDIE2: StateNum = 463;
+pub const S_TROO_XDIE3: StateNum = 464;
+pub const S_TROO_XDIE4: StateNum = 465;
+pub const S_TROO_XDIE5: StateNum = 466;
+pub const S_TROO_XDIE6: StateNum = 467;
+pub const S_TROO_XDIE7: StateNum = 468;
+pub const S_TROO_XDIE8: StateNum = 469;
+pub const S_TROO_RAISE1: StateNum = 470;
+pub const S_TROO_RAISE2: StateNum = 471;
+pub const S_TROO_RAISE3: StateNum = 472;
+pub const S_TROO_RAISE4: StateNum = 473;
+pub const S_TROO_RAISE5: StateNum = 474;
+pub const S_SARG_STND: StateNum = 475;
+pub const S_SARG_STND2: StateNum = 476;
+pub const S_SARG_RUN1: StateNum = 477;
+pub const S_SARG_RUN2: StateNum = 478;
+pub const S_SARG_RUN3: StateNum = 479;
+pub const S_SARG_RUN4: StateNum = 480;
+pub const S_SARG_RUN5: StateNum = 481;
+pub const S_SARG_RUN6: StateNum = 482;
+pub const S_SARG_RUN7: StateNum = 483;
+pub const S_SARG_RUN8: StateNum = 484;
+pub const S_SARG_ATK1: StateNum = 485;
+pub const S_SARG_ATK2: StateNum = 486;
+pub const S_SARG_ATK3: StateNum = 487;
+pub const S_SARG_PAIN: StateNum = 488;
+pub const S_SARG_PAIN2: StateNum = 489;
+pub const S_SARG_DIE1: StateNum = 490;
+pub const S_SARG_DIE2: StateNum = 491;
+pub const S_SARG_DIE3: StateNum = 492;
+pub const S_SARG_DIE4: StateNum = 493;
+pub const S_SARG_DIE5: StateNum = 494;
+pub const S_SARG_DIE6: StateNum = 495;
+pub const S_SARG_RAISE1: StateNum = 496;
+pub const S_SARG_RAISE2: StateNum = 497;
+pub const S_SARG_RAISE3: StateNum = 498;
+pub const S_SARG_RAISE4: StateNum = 499;
+pub const S_SARG_RAISE5: StateNum = 500;
+pub const S_SARG_RAISE6: StateNum = 501;
+pub const S_HEAD_STND: StateNum = 502;
+pub const S_HEAD_RUN1: StateNum = 503;
+pub const S_HEAD_ATK1: StateNum = 504;
+pub const S_HEAD_ATK2: StateNum = 505;
+pub const S_HEAD_ATK3: StateNum = 506;
+pub const S_HEAD_PAIN: StateNum = 507;
+pub const S_HEAD_PAIN2: StateNum = 508;
+pub const S_HEAD_PAIN3: StateNum = 509;
+pub const S_HEAD_DIE1: StateNum = 510;
+pub const S_HEAD_DIE2: StateNum = 511;
+pub const S_HEAD_DIE3: StateNum = 512;
+pub const S_HEAD_DIE4: StateNum = 513;
+pub const S_HEAD_DIE5: StateNum = 514;
+pub const S_HEAD_DIE6: StateNum = 515;
+pub const S_HEAD_RAISE1: StateNum = 516;
+pub const S_HEAD_RAISE2: StateNum = 517;
+pub const S_HEAD_RAISE3: StateNum = 518;
+pub const S_HEAD_RAISE4: StateNum = 519;
+pub const S_HEAD_RAISE5: StateNum = 520;
+pub const S_HEAD_RAISE6: StateNum = 521;
+pub const S_BRBALL1: StateNum = 522;
+pub const S_BRBALL2: StateNum = 523;
+pub const S_BRBALLX1: StateNum = 524;
+pub const S_BRBALLX2: StateNum = 525;
+pub const S_BRBALLX3: StateNum = 526;
+pub const S_BOSS_STND: StateNum = 527;
+pub const S_BOSS_STND2: StateNum = 528;
+pub const S_BOSS_RUN1: StateNum = 529;
+pub const S_BOSS_RUN2: StateNum = 530;
+pub const S_BOSS_RUN3: StateNum = 531;
+pub const S_BOSS_RUN4: StateNum = 532;
+pub const S_BOSS_RUN5: StateNum = 533;
+pub const S_BOSS_RUN6: StateNum = 534;
+pub const S_BOSS_RUN7: StateNum = 535;
+pub const S_BOSS_RUN8: StateNum = 536;
+pub const S_BOSS_ATK1: StateNum = 537;
+pub const S_BOSS_ATK2: StateNum = 538;
+pub const S_BOSS_ATK3: StateNum = 539;
+pub const S_BOSS_PAIN: StateNum = 540;
+pub const S_BOSS_PAIN2: StateNum = 541;
+pub const S_BOSS_DIE1: StateNum = 542;
+pub const S_BOSS_DIE2: StateNum = 543;
+pub const S_BOSS_DIE3: StateNum = 544;
+pub const S_BOSS_DIE4: StateNum = 545;
+pub const S_BOSS_DIE5: StateNum = 546;
+pub const S_BOSS_DIE6: StateNum = 547;
+pub const S_BOSS_DIE7: StateNum = 548;
+pub const S_BOSS_RAISE1: StateNum = 549;
+pub const S_BOSS_RAISE2: StateNum = 550;
+pub const S_BOSS_RAISE3: StateNum = 551;
+pub const S_BOSS_RAISE4: StateNum = 552;
+pub const S_BOSS_RAISE5: StateNum = 553;
+pub const S_BOSS_RAISE6: StateNum = 554;
+pub const S_BOSS_RAISE7: StateNum = 555;
+pub const S_BOS2_STND: StateNum = 556;
+pub const S_BOS2_STND2: StateNum = 557;
+pub const S_BOS2_RUN1: StateNum = 558;
+pub const S_BOS2_RUN2: StateNum = 559;
+pub const S_BOS2_RUN3: StateNum = 560;
+pub const S_BOS2_RUN4: StateNum = 561;
+pub const S_BOS2_RUN5: StateNum = 562;
+pub const S_BOS2_RUN6: StateNum = 563;
+pub const S_BOS2_RUN7: StateNum = 564;
+pub const S_BOS2_RUN8: StateNum = 565;
+pub const S_BOS2_ATK1: StateNum = 566;
+pub const S_BOS2_ATK2: StateNum = 567;
+pub const S_BOS2_ATK3: StateNum = 568;
+pub const S_BOS2_PAIN: StateNum = 569;
+pub const S_BOS2_PAIN2: StateNum = 570;
+pub const S_BOS2_DIE1: StateNum = 571;
+pub const S_BOS2_DIE2: StateNum = 572;
+pub const S_BOS2_DIE3: StateNum = 573;
+pub const S_BOS2_DIE4: StateNum = 574;
+pub const S_BOS2_DIE5: StateNum = 575;
+pub const S_BOS2_DIE6: StateNum = 576;
+pub const S_BOS2_DIE7: StateNum = 577;
+pub const S_BOS2_RAISE1: StateNum = 578;
+pub const S_BOS2_RAISE2: StateNum = 579;
+pub const S_BOS2_RAISE3: StateNum = 580;
+pub const S_BOS2_RAISE4: StateNum = 581;
+pub const S_BOS2_RAISE5: StateNum = 582;
+pub const S_BOS2_RAISE6: StateNum = 583;
+pub const S_BOS2_RAISE7: StateNum = 584;
+pub const S_SKULL_STND: StateNum = 585;
+pub const S_SKULL_STND2: StateNum = 586;
+pub const S_SKULL_RUN1: StateNum = 587;
+pub const S_SKULL_RUN2: StateNum = 588;
+pub const S_SKULL_ATK1: StateNum = 589;
+pub const S_SKULL_ATK2: StateNum = 590;
+pub const S_SKULL_ATK3: StateNum = 591;
+pub const S_SKULL_ATK4: StateNum = 592;
+pub const S_SKULL_PAIN: StateNum = 593;
+pub const S_SKULL_PAIN2: StateNum = 594;
+pub const S_SKULL_DIE1: StateNum = 595;
+pub const S_SKULL_DIE2: StateNum = 596;
+pub const S_SKULL_DIE3: StateNum = 597;
+pub const S_SKULL_DIE4: StateNum = 598;
+pub const S_SKULL_DIE5: StateNum = 599;
+pub const S_SKULL_DIE6: StateNum = 600;
+pub const S_SPID_STND: StateNum = 601;
+pub const S_SPID_STND2: StateNum = 602;
+pub const S_SPID_RUN1: StateNum = 603;
+pub const S_SPID_RUN2: StateNum = 604;
+pub const S_SPID_RUN3: StateNum = 605;
+pub const S_SPID_RUN4: StateNum = 606;
+pub const S_SPID_RUN5: StateNum = 607;
+pub const S_SPID_RUN6: StateNum = 608;
+pub const S_SPID_RUN7: StateNum = 609;
+pub const S_SPID_RUN8: StateNum = 610;
+pub const S_SPID_RUN9: StateNum = 611;
+pub const S_SPID_RUN10: StateNum = 612;
+pub const S_SPID_RUN11: StateNum = 613;
+pub const S_SPID_RUN12: StateNum = 614;
+pub const S_SPID_ATK1: StateNum = 615;
+pub const S_SPID_ATK2: StateNum = 616;
+pub const S_SPID_ATK3: StateNum = 617;
+pub const S_SPID_ATK4: StateNum = 618;
+pub const S_SPID_PAIN: StateNum = 619;
+pub const S_SPID_PAIN2: StateNum = 620;
+pub const S_SPID_DIE1: StateNum = 621;
+pub const S_SPID_DIE2: StateNum = 622;
+pub const S_SPID_DIE3: StateNum = 623;
+pub const S_SPID_DIE4: StateNum = 624;
+pub const S_SPID_DIE5: StateNum = 625;
+pub const S_SPID_DIE6: StateNum = 626;
+pub const S_SPID_DIE7: StateNum = 627;
+pub const S_SPID_DIE8: StateNum = 628;
+pub const S_SPID_DIE9: StateNum = 629;
+pub const S_SPID_DIE10: StateNum = 630;
+pub const S_SPID_DIE11: StateNum = 631;
+pub const S_BSPI_STND: StateNum = 632;
+pub const S_BSPI_STND2: StateNum = 633;
+pub const S_BSPI_SIGHT: StateNum = 634;
+pub const S_BSPI_RUN1: StateNum = 635;
+pub const S_BSPI_RUN2: StateNum = 636;
+pub const S_BSPI_RUN3: StateNum = 637;
+pub const S_BSPI_RUN4: StateNum = 638;
+pub const S_BSPI_RUN5: StateNum = 639;
+pub const S_BSPI_RUN6: StateNum = 640;
+pub const S_BSPI_RUN7: StateNum = 641;
+pub const S_BSPI_RUN8: StateNum = 642;
+pub const S_BSPI_RUN9: StateNum = 643;
+pub const S_BSPI_RUN10: StateNum = 644;
+pub const S_BSPI_RUN11: StateNum = 645;
+pub const S_BSPI_RUN12: StateNum = 646;
+pub const S_BSPI_ATK1: StateNum = 647;
+pub const S_BSPI_ATK2: StateNum = 648;
+pub const S_BSPI_ATK3: StateNum = 649;
+pub const S_BSPI_ATK4: StateNum = 650;
+pub const S_BSPI_PAIN: StateNum = 651;
+pub const S_BSPI_PAIN2: StateNum = 652;
+pub const S_BSPI_DIE1: StateNum = 653;
+pub const S_BSPI_DIE2: StateNum = 654;
+pub const S_BSPI_DIE3: StateNum = 655;
+pub const S_BSPI_DIE4: StateNum = 656;
+pub const S_BSPI_DIE5: StateNum = 657;
+pub const S_BSPI_DIE6: StateNum = 658;
+pub const S_BSPI_DIE7: StateNum = 659;
+pub const S_BSPI_RAISE1: StateNum = 660;
+pub const S_BSPI_RAISE2: StateNum = 661;
+pub const S_BSPI_RAISE3: StateNum = 662;
+pub const S_BSPI_RAISE4: StateNum = 663;
+pub const S_BSPI_RAISE5: StateNum = 664;
+pub const S_BSPI_RAISE6: StateNum = 665;
+pub const S_BSPI_RAISE7: StateNum = 666;
+pub const S_ARACH_PLAZ: StateNum = 667;
+pub const S_ARACH_PLAZ2: StateNum = 668;
+pub const S_ARACH_PLEX: StateNum = 669;
+pub const S_ARACH_PLEX2: StateNum = 670;
+pub const S_ARACH_PLEX3: StateNum = 671;
+pub const S_ARACH_PLEX4: StateNum = 672;
+pub const S_ARACH_PLEX5: StateNum = 673;
+pub const S_CYBER_STND: StateNum = 674;
+pub const S_CYBER_STND2: StateNum = 675;
+pub const S_CYBER_RUN1: StateNum = 676;
+pub const S_CYBER_RUN2: StateNum = 677;
+pub const S_CYBER_RUN3: StateNum = 678;
+pub const S_CYBER_RUN4: StateNum = 679;
+pub const S_CYBER_RUN5: StateNum = 680;
+pub const S_CYBER_RUN6: StateNum = 681;
+pub const S_CYBER_RUN7: StateNum = 682;
+pub const S_CYBER_RUN8: StateNum = 683;
+pub const S_CYBER_ATK1: StateNum = 684;
+pub const S_CYBER_ATK2: StateNum = 685;
+pub const S_CYBER_ATK3: StateNum = 686;
+pub const S_CYBER_ATK4: StateNum = 687;
+pub const S_CYBER_ATK5: StateNum = 688;
+pub const S_CYBER_ATK6: StateNum = 689;
+pub const S_CYBER_PAIN: StateNum = 690;
+pub const S_CYBER_DIE1: StateNum = 691;
+pub const S_CYBER_DIE2: StateNum = 692;
+pub const S_CYBER_DIE3: StateNum = 693;
+pub const S_CYBER_DIE4: StateNum = 694;
+pub const S_CYBER_DIE5: StateNum = 695;
+pub const S_CYBER_DIE6: StateNum = 696;
+pub const S_CYBER_DIE7: StateNum = 697;
+pub const S_CYBER_DIE8: StateNum = 698;
+pub const S_CYBER_DIE9: StateNum = 699;
+pub const S_CYBER_DIE10: StateNum = 700;
+pub const S_PAIN_STND: StateNum = 701;
+pub const S_PAIN_RUN1: StateNum = 702;
+pub const S_PAIN_RUN2: StateNum = 703;
+pub const S_PAIN_RUN3: StateNum = 704;
+pub const S_PAIN_RUN4: StateNum = 705;
+pub const S_PAIN_RUN5: StateNum = 706;
+pub const S_PAIN_RUN6: StateNum = 707;
+pub const S_PAIN_ATK1: StateNum = 708;
+pub const S_PAIN_ATK2: StateNum = 709;
+pub const S_PAIN_ATK3: StateNum = 710;
+pub const S_PAIN_ATK4: StateNum = 711;
+pub const S_PAIN_PAIN: StateNum = 712;
+pub const S_PAIN_PAIN2: StateNum = 713;
+pub const S_PAIN_DIE1: StateNum = 714;
+pub const S_PAIN_DIE2: StateNum = 715;
+pub const S_PAIN_DIE3: StateNum = 716;
+pub const S_PAIN_DIE4: StateNum = 717;
+pub const S_PAIN_DIE5: StateNum = 718;
+pub const S_PAIN_DIE6: StateNum = 719;
+pub const S_PAIN_RAISE1: StateNum = 720;
+pub const S_PAIN_RAISE2: StateNum = 721;
+pub const S_PAIN_RAISE3: StateNum = 722;
+pub const S_PAIN_RAISE4: StateNum = 723;
+pub const S_PAIN_RAISE5: StateNum = 724;
+pub const S_PAIN_RAISE6: StateNum = 725;
+pub const S_SSWV_STND: StateNum = 726;
+pub const S_SSWV_STND2: StateNum = 727;
+pub const S_SSWV_RUN1: StateNum = 728;
+pub const S_SSWV_RUN2: StateNum = 729;
+pub const S_SSWV_RUN3: StateNum = 730;
+pub const S_SSWV_RUN4: StateNum = 731;
+pub const S_SSWV_RUN5: StateNum = 732;
+pub const S_SSWV_RUN6: StateNum = 733;
+pub const S_SSWV_RUN7: StateNum = 734;
+pub const S_SSWV_RUN8: StateNum = 735;
+pub const S_SSWV_ATK1: StateNum = 736;
+pub const S_SSWV_ATK2: StateNum = 737;
+pub const S_SSWV_ATK3: StateNum = 738;
+pub const S_SSWV_ATK4: StateNum = 739;
+pub const S_SSWV_ATK5: StateNum = 740;
+pub const S_SSWV_ATK6: StateNum = 741;
+pub const S_SSWV_PAIN: StateNum = 742;
+pub const S_SSWV_PAIN2: StateNum = 743;
+pub const S_SSWV_DIE1: StateNum = 744;
+pub const S_SSWV_DIE2: StateNum = 745;
+pub const S_SSWV_DIE3: StateNum = 746;
+pub const S_SSWV_DIE4: StateNum = 747;
+pub const S_SSWV_DIE5: StateNum = 748;
+pub const S_SSWV_XDIE1: StateNum = 749;
+pub const S_SSWV_XDIE2: StateNum = 750;
+pub const S_SSWV_XDIE3: StateNum = 751;
+pub const S_SSWV_XDIE4: StateNum = 752;
+pub const S_SSWV_XDIE5: StateNum = 753;
+pub const S_SSWV_XDIE6: StateNum = 754;
+pub const S_SSWV_XDIE7: StateNum = 755;
+pub const S_SSWV_XDIE8: StateNum = 756;
+pub const S_SSWV_XDIE9: StateNum = 757;
+pub const S_SSWV_RAISE1: StateNum = 758;
+pub const S_SSWV_RAISE2: StateNum = 759;
+pub const S_SSWV_RAISE3: StateNum = 760;
+pub const S_SSWV_RAISE4: StateNum = 761;
+pub const S_SSWV_RAISE5: StateNum = 762;
+pub const S_KEENSTND: StateNum = 763;
+pub const S_COMMKEEN: StateNum = 764;
+pub const S_COMMKEEN2: StateNum = 765;
+pub const S_COMMKEEN3: StateNum = 766;
+pub const S_COMMKEEN4: StateNum = 767;
+pub const S_COMMKEEN5: StateNum = 768;
+pub const S_COMMKEEN6: StateNum = 769;
+pub const S_COMMKEEN7: StateNum = 770;
+pub const S_COMMKEEN8: StateNum = 771;
+pub const S_COMMKEEN9: StateNum = 772;
+pub const S_COMMKEEN10: StateNum = 773;
+pub const S_COMMKEEN11: StateNum = 774;
+pub const S_COMMKEEN12: StateNum = 775;
+pub const S_KEENPAIN: StateNum = 776;
+pub const S_KEENPAIN2: StateNum = 777;
+pub const S_BRAIN: StateNum = 778;
+pub const S_BRAIN_PAIN: StateNum = 779;
+pub const S_BRAIN_DIE1: StateNum = 780;
+pub const S_BRAIN_DIE2: StateNum = 781;
+pub const S_BRAIN_DIE3: StateNum = 782;
+pub const S_BRAIN_DIE4: StateNum = 783;
+pub const S_BRAINEYE: StateNum = 784;
+pub const S_BRAINEYESEE: StateNum = 785;
+pub const S_BRAINEYE1: StateNum = 786;
+pub const S_SPAWN1: StateNum = 787;
+pub const S_SPAWN2: StateNum = 788;
+pub const S_SPAWN3: StateNum = 789;
+pub const S_SPAWN4: StateNum = 790;
+pub const S_SPAWNFIRE1: StateNum = 791;
+pub const S_SPAWNFIRE2: StateNum = 792;
+pub const S_SPAWNFIRE3: StateNum = 793;
+pub const S_SPAWNFIRE4: StateNum = 794;
+pub const S_SPAWNFIRE5: StateNum = 795;
+pub const S_SPAWNFIRE6: StateNum = 796;
+pub const S_SPAWNFIRE7: StateNum = 797;
+pub const S_SPAWNFIRE8: StateNum = 798;
+pub const S_BRAINEXPLODE1: StateNum = 799;
+pub const S_BRAINEXPLODE2: StateNum = 800;
+pub const S_BRAINEXPLODE3: StateNum = 801;
+pub const S_ARM1: StateNum = 802;
+pub const S_ARM1A: StateNum = 803;
+pub const S_ARM2: StateNum = 804;
+pub const S_ARM2A: StateNum = 805;
+pub const S_BAR1: StateNum = 806;
+pub const S_BAR2: StateNum = 807;
+pub const S_BEXP: StateNum = 808;
+pub const S_BEXP2: StateNum = 809;
+pub const S_BEXP3: StateNum = 810;
+pub const S_BEXP4: StateNum = 811;
+pub const S_BEXP5: StateNum = 812;
+pub const S_BBAR1: StateNum = 813;
+pub const S_BBAR2: StateNum = 814;
+pub const S_BBAR3: StateNum = 815;
+pub const S_BON1: StateNum = 816;
+pub const S_BON1A: StateNum = 817;
+pub const S_BON1B: StateNum = 818;
+pub const S_BON1C: StateNum = 819;
+pub const S_BON1D: StateNum = 820;
+pub const S_BON1E: StateNum = 821;
+pub const S_BON2: StateNum = 822;
+pub const S_BON2A: StateNum = 823;
+pub const S_BON2B: StateNum = 824;
+pub const S_BON2C: StateNum = 825;
+pub const S_BON2D: StateNum = 826;
+pub const S_BON2E: StateNum = 827;
+pub const S_BKEY: StateNum = 828;
+pub const S_BKEY2: StateNum = 829;
+pub const S_RKEY: StateNum = 830;
+pub const S_RKEY2: StateNum = 831;
+pub const S_YKEY: StateNum = 832;
+pub const S_YKEY2: StateNum = 833;
+pub const S_BSKULL: StateNum = 834;
+pub const S_BSKULL2: StateNum = 835;
+pub const S_RSKULL: StateNum = 836;
+pub const S_RSKULL2: StateNum = 837;
+pub const S_YSKULL: StateNum = 838;
+pub const S_YSKULL2: StateNum = 839;
+pub const S_STIM: StateNum = 840;
+pub const S_MEDI: StateNum = 841;
+pub const S_SOUL: StateNum = 842;
+pub const S_SOUL2: StateNum = 843;
+pub const S_SOUL3: StateNum = 844;
+pub const S_SOUL4: StateNum = 845;
+pub const S_SOUL5: StateNum = 846;
+pub const S_SOUL6: StateNum = 847;
+pub const S_PINV: StateNum = 848;
+pub const S_PINV2: StateNum = 849;
+pub const S_PINV3: StateNum = 850;
+pub const S_PINV4: StateNum = 851;
+pub const S_PSTR: StateNum = 852;
+pub const S_PINS: StateNum = 853;
+pub const S_PINS2: StateNum = 854;
+pub const S_PINS3: StateNum = 855;
+pub const S_PINS4: StateNum = 856;
+pub const S_MEGA: StateNum = 857;
+pub const S_MEGA2: StateNum = 858;
+pub const S_MEGA3: StateNum = 859;
+pub const S_MEGA4: StateNum = 860;
+pub const S_SUIT: StateNum = 861;
+pub const S_PMAP: StateNum = 862;
+pub const S_PMAP2: StateNum = 863;
+pub const S_PMAP3: StateNum = 864;
+pub const S_PMAP4: StateNum = 865;
+pub const S_PMAP5: StateNum = 866;
+pub const S_PMAP6: StateNum = 867;
+pub const S_PVIS: StateNum = 868;
+pub const S_PVIS2: StateNum = 869;
+pub const S_CLIP: StateNum = 870;
+pub const S_AMMO: StateNum = 871;
+pub const S_ROCK: StateNum = 872;
+pub const S_BROK: StateNum = 873;
+pub const S_CELL: StateNum = 874;
+pub const S_CELP: StateNum = 875;
+pub const S_SHEL: StateNum = 876;
+pub const S_SBOX: StateNum = 877;
+pub const S_BPAK: StateNum = 878;
+pub const S_BFUG: StateNum = 879;
+pub const S_MGUN: StateNum = 880;
+pub const S_CSAW: StateNum = 881;
+pub const S_LAUN: StateNum = 882;
+pub const S_PLAS: StateNum = 883;
+pub const S_SHOT: StateNum = 884;
+pub const S_SHOT2: StateNum = 885;
+pub const S_COLU: StateNum = 886;
+pub const S_STALAG: StateNum = 887;
+pub const S_BLOODYTWITCH: StateNum = 888;
+pub const S_BLOODYTWITCH2: StateNum = 889;
+pub const S_BLOODYTWITCH3: StateNum = 890;
+pub const S_BLOODYTWITCH4: StateNum = 891;
+pub const S_DEADTORSO: StateNum = 892;
+pub const S_DEADBOTTOM: StateNum = 893;
+pub const S_HEADSONSTICK: StateNum = 894;
+pub const S_GIBS: StateNum = 895;
+pub const S_HEADONASTICK: StateNum = 896;
+pub const S_HEADCANDLES: StateNum = 897;
+pub const S_HEADCANDLES2: StateNum = 898;
+pub const S_DEADSTICK: StateNum = 899;
+pub const S_LIVESTICK: StateNum = 900;
+pub const S_LIVESTICK2: StateNum = 901;
+pub const S_MEAT2: StateNum = 902;
+pub const S_MEAT3: StateNum = 903;
+pub const S_MEAT4: StateNum = 904;
+pub const S_MEAT5: StateNum = 905;
+pub const S_STALAGTITE: StateNum = 906;
+pub const S_TALLGRNCOL: StateNum = 907;
+pub const S_SHRTGRNCOL: StateNum = 908;
+pub const S_TALLREDCOL: StateNum = 909;
+pub const S_SHRTREDCOL: StateNum = 910;
+pub const S_CANDLESTIK: StateNum = 911;
+pub const S_CANDELABRA: StateNum = 912;
+pub const S_SKULLCOL: StateNum = 913;
+pub const S_TORCHTREE: StateNum = 914;
+pub const S_BIGTREE: StateNum = 915;
+pub const S_TECHPILLAR: StateNum = 916;
+pub const S_EVILEYE: StateNum = 917;
+pub const S_EVILEYE2: StateNum = 918;
+pub const S_EVILEYE3: StateNum = 919;
+pub const S_EVILEYE4: StateNum = 920;
+pub const S_FLOATSKULL: StateNum = 921;
+pub const S_FLOATSKULL2: StateNum = 922;
+pub const S_FLOATSKULL3: StateNum = 923;
+pub const S_HEARTCOL: StateNum = 924;
+pub const S_HEARTCOL2: StateNum = 925;
+pub const S_BLUETORCH: StateNum = 926;
+pub const S_BLUETORCH2: StateNum = 927;
+pub const S_BLUETORCH3: StateNum = 928;
+pub const S_BLUETORCH4: StateNum = 929;
+pub const S_GREENTORCH: StateNum = 930;
+pub const S_GREENTORCH2: StateNum = 931;
+pub const S_GREENTORCH3: StateNum = 932;
+pub const S_GREENTORCH4: StateNum = 933;
+pub const S_REDTORCH: StateNum = 934;
+pub const S_REDTORCH2: StateNum = 935;
+pub const S_REDTORCH3: StateNum = 936;
+pub const S_REDTORCH4: StateNum = 937;
+pub const S_BTORCHSHRT: StateNum = 938;
+pub const S_BTORCHSHRT2: StateNum = 939;
+pub const S_BTORCHSHRT3: StateNum = 940;
+pub const S_BTORCHSHRT4: StateNum = 941;
+pub const S_GTORCHSHRT: StateNum = 942;
+pub const S_GTORCHSHRT2: StateNum = 943;
+pub const S_GTORCHSHRT3: StateNum = 944;
+pub const S_GTORCHSHRT4: StateNum = 945;
+pub const S_RTORCHSHRT: StateNum = 946;
+pub const S_RTORCHSHRT2: StateNum = 947;
+pub const S_RTORCHSHRT3: StateNum = 948;
+pub const S_RTORCHSHRT4: StateNum = 949;
+pub const S_HANGNOGUTS: StateNum = 950;
+pub const S_HANGBNOBRAIN: StateNum = 951;
+pub const S_HANGTLOOKDN: StateNum = 952;
+pub const S_HANGTSKULL: StateNum = 953;
+pub const S_HANGTLOOKUP: StateNum = 954;
+pub const S_HANGTNOBRAIN: StateNum = 955;
+pub const S_COLONGIBS: StateNum = 956;
+pub const S_SMALLPOOL: StateNum = 957;
+pub const S_BRAINSTEM: StateNum = 958;
+pub const S_TECHLAMP: StateNum = 959;
+pub const S_TECHLAMP2: StateNum = 960;
+pub const S_TECHLAMP3: StateNum = 961;
+pub const S_TECHLAMP4: StateNum = 962;
+pub const S_TECH2LAMP: StateNum = 963;
+pub const S_TECH2LAMP2: StateNum = 964;
+pub const S_TECH2LAMP3: StateNum = 965;
+pub const S_TECH2LAMP4: StateNum = 966;
+pub const NUM_STATES: usize = 967;
+
+/// A frame of a thing or weapon animation, see state_t.
+pub struct State {
+    pub(crate) sprite: SpriteNum,
+    pub(crate) frame: u32,
+    pub(crate) tics: i32,
+    pub(crate) action: Option<Action>,
+    pub(crate) next_state: StateNum,
+    pub(crate) misc1: i32,
+    pub(crate) misc2: i32,
+}
+
+impl State {
+    const fn new(sprite: SpriteNum, frame: u32, tics: i32, action: Option<Action>, next_state: StateNum) -> Self {
+        Self { sprite, frame, tics, action, next_state, misc1: 0, misc2: 0 }
+    }
+}
+
+pub static STATES: [State; NUM_STATES] = [
+    State::new(SPR_TROO, 0, -1, None, S_NULL), // S_NULL
+    State::new(SPR_SHTG, 4, 0, Some(Action::Light0), S_NULL), // S_LIGHTDONE
+    State::new(SPR_PUNG, 0, 1, Some(Action::WeaponReady), S_PUNCH), // S_PUNCH
+    State::new(SPR_PUNG, 0, 1, Some(Action::Lower), S_PUNCHDOWN), // S_PUNCHDOWN
+    State::new(SPR_PUNG, 0, 1, Some(Action::Raise), S_PUNCHUP), // S_PUNCHUP
+    State::new(SPR_PUNG, 1, 4, None, S_PUNCH2), // S_PUNCH1
+    State::new(SPR_PUNG, 2, 4, Some(Action::Punch), S_PUNCH3), // S_PUNCH2
+    State::new(SPR_PUNG, 3, 5, None, S_PUNCH4), // S_PUNCH3
+    State::new(SPR_PUNG, 2, 4, None, S_PUNCH5), // S_PUNCH4
+    State::new(SPR_PUNG, 1, 5, Some(Action::ReFire), S_PUNCH), // S_PUNCH5
+    State::new(SPR_PISG, 0, 1, Some(Action::WeaponReady), S_PISTOL), // S_PISTOL
+    State::new(SPR_PISG, 0, 1, Some(Action::Lower), S_PISTOLDOWN), // S_PISTOLDOWN
+    State::new(SPR_PISG, 0, 1, Some(Action::Raise), S_PISTOLUP), // S_PISTOLUP
+    State::new(SPR_PISG, 0, 4, None, S_PISTOL2), // S_PISTOL1
+    State::new(SPR_PISG, 1, 6, Some(Action::FirePistol), S_PISTOL3), // S_PISTOL2
+    State::new(SPR_PISG, 2, 4, None, S_PISTOL4), // S_PISTOL3
+    State::new(SPR_PISG, 1, 5, Some(Action::ReFire), S_PISTOL), // S_PISTOL4
+    State::new(SPR_PISF, 32768, 7, Some(Action::Light1), S_LIGHTDONE), // S_PISTOLFLASH
+    State::new(SPR_SHTG, 0, 1, Some(Action::WeaponReady), S_SGUN), // S_SGUN
+    State::new(SPR_SHTG, 0, 1, Some(Action::Lower), S_SGUNDOWN), // S_SGUNDOWN
+    State::new(SPR_SHTG, 0, 1, Some(Action::Raise), S_SGUNUP), // S_SGUNUP
+    State::new(SPR_SHTG, 0, 3, None, S_SGUN2), // S_SGUN1
+    State::new(SPR_SHTG, 0, 7, Some(Action::FireShotgun), S_SGUN3), // S_SGUN2
+    State::new(SPR_SHTG, 1, 5, None, S_SGUN4), // S_SGUN3
+    State::new(SPR_SHTG, 2, 5, None, S_SGUN5), // S_SGUN4
+    State::new(SPR_SHTG, 3, 4, None, S_SGUN6), // S_SGUN5
+    State::new(SPR_SHTG, 2, 5, None, S_SGUN7), // S_SGUN6
+    State::new(SPR_SHTG, 1, 5, None, S_SGUN8), // S_SGUN7
+    State::new(SPR_SHTG, 0, 3, None, S_SGUN9), // S_SGUN8
+    State::new(SPR_SHTG, 0, 7, Some(Action::ReFire), S_SGUN), // S_SGUN9
+    State::new(SPR_SHTF, 32768, 4, Some(Action::Light1), S_SGUNFLASH2), // S_SGUNFLASH1
+    State::new(SPR_SHTF, 32769, 3, Some(Action::Light2), S_LIGHTDONE), // S_SGUNFLASH2
+    State::new(SPR_SHT2, 0, 1, Some(Action::WeaponReady), S_DSGUN), // S_DSGUN
+    State::new(SPR_SHT2, 0, 1, Some(Action::Lower), S_DSGUNDOWN), // S_DSGUNDOWN
+    State::new(SPR_SHT2, 0, 1, Some(Action::Raise), S_DSGUNUP), // S_DSGUNUP
+    State::new(SPR_SHT2, 0, 3, None, S_DSGUN2), // S_DSGUN1
+    State::new(SPR_SHT2, 0, 7, Some(Action::FireShotgun2), S_DSGUN3), // S_DSGUN2
+    State::new(SPR_SHT2, 1, 7, None, S_DSGUN4), // S_DSGUN3
+    State::new(SPR_SHT2, 2, 7, Some(Action::CheckReload), S_DSGUN5), // S_DSGUN4
+    State::new(SPR_SHT2, 3, 7, Some(Action::OpenShotgun2), S_DSGUN6), // S_DSGUN5
+    State::new(SPR_SHT2, 4, 7, None, S_DSGUN7), // S_DSGUN6
+    State::new(SPR_SHT2, 5, 7, Some(Action::LoadShotgun2), S_DSGUN8), // S_DSGUN7
+    State::new(SPR_SHT2, 6, 6, None, S_DSGUN9), // S_DSGUN8
+    State::new(SPR_SHT2, 7, 6, Some(Action::CloseShotgun2), S_DSGUN10), // S_DSGUN9
+    State::new(SPR_SHT2, 0, 5, Some(Action::ReFire), S_DSGUN), // S_DSGUN10
+    State::new(SPR_SHT2, 1, 7, None, S_DSNR2), // S_DSNR1
+    State::new(SPR_SHT2, 0, 3, None, S_DSGUNDOWN), // S_DSNR2
+    State::new(SPR_SHT2, 32776, 5, Some(Action::Light1), S_DSGUNFLASH2), // S_DSGUNFLASH1
+    State::new(SPR_SHT2, 32777, 4, Some(Action::Light2), S_LIGHTDONE), // S_DSGUNFLASH2
+    State::new(SPR_CHGG, 0, 1, Some(Action::WeaponReady), S_CHAIN), // S_CHAIN
+    State::new(SPR_CHGG, 0, 1, Some(Action::Lower), S_CHAINDOWN), // S_CHAINDOWN
+    State::new(SPR_CHGG, 0, 1, Some(Action::Raise), S_CHAINUP), // S_CHAINUP
+    State::new(SPR_CHGG, 0, 4, Some(Action::FireCGun), S_CHAIN2), // S_CHAIN1
+    State::new(SPR_CHGG, 1, 4, Some(Action::FireCGun), S_CHAIN3), // S_CHAIN2
+    State::new(SPR_CHGG, 1, 0, Some(Action::ReFire), S_CHAIN), // S_CHAIN3
+    State::new(SPR_CHGF, 32768, 5, Some(Action::Light1), S_LIGHTDONE), // S_CHAINFLASH1
+    State::new(SPR_CHGF, 32769, 5, Some(Action::Light2), S_LIGHTDONE), // S_CHAINFLASH2
+    State::new(SPR_MISG, 0, 1, Some(Action::WeaponReady), S_MISSILE), // S_MISSILE
+    State::new(SPR_MISG, 0, 1, Some(Action::Lower), S_MISSILEDOWN), // S_MISSILEDOWN
+    State::new(SPR_MISG, 0, 1, Some(Action::Raise), S_MISSILEUP), // S_MISSILEUP
+    State::new(SPR_MISG, 1, 8, Some(Action::GunFlash), S_MISSILE2), // S_MISSILE1
+    State::new(SPR_MISG, 1, 12, Some(Action::FireMissile), S_MISSILE3), // S_MISSILE2
+    State::new(SPR_MISG, 1, 0, Some(Action::ReFire), S_MISSILE), // S_MISSILE3
+    State::new(SPR_MISF, 32768, 3, Some(Action::Light1), S_MISSILEFLASH2), // S_MISSILEFLASH1
+    State::new(SPR_MISF, 32769, 4, None, S_MISSILEFLASH3), // S_MISSILEFLASH2
+    State::new(SPR_MISF, 32770, 4, Some(Action::Light2), S_MISSILEFLASH4), // S_MISSILEFLASH3
+    State::new(SPR_MISF, 32771, 4, Some(Action::Light2), S_LIGHTDONE), // S_MISSILEFLASH4
+    State::new(SPR_SAWG, 2, 4, Some(Action::WeaponReady), S_SAWB), // S_SAW
+    State::new(SPR_SAWG, 3, 4, Some(Action::WeaponReady), S_SAW), // S_SAWB
+    State::new(SPR_SAWG, 2, 1, Some(Action::Lower), S_SAWDOWN), // S_SAWDOWN
+    State::new(SPR_SAWG, 2, 1, Some(Action::Raise), S_SAWUP), // S_SAWUP
+    State::new(SPR_SAWG, 0, 4, Some(Action::Saw), S_SAW2), // S_SAW1
+    State::new(SPR_SAWG, 1, 4, Some(Action::Saw), S_SAW3), // S_SAW2
+    State::new(SPR_SAWG, 1, 0, Some(Action::ReFire), S_SAW), // S_SAW3
+    State::new(SPR_PLSG, 0, 1, Some(Action::WeaponReady), S_PLASMA), // S_PLASMA
+    State::new(SPR_PLSG, 0, 1, Some(Action::Lower), S_PLASMADOWN), // S_PLASMADOWN
+    State::new(SPR_PLSG, 0, 1, Some(Action::Raise), S_PLASMAUP), // S_PLASMAUP
+    State::new(SPR_PLSG, 0, 3, Some(Action::FirePlasma), S_PLASMA2), // S_PLASMA1
+    State::new(SPR_PLSG, 1, 20, Some(Action::ReFire), S_PLASMA), // S_PLASMA2
+    State::new(SPR_PLSF, 32768, 4, Some(Action::Light1), S_LIGHTDONE), // S_PLASMAFLASH1
+    State::new(SPR_PLSF, 32769, 4, Some(Action::Light1), S_LIGHTDONE), // S_PLASMAFLASH2
+    State::new(SPR_BFGG, 0, 1, Some(Action::WeaponReady), S_BFG), // S_BFG
+    State::new(SPR_BFGG, 0, 1, Some(Action::Lower), S_BFGDOWN), // S_BFGDOWN
+    State::new(SPR_BFGG, 0, 1, Some(Action::Raise), S_BFGUP), // S_BFGUP
+    State::new(SPR_BFGG, 0, 20, Some(Action::BfgSound), S_BFG2), // S_BFG1
+    State::new(SPR_BFGG, 1, 10, Some(Action::GunFlash), S_BFG3), // S_BFG2
+    State::new(SPR_BFGG, 1, 10, Some(Action::FireBfg), S_BFG4), // S_BFG3
+    State::new(SPR_BFGG, 1, 20, Some(Action::ReFire), S_BFG), // S_BFG4
+    State::new(SPR_BFGF, 32768, 11, Some(Action::Light1), S_BFGFLASH2), // S_BFGFLASH1
+    State::new(SPR_BFGF, 32769, 6, Some(Action::Light2), S_LIGHTDONE), // S_BFGFLASH2
+    State::new(SPR_BLUD, 2, 8, None, S_BLOOD2), // S_BLOOD1
+    State::new(SPR_BLUD, 1, 8, None, S_BLOOD3), // S_BLOOD2
+    State::new(SPR_BLUD, 0, 8, None, S_NULL), // S_BLOOD3
+    State::new(SPR_PUFF, 32768, 4, None, S_PUFF2), // S_PUFF1
+    State::new(SPR_PUFF, 1, 4, None, S_PUFF3), // S_PUFF2
+    State::new(SPR_PUFF, 2, 4, None, S_PUFF4), // S_PUFF3
+    State::new(SPR_PUFF, 3, 4, None, S_NULL), // S_PUFF4
+    State::new(SPR_BAL1, 32768, 4, None, S_TBALL2), // S_TBALL1
+    State::new(SPR_BAL1, 32769, 4, None, S_TBALL1), // S_TBALL2
+    State::new(SPR_BAL1, 32770, 6, None, S_TBALLX2), // S_TBALLX1
+    State::new(SPR_BAL1, 32771, 6, None, S_TBALLX3), // S_TBALLX2
+    State::new(SPR_BAL1, 32772, 6, None, S_NULL), // S_TBALLX3
+    State::new(SPR_BAL2, 32768, 4, None, S_RBALL2), // S_RBALL1
+    State::new(SPR_BAL2, 32769, 4, None, S_RBALL1), // S_RBALL2
+    State::new(SPR_BAL2, 32770, 6, None, S_RBALLX2), // S_RBALLX1
+    State::new(SPR_BAL2, 32771, 6, None, S_RBALLX3), // S_RBALLX2
+    State::new(SPR_BAL2, 32772, 6, None, S_NULL), // S_RBALLX3
+    State::new(SPR_PLSS, 32768, 6, None, S_PLASBALL2), // S_PLASBALL
+    State::new(SPR_PLSS, 32769, 6, None, S_PLASBALL), // S_PLASBALL2
+    State::new(SPR_PLSE, 32768, 4, None, S_PLASEXP2), // S_PLASEXP
+    State::new(SPR_PLSE, 32769, 4, None, S_PLASEXP3), // S_PLASEXP2
+    State::new(SPR_PLSE, 32770, 4, None, S_PLASEXP4), // S_PLASEXP3
+    State::new(SPR_PLSE, 32771, 4, None, S_PLASEXP5), // S_PLASEXP4
+    State::new(SPR_PLSE, 32772, 4, None, S_NULL), // S_PLASEXP5
+    State::new(SPR_MISL, 32768, 1, None, S_ROCKET), // S_ROCKET
+    State::new(SPR_BFS1, 32768, 4, None, S_BFGSHOT2), // S_BFGSHOT
+    State::new(SPR_BFS1, 32769, 4, None, S_BFGSHOT), // S_BFGSHOT2
+    State::new(SPR_BFE1, 32768, 8, None, S_BFGLAND2), // S_BFGLAND
+    State::new(SPR_BFE1, 32769, 8, None, S_BFGLAND3), // S_BFGLAND2
+    State::new(SPR_BFE1, 32770, 8, Some(Action::BfgSpray), S_BFGLAND4), // S_BFGLAND3
+    State::new(SPR_BFE1, 32771, 8, None, S_BFGLAND5), // S_BFGLAND4
+    State::new(SPR_BFE1, 32772, 8, None, S_BFGLAND6), // S_BFGLAND5
+    State::new(SPR_BFE1, 32773, 8, None, S_NULL), // S_BFGLAND6
+    State::new(SPR_BFE2, 32768, 8, None, S_BFGEXP2), // S_BFGEXP
+    State::new(SPR_BFE2, 32769, 8, None, S_BFGEXP3), // S_BFGEXP2
+    State::new(SPR_BFE2, 32770, 8, None, S_BFGEXP4), // S_BFGEXP3
+    State::new(SPR_BFE2, 32771, 8, None, S_NULL), // S_BFGEXP4
+    State::new(SPR_MISL, 32769, 8, Some(Action::Explode), S_EXPLODE2), // S_EXPLODE1
+    State::new(SPR_MISL, 32770, 6, None, S_EXPLODE3), // S_EXPLODE2
+    State::new(SPR_MISL, 32771, 4, None, S_NULL), // S_EXPLODE3
+    State::new(SPR_TFOG, 32768, 6, None, S_TFOG01), // S_TFOG
+    State::new(SPR_TFOG, 32769, 6, None, S_TFOG02), // S_TFOG01
+    State::new(SPR_TFOG, 32768, 6, None, S_TFOG2), // S_TFOG02
+    State::new(SPR_TFOG, 32769, 6, None, S_TFOG3), // S_TFOG2
+    State::new(SPR_TFOG, 32770, 6, None, S_TFOG4), // S_TFOG3
+    State::new(SPR_TFOG, 32771, 6, None, S_TFOG5), // S_TFOG4
+    State::new(SPR_TFOG, 32772, 6, None, S_TFOG6), // S_TFOG5
+    State::new(SPR_TFOG, 32773, 6, None, S_TFOG7), // S_TFOG6
+    State::new(SPR_TFOG, 32774, 6, None, S_TFOG8), // S_TFOG7
+    State::new(SPR_TFOG, 32775, 6, None, S_TFOG9), // S_TFOG8
+    State::new(SPR_TFOG, 32776, 6, None, S_TFOG10), // S_TFOG9
+    State::new(SPR_TFOG, 32777, 6, None, S_NULL), // S_TFOG10
+    State::new(SPR_IFOG, 32768, 6, None, S_IFOG01), // S_IFOG
+    State::new(SPR_IFOG, 32769, 6, None, S_IFOG02), // S_IFOG01
+    State::new(SPR_IFOG, 32768, 6, None, S_IFOG2), // S_IFOG02
+    State::new(SPR_IFOG, 32769, 6, None, S_IFOG3), // S_IFOG2
+    State::new(SPR_IFOG, 32770, 6, None, S_IFOG4), // S_IFOG3
+    State::new(SPR_IFOG, 32771, 6, None, S_IFOG5), // S_IFOG4
+    State::new(SPR_IFOG, 32772, 6, None, S_NULL), // S_IFOG5
+    State::new(SPR_PLAY, 0, -1, None, S_NULL), // S_PLAY
+    State::new(SPR_PLAY, 0, 4, None, S_PLAY_RUN2), // S_PLAY_RUN1
+    State::new(SPR_PLAY, 1, 4, None, S_PLAY_RUN3), // S_PLAY_RUN2
+    State::new(SPR_PLAY, 2, 4, None, S_PLAY_RUN4), // S_PLAY_RUN3
+    State::new(SPR_PLAY, 3, 4, None, S_PLAY_RUN1), // S_PLAY_RUN4
+    State::new(SPR_PLAY, 4, 12, None, S_PLAY), // S_PLAY_ATK1
+    State::new(SPR_PLAY, 32773, 6, None, S_PLAY_ATK1), // S_PLAY_ATK2
+    State::new(SPR_PLAY, 6, 4, None, S_PLAY_PAIN2), // S_PLAY_PAIN
+    State::new(SPR_PLAY, 6, 4, Some(Action::Pain), S_PLAY), // S_PLAY_PAIN2
+    State::new(SPR_PLAY, 7, 10, None, S_PLAY_DIE2), // S_PLAY_DIE1
+    State::new(SPR_PLAY, 8, 10, Some(Action::PlayerScream), S_PLAY_DIE3), // S_PLAY_DIE2
+    State::new(SPR_PLAY, 9, 10, Some(Action::Fall), S_PLAY_DIE4), // S_PLAY_DIE3
+    State::new(SPR_PLAY, 10, 10, None, S_PLAY_DIE5), // S_PLAY_DIE4
+    State::new(SPR_PLAY, 11, 10, None, S_PLAY_DIE6), // S_PLAY_DIE5
+    State::new(SPR_PLAY, 12, 10, None, S_PLAY_DIE7), // S_PLAY_DIE6
+    State::new(SPR_PLAY, 13, -1, None, S_NULL), // S_PLAY_DIE7
+    State::new(SPR_PLAY, 14, 5, None, S_PLAY_XDIE2), // S_PLAY_XDIE1
+    State::new(SPR_PLAY, 15, 5, Some(Action::XScream), S_PLAY_XDIE3), // S_PLAY_XDIE2
+    State::new(SPR_PLAY, 16, 5, Some(Action::Fall), S_PLAY_XDIE4), // S_PLAY_XDIE3
+    State::new(SPR_PLAY, 17, 5, None, S_PLAY_XDIE5), // S_PLAY_XDIE4
+    State::new(SPR_PLAY, 18, 5, None, S_PLAY_XDIE6), // S_PLAY_XDIE5
+    State::new(SPR_PLAY, 19, 5, None, S_PLAY_XDIE7), // S_PLAY_XDIE6
+    State::new(SPR_PLAY, 20, 5, None, S_PLAY_XDIE8), // S_PLAY_XDIE7
+    State::new(SPR_PLAY, 21, 5, None, S_PLAY_XDIE9), // S_PLAY_XDIE8
+    State::new(SPR_PLAY, 22, -1, None, S_NULL), // S_PLAY_XDIE9
+    State::new(SPR_POSS, 0, 10, Some(Action::Look), S_POSS_STND2), // S_POSS_STND
+    State::new(SPR_POSS, 1, 10, Some(Action::Look), S_POSS_STND), // S_POSS_STND2
+    State::new(SPR_POSS, 0, 4, Some(Action::Chase), S_POSS_RUN2), // S_POSS_RUN1
+    State::new(SPR_POSS, 0, 4, Some(Action::Chase), S_POSS_RUN3), // S_POSS_RUN2
+    State::new(SPR_POSS, 1, 4, Some(Action::Chase), S_POSS_RUN4), // S_POSS_RUN3
+    State::new(SPR_POSS, 1, 4, Some(Action::Chase), S_POSS_RUN5), // S_POSS_RUN4
+    State::new(SPR_POSS, 2, 4, Some(Action::Chase), S_POSS_RUN6), // S_POSS_RUN5
+    State::new(SPR_POSS, 2, 4, Some(Action::Chase), S_POSS_RUN7), // S_POSS_RUN6
+    State::new(SPR_POSS, 3, 4, Some(Action::Chase), S_POSS_RUN8), // S_POSS_RUN7
+    State::new(SPR_POSS, 3, 4, Some(Action::Chase), S_POSS_RUN1), // S_POSS_RUN8
+    State::new(SPR_POSS, 4, 10, Some(Action::FaceTarget), S_POSS_ATK2), // S_POSS_ATK1
+    State::new(SPR_POSS, 5, 8, Some(Action::PosAttack), S_POSS_ATK3), // S_POSS_ATK2
+    State::new(SPR_POSS, 4, 8, None, S_POSS_RUN1), // S_POSS_ATK3
+    State::new(SPR_POSS, 6, 3, None, S_POSS_PAIN2), // S_POSS_PAIN
+    State::new(SPR_POSS, 6, 3, Some(Action::Pain), S_POSS_RUN1), // S_POSS_PAIN2
+    State::new(SPR_POSS, 7, 5, None, S_POSS_DIE2), // S_POSS_DIE1
+    State::new(SPR_POSS, 8, 5, Some(Action::Scream), S_POSS_DIE3), // S_POSS_DIE2
+    State::new(SPR_POSS, 9, 5, Some(Action::Fall), S_POSS_DIE4), // S_POSS_DIE3
+    State::new(SPR_POSS, 10, 5, None, S_POSS_DIE5), // S_POSS_DIE4
+    State::new(SPR_POSS, 11, -1, None, S_NULL), // S_POSS_DIE5
+    State::new(SPR_POSS, 12, 5, None, S_POSS_XDIE2), // S_POSS_XDIE1
+    State::new(SPR_POSS, 13, 5, Some(Action::XScream), S_POSS_XDIE3), // S_POSS_XDIE2
+    State::new(SPR_POSS, 14, 5, Some(Action::Fall), S_POSS_XDIE4), // S_POSS_XDIE3
+    State::new(SPR_POSS, 15, 5, None, S_POSS_XDIE5), // S_POSS_XDIE4
+    State::new(SPR_POSS, 16, 5, None, S_POSS_XDIE6), // S_POSS_XDIE5
+    State::new(SPR_POSS, 17, 5, None, S_POSS_XDIE7), // S_POSS_XDIE6
+    State::new(SPR_POSS, 18, 5, None, S_POSS_XDIE8), // S_POSS_XDIE7
+    State::new(SPR_POSS, 19, 5, None, S_POSS_XDIE9), // S_POSS_XDIE8
+    State::new(SPR_POSS, 20, -1, None, S_NULL), // S_POSS_XDIE9
+    State::new(SPR_POSS, 10, 5, None, S_POSS_RAISE2), // S_POSS_RAISE1
+    State::new(SPR_POSS, 9, 5, None, S_POSS_RAISE3), // S_POSS_RAISE2
+    State::new(SPR_POSS, 8, 5, None, S_POSS_RAISE4), // S_POSS_RAISE3
+    State::new(SPR_POSS, 7, 5, None, S_POSS_RUN1), // S_POSS_RAISE4
+    State::new(SPR_SPOS, 0, 10, Some(Action::Look), S_SPOS_STND2), // S_SPOS_STND
+    State::new(SPR_SPOS, 1, 10, Some(Action::Look), S_SPOS_STND), // S_SPOS_STND2
+    State::new(SPR_SPOS, 0, 3, Some(Action::Chase), S_SPOS_RUN2), // S_SPOS_RUN1
+    State::new(SPR_SPOS, 0, 3, Some(Action::Chase), S_SPOS_RUN3), // S_SPOS_RUN2
+    State::new(SPR_SPOS, 1, 3, Some(Action::Chase), S_SPOS_RUN4), // S_SPOS_RUN3
+    State::new(SPR_SPOS, 1, 3, Some(Action::Chase), S_SPOS_RUN5), // S_SPOS_RUN4
+    State::new(SPR_SPOS, 2, 3, Some(Action::Chase), S_SPOS_RUN6), // S_SPOS_RUN5
+    State::new(SPR_SPOS, 2, 3, Some(Action::Chase), S_SPOS_RUN7), // S_SPOS_RUN6
+    State::new(SPR_SPOS, 3, 3, Some(Action::Chase), S_SPOS_RUN8), // S_SPOS_RUN7
+    State::new(SPR_SPOS, 3, 3, Some(Action::Chase), S_SPOS_RUN1), // S_SPOS_RUN8
+    State::new(SPR_SPOS, 4, 10, Some(Action::FaceTarget), S_SPOS_ATK2), // S_SPOS_ATK1
+    State::new(SPR_SPOS, 32773, 10, Some(Action::SPosAttack), S_SPOS_ATK3), // S_SPOS_ATK2
+    State::new(SPR_SPOS, 4, 10, None, S_SPOS_RUN1), // S_SPOS_ATK3
+    State::new(SPR_SPOS, 6, 3, None, S_SPOS_PAIN2), // S_SPOS_PAIN
+    State::new(SPR_SPOS, 6, 3, Some(Action::Pain), S_SPOS_RUN1), // S_SPOS_PAIN2
+    State::new(SPR_SPOS, 7, 5, None, S_SPOS_DIE2), // S_SPOS_DIE1
+    State::new(SPR_SPOS, 8, 5, Some(Action::Scream), S_SPOS_DIE3), // S_SPOS_DIE2
+    State::new(SPR_SPOS, 9, 5, Some(Action::Fall), S_SPOS_DIE4), // S_SPOS_DIE3
+    State::new(SPR_SPOS, 10, 5, None, S_SPOS_DIE5), // S_SPOS_DIE4
+    State::new(SPR_SPOS, 11, -1, None, S_NULL), // S_SPOS_DIE5
+    State::new(SPR_SPOS, 12, 5, None, S_SPOS_XDIE2), // S_SPOS_XDIE1
+    State::new(SPR_SPOS, 13, 5, Some(Action::XScream), S_SPOS_XDIE3), // S_SPOS_XDIE2
+    State::new(SPR_SPOS, 14, 5, Some(Action::Fall), S_SPOS_XDIE4), // S_SPOS_XDIE3
+    State::new(SPR_SPOS, 15, 5, None, S_SPOS_XDIE5), // S_SPOS_XDIE4
+    State::new(SPR_SPOS, 16, 5, None, S_SPOS_XDIE6), // S_SPOS_XDIE5
+    State::new(SPR_SPOS, 17, 5, None, S_SPOS_XDIE7), // S_SPOS_XDIE6
+    State::new(SPR_SPOS, 18, 5, None, S_SPOS_XDIE8), // S_SPOS_XDIE7
+    State::new(SPR_SPOS, 19, 5, None, S_SPOS_XDIE9), // S_SPOS_XDIE8
+    State::new(SPR_SPOS, 20, -1, None, S_NULL), // S_SPOS_XDIE9
+    State::new(SPR_SPOS, 11, 5, None, S_SPOS_RAISE2), // S_SPOS_RAISE1
+    State::new(SPR_SPOS, 10, 5, None, S_SPOS_RAISE3), // S_SPOS_RAISE2
+    State::new(SPR_SPOS, 9, 5, None, S_SPOS_RAISE4), // S_SPOS_RAISE3
+    State::new(SPR_SPOS, 8, 5, None, S_SPOS_RAISE5), // S_SPOS_RAISE4
+    State::new(SPR_SPOS, 7, 5, None, S_SPOS_RUN1), // S_SPOS_RAISE5
+    State::new(SPR_VILE, 0, 10, Some(Action::Look), S_VILE_STND2), // S_VILE_STND
+    State::new(SPR_VILE, 1, 10, Some(Action::Look), S_VILE_STND), // S_VILE_STND2
+    State::new(SPR_VILE, 0, 2, Some(Action::VileChase), S_VILE_RUN2), // S_VILE_RUN1
+    State::new(SPR_VILE, 0, 2, Some(Action::VileChase), S_VILE_RUN3), // S_VILE_RUN2
+    State::new(SPR_VILE, 1, 2, Some(Action::VileChase), S_VILE_RUN4), // S_VILE_RUN3
+    State::new(SPR_VILE, 1, 2, Some(Action::VileChase), S_VILE_RUN5), // S_VILE_RUN4
+    State::new(SPR_VILE, 2, 2, Some(Action::VileChase), S_VILE_RUN6), // S_VILE_RUN5
+    State::new(SPR_VILE, 2, 2, Some(Action::VileChase), S_VILE_RUN7), // S_VILE_RUN6
+    State::new(SPR_VILE, 3, 2, Some(Action::VileChase), S_VILE_RUN8), // S_VILE_RUN7
+    State::new(SPR_VILE, 3, 2, Some(Action::VileChase), S_VILE_RUN9), // S_VILE_RUN8
+    State::new(SPR_VILE, 4, 2, Some(Action::VileChase), S_VILE_RUN10), // S_VILE_RUN9
+    State::new(SPR_VILE, 4, 2, Some(Action::VileChase), S_VILE_RUN11), // S_VILE_RUN10
+    State::new(SPR_VILE, 5, 2, Some(Action::VileChase), S_VILE_RUN12), // S_VILE_RUN11
+    State::new(SPR_VILE, 5, 2, Some(Action::VileChase), S_VILE_RUN1), // S_VILE_RUN12
+    State::new(SPR_VILE, 32774, 0, Some(Action::VileStart), S_VILE_ATK2), // S_VILE_ATK1
+    State::new(SPR_VILE, 32774, 10, Some(Action::FaceTarget), S_VILE_ATK3), // S_VILE_ATK2
+    State::new(SPR_VILE, 32775, 8, Some(Action::VileTarget), S_VILE_ATK4), // S_VILE_ATK3
+    State::new(SPR_VILE, 32776, 8, Some(Action::FaceTarget), S_VILE_ATK5), // S_VILE_ATK4
+    State::new(SPR_VILE, 32777, 8, Some(Action::FaceTarget), S_VILE_ATK6), // S_VILE_ATK5
+    State::new(SPR_VILE, 32778, 8, Some(Action::FaceTarget), S_VILE_ATK7), // S_VILE_ATK6
+    State::new(SPR_VILE, 32779, 8, Some(Action::FaceTarget), S_VILE_ATK8), // S_VILE_ATK7
+    State::new(SPR_VILE, 32780, 8, Some(Action::FaceTarget), S_VILE_ATK9), // S_VILE_ATK8
+    State::new(SPR_VILE, 32781, 8, Some(Action::FaceTarget), S_VILE_ATK10), // S_VILE_ATK9
+    State::new(SPR_VILE, 32782, 8, Some(Action::VileAttack), S_VILE_ATK11), // S_VILE_ATK10
+    State::new(SPR_VILE, 32783, 20, None, S_VILE_RUN1), // S_VILE_ATK11
+    State::new(SPR_VILE, 32794, 10, None, S_VILE_HEAL2), // S_VILE_HEAL1
+    State::new(SPR_VILE, 32795, 10, None, S_VILE_HEAL3), // S_VILE_HEAL2
+    State::new(SPR_VILE, 32796, 10, None, S_VILE_RUN1), // S_VILE_HEAL3
+    State::new(SPR_VILE, 16, 5, None, S_VILE_PAIN2), // S_VILE_PAIN
+    State::new(SPR_VILE, 16, 5, Some(Action::Pain), S_VILE_RUN1), // S_VILE_PAIN2
+    State::new(SPR_VILE, 16, 7, None, S_VILE_DIE2), // S_VILE_DIE1
+    State::new(SPR_VILE, 17, 7, Some(Action::Scream), S_VILE_DIE3), // S_VILE_DIE2
+    State::new(SPR_VILE, 18, 7, Some(Action::Fall), S_VILE_DIE4), // S_VILE_DIE3
+    State::new(SPR_VILE, 19, 7, None, S_VILE_DIE5), // S_VILE_DIE4
+    State::new(SPR_VILE, 20, 7, None, S_VILE_DIE6), // S_VILE_DIE5
+    State::new(SPR_VILE, 21, 7, None, S_VILE_DIE7), // S_VILE_DIE6
+    State::new(SPR_VILE, 22, 7, None, S_VILE_DIE8), // S_VILE_DIE7
+    State::new(SPR_VILE, 23, 5, None, S_VILE_DIE9), // S_VILE_DIE8
+    State::new(SPR_VILE, 24, 5, None, S_VILE_DIE10), // S_VILE_DIE9
+    State::new(SPR_VILE, 25, -1, None, S_NULL), // S_VILE_DIE10
+    State::new(SPR_FIRE, 32768, 2, Some(Action::StartFire), S_FIRE2), // S_FIRE1
+    State::new(SPR_FIRE, 32769, 2, Some(Action::Fire), S_FIRE3), // S_FIRE2
+    State::new(SPR_FIRE, 32768, 2, Some(Action::Fire), S_FIRE4), // S_FIRE3
+    State::new(SPR_FIRE, 32769, 2, Some(Action::Fire), S_FIRE5), // S_FIRE4
+    State::new(SPR_FIRE, 32770, 2, Some(Action::FireCrackle), S_FIRE6), // S_FIRE5
+    State::new(SPR_FIRE, 32769, 2, Some(Action::Fire), S_FIRE7), // S_FIRE6
+    State::new(SPR_FIRE, 32770, 2, Some(Action::Fire), S_FIRE8), // S_FIRE7
+    State::new(SPR_FIRE, 32769, 2, Some(Action::Fire), S_FIRE9), // S_FIRE8
+    State::new(SPR_FIRE, 32770, 2, Some(Action::Fire), S_FIRE10), // S_FIRE9
+    State::new(SPR_FIRE, 32771, 2, Some(Action::Fire), S_FIRE11), // S_FIRE10
+    State::new(SPR_FIRE, 32770, 2, Some(Action::Fire), S_FIRE12), // S_FIRE11
+    State::new(SPR_FIRE, 32771, 2, Some(Action::Fire), S_FIRE13), // S_FIRE12
+    State::new(SPR_FIRE, 32770, 2, Some(Action::Fire), S_FIRE14), // S_FIRE13
+    State::new(SPR_FIRE, 32771, 2, Some(Action::Fire), S_FIRE15), // S_FIRE14
+    State::new(SPR_FIRE, 32772, 2, Some(Action::Fire), S_FIRE16), // S_FIRE15
+    State::new(SPR_FIRE, 32771, 2, Some(Action::Fire), S_FIRE17), // S_FIRE16
+    State::new(SPR_FIRE, 32772, 2, Some(Action::Fire), S_FIRE18), // S_FIRE17
+    State::new(SPR_FIRE, 32771, 2, Some(Action::Fire), S_FIRE19), // S_FIRE18
+    State::new(SPR_FIRE, 32772, 2, Some(Action::FireCrackle), S_FIRE20), // S_FIRE19
+    State::new(SPR_FIRE, 32773, 2, Some(Action::Fire), S_FIRE21), // S_FIRE20
+    State::new(SPR_FIRE, 32772, 2, Some(Action::Fire), S_FIRE22), // S_FIRE21
+    State::new(SPR_FIRE, 32773, 2, Some(Action::Fire), S_FIRE23), // S_FIRE22
+    State::new(SPR_FIRE, 32772, 2, Some(Action::Fire), S_FIRE24), // S_FIRE23
+    State::new(SPR_FIRE, 32773, 2, Some(Action::Fire), S_FIRE25), // S_FIRE24
+    State::new(SPR_FIRE, 32774, 2, Some(Action::Fire), S_FIRE26), // S_FIRE25
+    State::new(SPR_FIRE, 32775, 2, Some(Action::Fire), S_FIRE27), // S_FIRE26
+    State::new(SPR_FIRE, 32774, 2, Some(Action::Fire), S_FIRE28), // S_FIRE27
+    State::new(SPR_FIRE, 32775, 2, Some(Action::Fire), S_FIRE29), // S_FIRE28
+    State::new(SPR_FIRE, 32774, 2, Some(Action::Fire), S_FIRE30), // S_FIRE29
+    State::new(SPR_FIRE, 32775, 2, Some(Action::Fire), S_NULL), // S_FIRE30
+    State::new(SPR_PUFF, 1, 4, None, S_SMOKE2), // S_SMOKE1
+    State::new(SPR_PUFF, 2, 4, None, S_SMOKE3), // S_SMOKE2
+    State::new(SPR_PUFF, 1, 4, None, S_SMOKE4), // S_SMOKE3
+    State::new(SPR_PUFF, 2, 4, None, S_SMOKE5), // S_SMOKE4
+    State::new(SPR_PUFF, 3, 4, None, S_NULL), // S_SMOKE5
+    State::new(SPR_FATB, 32768, 2, Some(Action::Tracer), S_TRACER2), // S_TRACER
+    State::new(SPR_FATB, 32769, 2, Some(Action::Tracer), S_TRACER), // S_TRACER2
+    State::new(SPR_FBXP, 32768, 8, None, S_TRACEEXP2), // S_TRACEEXP1
+    State::new(SPR_FBXP, 32769, 6, None, S_TRACEEXP3), // S_TRACEEXP2
+    State::new(SPR_FBXP, 32770, 4, None, S_NULL), // S_TRACEEXP3
+    State::new(SPR_SKEL, 0, 10, Some(Action::Look), S_SKEL_STND2), // S_SKEL_STND
+    State::new(SPR_SKEL, 1, 10, Some(Action::Look), S_SKEL_STND), // S_SKEL_STND2
+    State::new(SPR_SKEL, 0, 2, Some(Action::Chase), S_SKEL_RUN2), // S_SKEL_RUN1
+    State::new(SPR_SKEL, 0, 2, Some(Action::Chase), S_SKEL_RUN3), // S_SKEL_RUN2
+    State::new(SPR_SKEL, 1, 2, Some(Action::Chase), S_SKEL_RUN4), // S_SKEL_RUN3
+    State::new(SPR_SKEL, 1, 2, Some(Action::Chase), S_SKEL_RUN5), // S_SKEL_RUN4
+    State::new(SPR_SKEL, 2, 2, Some(Action::Chase), S_SKEL_RUN6), // S_SKEL_RUN5
+    State::new(SPR_SKEL, 2, 2, Some(Action::Chase), S_SKEL_RUN7), // S_SKEL_RUN6
+    State::new(SPR_SKEL, 3, 2, Some(Action::Chase), S_SKEL_RUN8), // S_SKEL_RUN7
+    State::new(SPR_SKEL, 3, 2, Some(Action::Chase), S_SKEL_RUN9), // S_SKEL_RUN8
+    State::new(SPR_SKEL, 4, 2, Some(Action::Chase), S_SKEL_RUN10), // S_SKEL_RUN9
+    State::new(SPR_SKEL, 4, 2, Some(Action::Chase), S_SKEL_RUN11), // S_SKEL_RUN10
+    State::new(SPR_SKEL, 5, 2, Some(Action::Chase), S_SKEL_RUN12), // S_SKEL_RUN11
+    State::new(SPR_SKEL, 5, 2, Some(Action::Chase), S_SKEL_RUN1), // S_SKEL_RUN12
+    State::new(SPR_SKEL, 6, 0, Some(Action::FaceTarget), S_SKEL_FIST2), // S_SKEL_FIST1
+    State::new(SPR_SKEL, 6, 6, Some(Action::SkelWhoosh), S_SKEL_FIST3), // S_SKEL_FIST2
+    State::new(SPR_SKEL, 7, 6, Some(Action::FaceTarget), S_SKEL_FIST4), // S_SKEL_FIST3
+    State::new(SPR_SKEL, 8, 6, Some(Action::SkelFist), S_SKEL_RUN1), // S_SKEL_FIST4
+    State::new(SPR_SKEL, 32777, 0, Some(Action::FaceTarget), S_SKEL_MISS2), // S_SKEL_MISS1
+    State::new(SPR_SKEL, 32777, 10, Some(Action::FaceTarget), S_SKEL_MISS3), // S_SKEL_MISS2
+    State::new(SPR_SKEL, 10, 10, Some(Action::SkelMissile), S_SKEL_MISS4), // S_SKEL_MISS3
+    State::new(SPR_SKEL, 10, 10, Some(Action::FaceTarget), S_SKEL_RUN1), // S_SKEL_MISS4
+    State::new(SPR_SKEL, 11, 5, None, S_SKEL_PAIN2), // S_SKEL_PAIN
+    State::new(SPR_SKEL, 11, 5, Some(Action::Pain), S_SKEL_RUN1), // S_SKEL_PAIN2
+    State::new(SPR_SKEL, 11, 7, None, S_SKEL_DIE2), // S_SKEL_DIE1
+    State::new(SPR_SKEL, 12, 7, None, S_SKEL_DIE3), // S_SKEL_DIE2
+    State::new(SPR_SKEL, 13, 7, Some(Action::Scream), S_SKEL_DIE4), // S_SKEL_DIE3
+    State::new(SPR_SKEL, 14, 7, Some(Action::Fall), S_SKEL_DIE5), // S_SKEL_DIE4
+    State::new(SPR_SKEL, 15, 7, None, S_SKEL_DIE6), // S_SKEL_DIE5
+    State::new(SPR_SKEL, 16, -1, None, S_NULL), // S_SKEL_DIE6
+    State::new(SPR_SKEL, 16, 5, None, S_SKEL_RAISE2), // S_SKEL_RAISE1
+    State::new(SPR_SKEL, 15, 5, None, S_SKEL_RAISE3), // S_SKEL_RAISE2
+    State::new(SPR_SKEL, 14, 5, None, S_SKEL_RAISE4), // S_SKEL_RAISE3
+    State::new(SPR_SKEL, 13, 5, None, S_SKEL_RAISE5), // S_SKEL_RAISE4
+    State::new(SPR_SKEL, 12, 5, None, S_SKEL_RAISE6), // S_SKEL_RAISE5
+    State::new(SPR_SKEL, 11, 5, None, S_SKEL_RUN1), // S_SKEL_RAISE6
+    State::new(SPR_MANF, 32768, 4, None, S_FATSHOT2), // S_FATSHOT1
+    State::new(SPR_MANF, 32769, 4, None, S_FATSHOT1), // S_FATSHOT2
+    State::new(SPR_MISL, 32769, 8, None, S_FATSHOTX2), // S_FATSHOTX1
+    State::new(SPR_MISL, 32770, 6, None, S_FATSHOTX3), // S_FATSHOTX2
+    State::new(SPR_MISL, 32771, 4, None, S_NULL), // S_FATSHOTX3
+    State::new(SPR_FATT, 0, 15, Some(Action::Look), S_FATT_STND2), // S_FATT_STND
+    State::new(SPR_FATT, 1, 15, Some(Action::Look), S_FATT_STND), // S_FATT_STND2
+    State::new(SPR_FATT, 0, 4, Some(Action::Chase), S_FATT_RUN2), // S_FATT_RUN1
+    State::new(SPR_FATT, 0, 4, Some(Action::Chase), S_FATT_RUN3), // S_FATT_RUN2
+    State::new(SPR_FATT, 1, 4, Some(Action::Chase), S_FATT_RUN4), // S_FATT_RUN3
+    State::new(SPR_FATT, 1, 4, Some(Action::Chase), S_FATT_RUN5), // S_FATT_RUN4
+    State::new(SPR_FATT, 2, 4, Some(Action::Chase), S_FATT_RUN6), // S_FATT_RUN5
+    State::new(SPR_FATT, 2, 4, Some(Action::Chase), S_FATT_RUN7), // S_FATT_RUN6
+    State::new(SPR_FATT, 3, 4, Some(Action::Chase), S_FATT_RUN8), // S_FATT_RUN7
+    State::new(SPR_FATT, 3, 4, Some(Action::Chase), S_FATT_RUN9), // S_FATT_RUN8
+    State::new(SPR_FATT, 4, 4, Some(Action::Chase), S_FATT_RUN10), // S_FATT_RUN9
+    State::new(SPR_FATT, 4, 4, Some(Action::Chase), S_FATT_RUN11), // S_FATT_RUN10
+    State::new(SPR_FATT, 5, 4, Some(Action::Chase), S_FATT_RUN12), // S_FATT_RUN11
+    State::new(SPR_FATT, 5, 4, Some(Action::Chase), S_FATT_RUN1), // S_FATT_RUN12
+    State::new(SPR_FATT, 6, 20, Some(Action::FatRaise), S_FATT_ATK2), // S_FATT_ATK1
+    State::new(SPR_FATT, 32775, 10, Some(Action::FatAttack1), S_FATT_ATK3), // S_FATT_ATK2
+    State::new(SPR_FATT, 8, 5, Some(Action::FaceTarget), S_FATT_ATK4), // S_FATT_ATK3
+    State::new(SPR_FATT, 6, 5, Some(Action::FaceTarget), S_FATT_ATK5), // S_FATT_ATK4
+    State::new(SPR_FATT, 32775, 10, Some(Action::FatAttack2), S_FATT_ATK6), // S_FATT_ATK5
+    State::new(SPR_FATT, 8, 5, Some(Action::FaceTarget), S_FATT_ATK7), // S_FATT_ATK6
+    State::new(SPR_FATT, 6, 5, Some(Action::FaceTarget), S_FATT_ATK8), // S_FATT_ATK7
+    State::new(SPR_FATT, 32775, 10, Some(Action::FatAttack3), S_FATT_ATK9), // S_FATT_ATK8
+    State::new(SPR_FATT, 8, 5, Some(Action::FaceTarget), S_FATT_ATK10), // S_FATT_ATK9
+    State::new(SPR_FATT, 6, 5, Some(Action::FaceTarget), S_FATT_RUN1), // S_FATT_ATK10
+    State::new(SPR_FATT, 9, 3, None, S_FATT_PAIN2), // S_FATT_PAIN
+    State::new(SPR_FATT, 9, 3, Some(Action::Pain), S_FATT_RUN1), // S_FATT_PAIN2
+    State::new(SPR_FATT, 10, 6, None, S_FATT_DIE2), // S_FATT_DIE1
+    State::new(SPR_FATT, 11, 6, Some(Action::Scream), S_FATT_DIE3), // S_FATT_DIE2
+    State::new(SPR_FATT, 12, 6, Some(Action::Fall), S_FATT_DIE4), // S_FATT_DIE3
+    State::new(SPR_FATT, 13, 6, None, S_FATT_DIE5), // S_FATT_DIE4
+    State::new(SPR_FATT, 14, 6, None, S_FATT_DIE6), // S_FATT_DIE5
+    State::new(SPR_FATT, 15, 6, None, S_FATT_DIE7), // S_FATT_DIE6
+    State::new(SPR_FATT, 16, 6, None, S_FATT_DIE8), // S_FATT_DIE7
+    State::new(SPR_FATT, 17, 6, None, S_FATT_DIE9), // S_FATT_DIE8
+    State::new(SPR_FATT, 18, 6, None, S_FATT_DIE10), // S_FATT_DIE9
+    State::new(SPR_FATT, 19, -1, Some(Action::BossDeath), S_NULL), // S_FATT_DIE10
+    State::new(SPR_FATT, 17, 5, None, S_FATT_RAISE2), // S_FATT_RAISE1
+    State::new(SPR_FATT, 16, 5, None, S_FATT_RAISE3), // S_FATT_RAISE2
+    State::new(SPR_FATT, 15, 5, None, S_FATT_RAISE4), // S_FATT_RAISE3
+    State::new(SPR_FATT, 14, 5, None, S_FATT_RAISE5), // S_FATT_RAISE4
+    State::new(SPR_FATT, 13, 5, None, S_FATT_RAISE6), // S_FATT_RAISE5
+    State::new(SPR_FATT, 12, 5, None, S_FATT_RAISE7), // S_FATT_RAISE6
+    State::new(SPR_FATT, 11, 5, None, S_FATT_RAISE8), // S_FATT_RAISE7
+    State::new(SPR_FATT, 10, 5, None, S_FATT_RUN1), // S_FATT_RAISE8
+    State::new(SPR_CPOS, 0, 10, Some(Action::Look), S_CPOS_STND2), // S_CPOS_STND
+    State::new(SPR_CPOS, 1, 10, Some(Action::Look), S_CPOS_STND), // S_CPOS_STND2
+    State::new(SPR_CPOS, 0, 3, Some(Action::Chase), S_CPOS_RUN2), // S_CPOS_RUN1
+    State::new(SPR_CPOS, 0, 3, Some(Action::Chase), S_CPOS_RUN3), // S_CPOS_RUN2
+    State::new(SPR_CPOS, 1, 3, Some(Action::Chase), S_CPOS_RUN4), // S_CPOS_RUN3
+    State::new(SPR_CPOS, 1, 3, Some(Action::Chase), S_CPOS_RUN5), // S_CPOS_RUN4
+    State::new(SPR_CPOS, 2, 3, Some(Action::Chase), S_CPOS_RUN6), // S_CPOS_RUN5
+    State::new(SPR_CPOS, 2, 3, Some(Action::Chase), S_CPOS_RUN7), // S_CPOS_RUN6
+    State::new(SPR_CPOS, 3, 3, Some(Action::Chase), S_CPOS_RUN8), // S_CPOS_RUN7
+    State::new(SPR_CPOS, 3, 3, Some(Action::Chase), S_CPOS_RUN1), // S_CPOS_RUN8
+    State::new(SPR_CPOS, 4, 10, Some(Action::FaceTarget), S_CPOS_ATK2), // S_CPOS_ATK1
+    State::new(SPR_CPOS, 32773, 4, Some(Action::CPosAttack), S_CPOS_ATK3), // S_CPOS_ATK2
+    State::new(SPR_CPOS, 32772, 4, Some(Action::CPosAttack), S_CPOS_ATK4), // S_CPOS_ATK3
+    State::new(SPR_CPOS, 5, 1, Some(Action::CPosRefire), S_CPOS_ATK2), // S_CPOS_ATK4
+    State::new(SPR_CPOS, 6, 3, None, S_CPOS_PAIN2), // S_CPOS_PAIN
+    State::new(SPR_CPOS, 6, 3, Some(Action::Pain), S_CPOS_RUN1), // S_CPOS_PAIN2
+    State::new(SPR_CPOS, 7, 5, None, S_CPOS_DIE2), // S_CPOS_DIE1
+    State::new(SPR_CPOS, 8, 5, Some(Action::Scream), S_CPOS_DIE3), // S_CPOS_DIE2
+    State::new(SPR_CPOS, 9, 5, Some(Action::Fall), S_CPOS_DIE4), // S_CPOS_DIE3
+    State::new(SPR_CPOS, 10, 5, None, S_CPOS_DIE5), // S_CPOS_DIE4
+    State::new(SPR_CPOS, 11, 5, None, S_CPOS_DIE6), // S_CPOS_DIE5
+    State::new(SPR_CPOS, 12, 5, None, S_CPOS_DIE7), // S_CPOS_DIE6
+    State::new(SPR_CPOS, 13, -1, None, S_NULL), // S_CPOS_DIE7
+    State::new(SPR_CPOS, 14, 5, None, S_CPOS_XDIE2), // S_CPOS_XDIE1
+    State::new(SPR_CPOS, 15, 5, Some(Action::XScream), S_CPOS_XDIE3), // S_CPOS_XDIE2
+    State::new(SPR_CPOS, 16, 5, Some(Action::Fall), S_CPOS_XDIE4), // S_CPOS_XDIE3
+    State::new(SPR_CPOS, 17, 5, None, S_CPOS_XDIE5), // S_CPOS_XDIE4
+    State::new(SPR_CPOS, 18, 5, None, S_CPOS_XDIE6), // S_CPOS_XDIE5
+    State::new(SPR_CPOS, 19, -1, None, S_NULL), // S_CPOS_XDIE6
+    State::new(SPR_CPOS, 13, 5, None, S_CPOS_RAISE2), // S_CPOS_RAISE1
+    State::new(SPR_CPOS, 12, 5, None, S_CPOS_RAISE3), // S_CPOS_RAISE2
+    State::new(SPR_CPOS, 11, 5, None, S_CPOS_RAISE4), // S_CPOS_RAISE3
+    State::new(SPR_CPOS, 10, 5, None, S_CPOS_RAISE5), // S_CPOS_RAISE4
+    State::new(SPR_CPOS, 9, 5, None, S_CPOS_RAISE6), // S_CPOS_RAISE5
+    State::new(SPR_CPOS, 8, 5, None, S_CPOS_RAISE7), // S_CPOS_RAISE6
+    State::new(SPR_CPOS, 7, 5, None, S_CPOS_RUN1), // S_CPOS_RAISE7
+    State::new(SPR_TROO, 0, 10, Some(Action::Look), S_TROO_STND2), // S_TROO_STND
+    State::new(SPR_TROO, 1, 10, Some(Action::Look), S_TROO_STND), // S_TROO_STND2
+    State::new(SPR_TROO, 0, 3, Some(Action::Chase), S_TROO_RUN2), // S_TROO_RUN1
+    State::new(SPR_TROO, 0, 3, Some(Action::Chase), S_TROO_RUN3), // S_TROO_RUN2
+    State::new(SPR_TROO, 1, 3, Some(Action::Chase), S_TROO_RUN4), // S_TROO_RUN3
+    State::new(SPR_TROO, 1, 3, Some(Action::Chase), S_TROO_RUN5), // S_TROO_RUN4
+    State::new(SPR_TROO, 2, 3, Some(Action::Chase), S_TROO_RUN6), // S_TROO_RUN5
+    State::new(SPR_TROO, 2, 3, Some(Action::Chase), S_TROO_RUN7), // S_TROO_RUN6
+    State::new(SPR_TROO, 3, 3, Some(Action::Chase), S_TROO_RUN8), // S_TROO_RUN7
+    State::new(SPR_TROO, 3, 3, Some(Action::Chase), S_TROO_RUN1), // S_TROO_RUN8
+    State::new(SPR_TROO, 4, 8, Some(Action::FaceTarget), S_TROO_ATK2), // S_TROO_ATK1
+    State::new(SPR_TROO, 5, 8, Some(Action::FaceTarget), S_TROO_ATK3), // S_TROO_ATK2
+    State::new(SPR_TROO, 6, 6, Some(Action::TroopAttack), S_TROO_RUN1), // S_TROO_ATK3
+    State::new(SPR_TROO, 7, 2, None, S_TROO_PAIN2), // S_TROO_PAIN
+    State::new(SPR_TROO, 7, 2, Some(Action::Pain), S_TROO_RUN1), // S_TROO_PAIN2
+    State::new(SPR_TROO, 8, 8, None, S_TROO_DIE2), // S_TROO_DIE1
+    State::new(SPR_TROO, 9, 8, Some(Action::Scream), S_TROO_DIE3), // S_TROO_DIE2
+    State::new(SPR_TROO, 10, 6, None, S_TROO_DIE4), // S_TROO_DIE3
+    State::new(SPR_TROO, 11, 6, Some(Action::Fall), S_TROO_DIE5), // S_TROO_DIE4
+    State::new(SPR_TROO, 12, -1, None, S_NULL), // S_TROO_DIE5
+    State::new(SPR_TROO, 13, 5, None, S_TROO_XDIE2), // S_TROO_XDIE1
+    State::new(SPR_TROO, 14, 5, Some(Action::XScream), S_TROO_XDIE3), // S_TROO_XDIE2
+    State::new(SPR_TROO, 15, 5, None, S_TROO_XDIE4), // S_TROO_XDIE3
+    State::new(SPR_TROO, 16, 5, Some(Action::Fall), S_TROO_XDIE5), // S_TROO_XDIE4
+    State::new(SPR_TROO, 17, 5, None, S_TROO_XDIE6), // S_TROO_XDIE5
+    State::new(SPR_TROO, 18, 5, None, S_TROO_XDIE7), // S_TROO_XDIE6
+    State::new(SPR_TROO, 19, 5, None, S_TROO_XDIE8), // S_TROO_XDIE7
+    State::new(SPR_TROO, 20, -1, None, S_NULL), // S_TROO_XDIE8
+    State::new(SPR_TROO, 12, 8, None, S_TROO_RAISE2), // S_TROO_RAISE1
+    State::new(SPR_TROO, 11, 8, None, S_TROO_RAISE3), // S_TROO_RAISE2
+    State::new(SPR_TROO, 10, 6, None, S_TROO_RAISE4), // S_TROO_RAISE3
+    State::new(SPR_TROO, 9, 6, None, S_TROO_RAISE5), // S_TROO_RAISE4
+    State::new(SPR_TROO, 8, 6, None, S_TROO_RUN1), // S_TROO_RAISE5
+    State::new(SPR_SARG, 0, 10, Some(Action::Look), S_SARG_STND2), // S_SARG_STND
+    State::new(SPR_SARG, 1, 10, Some(Action::Look), S_SARG_STND), // S_SARG_STND2
+    State::new(SPR_SARG, 0, 2, Some(Action::Chase), S_SARG_RUN2), // S_SARG_RUN1
+    State::new(SPR_SARG, 0, 2, Some(Action::Chase), S_SARG_RUN3), // S_SARG_RUN2
+    State::new(SPR_SARG, 1, 2, Some(Action::Chase), S_SARG_RUN4), // S_SARG_RUN3
+    State::new(SPR_SARG, 1, 2, Some(Action::Chase), S_SARG_RUN5), // S_SARG_RUN4
+    State::new(SPR_SARG, 2, 2, Some(Action::Chase), S_SARG_RUN6), // S_SARG_RUN5
+    State::new(SPR_SARG, 2, 2, Some(Action::Chase), S_SARG_RUN7), // S_SARG_RUN6
+    State::new(SPR_SARG, 3, 2, Some(Action::Chase), S_SARG_RUN8), // S_SARG_RUN7
+    State::new(SPR_SARG, 3, 2, Some(Action::Chase), S_SARG_RUN1), // S_SARG_RUN8
+    State::new(SPR_SARG, 4, 8, Some(Action::FaceTarget), S_SARG_ATK2), // S_SARG_ATK1
+    State::new(SPR_SARG, 5, 8, Some(Action::FaceTarget), S_SARG_ATK3), // S_SARG_ATK2
+    State::new(SPR_SARG, 6, 8, Some(Action::SargAttack), S_SARG_RUN1), // S_SARG_ATK3
+    State::new(SPR_SARG, 7, 2, None, S_SARG_PAIN2), // S_SARG_PAIN
+    State::new(SPR_SARG, 7, 2, Some(Action::Pain), S_SARG_RUN1), // S_SARG_PAIN2
+    State::new(SPR_SARG, 8, 8, None, S_SARG_DIE2), // S_SARG_DIE1
+    State::new(SPR_SARG, 9, 8, Some(Action::Scream), S_SARG_DIE3), // S_SARG_DIE2
+    State::new(SPR_SARG, 10, 4, None, S_SARG_DIE4), // S_SARG_DIE3
+    State::new(SPR_SARG, 11, 4, Some(Action::Fall), S_SARG_DIE5), // S_SARG_DIE4
+    State::new(SPR_SARG, 12, 4, None, S_SARG_DIE6), // S_SARG_DIE5
+    State::new(SPR_SARG, 13, -1, None, S_NULL), // S_SARG_DIE6
+    State::new(SPR_SARG, 13, 5, None, S_SARG_RAISE2), // S_SARG_RAISE1
+    State::new(SPR_SARG, 12, 5, None, S_SARG_RAISE3), // S_SARG_RAISE2
+    State::new(SPR_SARG, 11, 5, None, S_SARG_RAISE4), // S_SARG_RAISE3
+    State::new(SPR_SARG, 10, 5, None, S_SARG_RAISE5), // S_SARG_RAISE4
+    State::new(SPR_SARG, 9, 5, None, S_SARG_RAISE6), // S_SARG_RAISE5
+    State::new(SPR_SARG, 8, 5, None, S_SARG_RUN1), // S_SARG_RAISE6
+    State::new(SPR_HEAD, 0, 10, Some(Action::Look), S_HEAD_STND), // S_HEAD_STND
+    State::new(SPR_HEAD, 0, 3, Some(Action::Chase), S_HEAD_RUN1), // S_HEAD_RUN1
+    State::new(SPR_HEAD, 1, 5, Some(Action::FaceTarget), S_HEAD_ATK2), // S_HEAD_ATK1
+    State::new(SPR_HEAD, 2, 5, Some(Action::FaceTarget), S_HEAD_ATK3), // S_HEAD_ATK2
+    State::new(SPR_HEAD, 32771, 5, Some(Action::HeadAttack), S_HEAD_RUN1), // S_HEAD_ATK3
+    State::new(SPR_HEAD, 4, 3, None, S_HEAD_PAIN2), // S_HEAD_PAIN
+    State::new(SPR_HEAD, 4, 3, Some(Action::Pain), S_HEAD_PAIN3), // S_HEAD_PAIN2
+    State::new(SPR_HEAD, 5, 6, None, S_HEAD_RUN1), // S_HEAD_PAIN3
+    State::new(SPR_HEAD, 6, 8, None, S_HEAD_DIE2), // S_HEAD_DIE1
+    State::new(SPR_HEAD, 7, 8, Some(Action::Scream), S_HEAD_DIE3), // S_HEAD_DIE2
+    State::new(SPR_HEAD, 8, 8, None, S_HEAD_DIE4), // S_HEAD_DIE3
+    State::new(SPR_HEAD, 9, 8, None, S_HEAD_DIE5), // S_HEAD_DIE4
+    State::new(SPR_HEAD, 10, 8, Some(Action::Fall), S_HEAD_DIE6), // S_HEAD_DIE5
+    State::new(SPR_HEAD, 11, -1, None, S_NULL), // S_HEAD_DIE6
+    State::new(SPR_HEAD, 11, 8, None, S_HEAD_RAISE2), // S_HEAD_RAISE1
+    State::new(SPR_HEAD, 10, 8, None, S_HEAD_RAISE3), // S_HEAD_RAISE2
+    State::new(SPR_HEAD, 9, 8, None, S_HEAD_RAISE4), // S_HEAD_RAISE3
+    State::new(SPR_HEAD, 8, 8, None, S_HEAD_RAISE5), // S_HEAD_RAISE4
+    State::new(SPR_HEAD, 7, 8, None, S_HEAD_RAISE6), // S_HEAD_RAISE5
+    State::new(SPR_HEAD, 6, 8, None, S_HEAD_RUN1), // S_HEAD_RAISE6
+    State::new(SPR_BAL7, 32768, 4, None, S_BRBALL2), // S_BRBALL1
+    State::new(SPR_BAL7, 32769, 4, None, S_BRBALL1), // S_BRBALL2
+    State::new(SPR_BAL7, 32770, 6, None, S_BRBALLX2), // S_BRBALLX1
+    State::new(SPR_BAL7, 32771, 6, None, S_BRBALLX3), // S_BRBALLX2
+    State::new(SPR_BAL7, 32772, 6, None, S_NULL), // S_BRBALLX3
+    State::new(SPR_BOSS, 0, 10, Some(Action::Look), S_BOSS_STND2), // S_BOSS_STND
+    State::new(SPR_BOSS, 1, 10, Some(Action::Look), S_BOSS_STND), // S_BOSS_STND2
+    State::new(SPR_BOSS, 0, 3, Some(Action::Chase), S_BOSS_RUN2), // S_BOSS_RUN1
+    State::new(SPR_BOSS, 0, 3, Some(Action::Chase), S_BOSS_RUN3), // S_BOSS_RUN2
+    State::new(SPR_BOSS, 1, 3, Some(Action::Chase), S_BOSS_RUN4), // S_BOSS_RUN3
+    State::new(SPR_BOSS, 1, 3, Some(Action::Chase), S_BOSS_RUN5), // S_BOSS_RUN4
+    State::new(SPR_BOSS, 2, 3, Some(Action::Chase), S_BOSS_RUN6), // S_BOSS_RUN5
+    State::new(SPR_BOSS, 2, 3, Some(Action::Chase), S_BOSS_RUN7), // S_BOSS_RUN6
+    State::new(SPR_BOSS, 3, 3, Some(Action::Chase), S_BOSS_RUN8), // S_BOSS_RUN7
+    State::new(SPR_BOSS, 3, 3, Some(Action::Chase), S_BOSS_RUN1), // S_BOSS_RUN8
+    State::new(SPR_BOSS, 4, 8, Some(Action::FaceTarget), S_BOSS_ATK2), // S_BOSS_ATK1
+    State::new(SPR_BOSS, 5, 8, Some(Action::FaceTarget), S_BOSS_ATK3), // S_BOSS_ATK2
+    State::new(SPR_BOSS, 6, 8, Some(Action::BruisAttack), S_BOSS_RUN1), // S_BOSS_ATK3
+    State::new(SPR_BOSS, 7, 2, None, S_BOSS_PAIN2), // S_BOSS_PAIN
+    State::new(SPR_BOSS, 7, 2, Some(Action::Pain), S_BOSS_RUN1), // S_BOSS_PAIN2
+    State::new(SPR_BOSS, 8, 8, None, S_BOSS_DIE2), // S_BOSS_DIE1
+    State::new(SPR_BOSS, 9, 8, Some(Action::Scream), S_BOSS_DIE3), // S_BOSS_DIE2
+    State::new(SPR_BOSS, 10, 8, None, S_BOSS_DIE4), // S_BOSS_DIE3
+    State::new(SPR_BOSS, 11, 8, Some(Action::Fall), S_BOSS_DIE5), // S_BOSS_DIE4
+    State::new(SPR_BOSS, 12, 8, None, S_BOSS_DIE6), // S_BOSS_DIE5
+    State::new(SPR_BOSS, 13, 8, None, S_BOSS_DIE7), // S_BOSS_DIE6
+    State::new(SPR_BOSS, 14, -1, Some(Action::BossDeath), S_NULL), // S_BOSS_DIE7
+    State::new(SPR_BOSS, 14, 8, None, S_BOSS_RAISE2), // S_BOSS_RAISE1
+    State::new(SPR_BOSS, 13, 8, None, S_BOSS_RAISE3), // S_BOSS_RAISE2
+    State::new(SPR_BOSS, 12, 8, None, S_BOSS_RAISE4), // S_BOSS_RAISE3
+    State::new(SPR_BOSS, 11, 8, None, S_BOSS_RAISE5), // S_BOSS_RAISE4
+    State::new(SPR_BOSS, 10, 8, None, S_BOSS_RAISE6), // S_BOSS_RAISE5
+    State::new(SPR_BOSS, 9, 8, None, S_BOSS_RAISE7), // S_BOSS_RAISE6
+    State::new(SPR_BOSS, 8, 8, None, S_BOSS_RUN1), // S_BOSS_RAISE7
+    State::new(SPR_BOS2, 0, 10, Some(Action::Look), S_BOS2_STND2), // S_BOS2_STND
+    State::new(SPR_BOS2, 1, 10, Some(Action::Look), S_BOS2_STND), // S_BOS2_STND2
+    State::new(SPR_BOS2, 0, 3, Some(Action::Chase), S_BOS2_RUN2), // S_BOS2_RUN1
+    State::new(SPR_BOS2, 0, 3, Some(Action::Chase), S_BOS2_RUN3), // S_BOS2_RUN2
+    State::new(SPR_BOS2, 1, 3, Some(Action::Chase), S_BOS2_RUN4), // S_BOS2_RUN3
+    State::new(SPR_BOS2, 1, 3, Some(Action::Chase), S_BOS2_RUN5), // S_BOS2_RUN4
+    State::new(SPR_BOS2, 2, 3, Some(Action::Chase), S_BOS2_RUN6), // S_BOS2_RUN5
+    State::new(SPR_BOS2, 2, 3, Some(Action::Chase), S_BOS2_RUN7), // S_BOS2_RUN6
+    State::new(SPR_BOS2, 3, 3, Some(Action::Chase), S_BOS2_RUN8), // S_BOS2_RUN7
+    State::new(SPR_BOS2, 3, 3, Some(Action::Chase), S_BOS2_RUN1), // S_BOS2_RUN8
+    State::new(SPR_BOS2, 4, 8, Some(Action::FaceTarget), S_BOS2_ATK2), // S_BOS2_ATK1
+    State::new(SPR_BOS2, 5, 8, Some(Action::FaceTarget), S_BOS2_ATK3), // S_BOS2_ATK2
+    State::new(SPR_BOS2, 6, 8, Some(Action::BruisAttack), S_BOS2_RUN1), // S_BOS2_ATK3
+    State::new(SPR_BOS2, 7, 2, None, S_BOS2_PAIN2), // S_BOS2_PAIN
+    State::new(SPR_BOS2, 7, 2, Some(Action::Pain), S_BOS2_RUN1), // S_BOS2_PAIN2
+    State::new(SPR_BOS2, 8, 8, None, S_BOS2_DIE2), // S_BOS2_DIE1
+    State::new(SPR_BOS2, 9, 8, Some(Action::Scream), S_BOS2_DIE3), // S_BOS2_DIE2
+    State::new(SPR_BOS2, 10, 8, None, S_BOS2_DIE4), // S_BOS2_DIE3
+    State::new(SPR_BOS2, 11, 8, Some(Action::Fall), S_BOS2_DIE5), // S_BOS2_DIE4
+    State::new(SPR_BOS2, 12, 8, None, S_BOS2_DIE6), // S_BOS2_DIE5
+    State::new(SPR_BOS2, 13, 8, None, S_BOS2_DIE7), // S_BOS2_DIE6
+    State::new(SPR_BOS2, 14, -1, None, S_NULL), // S_BOS2_DIE7
+    State::new(SPR_BOS2, 14, 8, None, S_BOS2_RAISE2), // S_BOS2_RAISE1
+    State::new(SPR_BOS2, 13, 8, None, S_BOS2_RAISE3), // S_BOS2_RAISE2
+    State::new(SPR_BOS2, 12, 8, None, S_BOS2_RAISE4), // S_BOS2_RAISE3
+    State::new(SPR_BOS2, 11, 8, None, S_BOS2_RAISE5), // S_BOS2_RAISE4
+    State::new(SPR_BOS2, 10, 8, None, S_BOS2_RAISE6), // S_BOS2_RAISE5
+    State::new(SPR_BOS2, 9, 8, None, S_BOS2_RAISE7), // S_BOS2_RAISE6
+    State::new(SPR_BOS2, 8, 8, None, S_BOS2_RUN1), // S_BOS2_RAISE7
+    State::new(SPR_SKUL, 32768, 10, Some(Action::Look), S_SKULL_STND2), // S_SKULL_STND
+    State::new(SPR_SKUL, 32769, 10, Some(Action::Look), S_SKULL_STND), // S_SKULL_STND2
+    State::new(SPR_SKUL, 32768, 6, Some(Action::Chase), S_SKULL_RUN2), // S_SKULL_RUN1
+    State::new(SPR_SKUL, 32769, 6, Some(Action::Chase), S_SKULL_RUN1), // S_SKULL_RUN2
+    State::new(SPR_SKUL, 32770, 10, Some(Action::FaceTarget), S_SKULL_ATK2), // S_SKULL_ATK1
+    State::new(SPR_SKUL, 32771, 4, Some(Action::SkullAttack), S_SKULL_ATK3), // S_SKULL_ATK2
+    State::new(SPR_SKUL, 32770, 4, None, S_SKULL_ATK4), // S_SKULL_ATK3
+    State::new(SPR_SKUL, 32771, 4, None, S_SKULL_ATK3), // S_SKULL_ATK4
+    State::new(SPR_SKUL, 32772, 3, None, S_SKULL_PAIN2), // S_SKULL_PAIN
+    State::new(SPR_SKUL, 32772, 6, Some(Action::Pain), S_SKULL_RUN1), // S_SKULL_PAIN2
+    State::new(SPR_SKUL, 32773, 6, None, S_SKULL_DIE2), // S_SKULL_DIE1
+    State::new(SPR_SKUL, 32774, 6, Some(Action::Scream), S_SKULL_DIE3), // S_SKULL_DIE2
+    State::new(SPR_SKUL, 32775, 6, None, S_SKULL_DIE4), // S_SKULL_DIE3
+    State::new(SPR_SKUL, 32776, 6, Some(Action::Fall), S_SKULL_DIE5), // S_SKULL_DIE4
+    State::new(SPR_SKUL, 9, 6, None, S_SKULL_DIE6), // S_SKULL_DIE5
+    State::new(SPR_SKUL, 10, 6, None, S_NULL), // S_SKULL_DIE6
+    State::new(SPR_SPID, 0, 10, Some(Action::Look), S_SPID_STND2), // S_SPID_STND
+    State::new(SPR_SPID, 1, 10, Some(Action::Look), S_SPID_STND), // S_SPID_STND2
+    State::new(SPR_SPID, 0, 3, Some(Action::Metal), S_SPID_RUN2), // S_SPID_RUN1
+    State::new(SPR_SPID, 0, 3, Some(Action::Chase), S_SPID_RUN3), // S_SPID_RUN2
+    State::new(SPR_SPID, 1, 3, Some(Action::Chase), S_SPID_RUN4), // S_SPID_RUN3
+    State::new(SPR_SPID, 1, 3, Some(Action::Chase), S_SPID_RUN5), // S_SPID_RUN4
+    State::new(SPR_SPID, 2, 3, Some(Action::Metal), S_SPID_RUN6), // S_SPID_RUN5
+    State::new(SPR_SPID, 2, 3, Some(Action::Chase), S_SPID_RUN7), // S_SPID_RUN6
+    State::new(SPR_SPID, 3, 3, Some(Action::Chase), S_SPID_RUN8), // S_SPID_RUN7
+    State::new(SPR_SPID, 3, 3, Some(Action::Chase), S_SPID_RUN9), // S_SPID_RUN8
+    State::new(SPR_SPID, 4, 3, Some(Action::Metal), S_SPID_RUN10), // S_SPID_RUN9
+    State::new(SPR_SPID, 4, 3, Some(Action::Chase), S_SPID_RUN11), // S_SPID_RUN10
+    State::new(SPR_SPID, 5, 3, Some(Action::Chase), S_SPID_RUN12), // S_SPID_RUN11
+    State::new(SPR_SPID, 5, 3, Some(Action::Chase), S_SPID_RUN1), // S_SPID_RUN12
+    State::new(SPR_SPID, 32768, 20, Some(Action::FaceTarget), S_SPID_ATK2), // S_SPID_ATK1
+    State::new(SPR_SPID, 32774, 4, Some(Action::SPosAttack), S_SPID_ATK3), // S_SPID_ATK2
+    State::new(SPR_SPID, 32775, 4, Some(Action::SPosAttack), S_SPID_ATK4), // S_SPID_ATK3
+    State::new(SPR_SPID, 32775, 1, Some(Action::SpidRefire), S_SPID_ATK2), // S_SPID_ATK4
+    State::new(SPR_SPID, 8, 3, None, S_SPID_PAIN2), // S_SPID_PAIN
+    State::new(SPR_SPID, 8, 3, Some(Action::Pain), S_SPID_RUN1), // S_SPID_PAIN2
+    State::new(SPR_SPID, 9, 20, Some(Action::Scream), S_SPID_DIE2), // S_SPID_DIE1
+    State::new(SPR_SPID, 10, 10, Some(Action::Fall), S_SPID_DIE3), // S_SPID_DIE2
+    State::new(SPR_SPID, 11, 10, None, S_SPID_DIE4), // S_SPID_DIE3
+    State::new(SPR_SPID, 12, 10, None, S_SPID_DIE5), // S_SPID_DIE4
+    State::new(SPR_SPID, 13, 10, None, S_SPID_DIE6), // S_SPID_DIE5
+    State::new(SPR_SPID, 14, 10, None, S_SPID_DIE7), // S_SPID_DIE6
+    State::new(SPR_SPID, 15, 10, None, S_SPID_DIE8), // S_SPID_DIE7
+    State::new(SPR_SPID, 16, 10, None, S_SPID_DIE9), // S_SPID_DIE8
+    State::new(SPR_SPID, 17, 10, None, S_SPID_DIE10), // S_SPID_DIE9
+    State::new(SPR_SPID, 18, 30, None, S_SPID_DIE11), // S_SPID_DIE10
+    State::new(SPR_SPID, 18, -1, Some(Action::BossDeath), S_NULL), // S_SPID_DIE11
+    State::new(SPR_BSPI, 0, 10, Some(Action::Look), S_BSPI_STND2), // S_BSPI_STND
+    State::new(SPR_BSPI, 1, 10, Some(Action::Look), S_BSPI_STND), // S_BSPI_STND2
+    State::new(SPR_BSPI, 0, 20, None, S_BSPI_RUN1), // S_BSPI_SIGHT
+    State::new(SPR_BSPI, 0, 3, Some(Action::BabyMetal), S_BSPI_RUN2), // S_BSPI_RUN1
+    State::new(SPR_BSPI, 0, 3, Some(Action::Chase), S_BSPI_RUN3), // S_BSPI_RUN2
+    State::new(SPR_BSPI, 1, 3, Some(Action::Chase), S_BSPI_RUN4), // S_BSPI_RUN3
+    State::new(SPR_BSPI, 1, 3, Some(Action::Chase), S_BSPI_RUN5), // S_BSPI_RUN4
+    State::new(SPR_BSPI, 2, 3, Some(Action::Chase), S_BSPI_RUN6), // S_BSPI_RUN5
+    State::new(SPR_BSPI, 2, 3, Some(Action::Chase), S_BSPI_RUN7), // S_BSPI_RUN6
+    State::new(SPR_BSPI, 3, 3, Some(Action::BabyMetal), S_BSPI_RUN8), // S_BSPI_RUN7
+    State::new(SPR_BSPI, 3, 3, Some(Action::Chase), S_BSPI_RUN9), // S_BSPI_RUN8
+    State::new(SPR_BSPI, 4, 3, Some(Action::Chase), S_BSPI_RUN10), // S_BSPI_RUN9
+    State::new(SPR_BSPI, 4, 3, Some(Action::Chase), S_BSPI_RUN11), // S_BSPI_RUN10
+    State::new(SPR_BSPI, 5, 3, Some(Action::Chase), S_BSPI_RUN12), // S_BSPI_RUN11
+    State::new(SPR_BSPI, 5, 3, Some(Action::Chase), S_BSPI_RUN1), // S_BSPI_RUN12
+    State::new(SPR_BSPI, 32768, 20, Some(Action::FaceTarget), S_BSPI_ATK2), // S_BSPI_ATK1
+    State::new(SPR_BSPI, 32774, 4, Some(Action::BspiAttack), S_BSPI_ATK3), // S_BSPI_ATK2
+    State::new(SPR_BSPI, 32775, 4, None, S_BSPI_ATK4), // S_BSPI_ATK3
+    State::new(SPR_BSPI, 32775, 1, Some(Action::SpidRefire), S_BSPI_ATK2), // S_BSPI_ATK4
+    State::new(SPR_BSPI, 8, 3, None, S_BSPI_PAIN2), // S_BSPI_PAIN
+    State::new(SPR_BSPI, 8, 3, Some(Action::Pain), S_BSPI_RUN1), // S_BSPI_PAIN2
+    State::new(SPR_BSPI, 9, 20, Some(Action::Scream), S_BSPI_DIE2), // S_BSPI_DIE1
+    State::new(SPR_BSPI, 10, 7, Some(Action::Fall), S_BSPI_DIE3), // S_BSPI_DIE2
+    State::new(SPR_BSPI, 11, 7, None, S_BSPI_DIE4), // S_BSPI_DIE3
+    State::new(SPR_BSPI, 12, 7, None, S_BSPI_DIE5), // S_BSPI_DIE4
+    State::new(SPR_BSPI, 13, 7, None, S_BSPI_DIE6), // S_BSPI_DIE5
+    State::new(SPR_BSPI, 14, 7, None, S_BSPI_DIE7), // S_BSPI_DIE6
+    State::new(SPR_BSPI, 15, -1, Some(Action::BossDeath), S_NULL), // S_BSPI_DIE7
+    State::new(SPR_BSPI, 15, 5, None, S_BSPI_RAISE2), // S_BSPI_RAISE1
+    State::new(SPR_BSPI, 14, 5, None, S_BSPI_RAISE3), // S_BSPI_RAISE2
+    State::new(SPR_BSPI, 13, 5, None, S_BSPI_RAISE4), // S_BSPI_RAISE3
+    State::new(SPR_BSPI, 12, 5, None, S_BSPI_RAISE5), // S_BSPI_RAISE4
+    State::new(SPR_BSPI, 11, 5, None, S_BSPI_RAISE6), // S_BSPI_RAISE5
+    State::new(SPR_BSPI, 10, 5, None, S_BSPI_RAISE7), // S_BSPI_RAISE6
+    State::new(SPR_BSPI, 9, 5, None, S_BSPI_RUN1), // S_BSPI_RAISE7
+    State::new(SPR_APLS, 32768, 5, None, S_ARACH_PLAZ2), // S_ARACH_PLAZ
+    State::new(SPR_APLS, 32769, 5, None, S_ARACH_PLAZ), // S_ARACH_PLAZ2
+    State::new(SPR_APBX, 32768, 5, None, S_ARACH_PLEX2), // S_ARACH_PLEX
+    State::new(SPR_APBX, 32769, 5, None, S_ARACH_PLEX3), // S_ARACH_PLEX2
+    State::new(SPR_APBX, 32770, 5, None, S_ARACH_PLEX4), // S_ARACH_PLEX3
+    State::new(SPR_APBX, 32771, 5, None, S_ARACH_PLEX5), // S_ARACH_PLEX4
+    State::new(SPR_APBX, 32772, 5, None, S_NULL), // S_ARACH_PLEX5
+    State::new(SPR_CYBR, 0, 10, Some(Action::Look), S_CYBER_STND2), // S_CYBER_STND
+    State::new(SPR_CYBR, 1, 10, Some(Action::Look), S_CYBER_STND), // S_CYBER_STND2
+    State::new(SPR_CYBR, 0, 3, Some(Action::Hoof), S_CYBER_RUN2), // S_CYBER_RUN1
+    State::new(SPR_CYBR, 0, 3, Some(Action::Chase), S_CYBER_RUN3), // S_CYBER_RUN2
+    State::new(SPR_CYBR, 1, 3, Some(Action::Chase), S_CYBER_RUN4), // S_CYBER_RUN3
+    State::new(SPR_CYBR, 1, 3, Some(Action::Chase), S_CYBER_RUN5), // S_CYBER_RUN4
+    State::new(SPR_CYBR, 2, 3, Some(Action::Chase), S_CYBER_RUN6), // S_CYBER_RUN5
+    State::new(SPR_CYBR, 2, 3, Some(Action::Chase), S_CYBER_RUN7), // S_CYBER_RUN6
+    State::new(SPR_CYBR, 3, 3, Some(Action::Metal), S_CYBER_RUN8), // S_CYBER_RUN7
+    State::new(SPR_CYBR, 3, 3, Some(Action::Chase), S_CYBER_RUN1), // S_CYBER_RUN8
+    State::new(SPR_CYBR, 4, 6, Some(Action::FaceTarget), S_CYBER_ATK2), // S_CYBER_ATK1
+    State::new(SPR_CYBR, 5, 12, Some(Action::CyberAttack), S_CYBER_ATK3), // S_CYBER_ATK2
+    State::new(SPR_CYBR, 4, 12, Some(Action::FaceTarget), S_CYBER_ATK4), // S_CYBER_ATK3
+    State::new(SPR_CYBR, 5, 12, Some(Action::CyberAttack), S_CYBER_ATK5), // S_CYBER_ATK4
+    State::new(SPR_CYBR, 4, 12, Some(Action::FaceTarget), S_CYBER_ATK6), // S_CYBER_ATK5
+    State::new(SPR_CYBR, 5, 12, Some(Action::CyberAttack), S_CYBER_RUN1), // S_CYBER_ATK6
+    State::new(SPR_CYBR, 6, 10, Some(Action::Pain), S_CYBER_RUN1), // S_CYBER_PAIN
+    State::new(SPR_CYBR, 7, 10, None, S_CYBER_DIE2), // S_CYBER_DIE1
+    State::new(SPR_CYBR, 8, 10, Some(Action::Scream), S_CYBER_DIE3), // S_CYBER_DIE2
+    State::new(SPR_CYBR, 9, 10, None, S_CYBER_DIE4), // S_CYBER_DIE3
+    State::new(SPR_CYBR, 10, 10, None, S_CYBER_DIE5), // S_CYBER_DIE4
+    State::new(SPR_CYBR, 11, 10, None, S_CYBER_DIE6), // S_CYBER_DIE5
+    State::new(SPR_CYBR, 12, 10, Some(Action::Fall), S_CYBER_DIE7), // S_CYBER_DIE6
+    State::new(SPR_CYBR, 13, 10, None, S_CYBER_DIE8), // S_CYBER_DIE7
+    State::new(SPR_CYBR, 14, 10, None, S_CYBER_DIE9), // S_CYBER_DIE8
+    State::new(SPR_CYBR, 15, 30, None, S_CYBER_DIE10), // S_CYBER_DIE9
+    State::new(SPR_CYBR, 15, -1, Some(Action::BossDeath), S_NULL), // S_CYBER_DIE10
+    State::new(SPR_PAIN, 0, 10, Some(Action::Look), S_PAIN_STND), // S_PAIN_STND
+    State::new(SPR_PAIN, 0, 3, Some(Action::Chase), S_PAIN_RUN2), // S_PAIN_RUN1
+    State::new(SPR_PAIN, 0, 3, Some(Action::Chase), S_PAIN_RUN3), // S_PAIN_RUN2
+    State::new(SPR_PAIN, 1, 3, Some(Action::Chase), S_PAIN_RUN4), // S_PAIN_RUN3
+    State::new(SPR_PAIN, 1, 3, Some(Action::Chase), S_PAIN_RUN5), // S_PAIN_RUN4
+    State::new(SPR_PAIN, 2, 3, Some(Action::Chase), S_PAIN_RUN6), // S_PAIN_RUN5
+    State::new(SPR_PAIN, 2, 3, Some(Action::Chase), S_PAIN_RUN1), // S_PAIN_RUN6
+    State::new(SPR_PAIN, 3, 5, Some(Action::FaceTarget), S_PAIN_ATK2), // S_PAIN_ATK1
+    State::new(SPR_PAIN, 4, 5, Some(Action::FaceTarget), S_PAIN_ATK3), // S_PAIN_ATK2
+    State::new(SPR_PAIN, 32773, 5, Some(Action::FaceTarget), S_PAIN_ATK4), // S_PAIN_ATK3
+    State::new(SPR_PAIN, 32773, 0, Some(Action::PainAttack), S_PAIN_RUN1), // S_PAIN_ATK4
+    State::new(SPR_PAIN, 6, 6, None, S_PAIN_PAIN2), // S_PAIN_PAIN
+    State::new(SPR_PAIN, 6, 6, Some(Action::Pain), S_PAIN_RUN1), // S_PAIN_PAIN2
+    State::new(SPR_PAIN, 32775, 8, None, S_PAIN_DIE2), // S_PAIN_DIE1
+    State::new(SPR_PAIN, 32776, 8, Some(Action::Scream), S_PAIN_DIE3), // S_PAIN_DIE2
+    State::new(SPR_PAIN, 32777, 8, None, S_PAIN_DIE4), // S_PAIN_DIE3
+    State::new(SPR_PAIN, 32778, 8, None, S_PAIN_DIE5), // S_PAIN_DIE4
+    State::new(SPR_PAIN, 32779, 8, Some(Action::PainDie), S_PAIN_DIE6), // S_PAIN_DIE5
+    State::new(SPR_PAIN, 32780, 8, None, S_NULL), // S_PAIN_DIE6
+    State::new(SPR_PAIN, 12, 8, None, S_PAIN_RAISE2), // S_PAIN_RAISE1
+    State::new(SPR_PAIN, 11, 8, None, S_PAIN_RAISE3), // S_PAIN_RAISE2
+    State::new(SPR_PAIN, 10, 8, None, S_PAIN_RAISE4), // S_PAIN_RAISE3
+    State::new(SPR_PAIN, 9, 8, None, S_PAIN_RAISE5), // S_PAIN_RAISE4
+    State::new(SPR_PAIN, 8, 8, None, S_PAIN_RAISE6), // S_PAIN_RAISE5
+    State::new(SPR_PAIN, 7, 8, None, S_PAIN_RUN1), // S_PAIN_RAISE6
+    State::new(SPR_SSWV, 0, 10, Some(Action::Look), S_SSWV_STND2), // S_SSWV_STND
+    State::new(SPR_SSWV, 1, 10, Some(Action::Look), S_SSWV_STND), // S_SSWV_STND2
+    State::new(SPR_SSWV, 0, 3, Some(Action::Chase), S_SSWV_RUN2), // S_SSWV_RUN1
+    State::new(SPR_SSWV, 0, 3, Some(Action::Chase), S_SSWV_RUN3), // S_SSWV_RUN2
+    State::new(SPR_SSWV, 1, 3, Some(Action::Chase), S_SSWV_RUN4), // S_SSWV_RUN3
+    State::new(SPR_SSWV, 1, 3, Some(Action::Chase), S_SSWV_RUN5), // S_SSWV_RUN4
+    State::new(SPR_SSWV, 2, 3, Some(Action::Chase), S_SSWV_RUN6), // S_SSWV_RUN5
+    State::new(SPR_SSWV, 2, 3, Some(Action::Chase), S_SSWV_RUN7), // S_SSWV_RUN6
+    State::new(SPR_SSWV, 3, 3, Some(Action::Chase), S_SSWV_RUN8), // S_SSWV_RUN7
+    State::new(SPR_SSWV, 3, 3, Some(Action::Chase), S_SSWV_RUN1), // S_SSWV_RUN8
+    State::new(SPR_SSWV, 4, 10, Some(Action::FaceTarget), S_SSWV_ATK2), // S_SSWV_ATK1
+    State::new(SPR_SSWV, 5, 10, Some(Action::FaceTarget), S_SSWV_ATK3), // S_SSWV_ATK2
+    State::new(SPR_SSWV, 32774, 4, Some(Action::CPosAttack), S_SSWV_ATK4), // S_SSWV_ATK3
+    State::new(SPR_SSWV, 5, 6, Some(Action::FaceTarget), S_SSWV_ATK5), // S_SSWV_ATK4
+    State::new(SPR_SSWV, 32774, 4, Some(Action::CPosAttack), S_SSWV_ATK6), // S_SSWV_ATK5
+    State::new(SPR_SSWV, 5, 1, Some(Action::CPosRefire), S_SSWV_ATK2), // S_SSWV_ATK6
+    State::new(SPR_SSWV, 7, 3, None, S_SSWV_PAIN2), // S_SSWV_PAIN
+    State::new(SPR_SSWV, 7, 3, Some(Action::Pain), S_SSWV_RUN1), // S_SSWV_PAIN2
+    State::new(SPR_SSWV, 8, 5, None, S_SSWV_DIE2), // S_SSWV_DIE1
+    State::new(SPR_SSWV, 9, 5, Some(Action::Scream), S_SSWV_DIE3), // S_SSWV_DIE2
+    State::new(SPR_SSWV, 10, 5, Some(Action::Fall), S_SSWV_DIE4), // S_SSWV_DIE3
+    State::new(SPR_SSWV, 11, 5, None, S_SSWV_DIE5), // S_SSWV_DIE4
+    State::new(SPR_SSWV, 12, -1, None, S_NULL), // S_SSWV_DIE5
+    State::new(SPR_SSWV, 13, 5, None, S_SSWV_XDIE2), // S_SSWV_XDIE1
+    State::new(SPR_SSWV, 14, 5, Some(Action::XScream), S_SSWV_XDIE3), // S_SSWV_XDIE2
+    State::new(SPR_SSWV, 15, 5, Some(Action::Fall), S_SSWV_XDIE4), // S_SSWV_XDIE3
+    State::new(SPR_SSWV, 16, 5, None, S_SSWV_XDIE5), // S_SSWV_XDIE4
+    State::new(SPR_SSWV, 17, 5, None, S_SSWV_XDIE6), // S_SSWV_XDIE5
+    State::new(SPR_SSWV, 18, 5, None, S_SSWV_XDIE7), // S_SSWV_XDIE6
+    State::new(SPR_SSWV, 19, 5, None, S_SSWV_XDIE8), // S_SSWV_XDIE7
+    State::new(SPR_SSWV, 20, 5, None, S_SSWV_XDIE9), // S_SSWV_XDIE8
+    State::new(SPR_SSWV, 21, -1, None, S_NULL), // S_SSWV_XDIE9
+    State::new(SPR_SSWV, 12, 5, None, S_SSWV_RAISE2), // S_SSWV_RAISE1
+    State::new(SPR_SSWV, 11, 5, None, S_SSWV_RAISE3), // S_SSWV_RAISE2
+    State::new(SPR_SSWV, 10, 5, None, S_SSWV_RAISE4), // S_SSWV_RAISE3
+    State::new(SPR_SSWV, 9, 5, None, S_SSWV_RAISE5), // S_SSWV_RAISE4
+    State::new(SPR_SSWV, 8, 5, None, S_SSWV_RUN1), // S_SSWV_RAISE5
+    State::new(SPR_KEEN, 0, -1, None, S_KEENSTND), // S_KEENSTND
+    State::new(SPR_KEEN, 0, 6, None, S_COMMKEEN2), // S_COMMKEEN
+    State::new(SPR_KEEN, 1, 6, None, S_COMMKEEN3), // S_COMMKEEN2
+    State::new(SPR_KEEN, 2, 6, Some(Action::Scream), S_COMMKEEN4), // S_COMMKEEN3
+    State::new(SPR_KEEN, 3, 6, None, S_COMMKEEN5), // S_COMMKEEN4
+    State::new(SPR_KEEN, 4, 6, None, S_COMMKEEN6), // S_COMMKEEN5
+    State::new(SPR_KEEN, 5, 6, None, S_COMMKEEN7), // S_COMMKEEN6
+    State::new(SPR_KEEN, 6, 6, None, S_COMMKEEN8), // S_COMMKEEN7
+    State::new(SPR_KEEN, 7, 6, None, S_COMMKEEN9), // S_COMMKEEN8
+    State::new(SPR_KEEN, 8, 6, None, S_COMMKEEN10), // S_COMMKEEN9
+    State::new(SPR_KEEN, 9, 6, None, S_COMMKEEN11), // S_COMMKEEN10
+    State::new(SPR_KEEN, 10, 6, Some(Action::KeenDie), S_COMMKEEN12), // S_COMMKEEN11
+    State::new(SPR_KEEN, 11, -1, None, S_NULL), // S_COMMKEEN12
+    State::new(SPR_KEEN, 12, 4, None, S_KEENPAIN2), // S_KEENPAIN
+    State::new(SPR_KEEN, 12, 8, Some(Action::Pain), S_KEENSTND), // S_KEENPAIN2
+    State::new(SPR_BBRN, 0, -1, None, S_NULL), // S_BRAIN
+    State::new(SPR_BBRN, 1, 36, Some(Action::BrainPain), S_BRAIN), // S_BRAIN_PAIN
+    State::new(SPR_BBRN, 0, 100, Some(Action::BrainScream), S_BRAIN_DIE2), // S_BRAIN_DIE1
+    State::new(SPR_BBRN, 0, 10, None, S_BRAIN_DIE3), // S_BRAIN_DIE2
+    State::new(SPR_BBRN, 0, 10, None, S_BRAIN_DIE4), // S_BRAIN_DIE3
+    State::new(SPR_BBRN, 0, -1, Some(Action::BrainDie), S_NULL), // S_BRAIN_DIE4
+    State::new(SPR_SSWV, 0, 10, Some(Action::Look), S_BRAINEYE), // S_BRAINEYE
+    State::new(SPR_SSWV, 0, 181, Some(Action::BrainAwake), S_BRAINEYE1), // S_BRAINEYESEE
+    State::new(SPR_SSWV, 0, 150, Some(Action::BrainSpit), S_BRAINEYE1), // S_BRAINEYE1
+    State::new(SPR_BOSF, 32768, 3, Some(Action::SpawnSound), S_SPAWN2), // S_SPAWN1
+    State::new(SPR_BOSF, 32769, 3, Some(Action::SpawnFly), S_SPAWN3), // S_SPAWN2
+    State::new(SPR_BOSF, 32770, 3, Some(Action::SpawnFly), S_SPAWN4), // S_SPAWN3
+    State::new(SPR_BOSF, 32771, 3, Some(Action::SpawnFly), S_SPAWN1), // S_SPAWN4
+    State::new(SPR_FIRE, 32768, 4, Some(Action::Fire), S_SPAWNFIRE2), // S_SPAWNFIRE1
+    State::new(SPR_FIRE, 32769, 4, Some(Action::Fire), S_SPAWNFIRE3), // S_SPAWNFIRE2
+    State::new(SPR_FIRE, 32770, 4, Some(Action::Fire), S_SPAWNFIRE4), // S_SPAWNFIRE3
+    State::new(SPR_FIRE, 32771, 4, Some(Action::Fire), S_SPAWNFIRE5), // S_SPAWNFIRE4
+    State::new(SPR_FIRE, 32772, 4, Some(Action::Fire), S_SPAWNFIRE6), // S_SPAWNFIRE5
+    State::new(SPR_FIRE, 32773, 4, Some(Action::Fire), S_SPAWNFIRE7), // S_SPAWNFIRE6
+    State::new(SPR_FIRE, 32774, 4, Some(Action::Fire), S_SPAWNFIRE8), // S_SPAWNFIRE7
+    State::new(SPR_FIRE, 32775, 4, Some(Action::Fire), S_NULL), // S_SPAWNFIRE8
+    State::new(SPR_MISL, 32769, 10, None, S_BRAINEXPLODE2), // S_BRAINEXPLODE1
+    State::new(SPR_MISL, 32770, 10, None, S_BRAINEXPLODE3), // S_BRAINEXPLODE2
+    State::new(SPR_MISL, 32771, 10, Some(Action::BrainExplode), S_NULL), // S_BRAINEXPLODE3
+    State::new(SPR_ARM1, 0, 6, None, S_ARM1A), // S_ARM1
+    State::new(SPR_ARM1, 32769, 7, None, S_ARM1), // S_ARM1A
+    State::new(SPR_ARM2, 0, 6, None, S_ARM2A), // S_ARM2
+    State::new(SPR_ARM2, 32769, 6, None, S_ARM2), // S_ARM2A
+    State::new(SPR_BAR1, 0, 6, None, S_BAR2), // S_BAR1
+    State::new(SPR_BAR1, 1, 6, None, S_BAR1), // S_BAR2
+    State::new(SPR_BEXP, 32768, 5, None, S_BEXP2), // S_BEXP
+    State::new(SPR_BEXP, 32769, 5, Some(Action::Scream), S_BEXP3), // S_BEXP2
+    State::new(SPR_BEXP, 32770, 5, None, S_BEXP4), // S_BEXP3
+    State::new(SPR_BEXP, 32771, 10, Some(Action::Explode), S_BEXP5), // S_BEXP4
+    State::new(SPR_BEXP, 32772, 10, None, S_NULL), // S_BEXP5
+    State::new(SPR_FCAN, 32768, 4, None, S_BBAR2), // S_BBAR1
+    State::new(SPR_FCAN, 32769, 4, None, S_BBAR3), // S_BBAR2
+    State::new(SPR_FCAN, 32770, 4, None, S_BBAR1), // S_BBAR3
+    State::new(SPR_BON1, 0, 6, None, S_BON1A), // S_BON1
+    State::new(SPR_BON1, 1, 6, None, S_BON1B), // S_BON1A
+    State::new(SPR_BON1, 2, 6, None, S_BON1C), // S_BON1B
+    State::new(SPR_BON1, 3, 6, None, S_BON1D), // S_BON1C
+    State::new(SPR_BON1, 2, 6, None, S_BON1E), // S_BON1D
+    State::new(SPR_BON1, 1, 6, None, S_BON1), // S_BON1E
+    State::new(SPR_BON2, 0, 6, None, S_BON2A), // S_BON2
+    State::new(SPR_BON2, 1, 6, None, S_BON2B), // S_BON2A
+    State::new(SPR_BON2, 2, 6, None, S_BON2C), // S_BON2B
+    State::new(SPR_BON2, 3, 6, None, S_BON2D), // S_BON2C
+    State::new(SPR_BON2, 2, 6, None, S_BON2E), // S_BON2D
+    State::new(SPR_BON2, 1, 6, None, S_BON2), // S_BON2E
+    State::new(SPR_BKEY, 0, 10, None, S_BKEY2), // S_BKEY
+    State::new(SPR_BKEY, 32769, 10, None, S_BKEY), // S_BKEY2
+    State::new(SPR_RKEY, 0, 10, None, S_RKEY2), // S_RKEY
+    State::new(SPR_RKEY, 32769, 10, None, S_RKEY), // S_RKEY2
+    State::new(SPR_YKEY, 0, 10, None, S_YKEY2), // S_YKEY
+    State::new(SPR_YKEY, 32769, 10, None, S_YKEY), // S_YKEY2
+    State::new(SPR_BSKU, 0, 10, None, S_BSKULL2), // S_BSKULL
+    State::new(SPR_BSKU, 32769, 10, None, S_BSKULL), // S_BSKULL2
+    State::new(SPR_RSKU, 0, 10, None, S_RSKULL2), // S_RSKULL
+    State::new(SPR_RSKU, 32769, 10, None, S_RSKULL), // S_RSKULL2
+    State::new(SPR_YSKU, 0, 10, None, S_YSKULL2), // S_YSKULL
+    State::new(SPR_YSKU, 32769, 10, None, S_YSKULL), // S_YSKULL2
+    State::new(SPR_STIM, 0, -1, None, S_NULL), // S_STIM
+    State::new(SPR_MEDI, 0, -1, None, S_NULL), // S_MEDI
+    State::new(SPR_SOUL, 32768, 6, None, S_SOUL2), // S_SOUL
+    State::new(SPR_SOUL, 32769, 6, None, S_SOUL3), // S_SOUL2
+    State::new(SPR_SOUL, 32770, 6, None, S_SOUL4), // S_SOUL3
+    State::new(SPR_SOUL, 32771, 6, None, S_SOUL5), // S_SOUL4
+    State::new(SPR_SOUL, 32770, 6, None, S_SOUL6), // S_SOUL5
+    State::new(SPR_SOUL, 32769, 6, None, S_SOUL), // S_SOUL6
+    State::new(SPR_PINV, 32768, 6, None, S_PINV2), // S_PINV
+    State::new(SPR_PINV, 32769, 6, None, S_PINV3), // S_PINV2
+    State::new(SPR_PINV, 32770, 6, None, S_PINV4), // S_PINV3
+    State::new(SPR_PINV, 32771, 6, None, S_PINV), // S_PINV4
+    State::new(SPR_PSTR, 32768, -1, None, S_NULL), // S_PSTR
+    State::new(SPR_PINS, 32768, 6, None, S_PINS2), // S_PINS
+    State::new(SPR_PINS, 32769, 6, None, S_PINS3), // S_PINS2
+    State::new(SPR_PINS, 32770, 6, None, S_PINS4), // S_PINS3
+    State::new(SPR_PINS, 32771, 6, None, S_PINS), // S_PINS4
+    State::new(SPR_MEGA, 32768, 6, None, S_MEGA2), // S_MEGA
+    State::new(SPR_MEGA, 32769, 6, None, S_MEGA3), // S_MEGA2
+    State::new(SPR_MEGA, 32770, 6, None, S_MEGA4), // S_MEGA3
+    State::new(SPR_MEGA, 32771, 6, None, S_MEGA), // S_MEGA4
+    State::new(SPR_SUIT, 32768, -1, None, S_NULL), // S_SUIT
+    State::new(SPR_PMAP, 32768, 6, None, S_PMAP2), // S_PMAP
+    State::new(SPR_PMAP, 32769, 6, None, S_PMAP3), // S_PMAP2
+    State::new(SPR_PMAP, 32770, 6, None, S_PMAP4), // S_PMAP3
+    State::new(SPR_PMAP, 32771, 6, None, S_PMAP5), // S_PMAP4
+    State::new(SPR_PMAP, 32770, 6, None, S_PMAP6), // S_PMAP5
+    State::new(SPR_PMAP, 32769, 6, None, S_PMAP), // S_PMAP6
+    State::new(SPR_PVIS, 32768, 6, None, S_PVIS2), // S_PVIS
+    State::new(SPR_PVIS, 1, 6, None, S_PVIS), // S_PVIS2
+    State::new(SPR_CLIP, 0, -1, None, S_NULL), // S_CLIP
+    State::new(SPR_AMMO, 0, -1, None, S_NULL), // S_AMMO
+    State::new(SPR_ROCK, 0, -1, None, S_NULL), // S_ROCK
+    State::new(SPR_BROK, 0, -1, None, S_NULL), // S_BROK
+    State::new(SPR_CELL, 0, -1, None, S_NULL), // S_CELL
+    State::new(SPR_CELP, 0, -1, None, S_NULL), // S_CELP
+    State::new(SPR_SHEL, 0, -1, None, S_NULL), // S_SHEL
+    State::new(SPR_SBOX, 0, -1, None, S_NULL), // S_SBOX
+    State::new(SPR_BPAK, 0, -1, None, S_NULL), // S_BPAK
+    State::new(SPR_BFUG, 0, -1, None, S_NULL), // S_BFUG
+    State::new(SPR_MGUN, 0, -1, None, S_NULL), // S_MGUN
+    State::new(SPR_CSAW, 0, -1, None, S_NULL), // S_CSAW
+    State::new(SPR_LAUN, 0, -1, None, S_NULL), // S_LAUN
+    State::new(SPR_PLAS, 0, -1, None, S_NULL), // S_PLAS
+    State::new(SPR_SHOT, 0, -1, None, S_NULL), // S_SHOT
+    State::new(SPR_SGN2, 0, -1, None, S_NULL), // S_SHOT2
+    State::new(SPR_COLU, 32768, -1, None, S_NULL), // S_COLU
+    State::new(SPR_SMT2, 0, -1, None, S_NULL), // S_STALAG
+    State::new(SPR_GOR1, 0, 10, None, S_BLOODYTWITCH2), // S_BLOODYTWITCH
+    State::new(SPR_GOR1, 1, 15, None, S_BLOODYTWITCH3), // S_BLOODYTWITCH2
+    State::new(SPR_GOR1, 2, 8, None, S_BLOODYTWITCH4), // S_BLOODYTWITCH3
+    State::new(SPR_GOR1, 1, 6, None, S_BLOODYTWITCH), // S_BLOODYTWITCH4
+    State::new(SPR_PLAY, 13, -1, None, S_NULL), // S_DEADTORSO
+    State::new(SPR_PLAY, 18, -1, None, S_NULL), // S_DEADBOTTOM
+    State::new(SPR_POL2, 0, -1, None, S_NULL), // S_HEADSONSTICK
+    State::new(SPR_POL5, 0, -1, None, S_NULL), // S_GIBS
+    State::new(SPR_POL4, 0, -1, None, S_NULL), // S_HEADONASTICK
+    State::new(SPR_POL3, 32768, 6, None, S_HEADCANDLES2), // S_HEADCANDLES
+    State::new(SPR_POL3, 32769, 6, None, S_HEADCANDLES), // S_HEADCANDLES2
+    State::new(SPR_POL1, 0, -1, None, S_NULL), // S_DEADSTICK
+    State::new(SPR_POL6, 0, 6, None, S_LIVESTICK2), // S_LIVESTICK
+    State::new(SPR_POL6, 1, 8, None, S_LIVESTICK), // S_LIVESTICK2
+    State::new(SPR_GOR2, 0, -1, None, S_NULL), // S_MEAT2
+    State::new(SPR_GOR3, 0, -1, None, S_NULL), // S_MEAT3
+    State::new(SPR_GOR4, 0, -1, None, S_NULL), // S_MEAT4
+    State::new(SPR_GOR5, 0, -1, None, S_NULL), // S_MEAT5
+    State::new(SPR_SMIT, 0, -1, None, S_NULL), // S_STALAGTITE
+    State::new(SPR_COL1, 0, -1, None, S_NULL), // S_TALLGRNCOL
+    State::new(SPR_COL2, 0, -1, None, S_NULL), // S_SHRTGRNCOL
+    State::new(SPR_COL3, 0, -1, None, S_NULL), // S_TALLREDCOL
+    State::new(SPR_COL4, 0, -1, None, S_NULL), // S_SHRTREDCOL
+    State::new(SPR_CAND, 32768, -1, None, S_NULL), // S_CANDLESTIK
+    State::new(SPR_CBRA, 32768, -1, None, S_NULL), // S_CANDELABRA
+    State::new(SPR_COL6, 0, -1, None, S_NULL), // S_SKULLCOL
+    State::new(SPR_TRE1, 0, -1, None, S_NULL), // S_TORCHTREE
+    State::new(SPR_TRE2, 0, -1, None, S_NULL), // S_BIGTREE
+    State::new(SPR_ELEC, 0, -1, None, S_NULL), // S_TECHPILLAR
+    State::new(SPR_CEYE, 32768, 6, None, S_EVILEYE2), // S_EVILEYE
+    State::new(SPR_CEYE, 32769, 6, None, S_EVILEYE3), // S_EVILEYE2
+    State::new(SPR_CEYE, 32770, 6, None, S_EVILEYE4), // S_EVILEYE3
+    State::new(SPR_CEYE, 32769, 6, None, S_EVILEYE), // S_EVILEYE4
+    State::new(SPR_FSKU, 32768, 6, None, S_FLOATSKULL2), // S_FLOATSKULL
+    State::new(SPR_FSKU, 32769, 6, None, S_FLOATSKULL3), // S_FLOATSKULL2
+    State::new(SPR_FSKU, 32770, 6, None, S_FLOATSKULL), // S_FLOATSKULL3
+    State::new(SPR_COL5, 0, 14, None, S_HEARTCOL2), // S_HEARTCOL
+    State::new(SPR_COL5, 1, 14, None, S_HEARTCOL), // S_HEARTCOL2
+    State::new(SPR_TBLU, 32768, 4, None, S_BLUETORCH2), // S_BLUETORCH
+    State::new(SPR_TBLU, 32769, 4, None, S_BLUETORCH3), // S_BLUETORCH2
+    State::new(SPR_TBLU, 32770, 4, None, S_BLUETORCH4), // S_BLUETORCH3
+    State::new(SPR_TBLU, 32771, 4, None, S_BLUETORCH), // S_BLUETORCH4
+    State::new(SPR_TGRN, 32768, 4, None, S_GREENTORCH2), // S_GREENTORCH
+    State::new(SPR_TGRN, 32769, 4, None, S_GREENTORCH3), // S_GREENTORCH2
+    State::new(SPR_TGRN, 32770, 4, None, S_GREENTORCH4), // S_GREENTORCH3
+    State::new(SPR_TGRN, 32771, 4, None, S_GREENTORCH), // S_GREENTORCH4
+    State::new(SPR_TRED, 32768, 4, None, S_REDTORCH2), // S_REDTORCH
+    State::new(SPR_TRED, 32769, 4, None, S_REDTORCH3), // S_REDTORCH2
+    State::new(SPR_TRED, 32770, 4, None, S_REDTORCH4), // S_REDTORCH3
+    State::new(SPR_TRED, 32771, 4, None, S_REDTORCH), // S_REDTORCH4
+    State::new(SPR_SMBT, 32768, 4, None, S_BTORCHSHRT2), // S_BTORCHSHRT
+    State::new(SPR_SMBT, 32769, 4, None, S_BTORCHSHRT3), // S_BTORCHSHRT2
+    State::new(SPR_SMBT, 32770, 4, None, S_BTORCHSHRT4), // S_BTORCHSHRT3
+    State::new(SPR_SMBT, 32771, 4, None, S_BTORCHSHRT), // S_BTORCHSHRT4
+    State::new(SPR_SMGT, 32768, 4, None, S_GTORCHSHRT2), // S_GTORCHSHRT
+    State::new(SPR_SMGT, 32769, 4, None, S_GTORCHSHRT3), // S_GTORCHSHRT2
+    State::new(SPR_SMGT, 32770, 4, None, S_GTORCHSHRT4), // S_GTORCHSHRT3
+    State::new(SPR_SMGT, 32771, 4, None, S_GTORCHSHRT), // S_GTORCHSHRT4
+    State::new(SPR_SMRT, 32768, 4, None, S_RTORCHSHRT2), // S_RTORCHSHRT
+    State::new(SPR_SMRT, 32769, 4, None, S_RTORCHSHRT3), // S_RTORCHSHRT2
+    State::new(SPR_SMRT, 32770, 4, None, S_RTORCHSHRT4), // S_RTORCHSHRT3
+    State::new(SPR_SMRT, 32771, 4, None, S_RTORCHSHRT), // S_RTORCHSHRT4
+    State::new(SPR_HDB1, 0, -1, None, S_NULL), // S_HANGNOGUTS
+    State::new(SPR_HDB2, 0, -1, None, S_NULL), // S_HANGBNOBRAIN
+    State::new(SPR_HDB3, 0, -1, None, S_NULL), // S_HANGTLOOKDN
+    State::new(SPR_HDB4, 0, -1, None, S_NULL), // S_HANGTSKULL
+    State::new(SPR_HDB5, 0, -1, None, S_NULL), // S_HANGTLOOKUP
+    State::new(SPR_HDB6, 0, -1, None, S_NULL), // S_HANGTNOBRAIN
+    State::new(SPR_POB1, 0, -1, None, S_NULL), // S_COLONGIBS
+    State::new(SPR_POB2, 0, -1, None, S_NULL), // S_SMALLPOOL
+    State::new(SPR_BRS1, 0, -1, None, S_NULL), // S_BRAINSTEM
+    State::new(SPR_TLMP, 32768, 4, None, S_TECHLAMP2), // S_TECHLAMP
+    State::new(SPR_TLMP, 32769, 4, None, S_TECHLAMP3), // S_TECHLAMP2
+    State::new(SPR_TLMP, 32770, 4, None, S_TECHLAMP4), // S_TECHLAMP3
+    State::new(SPR_TLMP, 32771, 4, None, S_TECHLAMP), // S_TECHLAMP4
+    State::new(SPR_TLP2, 32768, 4, None, S_TECH2LAMP2), // S_TECH2LAMP
+    State::new(SPR_TLP2, 32769, 4, None, S_TECH2LAMP3), // S_TECH2LAMP2
+    State::new(SPR_TLP2, 32770, 4, None, S_TECH2LAMP4), // S_TECH2LAMP3
+    State::new(SPR_TLP2, 32771, 4, None, S_TECH2LAMP), // S_TECH2LAMP4
+];
+
+pub type MobjType = usize;
+
+// mobjtype_t
+pub const MT_PLAYER: MobjType = 0;
+pub const MT_POSSESSED: MobjType = 1;
+pub const MT_SHOTGUY: MobjType = 2;
+pub const MT_VILE: MobjType = 3;
+pub const MT_FIRE: MobjType = 4;
+pub const MT_UNDEAD: MobjType = 5;
+pub const MT_TRACER: MobjType = 6;
+pub const MT_SMOKE: MobjType = 7;
+pub const MT_FATSO: MobjType = 8;
+pub const MT_FATSHOT: MobjType = 9;
+pub const MT_CHAINGUY: MobjType = 10;
+pub const MT_TROOP: MobjType = 11;
+pub const MT_SERGEANT: MobjType = 12;
+pub const MT_SHADOWS: MobjType = 13;
+pub const MT_HEAD: MobjType = 14;
+pub const MT_BRUISER: MobjType = 15;
+pub const MT_BRUISERSHOT: MobjType = 16;
+pub const MT_KNIGHT: MobjType = 17;
+pub const MT_SKULL: MobjType = 18;
+pub const MT_SPIDER: MobjType = 19;
+pub const MT_BABY: MobjType = 20;
+pub const MT_CYBORG: MobjType = 21;
+pub const MT_PAIN: MobjType = 22;
+pub const MT_WOLFSS: MobjType = 23;
+pub const MT_KEEN: MobjType = 24;
+pub const MT_BOSSBRAIN: MobjType = 25;
+pub const MT_BOSSSPIT: MobjType = 26;
+pub const MT_BOSSTARGET: MobjType = 27;
+pub const MT_SPAWNSHOT: MobjType = 28;
+pub const MT_SPAWNFIRE: MobjType = 29;
+pub const MT_BARREL: MobjType = 30;
+pub const MT_TROOPSHOT: MobjType = 31;
+pub const MT_HEADSHOT: MobjType = 32;
+pub const MT_ROCKET: MobjType = 33;
+pub const MT_PLASMA: MobjType = 34;
+pub const MT_BFG: MobjType = 35;
+pub const MT_ARACHPLAZ: MobjType = 36;
+pub const MT_PUFF: MobjType = 37;
+pub const MT_BLOOD: MobjType = 38;
+pub const MT_TFOG: MobjType = 39;
+pub const MT_IFOG: MobjType = 40;
+pub const MT_TELEPORTMAN: MobjType = 41;
+pub const MT_EXTRABFG: MobjType = 42;
+pub const MT_MISC0: MobjType = 43;
+pub const MT_MISC1: MobjType = 44;
+pub const MT_MISC2: MobjType = 45;
+pub const MT_MISC3: MobjType = 46;
+pub const MT_MISC4: MobjType = 47;
+pub const MT_MISC5: MobjType = 48;
+pub const MT_MISC6: MobjType = 49;
+pub const MT_MISC7: MobjType = 50;
+pub const MT_MISC8: MobjType = 51;
+pub const MT_MISC9: MobjType = 52;
+pub const MT_MISC10: MobjType = 53;
+pub const MT_MISC11: MobjType = 54;
+pub const MT_MISC12: MobjType = 55;
+pub const MT_INV: MobjType = 56;
+pub const MT_MISC13: MobjType = 57;
+pub const MT_INS: MobjType = 58;
+pub const MT_MISC14: MobjType = 59;
+pub const MT_MISC15: MobjType = 60;
+pub const MT_MISC16: MobjType = 61;
+pub const MT_MEGA: MobjType = 62;
+pub const MT_CLIP: MobjType = 63;
+pub const MT_MISC17: MobjType = 64;
+pub const MT_MISC18: MobjType = 65;
+pub const MT_MISC19: MobjType = 66;
+pub const MT_MISC20: MobjType = 67;
+pub const MT_MISC21: MobjType = 68;
+pub const MT_MISC22: MobjType = 69;
+pub const MT_MISC23: MobjType = 70;
+pub const MT_MISC24: MobjType = 71;
+pub const MT_MISC25: MobjType = 72;
+pub const MT_CHAINGUN: MobjType = 73;
+pub const MT_MISC26: MobjType = 74;
+pub const MT_MISC27: MobjType = 75;
+pub const MT_MISC28: MobjType = 76;
+pub const MT_SHOTGUN: MobjType = 77;
+pub const MT_SUPERSHOTGUN: MobjType = 78;
+pub const MT_MISC29: MobjType = 79;
+pub const MT_MISC30: MobjType = 80;
+pub const MT_MISC31: MobjType = 81;
+pub const MT_MISC32: MobjType = 82;
+pub const MT_MISC33: MobjType = 83;
+pub const MT_MISC34: MobjType = 84;
+pub const MT_MISC35: MobjType = 85;
+pub const MT_MISC36: MobjType = 86;
+pub const MT_MISC37: MobjType = 87;
+pub const MT_MISC38: MobjType = 88;
+pub const MT_MISC39: MobjType = 89;
+pub const MT_MISC40: MobjType = 90;
+pub const MT_MISC41: MobjType = 91;
+pub const MT_MISC42: MobjType = 92;
+pub const MT_MISC43: MobjType = 93;
+pub const MT_MISC44: MobjType = 94;
+pub const MT_MISC45: MobjType = 95;
+pub const MT_MISC46: MobjType = 96;
+pub const MT_MISC47: MobjType = 97;
+pub const MT_MISC48: MobjType = 98;
+pub const MT_MISC49: MobjType = 99;
+pub const MT_MISC50: MobjType = 100;
+pub const MT_MISC51: MobjType = 101;
+pub const MT_MISC52: MobjType = 102;
+pub const MT_MISC53: MobjType = 103;
+pub const MT_MISC54: MobjType = 104;
+pub const MT_MISC55: MobjType = 105;
+pub const MT_MISC56: MobjType = 106;
+pub const MT_MISC57: MobjType = 107;
+pub const MT_MISC58: MobjType = 108;
+pub const MT_MISC59: MobjType = 109;
+pub const MT_MISC60: MobjType = 110;
+pub const MT_MISC61: MobjType = 111;
+pub const MT_MISC62: MobjType = 112;
+pub const MT_MISC63: MobjType = 113;
+pub const MT_MISC64: MobjType = 114;
+pub const MT_MISC65: MobjType = 115;
+pub const MT_MISC66: MobjType = 116;
+pub const MT_MISC67: MobjType = 117;
+pub const MT_MISC68: MobjType = 118;
+pub const MT_MISC69: MobjType = 119;
+pub const MT_MISC70: MobjType = 120;
+pub const MT_MISC71: MobjType = 121;
+pub const MT_MISC72: MobjType = 122;
+pub const MT_MISC73: MobjType = 123;
+pub const MT_MISC74: MobjType = 124;
+pub const MT_MISC75: MobjType = 125;
+pub const MT_MISC76: MobjType = 126;
+pub const MT_MISC77: MobjType = 127;
+pub const MT_MISC78: MobjType = 128;
+pub const MT_MISC79: MobjType = 129;
+pub const MT_MISC80: MobjType = 130;
+pub const MT_MISC81: MobjType = 131;
+pub const MT_MISC82: MobjType = 132;
+pub const MT_MISC83: MobjType = 133;
+pub const MT_MISC84: MobjType = 134;
+pub const MT_MISC85: MobjType = 135;
+pub const MT_MISC86: MobjType = 136;
+pub const NUM_MOBJ_TYPES: usize = 137;
+
+/// The static properties of a kind of thing, see mobjinfo_t.
+pub struct MobjInfo {
+    // The thing type number used in the THINGS lump,
+    //  None for things only spawned by the game.
+    pub(crate) doomed_num: Option<i16>,
+    pub(crate) spawn_state: StateNum,
+    pub(crate) spawn_health: i32,
+    pub(crate) see_state: StateNum,
+    pub(crate) see_sound: SfxNum,
+    pub(crate) reaction_time: i32,
+    pub(crate) attack_sound: SfxNum,
+    pub(crate) pain_state: StateNum,
+    pub(crate) pain_chance: i32,
+    pub(crate) pain_sound: SfxNum,
+    pub(crate) melee_state: StateNum,
+    pub(crate) missile_state: StateNum,
+    pub(crate) death_state: StateNum,
+    pub(crate) xdeath_state: StateNum,
+    pub(crate) death_sound: SfxNum,
+    // Map units per step for monsters, fixed point per tic for missiles.
+    pub(crate) speed: i32,
+    pub(crate) radius: DoomRealNum,
+    pub(crate) height: DoomRealNum,
+    pub(crate) mass: i32,
+    pub(crate) damage: i32,
+    pub(crate) active_sound: SfxNum,
+    pub(crate) flags: u32,
+    pub(crate) raise_state: StateNum,
+}
+
+const fn units(value: i32) -> DoomRealNum {
+    from_fixed_bits(value << FRAC_BITS)
+}
+
+pub static MOBJ_INFO: [MobjInfo; NUM_MOBJ_TYPES] = [
+    // MT_PLAYER
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_PLAY,
+        spawn_health: 100,
+        see_state: S_PLAY_RUN1,
+        see_sound: SFX_NONE,
+        reaction_time: 0,
+        attack_sound: SFX_NONE,
+        pain_state: S_PLAY_PAIN,
+        pain_chance: 255,
+        pain_sound: SFX_PLPAIN,
+        melee_state: S_NULL,
+        missile_state: S_PLAY_ATK1,
+        death_state: S_PLAY_DIE1,
+        xdeath_state: S_PLAY_XDIE1,
+        death_sound: SFX_PLDETH,
+        speed: 0,
+        radius: units(16),
+        height: units(56),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_DROPOFF | MF_PICKUP | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_POSSESSED
+    MobjInfo {
+        doomed_num: Some(3004),
+        spawn_state: S_POSS_STND,
+        spawn_health: 20,
+        see_state: S_POSS_RUN1,
+        see_sound: SFX_POSIT1,
+        reaction_time: 8,
+        attack_sound: SFX_PISTOL,
+        pain_state: S_POSS_PAIN,
+        pain_chance: 200,
+        pain_sound: SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_POSS_ATK1,
+        death_state: S_POSS_DIE1,
+        xdeath_state: S_POSS_XDIE1,
+        death_sound: SFX_PODTH1,
+        speed: 8,
+        radius: units(20),
+        height: units(56),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_POSS_RAISE1,
+    },
+    // MT_SHOTGUY
+    MobjInfo {
+        doomed_num: Some(9),
+        spawn_state: S_SPOS_STND,
+        spawn_health: 30,
+        see_state: S_SPOS_RUN1,
+        see_sound: SFX_POSIT2,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_SPOS_PAIN,
+        pain_chance: 170,
+        pain_sound: SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SPOS_ATK1,
+        death_state: S_SPOS_DIE1,
+        xdeath_state: S_SPOS_XDIE1,
+        death_sound: SFX_PODTH2,
+        speed: 8,
+        radius: units(20),
+        height: units(56),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SPOS_RAISE1,
+    },
+    // MT_VILE
+    MobjInfo {
+        doomed_num: Some(64),
+        spawn_state: S_VILE_STND,
+        spawn_health: 700,
+        see_state: S_VILE_RUN1,
+        see_sound: SFX_VILSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_VILE_PAIN,
+        pain_chance: 10,
+        pain_sound: SFX_VIPAIN,
+        melee_state: S_NULL,
+        missile_state: S_VILE_ATK1,
+        death_state: S_VILE_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_VILDTH,
+        speed: 15,
+        radius: units(20),
+        height: units(56),
+        mass: 500,
+        damage: 0,
+        active_sound: SFX_VILACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_FIRE
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_FIRE1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_UNDEAD
+    MobjInfo {
+        doomed_num: Some(66),
+        spawn_state: S_SKEL_STND,
+        spawn_health: 300,
+        see_state: S_SKEL_RUN1,
+        see_sound: SFX_SKESIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_SKEL_PAIN,
+        pain_chance: 100,
+        pain_sound: SFX_POPAIN,
+        melee_state: S_SKEL_FIST1,
+        missile_state: S_SKEL_MISS1,
+        death_state: S_SKEL_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_SKEDTH,
+        speed: 10,
+        radius: units(20),
+        height: units(56),
+        mass: 500,
+        damage: 0,
+        active_sound: SFX_SKEACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SKEL_RAISE1,
+    },
+    // MT_TRACER
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_TRACER,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_SKEATK,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_TRACEEXP1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_BAREXP,
+        speed: 10 << FRAC_BITS,
+        radius: units(11),
+        height: units(8),
+        mass: 100,
+        damage: 10,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_SMOKE
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_SMOKE1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_FATSO
+    MobjInfo {
+        doomed_num: Some(67),
+        spawn_state: S_FATT_STND,
+        spawn_health: 600,
+        see_state: S_FATT_RUN1,
+        see_sound: SFX_MANSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_FATT_PAIN,
+        pain_chance: 80,
+        pain_sound: SFX_MNPAIN,
+        melee_state: S_NULL,
+        missile_state: S_FATT_ATK1,
+        death_state: S_FATT_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_MANDTH,
+        speed: 8,
+        radius: units(48),
+        height: units(64),
+        mass: 1000,
+        damage: 0,
+        active_sound: SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_FATT_RAISE1,
+    },
+    // MT_FATSHOT
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_FATSHOT1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_FATSHOTX1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 20 << FRAC_BITS,
+        radius: units(6),
+        height: units(8),
+        mass: 100,
+        damage: 8,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_CHAINGUY
+    MobjInfo {
+        doomed_num: Some(65),
+        spawn_state: S_CPOS_STND,
+        spawn_health: 70,
+        see_state: S_CPOS_RUN1,
+        see_sound: SFX_POSIT2,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_CPOS_PAIN,
+        pain_chance: 170,
+        pain_sound: SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_CPOS_ATK1,
+        death_state: S_CPOS_DIE1,
+        xdeath_state: S_CPOS_XDIE1,
+        death_sound: SFX_PODTH2,
+        speed: 8,
+        radius: units(20),
+        height: units(56),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_CPOS_RAISE1,
+    },
+    // MT_TROOP
+    MobjInfo {
+        doomed_num: Some(3001),
+        spawn_state: S_TROO_STND,
+        spawn_health: 60,
+        see_state: S_TROO_RUN1,
+        see_sound: SFX_BGSIT1,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_TROO_PAIN,
+        pain_chance: 200,
+        pain_sound: SFX_POPAIN,
+        melee_state: S_TROO_ATK1,
+        missile_state: S_TROO_ATK1,
+        death_state: S_TROO_DIE1,
+        xdeath_state: S_TROO_XDIE1,
+        death_sound: SFX_BGDTH1,
+        speed: 8,
+        radius: units(20),
+        height: units(56),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_BGACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_TROO_RAISE1,
+    },
+    // MT_SERGEANT
+    MobjInfo {
+        doomed_num: Some(3002),
+        spawn_state: S_SARG_STND,
+        spawn_health: 150,
+        see_state: S_SARG_RUN1,
+        see_sound: SFX_SGTSIT,
+        reaction_time: 8,
+        attack_sound: SFX_SGTATK,
+        pain_state: S_SARG_PAIN,
+        pain_chance: 180,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_SARG_ATK1,
+        missile_state: S_NULL,
+        death_state: S_SARG_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_SGTDTH,
+        speed: 10,
+        radius: units(30),
+        height: units(56),
+        mass: 400,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SARG_RAISE1,
+    },
+    // MT_SHADOWS
+    MobjInfo {
+        doomed_num: Some(58),
+        spawn_state: S_SARG_STND,
+        spawn_health: 150,
+        see_state: S_SARG_RUN1,
+        see_sound: SFX_SGTSIT,
+        reaction_time: 8,
+        attack_sound: SFX_SGTATK,
+        pain_state: S_SARG_PAIN,
+        pain_chance: 180,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_SARG_ATK1,
+        missile_state: S_NULL,
+        death_state: S_SARG_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_SGTDTH,
+        speed: 10,
+        radius: units(30),
+        height: units(56),
+        mass: 400,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_SHADOW | MF_COUNTKILL,
+        raise_state: S_SARG_RAISE1,
+    },
+    // MT_HEAD
+    MobjInfo {
+        doomed_num: Some(3005),
+        spawn_state: S_HEAD_STND,
+        spawn_health: 400,
+        see_state: S_HEAD_RUN1,
+        see_sound: SFX_CACSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_HEAD_PAIN,
+        pain_chance: 128,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_HEAD_ATK1,
+        death_state: S_HEAD_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_CACDTH,
+        speed: 8,
+        radius: units(31),
+        height: units(56),
+        mass: 400,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY | MF_COUNTKILL,
+        raise_state: S_HEAD_RAISE1,
+    },
+    // MT_BRUISER
+    MobjInfo {
+        doomed_num: Some(3003),
+        spawn_state: S_BOSS_STND,
+        spawn_health: 1000,
+        see_state: S_BOSS_RUN1,
+        see_sound: SFX_BRSSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_BOSS_PAIN,
+        pain_chance: 50,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_BOSS_ATK1,
+        missile_state: S_BOSS_ATK1,
+        death_state: S_BOSS_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_BRSDTH,
+        speed: 8,
+        radius: units(24),
+        height: units(64),
+        mass: 1000,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_BOSS_RAISE1,
+    },
+    // MT_BRUISERSHOT
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_BRBALL1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BRBALLX1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 15 << FRAC_BITS,
+        radius: units(6),
+        height: units(8),
+        mass: 100,
+        damage: 8,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_KNIGHT
+    MobjInfo {
+        doomed_num: Some(69),
+        spawn_state: S_BOS2_STND,
+        spawn_health: 500,
+        see_state: S_BOS2_RUN1,
+        see_sound: SFX_KNTSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_BOS2_PAIN,
+        pain_chance: 50,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_BOS2_ATK1,
+        missile_state: S_BOS2_ATK1,
+        death_state: S_BOS2_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_KNTDTH,
+        speed: 8,
+        radius: units(24),
+        height: units(64),
+        mass: 1000,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_BOS2_RAISE1,
+    },
+    // MT_SKULL
+    MobjInfo {
+        doomed_num: Some(3006),
+        spawn_state: S_SKULL_STND,
+        spawn_health: 100,
+        see_state: S_SKULL_RUN1,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_SKLATK,
+        pain_state: S_SKULL_PAIN,
+        pain_chance: 256,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SKULL_ATK1,
+        death_state: S_SKULL_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 8,
+        radius: units(16),
+        height: units(56),
+        mass: 50,
+        damage: 3,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_SPIDER
+    MobjInfo {
+        doomed_num: Some(7),
+        spawn_state: S_SPID_STND,
+        spawn_health: 3000,
+        see_state: S_SPID_RUN1,
+        see_sound: SFX_SPISIT,
+        reaction_time: 8,
+        attack_sound: SFX_SHOTGN,
+        pain_state: S_SPID_PAIN,
+        pain_chance: 40,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SPID_ATK1,
+        death_state: S_SPID_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_SPIDTH,
+        speed: 12,
+        radius: units(128),
+        height: units(100),
+        mass: 1000,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_BABY
+    MobjInfo {
+        doomed_num: Some(68),
+        spawn_state: S_BSPI_STND,
+        spawn_health: 500,
+        see_state: S_BSPI_SIGHT,
+        see_sound: SFX_BSPSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_BSPI_PAIN,
+        pain_chance: 128,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_BSPI_ATK1,
+        death_state: S_BSPI_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_BSPDTH,
+        speed: 12,
+        radius: units(64),
+        height: units(64),
+        mass: 600,
+        damage: 0,
+        active_sound: SFX_BSPACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_BSPI_RAISE1,
+    },
+    // MT_CYBORG
+    MobjInfo {
+        doomed_num: Some(16),
+        spawn_state: S_CYBER_STND,
+        spawn_health: 4000,
+        see_state: S_CYBER_RUN1,
+        see_sound: SFX_CYBSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_CYBER_PAIN,
+        pain_chance: 20,
+        pain_sound: SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_CYBER_ATK1,
+        death_state: S_CYBER_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_CYBDTH,
+        speed: 16,
+        radius: units(40),
+        height: units(110),
+        mass: 1000,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_PAIN
+    MobjInfo {
+        doomed_num: Some(71),
+        spawn_state: S_PAIN_STND,
+        spawn_health: 400,
+        see_state: S_PAIN_RUN1,
+        see_sound: SFX_PESIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_PAIN_PAIN,
+        pain_chance: 128,
+        pain_sound: SFX_PEPAIN,
+        melee_state: S_NULL,
+        missile_state: S_PAIN_ATK1,
+        death_state: S_PAIN_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_PEDTH,
+        speed: 8,
+        radius: units(31),
+        height: units(56),
+        mass: 400,
+        damage: 0,
+        active_sound: SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY | MF_COUNTKILL,
+        raise_state: S_PAIN_RAISE1,
+    },
+    // MT_WOLFSS
+    MobjInfo {
+        doomed_num: Some(84),
+        spawn_state: S_SSWV_STND,
+        spawn_health: 50,
+        see_state: S_SSWV_RUN1,
+        see_sound: SFX_SSSIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_SSWV_PAIN,
+        pain_chance: 170,
+        pain_sound: SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SSWV_ATK1,
+        death_state: S_SSWV_DIE1,
+        xdeath_state: S_SSWV_XDIE1,
+        death_sound: SFX_SSDTH,
+        speed: 8,
+        radius: units(20),
+        height: units(56),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SSWV_RAISE1,
+    },
+    // MT_KEEN
+    MobjInfo {
+        doomed_num: Some(72),
+        spawn_state: S_KEENSTND,
+        spawn_health: 100,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_KEENPAIN,
+        pain_chance: 256,
+        pain_sound: SFX_KEENPN,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_COMMKEEN,
+        xdeath_state: S_NULL,
+        death_sound: SFX_KEENDT,
+        speed: 0,
+        radius: units(16),
+        height: units(72),
+        mass: 10000000,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_BOSSBRAIN
+    MobjInfo {
+        doomed_num: Some(88),
+        spawn_state: S_BRAIN,
+        spawn_health: 250,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_BRAIN_PAIN,
+        pain_chance: 255,
+        pain_sound: SFX_BOSPN,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BRAIN_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_BOSDTH,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 10000000,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SHOOTABLE,
+        raise_state: S_NULL,
+    },
+    // MT_BOSSSPIT
+    MobjInfo {
+        doomed_num: Some(89),
+        spawn_state: S_BRAINEYE,
+        spawn_health: 1000,
+        see_state: S_BRAINEYESEE,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(32),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+        raise_state: S_NULL,
+    },
+    // MT_BOSSTARGET
+    MobjInfo {
+        doomed_num: Some(87),
+        spawn_state: S_NULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(32),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+        raise_state: S_NULL,
+    },
+    // MT_SPAWNSHOT
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_SPAWN1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_BOSPIT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 10 << FRAC_BITS,
+        radius: units(6),
+        height: units(32),
+        mass: 100,
+        damage: 3,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY | MF_NOCLIP,
+        raise_state: S_NULL,
+    },
+    // MT_SPAWNFIRE
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_SPAWNFIRE1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_BARREL
+    MobjInfo {
+        doomed_num: Some(2035),
+        spawn_state: S_BAR1,
+        spawn_health: 20,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BEXP,
+        xdeath_state: S_NULL,
+        death_sound: SFX_BAREXP,
+        speed: 0,
+        radius: units(10),
+        height: units(42),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_NOBLOOD,
+        raise_state: S_NULL,
+    },
+    // MT_TROOPSHOT
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_TBALL1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_TBALLX1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 10 << FRAC_BITS,
+        radius: units(6),
+        height: units(8),
+        mass: 100,
+        damage: 3,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_HEADSHOT
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_RBALL1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_RBALLX1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 10 << FRAC_BITS,
+        radius: units(6),
+        height: units(8),
+        mass: 100,
+        damage: 5,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_ROCKET
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_ROCKET,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_RLAUNC,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_EXPLODE1,
+        xdeath_state: S_NULL,
+        death_sound: SFX_BAREXP,
+        speed: 20 << FRAC_BITS,
+        radius: units(11),
+        height: units(8),
+        mass: 100,
+        damage: 20,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_PLASMA
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_PLASBALL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_PLASMA,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_PLASEXP,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 25 << FRAC_BITS,
+        radius: units(13),
+        height: units(8),
+        mass: 100,
+        damage: 5,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_BFG
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_BFGSHOT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BFGLAND,
+        xdeath_state: S_NULL,
+        death_sound: SFX_RXPLOD,
+        speed: 25 << FRAC_BITS,
+        radius: units(13),
+        height: units(8),
+        mass: 100,
+        damage: 100,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_ARACHPLAZ
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_ARACH_PLAZ,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_PLASMA,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_ARACH_PLEX,
+        xdeath_state: S_NULL,
+        death_sound: SFX_FIRXPL,
+        speed: 25 << FRAC_BITS,
+        radius: units(13),
+        height: units(8),
+        mass: 100,
+        damage: 5,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_PUFF
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_PUFF1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_BLOOD
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_BLOOD1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+    // MT_TFOG
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_TFOG,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_IFOG
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_IFOG,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_TELEPORTMAN
+    MobjInfo {
+        doomed_num: Some(14),
+        spawn_state: S_NULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+        raise_state: S_NULL,
+    },
+    // MT_EXTRABFG
+    MobjInfo {
+        doomed_num: None,
+        spawn_state: S_BFGEXP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC0
+    MobjInfo {
+        doomed_num: Some(2018),
+        spawn_state: S_ARM1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC1
+    MobjInfo {
+        doomed_num: Some(2019),
+        spawn_state: S_ARM2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC2
+    MobjInfo {
+        doomed_num: Some(2014),
+        spawn_state: S_BON1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC3
+    MobjInfo {
+        doomed_num: Some(2015),
+        spawn_state: S_BON2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC4
+    MobjInfo {
+        doomed_num: Some(5),
+        spawn_state: S_BKEY,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC5
+    MobjInfo {
+        doomed_num: Some(13),
+        spawn_state: S_RKEY,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC6
+    MobjInfo {
+        doomed_num: Some(6),
+        spawn_state: S_YKEY,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC7
+    MobjInfo {
+        doomed_num: Some(39),
+        spawn_state: S_YSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC8
+    MobjInfo {
+        doomed_num: Some(38),
+        spawn_state: S_RSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC9
+    MobjInfo {
+        doomed_num: Some(40),
+        spawn_state: S_BSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC10
+    MobjInfo {
+        doomed_num: Some(2011),
+        spawn_state: S_STIM,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC11
+    MobjInfo {
+        doomed_num: Some(2012),
+        spawn_state: S_MEDI,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC12
+    MobjInfo {
+        doomed_num: Some(2013),
+        spawn_state: S_SOUL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_INV
+    MobjInfo {
+        doomed_num: Some(2022),
+        spawn_state: S_PINV,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC13
+    MobjInfo {
+        doomed_num: Some(2023),
+        spawn_state: S_PSTR,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_INS
+    MobjInfo {
+        doomed_num: Some(2024),
+        spawn_state: S_PINS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC14
+    MobjInfo {
+        doomed_num: Some(2025),
+        spawn_state: S_SUIT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC15
+    MobjInfo {
+        doomed_num: Some(2026),
+        spawn_state: S_PMAP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC16
+    MobjInfo {
+        doomed_num: Some(2045),
+        spawn_state: S_PVIS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MEGA
+    MobjInfo {
+        doomed_num: Some(83),
+        spawn_state: S_MEGA,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_CLIP
+    MobjInfo {
+        doomed_num: Some(2007),
+        spawn_state: S_CLIP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC17
+    MobjInfo {
+        doomed_num: Some(2048),
+        spawn_state: S_AMMO,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC18
+    MobjInfo {
+        doomed_num: Some(2010),
+        spawn_state: S_ROCK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC19
+    MobjInfo {
+        doomed_num: Some(2046),
+        spawn_state: S_BROK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC20
+    MobjInfo {
+        doomed_num: Some(2047),
+        spawn_state: S_CELL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC21
+    MobjInfo {
+        doomed_num: Some(17),
+        spawn_state: S_CELP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC22
+    MobjInfo {
+        doomed_num: Some(2008),
+        spawn_state: S_SHEL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC23
+    MobjInfo {
+        doomed_num: Some(2049),
+        spawn_state: S_SBOX,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC24
+    MobjInfo {
+        doomed_num: Some(8),
+        spawn_state: S_BPAK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC25
+    MobjInfo {
+        doomed_num: Some(2006),
+        spawn_state: S_BFUG,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_CHAINGUN
+    MobjInfo {
+        doomed_num: Some(2002),
+        spawn_state: S_MGUN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC26
+    MobjInfo {
+        doomed_num: Some(2005),
+        spawn_state: S_CSAW,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC27
+    MobjInfo {
+        doomed_num: Some(2003),
+        spawn_state: S_LAUN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC28
+    MobjInfo {
+        doomed_num: Some(2004),
+        spawn_state: S_PLAS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_SHOTGUN
+    MobjInfo {
+        doomed_num: Some(2001),
+        spawn_state: S_SHOT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_SUPERSHOTGUN
+    MobjInfo {
+        doomed_num: Some(82),
+        spawn_state: S_SHOT2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC29
+    MobjInfo {
+        doomed_num: Some(85),
+        spawn_state: S_TECHLAMP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC30
+    MobjInfo {
+        doomed_num: Some(86),
+        spawn_state: S_TECH2LAMP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC31
+    MobjInfo {
+        doomed_num: Some(2028),
+        spawn_state: S_COLU,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC32
+    MobjInfo {
+        doomed_num: Some(30),
+        spawn_state: S_TALLGRNCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC33
+    MobjInfo {
+        doomed_num: Some(31),
+        spawn_state: S_SHRTGRNCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC34
+    MobjInfo {
+        doomed_num: Some(32),
+        spawn_state: S_TALLREDCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC35
+    MobjInfo {
+        doomed_num: Some(33),
+        spawn_state: S_SHRTREDCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC36
+    MobjInfo {
+        doomed_num: Some(37),
+        spawn_state: S_SKULLCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC37
+    MobjInfo {
+        doomed_num: Some(36),
+        spawn_state: S_HEARTCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC38
+    MobjInfo {
+        doomed_num: Some(41),
+        spawn_state: S_EVILEYE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC39
+    MobjInfo {
+        doomed_num: Some(42),
+        spawn_state: S_FLOATSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC40
+    MobjInfo {
+        doomed_num: Some(43),
+        spawn_state: S_TORCHTREE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC41
+    MobjInfo {
+        doomed_num: Some(44),
+        spawn_state: S_BLUETORCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC42
+    MobjInfo {
+        doomed_num: Some(45),
+        spawn_state: S_GREENTORCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC43
+    MobjInfo {
+        doomed_num: Some(46),
+        spawn_state: S_REDTORCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC44
+    MobjInfo {
+        doomed_num: Some(55),
+        spawn_state: S_BTORCHSHRT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC45
+    MobjInfo {
+        doomed_num: Some(56),
+        spawn_state: S_GTORCHSHRT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC46
+    MobjInfo {
+        doomed_num: Some(57),
+        spawn_state: S_RTORCHSHRT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC47
+    MobjInfo {
+        doomed_num: Some(47),
+        spawn_state: S_STALAGTITE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC48
+    MobjInfo {
+        doomed_num: Some(48),
+        spawn_state: S_TECHPILLAR,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC49
+    MobjInfo {
+        doomed_num: Some(34),
+        spawn_state: S_CANDLESTIK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC50
+    MobjInfo {
+        doomed_num: Some(35),
+        spawn_state: S_CANDELABRA,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC51
+    MobjInfo {
+        doomed_num: Some(49),
+        spawn_state: S_BLOODYTWITCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(68),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC52
+    MobjInfo {
+        doomed_num: Some(50),
+        spawn_state: S_MEAT2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(84),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC53
+    MobjInfo {
+        doomed_num: Some(51),
+        spawn_state: S_MEAT3,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(84),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC54
+    MobjInfo {
+        doomed_num: Some(52),
+        spawn_state: S_MEAT4,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(68),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC55
+    MobjInfo {
+        doomed_num: Some(53),
+        spawn_state: S_MEAT5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(52),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC56
+    MobjInfo {
+        doomed_num: Some(59),
+        spawn_state: S_MEAT2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(84),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC57
+    MobjInfo {
+        doomed_num: Some(60),
+        spawn_state: S_MEAT4,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(68),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC58
+    MobjInfo {
+        doomed_num: Some(61),
+        spawn_state: S_MEAT3,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(52),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC59
+    MobjInfo {
+        doomed_num: Some(62),
+        spawn_state: S_MEAT5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(52),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC60
+    MobjInfo {
+        doomed_num: Some(63),
+        spawn_state: S_BLOODYTWITCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(68),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC61
+    MobjInfo {
+        doomed_num: Some(22),
+        spawn_state: S_HEAD_DIE6,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC62
+    MobjInfo {
+        doomed_num: Some(15),
+        spawn_state: S_PLAY_DIE7,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC63
+    MobjInfo {
+        doomed_num: Some(18),
+        spawn_state: S_POSS_DIE5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC64
+    MobjInfo {
+        doomed_num: Some(21),
+        spawn_state: S_SARG_DIE6,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC65
+    MobjInfo {
+        doomed_num: Some(23),
+        spawn_state: S_SKULL_DIE6,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC66
+    MobjInfo {
+        doomed_num: Some(20),
+        spawn_state: S_TROO_DIE5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC67
+    MobjInfo {
+        doomed_num: Some(19),
+        spawn_state: S_SPOS_DIE5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC68
+    MobjInfo {
+        doomed_num: Some(10),
+        spawn_state: S_PLAY_XDIE9,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC69
+    MobjInfo {
+        doomed_num: Some(12),
+        spawn_state: S_PLAY_XDIE9,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC70
+    MobjInfo {
+        doomed_num: Some(28),
+        spawn_state: S_HEADSONSTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC71
+    MobjInfo {
+        doomed_num: Some(24),
+        spawn_state: S_GIBS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC72
+    MobjInfo {
+        doomed_num: Some(27),
+        spawn_state: S_HEADONASTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC73
+    MobjInfo {
+        doomed_num: Some(29),
+        spawn_state: S_HEADCANDLES,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC74
+    MobjInfo {
+        doomed_num: Some(25),
+        spawn_state: S_DEADSTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC75
+    MobjInfo {
+        doomed_num: Some(26),
+        spawn_state: S_LIVESTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC76
+    MobjInfo {
+        doomed_num: Some(54),
+        spawn_state: S_BIGTREE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(32),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC77
+    MobjInfo {
+        doomed_num: Some(70),
+        spawn_state: S_BBAR1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC78
+    MobjInfo {
+        doomed_num: Some(73),
+        spawn_state: S_HANGNOGUTS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(88),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC79
+    MobjInfo {
+        doomed_num: Some(74),
+        spawn_state: S_HANGBNOBRAIN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(88),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC80
+    MobjInfo {
+        doomed_num: Some(75),
+        spawn_state: S_HANGTLOOKDN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(64),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC81
+    MobjInfo {
+        doomed_num: Some(76),
+        spawn_state: S_HANGTSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(64),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC82
+    MobjInfo {
+        doomed_num: Some(77),
+        spawn_state: S_HANGTLOOKUP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(64),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC83
+    MobjInfo {
+        doomed_num: Some(78),
+        spawn_state: S_HANGTNOBRAIN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(16),
+        height: units(64),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC84
+    MobjInfo {
+        doomed_num: Some(79),
+        spawn_state: S_COLONGIBS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+    // MT_MISC85
+    MobjInfo {
+        doomed_num: Some(80),
+        spawn_state: S_SMALLPOOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+    // MT_MISC86
+    MobjInfo {
+        doomed_num: Some(81),
+        spawn_state: S_BRAINSTEM,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: SFX_NONE,
+        reaction_time: 8,
+        attack_sound: SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: SFX_NONE,
+        speed: 0,
+        radius: units(20),
+        height: units(16),
+        mass: 100,
+        damage: 0,
+        active_sound: SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+];
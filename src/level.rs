@@ -1,23 +1,280 @@
-mod nodes;
-mod segs;
+pub mod nodes;
+pub mod segs;
+pub mod sidedefs;
+pub mod linedefs;
+pub mod subsectors;
+pub mod things;
 pub mod blockmap;
 pub mod vertices;
 pub mod sectors;
+pub mod reject;
+pub mod node_builder;
+pub mod extended_nodes;
+pub mod udmf;
+#[cfg(test)]
+pub(crate) mod test_map;
 
-use crate::wad::{LumpStore, By};
-use crate::level::nodes::{Node, load_nodes};
-use crate::level::blockmap::{Blockmap};
+use crate::wad::{LumpStore, By, WadError};
+use crate::game_context::GameMode;
+use crate::rendering::RenderData;
+use crate::rendering::sky::sky_texture_name;
+use crate::types::{DoomRealNum, real, BOX_TOP, BOX_BOTTOM, BOX_LEFT, BOX_RIGHT, RealNum};
+use crate::level::nodes::{Node, BspTree};
+use crate::level::blockmap::Blockmap;
+use crate::level::vertices::Vertex;
+use crate::level::sectors::Sector;
+use crate::level::sidedefs::SideDef;
+use crate::level::linedefs::LineDef;
+use crate::level::segs::Seg;
+use crate::level::subsectors::SubSector;
+use crate::level::things::Thing;
+use crate::level::reject::Reject;
+
+// Lump order in a map WAD: each map needs a couple of lumps
+// to provide a complete scene geometry description.
+pub(crate) const ML_THINGS: usize = 1;
+pub(crate) const ML_LINEDEFS: usize = 2;
+pub(crate) const ML_SIDEDEFS: usize = 3;
+pub(crate) const ML_VERTEXES: usize = 4;
+pub(crate) const ML_SEGS: usize = 5;
+pub(crate) const ML_SSECTORS: usize = 6;
+pub(crate) const ML_NODES: usize = 7;
+pub(crate) const ML_SECTORS: usize = 8;
+pub(crate) const ML_REJECT: usize = 9;
+pub(crate) const ML_BLOCKMAP: usize = 10;
+// Hexen: the compiled scripts.
+pub(crate) const ML_BEHAVIOR: usize = 11;
+
+/// The layout the map was loaded from.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MapFormat {
+    Doom,
+    // LINEDEFS with action specials and THINGS with tids,
+    //  told apart by the BEHAVIOR lump.
+    Hexen,
+    Udmf,
+}
 
 pub struct Level {
-    nodes: Vec<Node>,
+    pub(crate) vertices: Vec<Vertex>,
+    pub(crate) sectors: Vec<Sector>,
+    pub(crate) sides: Vec<SideDef>,
+    pub(crate) lines: Vec<LineDef>,
+    pub(crate) segs: Vec<Seg>,
+    pub(crate) subsectors: Vec<SubSector>,
+    pub(crate) nodes: Vec<Node>,
+    pub(crate) things: Vec<Thing>,
+    pub(crate) reject: Reject,
+    pub(crate) blockmap: Blockmap,
+    pub(crate) format: MapFormat,
+    pub(crate) sky_texture: usize,
+    // The flat marking sky ceilings, skyflatnum.
+    pub(crate) sky_flat: usize,
+}
+
+impl Level {
+    // R_PointInSubsector
+    // Returns the index of the subsector containing the point.
+    pub fn point_in_subsector(&self, x: DoomRealNum, y: DoomRealNum) -> usize {
+        nodes::point_in_subsector(&self.nodes, x, y)
+    }
+
+    /// Returns the index of the sector containing the point.
+    pub fn point_in_sector(&self, x: DoomRealNum, y: DoomRealNum) -> usize {
+        self.subsectors[self.point_in_subsector(x, y)].sector
+    }
+}
+
+/// Returns the name of the map marker lump, E1M1 style for the
+/// episodic games and MAP01 style for commercial ones.
+pub fn map_lump_name(mode: &GameMode, episode: u32, map: u32) -> String {
+    match mode {
+        GameMode::Commercial => format!("MAP{:02}", map),
+        _ => format!("E{}M{}", episode, map),
+    }
 }
 
-pub fn load(lumps: &LumpStore, episode: u32, map: u32) -> Level {
-    /*let lump_num = lumps.get_lump_number(&format!("map{:02}", map)).unwrap();
+// P_SetupLevel
+pub fn load(lumps: &LumpStore, render_data: &RenderData, mode: &GameMode, episode: u32, map: u32) -> Result<Level, WadError> {
+    let map_name = map_lump_name(mode, episode, map);
+    let map_lump = lumps.get_lump_number(&map_name)
+        .ok_or(WadError::MissingLump(map_name))?;
 
-    Level {
-        blockmap: load_blockmap(lumps.get_lump(By::Number(lump_num + 10))),
-        nodes: load_nodes(lumps.get_lump(By::Number(lump_num + 7)))
-    }*/
-    todo!();
-}
\ No newline at end of file
+    // UDMF maps have their lumps between the marker and ENDMAP,
+    //  binary maps at fixed offsets from the marker
+    let textmap_lump = Some(map_lump.offset(1))
+        .filter(|&lump_number| matches!(lumps.get_lump_name(lump_number), Ok("TEXTMAP")));
+    let find_lump = |offset: usize, name: &str| match textmap_lump {
+        Some(_) => udmf::find_lump(lumps, map_lump, name),
+        None => Some(map_lump.offset(offset))
+            .filter(|&lump_number| matches!(lumps.get_lump_name(lump_number), Ok(lump_name) if lump_name == name)),
+    };
+    let format = if textmap_lump.is_some() {
+        MapFormat::Udmf
+    } else if find_lump(ML_BEHAVIOR, "BEHAVIOR").is_some() {
+        MapFormat::Hexen
+    } else {
+        MapFormat::Doom
+    };
+
+    // note: most of this ordering is important
+    let (mut vertices, mut sectors, sides, lines, things) = match textmap_lump {
+        Some(textmap_lump) => {
+            let map = udmf::load(lumps, textmap_lump, render_data)?;
+            (map.vertices, map.sectors, map.sides, map.lines, map.things)
+        }
+        None => {
+            let vertices = vertices::load(lumps, map_lump)?;
+            let sectors = sectors::load(lumps, map_lump, &render_data.flats)?;
+            let sides = sidedefs::load(lumps, map_lump, &render_data.texture, sectors.len())?;
+            let (lines, things) = match format {
+                MapFormat::Hexen => (
+                    linedefs::load_hexen(lumps, map_lump, &vertices, &sides)?,
+                    things::load_hexen(lumps, map_lump)?,
+                ),
+                _ => (
+                    linedefs::load(lumps, map_lump, &vertices, &sides)?,
+                    things::load(lumps, map_lump)?,
+                ),
+            };
+            (vertices, sectors, sides, lines, things)
+        }
+    };
+
+    // the blockmap lists are checked against the lines,
+    //  or built from them
+    let blockmap = blockmap::load(lumps, find_lump(ML_BLOCKMAP, "BLOCKMAP"), &lines, &vertices)?;
+
+    let tree = match textmap_lump {
+        Some(_) => match udmf::find_lump(lumps, map_lump, "ZNODES") {
+            Some(znodes) => extended_nodes::load_znodes(lumps.get_lump(By::Number(znodes))?, &mut vertices, &lines, &sides)?,
+            None => None,
+        },
+        None => match extended_nodes::node_format(lumps, map_lump) {
+            Some(format) => Some(extended_nodes::load(format, lumps, map_lump, &mut vertices, &lines, &sides)?),
+            None if node_builder::has_nodes(lumps, map_lump) => Some(BspTree {
+                segs: segs::load(lumps, map_lump, &vertices, &lines, &sides)?,
+                subsectors: subsectors::load(lumps, map_lump)?,
+                nodes: nodes::load(lumps, map_lump)?,
+            }),
+            None => None,
+        },
+    };
+    // maps that have not been through a node builder
+    let BspTree { segs, mut subsectors, nodes } = match tree {
+        Some(tree) => tree,
+        None => node_builder::build(&mut vertices, &lines, &sides)?,
+    };
+    nodes::check_children(&nodes, subsectors.len())?;
+    let reject = reject::load(lumps, find_lump(ML_REJECT, "REJECT"), sectors.len());
+
+    group_lines(&mut sectors, &mut subsectors, &lines, &segs, &vertices, &blockmap)?;
+
+    let sky_name = sky_texture_name(mode, episode, map);
+    let sky_texture = render_data.texture.check_texture_num_for_name(sky_name)
+        .ok_or_else(|| WadError::MissingLump(sky_name.to_string()))?;
+
+    Ok(Level {
+        vertices,
+        sectors,
+        sides,
+        lines,
+        segs,
+        subsectors,
+        nodes,
+        things,
+        reject,
+        blockmap,
+        format,
+        sky_texture,
+        sky_flat: render_data.sky_flat,
+    })
+}
+
+// P_GroupLines
+// Builds sector line lists and subsector sector pointers.
+fn group_lines(sectors: &mut [Sector], subsectors: &mut [SubSector], lines: &[LineDef], segs: &[Seg], vertices: &[Vertex], blockmap: &Blockmap) -> Result<(), WadError> {
+    // look up sector number for each subsector
+    for subsector in subsectors.iter_mut() {
+        if subsector.num_lines == 0 || subsector.first_line + subsector.num_lines > segs.len() {
+            return Err(WadError::MalformedLump {
+                name: "SSECTORS".to_string(),
+                reason: format!("Subsector references missing segs {}..{}",
+                                subsector.first_line, subsector.first_line + subsector.num_lines),
+            });
+        }
+        subsector.sector = segs[subsector.first_line].front_sector;
+    }
+
+    // build line tables for each sector
+    for (line_index, line) in lines.iter().enumerate() {
+        if let Some(front) = line.front_sector {
+            sectors[front].lines.push(line_index);
+        }
+        if let Some(back) = line.back_sector {
+            if Some(back) != line.front_sector {
+                sectors[back].lines.push(line_index);
+            }
+        }
+    }
+
+    for sector in sectors.iter_mut() {
+        let mut bbox = [real(i16::MIN), real(i16::MAX), real(i16::MAX), real(i16::MIN)];
+        for &line_index in &sector.lines {
+            let line = &lines[line_index];
+            for vertex in [&vertices[line.v1], &vertices[line.v2]] {
+                if vertex.x < bbox[BOX_LEFT] { bbox[BOX_LEFT] = vertex.x; }
+                if vertex.x > bbox[BOX_RIGHT] { bbox[BOX_RIGHT] = vertex.x; }
+                if vertex.y < bbox[BOX_BOTTOM] { bbox[BOX_BOTTOM] = vertex.y; }
+                if vertex.y > bbox[BOX_TOP] { bbox[BOX_TOP] = vertex.y; }
+            }
+        }
+
+        // set the degenmobj_t to the middle of the bounding box
+        sector.sound_origin = (
+            (bbox[BOX_RIGHT] + bbox[BOX_LEFT]).div_int(2),
+            (bbox[BOX_TOP] + bbox[BOX_BOTTOM]).div_int(2)
+        );
+
+        // adjust bounding box to map blocks
+        let clamp = |block: i32, max: usize| block.min(max as i32 - 1).max(0) as usize;
+        sector.block_box[BOX_TOP] = clamp(blockmap.block_y(bbox[BOX_TOP] + blockmap::MAX_RADIUS), blockmap.height);
+        sector.block_box[BOX_BOTTOM] = clamp(blockmap.block_y(bbox[BOX_BOTTOM] - blockmap::MAX_RADIUS), blockmap.height);
+        sector.block_box[BOX_RIGHT] = clamp(blockmap.block_x(bbox[BOX_RIGHT] + blockmap::MAX_RADIUS), blockmap.width);
+        sector.block_box[BOX_LEFT] = clamp(blockmap.block_x(bbox[BOX_LEFT] - blockmap::MAX_RADIUS), blockmap.width);
+        sector.bbox = bbox;
+    }
+
+    Ok(())
+}
+
+fn bad_reference(lump: &str, what: &str, index: usize) -> WadError {
+    WadError::MalformedLump {
+        name: lump.to_string(),
+        reason: format!("Reference to missing {} {}", what, index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level::test_map::TestMap;
+    use crate::types::real;
+    use crate::wad::WadError;
+
+    #[test]
+    fn single_subsector() {
+        let mut map = TestMap { sectors: vec![(0, 128)], ..TestMap::default() };
+        map.add_loop(&[(0, 0), (0, 256), (256, 256), (256, 0)], 0, None);
+        let level = map.load().unwrap();
+
+        assert!(level.nodes.is_empty());
+        assert_eq!(level.subsectors.len(), 1);
+        assert_eq!(level.point_in_sector(real(128), real(128)), 0);
+    }
+
+    #[test]
+    fn map_without_subsectors() {
+        let map = TestMap { sectors: vec![(0, 128)], ..TestMap::default() };
+        assert!(matches!(map.load(), Err(WadError::MalformedLump { name, .. }) if name == "SSECTORS"));
+    }
+}
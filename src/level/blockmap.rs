@@ -1,26 +1,208 @@
-use crate::types::{DoomRealNum, real};
-use std::io::Cursor;
+use crate::types::{DoomRealNum, real, from_fixed_bits, RealNum};
 use byteorder::{ReadBytesExt, LittleEndian};
-use crate::wad::{LumpStore, By, LumpNumber};
-
-pub struct Blockmap<'a> {
-    data: &'a [u8],
-    origin_x: DoomRealNum,
-    origin_y: DoomRealNum,
-    width: DoomRealNum,
-    height: DoomRealNum,
-    // blocklinks: Vec<>, TODO SKIP FOR NOW
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::linedefs::LineDef;
+use crate::level::vertices::Vertex;
+
+// MAPBLOCKUNITS
+pub const MAP_BLOCK_UNITS: i32 = 128;
+// Shift from whole map units to blocks, MAPBLOCKSHIFT is FRACBITS + 7
+pub const MAP_BLOCK_SHIFT: i32 = 7;
+
+// Blockmap offsets are shorts, a lump can address no more of them.
+const MAX_LUMP_SHORTS: usize = 0x10000;
+
+// MAXRADIUS is for precalculated sector block boxes,
+// the spider demon is larger, but we do not have any
+// moving sectors nearby
+pub const MAX_RADIUS: DoomRealNum = from_fixed_bits(32 << 16);
+
+pub struct Blockmap {
+    pub(crate) origin_x: DoomRealNum,
+    pub(crate) origin_y: DoomRealNum,
+    // Size in map blocks
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    // The lines of block b are block_lines[block_offsets[b]..block_offsets[b + 1]]
+    pub(crate) block_offsets: Vec<usize>,
+    pub(crate) block_lines: Vec<usize>,
+    // for thing chains, the first mobj's thinker of each block
+    pub(crate) blocklinks: Vec<Option<usize>>,
+}
+
+impl Blockmap {
+    /// The block column containing the map x coordinate,
+    /// which may be outside the blockmap.
+    pub fn block_x(&self, x: DoomRealNum) -> i32 {
+        x.wrapping_sub(self.origin_x).to_int() >> MAP_BLOCK_SHIFT
+    }
+
+    /// The block row containing the map y coordinate,
+    /// which may be outside the blockmap.
+    pub fn block_y(&self, y: DoomRealNum) -> i32 {
+        y.wrapping_sub(self.origin_y).to_int() >> MAP_BLOCK_SHIFT
+    }
+
+    /// The index of the block in the given column and row,
+    /// None if it is outside the blockmap.
+    pub fn block_index(&self, block_x: i32, block_y: i32) -> Option<usize> {
+        if block_x >= 0 && (block_x as usize) < self.width
+            && block_y >= 0 && (block_y as usize) < self.height {
+            Some(block_y as usize * self.width + block_x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The index of the block containing the point,
+    /// None if it is outside the blockmap.
+    pub fn point_block_index(&self, x: DoomRealNum, y: DoomRealNum) -> Option<usize> {
+        self.block_index(self.block_x(x), self.block_y(y))
+    }
 }
 
-pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Blockmap {
-    let data = lumps.get_lump(By::Number(map_lump.offset(10)));
-    let mut cursor = Cursor::new(data);
-    // TODO Blocklinks? See P_LoadBlockMap
+fn malformed(reason: String) -> WadError {
+    WadError::MalformedLump {
+        name: "BLOCKMAP".to_string(),
+        reason,
+    }
+}
+
+// P_LoadBlockMap
+// Builds the blockmap from the lines when the lump is missing,
+//  can not be read or is too large for its offsets.
+pub fn load(lumps: &LumpStore, blockmap_lump: Option<LumpNumber>, lines: &[LineDef], vertices: &[Vertex]) -> Result<Blockmap, WadError> {
+    let lump_number = match blockmap_lump {
+        Some(lump_number) => lump_number,
+        None => return Ok(build(lines, vertices)),
+    };
+
+    let data = lumps.get_lump(By::Number(lump_number))?;
+    if data.len() / 2 > MAX_LUMP_SHORTS {
+        return Ok(build(lines, vertices));
+    }
+
+    match parse(data, lines.len()) {
+        Ok(blockmap) => Ok(blockmap),
+        Err(WadError::MalformedLump { .. }) => Ok(build(lines, vertices)),
+        Err(error) => Err(error),
+    }
+}
+
+fn parse(data: &[u8], line_count: usize) -> Result<Blockmap, WadError> {
+    if data.len() < 8 {
+        return Err(malformed("Blockmap header is truncated".to_string()));
+    }
+
+    let mut lump = vec![0i16; data.len() / 2];
+    (&data[..lump.len() * 2]).read_i16_into::<LittleEndian>(&mut lump)?;
+
+    let width = lump[2] as u16 as usize;
+    let height = lump[3] as u16 as usize;
+    let block_count = width * height;
+
+    if lump.len() < 4 + block_count {
+        return Err(malformed(format!("Offsets for {}x{} blocks are truncated", width, height)));
+    }
+
+    // Offsets are in shorts from the start of the lump,
+    //  each list starts with a 0 and ends with -1.
+    let mut block_offsets = Vec::with_capacity(block_count + 1);
+    let mut block_lines = Vec::new();
+
+    for block in 0..block_count {
+        block_offsets.push(block_lines.len());
+
+        let offset = lump[4 + block] as u16 as usize;
+        let list = lump.get(offset..)
+            .ok_or_else(|| malformed(format!("Block {} starts outside the lump", block)))?;
+
+        let end = list.iter().position(|&line| line == -1)
+            .ok_or_else(|| malformed(format!("Block {} list is not terminated", block)))?;
+
+        // The leading 0 is kept as a line, P_BlockLinesIterator
+        //  checks line 0 in every block and demos depend on it.
+        for &line in &list[..end] {
+            let line = line as u16 as usize;
+            if line >= line_count {
+                return Err(malformed(format!("Block {} references missing linedef {}", block, line)));
+            }
+            block_lines.push(line);
+        }
+    }
+    block_offsets.push(block_lines.len());
+
+    // clear out mobj chains
+    Ok(Blockmap {
+        origin_x: real(lump[0]),
+        origin_y: real(lump[1]),
+        width,
+        height,
+        block_offsets,
+        block_lines,
+        blocklinks: vec![None; block_count],
+    })
+}
+
+/// Generates the 128x128 unit blockmap grid from the lines,
+/// for maps that come without a usable BLOCKMAP lump.
+pub fn build(lines: &[LineDef], vertices: &[Vertex]) -> Blockmap {
+    let map_unit = |value: DoomRealNum| value.to_int() as i64;
+
+    let min_x = vertices.iter().map(|vertex| map_unit(vertex.x)).min().unwrap_or(0);
+    let max_x = vertices.iter().map(|vertex| map_unit(vertex.x)).max().unwrap_or(0);
+    let min_y = vertices.iter().map(|vertex| map_unit(vertex.y)).min().unwrap_or(0);
+    let max_y = vertices.iter().map(|vertex| map_unit(vertex.y)).max().unwrap_or(0);
+
+    let block_units = MAP_BLOCK_UNITS as i64;
+    let width = ((max_x - min_x) >> MAP_BLOCK_SHIFT) as usize + 1;
+    let height = ((max_y - min_y) >> MAP_BLOCK_SHIFT) as usize + 1;
+
+    // Lists start with a 0 like those of the node builders,
+    //  so line 0 is checked in every block as with a lump.
+    let first_lines = if lines.is_empty() { vec![] } else { vec![0] };
+    let mut blocks = vec![first_lines; width * height];
+
+    for (line_index, line) in lines.iter().enumerate() {
+        let (x1, y1) = (map_unit(vertices[line.v1].x) - min_x, map_unit(vertices[line.v1].y) - min_y);
+        let (x2, y2) = (map_unit(vertices[line.v2].x) - min_x, map_unit(vertices[line.v2].y) - min_y);
+        let (dx, dy) = (x2 - x1, y2 - y1);
+
+        // Which side of the line a point is on, 0 when on it.
+        let side = |x: i64, y: i64| ((x - x1) * dy - (y - y1) * dx).signum();
+
+        for block_y in (y1.min(y2) >> MAP_BLOCK_SHIFT)..=(y1.max(y2) >> MAP_BLOCK_SHIFT) {
+            for block_x in (x1.min(x2) >> MAP_BLOCK_SHIFT)..=(x1.max(x2) >> MAP_BLOCK_SHIFT) {
+                let (left, bottom) = (block_x * block_units, block_y * block_units);
+                let (right, top) = (left + block_units, bottom + block_units);
+
+                // Within the bounding box of the line, the line touches
+                //  the block unless all corners are on the same side of it.
+                let corners = [side(left, bottom), side(right, bottom), side(left, top), side(right, top)];
+                if corners.iter().all(|&corner| corner > 0) || corners.iter().all(|&corner| corner < 0) {
+                    continue;
+                }
+
+                blocks[block_y as usize * width + block_x as usize].push(line_index);
+            }
+        }
+    }
+
+    let mut block_offsets = Vec::with_capacity(blocks.len() + 1);
+    let mut block_lines = Vec::new();
+    for block in &blocks {
+        block_offsets.push(block_lines.len());
+        block_lines.extend_from_slice(block);
+    }
+    block_offsets.push(block_lines.len());
+
     Blockmap {
-        data,
-        origin_x: real(cursor.read_i16::<LittleEndian>().unwrap()),
-        origin_y: real(cursor.read_i16::<LittleEndian>().unwrap()),
-        width: real(cursor.read_i16::<LittleEndian>().unwrap()),
-        height: real(cursor.read_i16::<LittleEndian>().unwrap()),
+        origin_x: real(min_x as i32),
+        origin_y: real(min_y as i32),
+        width,
+        height,
+        block_offsets,
+        block_lines,
+        blocklinks: vec![None; width * height],
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,334 @@
+use crate::types::{real, from_fixed_bits, RealNum};
+use crate::tables::point_to_angle;
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::{ML_SEGS, ML_SSECTORS, ML_NODES, bad_reference};
+use crate::level::vertices::Vertex;
+use crate::level::linedefs::LineDef;
+use crate::level::sidedefs::SideDef;
+use crate::level::segs::{Seg, seg_sides, seg_offset};
+use crate::level::subsectors::SubSector;
+use crate::level::nodes::{BspTree, Node, NF_SUBSECTOR};
+use flate2::read::ZlibDecoder;
+use std::io::{Cursor, Read};
+use serde::Deserialize;
+
+// Leaf marker in the 32 bit children of extended nodes.
+const NF_SUBSECTOR_EXTENDED: u32 = 0x8000_0000;
+
+// The node formats of node builders for large maps,
+// told apart by the signature the NODES lump starts with.
+pub enum NodeFormat {
+    // DeePBSP V4, 32 bit indices in NODES, SEGS and SSECTORS.
+    DeePBsp,
+    // ZDoom extended nodes, everything is in NODES.
+    ZDoom,
+    // ZDoom extended nodes compressed with zlib.
+    ZDoomCompressed,
+}
+
+const DEEP_BSP_SIGNATURE: &[u8; 8] = b"xNd4\0\0\0\0";
+const ZDOOM_SIGNATURE: &[u8; 4] = b"XNOD";
+const ZDOOM_COMPRESSED_SIGNATURE: &[u8; 4] = b"ZNOD";
+// GL nodes, with minisegs and segs that have no second vertex.
+const GL_NODES_SIGNATURES: [&[u8; 4]; 6] = [b"XGLN", b"XGL2", b"XGL3", b"ZGLN", b"ZGL2", b"ZGL3"];
+
+#[derive(Deserialize)]
+struct NodeRawV4 {
+    x: i16,
+    y: i16,
+    dx: i16,
+    dy: i16,
+
+    bbox: [[i16; 4]; 2],
+
+    children: [u32; 2],
+}
+
+#[derive(Deserialize)]
+struct SegRawV4 {
+    v1: u32,
+    v2: u32,
+    angle: u16,
+    linedef: u16,
+    side: i16,
+    offset: u16,
+}
+
+#[derive(Deserialize)]
+struct SubSectorRawV4 {
+    num_segs: u32,
+    first_seg: u32,
+}
+
+#[derive(Deserialize)]
+struct VertexRawZDoom {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Deserialize)]
+struct SegRawZDoom {
+    v1: u32,
+    v2: u32,
+    linedef: u16,
+    side: u8,
+}
+
+fn malformed(reason: String) -> WadError {
+    WadError::MalformedLump {
+        name: "NODES".to_string(),
+        reason,
+    }
+}
+
+fn child(child: u32) -> usize {
+    if child & NF_SUBSECTOR_EXTENDED != 0 {
+        (child & !NF_SUBSECTOR_EXTENDED) as usize | NF_SUBSECTOR
+    } else {
+        child as usize
+    }
+}
+
+fn node(raw_node: NodeRawV4) -> Node {
+    let bbox = raw_node.bbox.map(|bbox| bbox.map(real));
+
+    Node {
+        x: real(raw_node.x),
+        y: real(raw_node.y),
+        dx: real(raw_node.dx),
+        dy: real(raw_node.dy),
+        bbox,
+        children: raw_node.children.map(child),
+    }
+}
+
+fn check_vertex(lump: &str, vertex: u32, vertices: &[Vertex]) -> Result<usize, WadError> {
+    let vertex = vertex as usize;
+    if vertex >= vertices.len() {
+        return Err(bad_reference(lump, "vertex", vertex));
+    }
+    Ok(vertex)
+}
+
+/// The extended format of the nodes of the map,
+/// None for vanilla nodes or no nodes at all.
+pub fn node_format(lumps: &LumpStore, map_lump: LumpNumber) -> Option<NodeFormat> {
+    let lump_number = map_lump.offset(ML_NODES);
+    if !matches!(lumps.get_lump_name(lump_number), Ok("NODES")) {
+        return None;
+    }
+
+    signature_format(lumps.get_lump(By::Number(lump_number)).ok()?)
+}
+
+fn signature_format(data: &[u8]) -> Option<NodeFormat> {
+    if data.starts_with(DEEP_BSP_SIGNATURE) {
+        Some(NodeFormat::DeePBsp)
+    } else if data.starts_with(ZDOOM_SIGNATURE) {
+        Some(NodeFormat::ZDoom)
+    } else if data.starts_with(ZDOOM_COMPRESSED_SIGNATURE) {
+        Some(NodeFormat::ZDoomCompressed)
+    } else {
+        None
+    }
+}
+
+/// Loads extended nodes with their segs and subsectors.
+/// ZDoom nodes can add vertices, which are put after
+/// the ones of the VERTEXES lump that are kept.
+pub fn load(format: NodeFormat, lumps: &LumpStore, map_lump: LumpNumber, vertices: &mut Vec<Vertex>,
+            lines: &[LineDef], sides: &[SideDef]) -> Result<BspTree, WadError> {
+    let data = lumps.get_lump(By::Number(map_lump.offset(ML_NODES)))?;
+
+    match format {
+        NodeFormat::DeePBsp => load_deep_bsp(lumps, map_lump, &data[DEEP_BSP_SIGNATURE.len()..], vertices, lines, sides),
+        NodeFormat::ZDoom | NodeFormat::ZDoomCompressed => load_zdoom_lump(format, data, vertices, lines, sides),
+    }
+}
+
+/// Loads the ZNODES lump of a UDMF map, None if it does not
+/// start with a signature. GL nodes are not supported.
+pub fn load_znodes(data: &[u8], vertices: &mut Vec<Vertex>, lines: &[LineDef], sides: &[SideDef]) -> Result<Option<BspTree>, WadError> {
+    match signature_format(data) {
+        Some(format @ (NodeFormat::ZDoom | NodeFormat::ZDoomCompressed)) =>
+            load_zdoom_lump(format, data, vertices, lines, sides).map(Some),
+        _ => match GL_NODES_SIGNATURES.iter().find(|&&signature| data.starts_with(signature)) {
+            Some(signature) => Err(WadError::MalformedLump {
+                name: "ZNODES".to_string(),
+                reason: format!("{} GL nodes are not supported", String::from_utf8_lossy(&signature[..])),
+            }),
+            None => Ok(None),
+        },
+    }
+}
+
+fn load_zdoom_lump(format: NodeFormat, data: &[u8], vertices: &mut Vec<Vertex>, lines: &[LineDef], sides: &[SideDef]) -> Result<BspTree, WadError> {
+    if let NodeFormat::ZDoomCompressed = format {
+        let mut inflated = Vec::new();
+        ZlibDecoder::new(&data[ZDOOM_COMPRESSED_SIGNATURE.len()..]).read_to_end(&mut inflated)
+            .map_err(|err| malformed(format!("Compressed nodes do not inflate: {}", err)))?;
+        load_zdoom(&inflated, vertices, lines, sides)
+    } else {
+        load_zdoom(&data[ZDOOM_SIGNATURE.len()..], vertices, lines, sides)
+    }
+}
+
+// P_LoadNodes_V4, P_LoadSegs_V4 and P_LoadSubsectors_V4
+fn load_deep_bsp(lumps: &LumpStore, map_lump: LumpNumber, nodes_data: &[u8], vertices: &[Vertex],
+                 lines: &[LineDef], sides: &[SideDef]) -> Result<BspTree, WadError> {
+    const NODE_SIZE: usize = 32;
+    const SEG_SIZE: usize = 16;
+    const SUBSECTOR_SIZE: usize = 8;
+
+    let mut data = Cursor::new(nodes_data);
+    let mut nodes = Vec::with_capacity(nodes_data.len() / NODE_SIZE);
+    for _ in 0..nodes_data.len() / NODE_SIZE {
+        let raw_node: NodeRawV4 = bincode::deserialize_from(&mut data)?;
+        nodes.push(node(raw_node));
+    }
+
+    let segs_data = lumps.get_lump(By::Number(map_lump.offset(ML_SEGS)))?;
+    let mut data = Cursor::new(segs_data);
+    let mut segs = Vec::with_capacity(segs_data.len() / SEG_SIZE);
+    for _ in 0..segs_data.len() / SEG_SIZE {
+        let raw_seg: SegRawV4 = bincode::deserialize_from(&mut data)?;
+
+        let linedef = raw_seg.linedef as usize;
+        let (sidedef, front_sector, back_sector) =
+            seg_sides("SEGS", linedef, (raw_seg.side & 1) as usize, lines, sides)?;
+
+        segs.push(Seg {
+            v1: check_vertex("SEGS", raw_seg.v1, vertices)?,
+            v2: check_vertex("SEGS", raw_seg.v2, vertices)?,
+            offset: seg_offset(raw_seg.offset as f64),
+            angle: (raw_seg.angle as u32) << 16,
+            sidedef,
+            linedef,
+            front_sector,
+            back_sector,
+        });
+    }
+
+    let subsectors_data = lumps.get_lump(By::Number(map_lump.offset(ML_SSECTORS)))?;
+    let mut data = Cursor::new(subsectors_data);
+    let mut subsectors = Vec::with_capacity(subsectors_data.len() / SUBSECTOR_SIZE);
+    for _ in 0..subsectors_data.len() / SUBSECTOR_SIZE {
+        let raw_subsector: SubSectorRawV4 = bincode::deserialize_from(&mut data)?;
+        subsectors.push(SubSector {
+            // Filled in by group_lines
+            sector: 0,
+            num_lines: raw_subsector.num_segs as usize,
+            first_line: raw_subsector.first_seg as usize,
+        });
+    }
+
+    Ok(BspTree { segs, subsectors, nodes })
+}
+
+fn read_count(data: &mut Cursor<&[u8]>) -> Result<usize, WadError> {
+    let count: u32 = bincode::deserialize_from(data)
+        .map_err(|_| malformed("Extended nodes are truncated".to_string()))?;
+    Ok(count as usize)
+}
+
+// P_LoadZNodes
+fn load_zdoom(data: &[u8], vertices: &mut Vec<Vertex>, lines: &[LineDef], sides: &[SideDef]) -> Result<BspTree, WadError> {
+    let mut data = Cursor::new(data);
+
+    // Vertices: the ones of VERTEXES that are kept, then the new ones
+    let original_vertices = read_count(&mut data)?;
+    let new_vertices = read_count(&mut data)?;
+    if original_vertices > vertices.len() {
+        return Err(malformed(format!("Keeps {} of {} vertices", original_vertices, vertices.len())));
+    }
+    if let Some(line) = lines.iter().find(|line| line.v1.max(line.v2) >= original_vertices) {
+        return Err(bad_reference("NODES", "vertex", line.v1.max(line.v2)));
+    }
+
+    vertices.truncate(original_vertices);
+    for _ in 0..new_vertices {
+        let raw_vertex: VertexRawZDoom = bincode::deserialize_from(&mut data)?;
+        vertices.push(Vertex {
+            x: from_fixed_bits(raw_vertex.x),
+            y: from_fixed_bits(raw_vertex.y),
+        });
+    }
+
+    // Subsectors: only the seg counts, the segs follow each other
+    let subsector_count = read_count(&mut data)?;
+    let mut subsectors = Vec::with_capacity(subsector_count.min(data.get_ref().len()));
+    let mut first_line = 0;
+    for _ in 0..subsector_count {
+        let num_lines = read_count(&mut data)?;
+        subsectors.push(SubSector {
+            // Filled in by group_lines
+            sector: 0,
+            num_lines,
+            first_line,
+        });
+        first_line += num_lines;
+    }
+
+    // Segs: the offset and angle are left to the loader
+    let seg_count = read_count(&mut data)?;
+    if seg_count != first_line {
+        return Err(malformed(format!("Subsectors have {} segs, but there are {}", first_line, seg_count)));
+    }
+
+    let mut segs = Vec::with_capacity(seg_count.min(data.get_ref().len()));
+    for _ in 0..seg_count {
+        let raw_seg: SegRawZDoom = bincode::deserialize_from(&mut data)?;
+
+        let v1 = check_vertex("NODES", raw_seg.v1, vertices)?;
+        let v2 = check_vertex("NODES", raw_seg.v2, vertices)?;
+        let linedef = raw_seg.linedef as usize;
+        let side = (raw_seg.side & 1) as usize;
+        let (sidedef, front_sector, back_sector) = seg_sides("NODES", linedef, side, lines, sides)?;
+
+        // the offset is from the vertex the side starts at
+        let line = &lines[linedef];
+        let start = &vertices[if side == 0 { line.v1 } else { line.v2 }];
+        let (from, to) = (&vertices[v1], &vertices[v2]);
+        let offset = (from.x.to_f64() - start.x.to_f64())
+            .hypot(from.y.to_f64() - start.y.to_f64());
+
+        segs.push(Seg {
+            v1,
+            v2,
+            offset: seg_offset(offset),
+            angle: point_to_angle(to.x.wrapping_sub(from.x), to.y.wrapping_sub(from.y)),
+            sidedef,
+            linedef,
+            front_sector,
+            back_sector,
+        });
+    }
+
+    let node_count = read_count(&mut data)?;
+    let mut nodes = Vec::with_capacity(node_count.min(data.get_ref().len()));
+    for _ in 0..node_count {
+        let raw_node: NodeRawV4 = bincode::deserialize_from(&mut data)?;
+        nodes.push(node(raw_node));
+    }
+
+    Ok(BspTree { segs, subsectors, nodes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_znodes;
+    use crate::wad::WadError;
+
+    #[test]
+    fn gl_nodes_are_not_supported() {
+        let mut vertices = Vec::new();
+        let result = load_znodes(b"XGL2\0\0\0\0", &mut vertices, &[], &[]);
+        assert!(matches!(result, Err(WadError::MalformedLump { reason, .. }) if reason.starts_with("XGL2")));
+    }
+
+    #[test]
+    fn no_signature() {
+        let mut vertices = Vec::new();
+        assert!(matches!(load_znodes(b"", &mut vertices, &[], &[]), Ok(None)));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::types::{DoomRealNum, real, BOX_TOP, BOX_BOTTOM, BOX_LEFT, BOX_RIGHT, RealNum};
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::{ML_LINEDEFS, bad_reference};
+use crate::level::vertices::Vertex;
+use crate::level::sidedefs::SideDef;
+use std::io::Seek;
+use std::mem::size_of;
+use serde::Deserialize;
+
+// LineDef attributes.
+
+// Solid, is an obstacle.
+pub const ML_BLOCKING: i16 = 1;
+// Blocks monsters only.
+pub const ML_BLOCKMONSTERS: i16 = 2;
+// Backside will not be present at all if not two sided.
+pub const ML_TWOSIDED: i16 = 4;
+// upper texture unpegged
+pub const ML_DONTPEGTOP: i16 = 8;
+// lower texture unpegged
+pub const ML_DONTPEGBOTTOM: i16 = 16;
+// In AutoMap: don't map as two sided: IT'S A SECRET!
+pub const ML_SECRET: i16 = 32;
+// Sound rendering: don't let sound cross two of these.
+pub const ML_SOUNDBLOCK: i16 = 64;
+// Don't draw on the automap at all.
+pub const ML_DONTDRAW: i16 = 128;
+// Set if already seen, thus drawn in automap.
+pub const ML_MAPPED: i16 = 256;
+// Boom: use passes through the line to the ones behind it.
+pub const ML_PASSUSE: i16 = 512;
+
+// Hexen: the flags above ML_MAPPED mean something else.
+// The special can be activated more than once.
+pub const ML_REPEAT_SPECIAL: i16 = 512;
+// How the special is activated, one of the SPAC values.
+pub const ML_SPAC_SHIFT: i16 = 10;
+pub const ML_SPAC_MASK: i16 = 0x1c00;
+
+// Hexen special activation types.
+pub const SPAC_CROSS: u8 = 0; // when player crosses line
+pub const SPAC_USE: u8 = 1; // when player uses line
+pub const SPAC_MCROSS: u8 = 2; // when monster crosses line
+pub const SPAC_IMPACT: u8 = 3; // when projectile hits line
+pub const SPAC_PUSH: u8 = 4; // when player/monster pushes line
+pub const SPAC_PCROSS: u8 = 5; // when projectile crosses line
+
+#[derive(Deserialize)]
+struct LineDefRaw {
+    v1: i16,
+    v2: i16,
+    flags: i16,
+    special: i16,
+    tag: i16,
+    // sidenum[1] will be -1 if one sided
+    sidenum: [i16; 2],
+}
+
+#[derive(Deserialize)]
+struct HexenLineDefRaw {
+    v1: i16,
+    v2: i16,
+    flags: i16,
+    special: u8,
+    args: [u8; 5],
+    sidenum: [i16; 2],
+}
+
+// The special of a Hexen format line, which takes
+// arguments in place of the tag.
+#[derive(Copy, Clone)]
+pub struct ActionSpecial {
+    pub(crate) special: u8,
+    pub(crate) args: [u8; 5],
+    // One of the SPAC values.
+    pub(crate) activation: u8,
+    pub(crate) repeat: bool,
+}
+
+// Move clipping aid for LineDefs.
+#[derive(Copy, Clone, PartialEq)]
+pub enum SlopeType {
+    Horizontal,
+    Vertical,
+    Positive,
+    Negative,
+}
+
+pub struct LineDef {
+    // Vertices, from v1 to v2.
+    pub(crate) v1: usize,
+    pub(crate) v2: usize,
+
+    // Precalculated v2 - v1 for side checking.
+    pub(crate) dx: DoomRealNum,
+    pub(crate) dy: DoomRealNum,
+
+    // Animation related.
+    pub(crate) flags: i16,
+    pub(crate) special: i16,
+    pub(crate) tag: i16,
+
+    // Hexen: the line special is kept apart,
+    //  special and tag are left at 0.
+    pub(crate) action: Option<ActionSpecial>,
+
+    // Visual appearance: SideDefs.
+    // sidenum[1] will be None if one sided
+    pub(crate) sidenum: [Option<usize>; 2],
+
+    // Neat. Another bounding box, for the extent
+    //  of the LineDef.
+    pub(crate) bbox: [DoomRealNum; 4],
+
+    // To aid move clipping.
+    pub(crate) slope_type: SlopeType,
+
+    // Front and back sector.
+    // Note: redundant? Can be retrieved from SideDefs.
+    pub(crate) front_sector: Option<usize>,
+    pub(crate) back_sector: Option<usize>,
+
+    // if == validcount, already checked
+    pub(crate) valid_count: i32,
+}
+
+impl LineDef {
+    /// A line between the vertices with the given sides,
+    /// without flags or special.
+    pub fn new(v1: usize, v2: usize, sidenum: [Option<usize>; 2], vertices: &[Vertex], sides: &[SideDef]) -> Self {
+        let (vertex1, vertex2) = (&vertices[v1], &vertices[v2]);
+        let dx = RealNum::wrapping_sub(vertex2.x, vertex1.x);
+        let dy = RealNum::wrapping_sub(vertex2.y, vertex1.y);
+
+        let slope_type = if dx == real(0) {
+            SlopeType::Vertical
+        } else if dy == real(0) {
+            SlopeType::Horizontal
+        } else if (dy > real(0)) == (dx > real(0)) {
+            SlopeType::Positive
+        } else {
+            SlopeType::Negative
+        };
+
+        let mut bbox = [real(0); 4];
+        if vertex1.x < vertex2.x {
+            bbox[BOX_LEFT] = vertex1.x;
+            bbox[BOX_RIGHT] = vertex2.x;
+        } else {
+            bbox[BOX_LEFT] = vertex2.x;
+            bbox[BOX_RIGHT] = vertex1.x;
+        }
+
+        if vertex1.y < vertex2.y {
+            bbox[BOX_BOTTOM] = vertex1.y;
+            bbox[BOX_TOP] = vertex2.y;
+        } else {
+            bbox[BOX_BOTTOM] = vertex2.y;
+            bbox[BOX_TOP] = vertex1.y;
+        }
+
+        LineDef {
+            v1,
+            v2,
+            dx,
+            dy,
+            flags: 0,
+            special: 0,
+            tag: 0,
+            action: None,
+            sidenum,
+            bbox,
+            slope_type,
+            front_sector: sidenum[0].map(|side| sides[side].sector),
+            back_sector: sidenum[1].map(|side| sides[side].sector),
+            valid_count: 0,
+        }
+    }
+}
+
+fn side_index(raw: i16, side_count: usize) -> Result<Option<usize>, WadError> {
+    if raw == -1 {
+        return Ok(None);
+    }
+
+    let side = raw as u16 as usize;
+    if side >= side_count {
+        return Err(bad_reference("LINEDEFS", "sidedef", side));
+    }
+
+    Ok(Some(side))
+}
+
+fn vertex_index(raw: i16, vertex_count: usize) -> Result<usize, WadError> {
+    let vertex = raw as u16 as usize;
+    if vertex >= vertex_count {
+        return Err(bad_reference("LINEDEFS", "vertex", vertex));
+    }
+
+    Ok(vertex)
+}
+
+// P_LoadLineDefs
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber, vertices: &[Vertex], sides: &[SideDef]) -> Result<Vec<LineDef>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_LINEDEFS)))?;
+
+    let line_count = data.stream_len()? as usize / size_of::<LineDefRaw>();
+    let mut lines = Vec::with_capacity(line_count);
+
+    for _ in 0..line_count {
+        let raw_line: LineDefRaw = bincode::deserialize_from(&mut data)?;
+
+        let v1 = vertex_index(raw_line.v1, vertices.len())?;
+        let v2 = vertex_index(raw_line.v2, vertices.len())?;
+        let sidenum = [
+            side_index(raw_line.sidenum[0], sides.len())?,
+            side_index(raw_line.sidenum[1], sides.len())?,
+        ];
+
+        lines.push(LineDef {
+            flags: raw_line.flags,
+            special: raw_line.special,
+            tag: raw_line.tag,
+            ..LineDef::new(v1, v2, sidenum, vertices, sides)
+        });
+    }
+
+    Ok(lines)
+}
+
+// P_LoadLineDefs of Hexen
+pub fn load_hexen(lumps: &LumpStore, map_lump: LumpNumber, vertices: &[Vertex], sides: &[SideDef]) -> Result<Vec<LineDef>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_LINEDEFS)))?;
+
+    let line_count = data.stream_len()? as usize / size_of::<HexenLineDefRaw>();
+    let mut lines = Vec::with_capacity(line_count);
+
+    for _ in 0..line_count {
+        let raw_line: HexenLineDefRaw = bincode::deserialize_from(&mut data)?;
+
+        let v1 = vertex_index(raw_line.v1, vertices.len())?;
+        let v2 = vertex_index(raw_line.v2, vertices.len())?;
+        let sidenum = [
+            side_index(raw_line.sidenum[0], sides.len())?,
+            side_index(raw_line.sidenum[1], sides.len())?,
+        ];
+
+        let action = (raw_line.special != 0).then_some(ActionSpecial {
+            special: raw_line.special,
+            args: raw_line.args,
+            activation: ((raw_line.flags & ML_SPAC_MASK) >> ML_SPAC_SHIFT) as u8,
+            repeat: raw_line.flags & ML_REPEAT_SPECIAL != 0,
+        });
+
+        lines.push(LineDef {
+            flags: raw_line.flags & (ML_REPEAT_SPECIAL - 1),
+            action,
+            ..LineDef::new(v1, v2, sidenum, vertices, sides)
+        });
+    }
+
+    Ok(lines)
+}
@@ -0,0 +1,486 @@
+use crate::types::{DoomRealNum, Angle, real, FRAC_BITS, BOX_TOP, BOX_BOTTOM, BOX_LEFT, BOX_RIGHT, from_fixed_bits, RealNum};
+use crate::tables::point_to_angle;
+use std::convert::TryFrom;
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::{ML_SEGS, ML_SSECTORS, ML_NODES};
+use crate::level::vertices::Vertex;
+use crate::level::linedefs::LineDef;
+use crate::level::sidedefs::SideDef;
+use crate::level::segs::{Seg, seg_sides, seg_offset};
+use crate::level::subsectors::SubSector;
+use crate::level::nodes::{BspTree, Node, NF_SUBSECTOR};
+
+// How many segs of imbalance between the two sides
+//  a partition line is allowed to save by splitting a seg.
+const SPLIT_COST: usize = 8;
+
+// A seg while building, the sectors are looked up
+//  from the side of the linedef when it is done.
+#[derive(Copy, Clone)]
+struct BuildSeg {
+    v1: usize,
+    v2: usize,
+    offset: DoomRealNum,
+    angle: Angle,
+    linedef: usize,
+    side: usize,
+}
+
+// A partition line, in fixed point bits.
+#[derive(Copy, Clone)]
+struct Partition {
+    x: i64,
+    y: i64,
+    dx: i64,
+    dy: i64,
+}
+
+impl Partition {
+    // The partition through the whole linedef of the seg,
+    //  facing the same way as the seg.
+    // Linedef vertices have whole coordinates, split vertices may not.
+    fn along(seg: &BuildSeg, lines: &[LineDef], vertices: &[Vertex]) -> Self {
+        let line = &lines[seg.linedef];
+        let (from, to) = if seg.side == 0 { (line.v1, line.v2) } else { (line.v2, line.v1) };
+        let (from, to) = (&vertices[from], &vertices[to]);
+
+        Self {
+            x: from.x.to_fixed_bits() as i64,
+            y: from.y.to_fixed_bits() as i64,
+            dx: to.x.to_fixed_bits() as i64 - from.x.to_fixed_bits() as i64,
+            dy: to.y.to_fixed_bits() as i64 - from.y.to_fixed_bits() as i64,
+        }
+    }
+
+    // Positive in front of the partition, negative behind it,
+    //  the same sides R_PointOnSide decides on.
+    fn side(&self, vertex: &Vertex) -> i128 {
+        let x = vertex.x.to_fixed_bits() as i64 - self.x;
+        let y = vertex.y.to_fixed_bits() as i64 - self.y;
+        self.dy as i128 * x as i128 - self.dx as i128 * y as i128
+    }
+
+    // Whether a seg on the partition line faces the same way.
+    fn same_direction(&self, seg: &BuildSeg, vertices: &[Vertex]) -> bool {
+        let dx = vertices[seg.v2].x.to_fixed_bits() as i64 - vertices[seg.v1].x.to_fixed_bits() as i64;
+        let dy = vertices[seg.v2].y.to_fixed_bits() as i64 - vertices[seg.v1].y.to_fixed_bits() as i64;
+        self.dx as i128 * dx as i128 + self.dy as i128 * dy as i128 > 0
+    }
+
+    // Nodes have the map units of a NODES lump, which a
+    //  partition across more than the map can not fit in.
+    fn to_node(self, bbox: [[DoomRealNum; 4]; 2], children: [usize; 2]) -> Result<Node, WadError> {
+        let map_units = |bits: i64| match i16::try_from(bits >> FRAC_BITS) {
+            Ok(_) => Ok(from_fixed_bits(bits as i32)),
+            Err(_) => Err(WadError::MalformedLump {
+                name: "LINEDEFS".to_string(),
+                reason: format!("Partition line ({}, {}) + ({}, {}) is out of range",
+                                self.x >> FRAC_BITS, self.y >> FRAC_BITS,
+                                self.dx >> FRAC_BITS, self.dy >> FRAC_BITS),
+            }),
+        };
+
+        Ok(Node {
+            x: map_units(self.x)?,
+            y: map_units(self.y)?,
+            dx: map_units(self.dx)?,
+            dy: map_units(self.dy)?,
+            bbox,
+            children,
+        })
+    }
+}
+
+// Where a seg ends up relative to a partition line.
+enum SegSide {
+    Front,
+    Back,
+    // Crosses the partition, with the sides of its vertices.
+    Split(i128, i128),
+}
+
+fn classify(partition: &Partition, seg: &BuildSeg, vertices: &[Vertex]) -> SegSide {
+    let side1 = partition.side(&vertices[seg.v1]);
+    let side2 = partition.side(&vertices[seg.v2]);
+
+    if side1 == 0 && side2 == 0 {
+        // on the partition line, front if facing the same way
+        if partition.same_direction(seg, vertices) {
+            SegSide::Front
+        } else {
+            SegSide::Back
+        }
+    } else if side1 >= 0 && side2 >= 0 {
+        SegSide::Front
+    } else if side1 <= 0 && side2 <= 0 {
+        SegSide::Back
+    } else {
+        SegSide::Split(side1, side2)
+    }
+}
+
+// Divides rounding to the nearest whole number.
+fn div_round(num: i128, den: i128) -> i128 {
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+    if num >= 0 {
+        (num + den / 2) / den
+    } else {
+        -((-num + den / 2) / den)
+    }
+}
+
+/// Whether the map has NODES, SEGS and SSECTORS lumps with
+/// something in them, otherwise they are built at load time.
+pub fn has_nodes(lumps: &LumpStore, map_lump: LumpNumber) -> bool {
+    [(ML_SEGS, "SEGS"), (ML_SSECTORS, "SSECTORS"), (ML_NODES, "NODES")].iter()
+        .all(|&(offset, name)| {
+            let lump_number = map_lump.offset(offset);
+            matches!(lumps.get_lump_name(lump_number), Ok(lump_name) if lump_name == name)
+                && lumps.get_lump(By::Number(lump_number)).is_ok_and(|data| !data.is_empty())
+        })
+}
+
+/// Builds the BSP tree of a map from its linedefs.
+/// Vertices where segs are split are added to the vertices.
+/// The same map always gives the same tree.
+pub fn build(vertices: &mut Vec<Vertex>, lines: &[LineDef], sides: &[SideDef]) -> Result<BspTree, WadError> {
+    let mut segs = Vec::new();
+    for (line_index, line) in lines.iter().enumerate() {
+        let (v1, v2) = (&vertices[line.v1], &vertices[line.v2]);
+        if v1.x == v2.x && v1.y == v2.y {
+            continue;
+        }
+
+        for side in 0..2 {
+            if line.sidenum[side].is_none() {
+                continue;
+            }
+
+            let (from, to) = if side == 0 { (line.v1, line.v2) } else { (line.v2, line.v1) };
+            segs.push(BuildSeg {
+                v1: from,
+                v2: to,
+                offset: real(0),
+                angle: point_to_angle(vertices[to].x.wrapping_sub(vertices[from].x),
+                                      vertices[to].y.wrapping_sub(vertices[from].y)),
+                linedef: line_index,
+                side,
+            });
+        }
+    }
+
+    let mut builder = Builder {
+        vertices,
+        lines,
+        segs: Vec::new(),
+        subsectors: Vec::new(),
+        nodes: Vec::new(),
+    };
+    if !segs.is_empty() {
+        builder.build_node(segs)?;
+    }
+
+    let segs = builder.segs.into_iter()
+        .map(|seg| {
+            let (sidedef, front_sector, back_sector) = seg_sides("LINEDEFS", seg.linedef, seg.side, lines, sides)?;
+
+            Ok(Seg {
+                v1: seg.v1,
+                v2: seg.v2,
+                offset: seg.offset,
+                angle: seg.angle,
+                sidedef,
+                linedef: seg.linedef,
+                front_sector,
+                back_sector,
+            })
+        })
+        .collect::<Result<_, WadError>>()?;
+
+    Ok(BspTree {
+        segs,
+        subsectors: builder.subsectors,
+        nodes: builder.nodes,
+    })
+}
+
+struct Builder<'a> {
+    vertices: &'a mut Vec<Vertex>,
+    lines: &'a [LineDef],
+    segs: Vec<BuildSeg>,
+    subsectors: Vec<SubSector>,
+    nodes: Vec<Node>,
+}
+
+impl Builder<'_> {
+    // Returns the child for the segs with their bounding box,
+    //  nodes are output after their children so the root is last.
+    fn build_node(&mut self, segs: Vec<BuildSeg>) -> Result<(usize, [DoomRealNum; 4]), WadError> {
+        let bbox = self.bounding_box(&segs);
+
+        let partition = match self.choose_partition(&segs) {
+            Some(partition) => partition,
+            None => return Ok((self.add_subsector(segs), bbox)),
+        };
+
+        let (front, back) = self.divide(segs, &partition);
+        if front.is_empty() || back.is_empty() {
+            // rounding the split vertices can leave nothing to divide
+            let mut segs = front;
+            segs.extend(back);
+            return Ok((self.add_subsector(segs), bbox));
+        }
+
+        let (front_child, front_bbox) = self.build_node(front)?;
+        let (back_child, back_bbox) = self.build_node(back)?;
+
+        self.nodes.push(partition.to_node([front_bbox, back_bbox], [front_child, back_child])?);
+        Ok((self.nodes.len() - 1, bbox))
+    }
+
+    fn add_subsector(&mut self, segs: Vec<BuildSeg>) -> usize {
+        self.subsectors.push(SubSector {
+            // Filled in by group_lines
+            sector: 0,
+            num_lines: segs.len(),
+            first_line: self.segs.len(),
+        });
+        self.segs.extend(segs);
+        (self.subsectors.len() - 1) | NF_SUBSECTOR
+    }
+
+    fn bounding_box(&self, segs: &[BuildSeg]) -> [DoomRealNum; 4] {
+        let mut bbox = [DoomRealNum::MIN, DoomRealNum::MAX, DoomRealNum::MAX, DoomRealNum::MIN];
+        for seg in segs {
+            for vertex in [&self.vertices[seg.v1], &self.vertices[seg.v2]] {
+                bbox[BOX_TOP] = bbox[BOX_TOP].max(vertex.y);
+                bbox[BOX_BOTTOM] = bbox[BOX_BOTTOM].min(vertex.y);
+                bbox[BOX_LEFT] = bbox[BOX_LEFT].min(vertex.x);
+                bbox[BOX_RIGHT] = bbox[BOX_RIGHT].max(vertex.x);
+            }
+        }
+        bbox
+    }
+
+    // Picks the linedef that splits the fewest segs and leaves
+    //  the sides most even, None when the segs are convex already.
+    // Ties go to the first linedef, keeping the tree deterministic.
+    fn choose_partition(&self, segs: &[BuildSeg]) -> Option<Partition> {
+        let mut best: Option<(usize, Partition)> = None;
+        let mut tried_lines = Vec::new();
+
+        for candidate in segs {
+            // both sides of a linedef give the same split
+            if tried_lines.contains(&candidate.linedef) {
+                continue;
+            }
+            tried_lines.push(candidate.linedef);
+
+            let partition = Partition::along(candidate, self.lines, self.vertices);
+            let (mut front, mut back, mut splits) = (0usize, 0usize, 0usize);
+            for seg in segs {
+                match classify(&partition, seg, self.vertices) {
+                    SegSide::Front => front += 1,
+                    SegSide::Back => back += 1,
+                    SegSide::Split(..) => splits += 1,
+                }
+
+                if best.is_some_and(|(cost, _)| splits * SPLIT_COST > cost) {
+                    break;
+                }
+            }
+
+            // nothing behind this line, it does not divide the segs
+            if back == 0 && splits == 0 {
+                continue;
+            }
+
+            let cost = splits * SPLIT_COST + front.abs_diff(back);
+            if best.is_none_or(|(best_cost, _)| cost < best_cost) {
+                best = Some((cost, partition));
+            }
+        }
+
+        best.map(|(_, partition)| partition)
+    }
+
+    // Sorts the segs to the front and back of the partition,
+    //  splitting the ones crossing it.
+    fn divide(&mut self, segs: Vec<BuildSeg>, partition: &Partition) -> (Vec<BuildSeg>, Vec<BuildSeg>) {
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+
+        for seg in segs {
+            let (side1, side2) = match classify(partition, &seg, self.vertices) {
+                SegSide::Front => {
+                    front.push(seg);
+                    continue;
+                }
+                SegSide::Back => {
+                    back.push(seg);
+                    continue;
+                }
+                SegSide::Split(side1, side2) => (side1, side2),
+            };
+
+            let (v1, v2) = (&self.vertices[seg.v1], &self.vertices[seg.v2]);
+            let (x1, y1) = (v1.x.to_fixed_bits() as i128, v1.y.to_fixed_bits() as i128);
+            let (x2, y2) = (v2.x.to_fixed_bits() as i128, v2.y.to_fixed_bits() as i128);
+            let x = x1 + div_round((x2 - x1) * side1, side1 - side2);
+            let y = y1 + div_round((y2 - y1) * side1, side1 - side2);
+
+            // too close to an end to split, the other end decides
+            if (x, y) == (x1, y1) || (x, y) == (x2, y2) {
+                let far_side = if (x, y) == (x1, y1) { side2 } else { side1 };
+                if far_side > 0 {
+                    front.push(seg);
+                } else {
+                    back.push(seg);
+                }
+                continue;
+            }
+
+            let split = self.vertices.len();
+            self.vertices.push(Vertex {
+                x: from_fixed_bits(x as i32),
+                y: from_fixed_bits(y as i32),
+            });
+
+            let distance = (((x - x1) as f64).powi(2) + ((y - y1) as f64).powi(2)).sqrt()
+                / (1 << FRAC_BITS) as f64;
+            let first = BuildSeg { v2: split, ..seg };
+            let second = BuildSeg { v1: split, offset: seg_offset(seg.offset.to_f64() + distance), ..seg };
+
+            if side1 > 0 {
+                front.push(first);
+                back.push(second);
+            } else {
+                back.push(first);
+                front.push(second);
+            }
+        }
+
+        (front, back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level::test_map::TestMap;
+    use crate::level::Level;
+    use crate::level::vertices::Vertex;
+    use crate::types::{real, RealNum};
+    use crate::wad::WadError;
+
+    // An L shaped room with a pillar and a raised
+    //  platform, neither of them convex.
+    fn l_shaped_room() -> TestMap {
+        let mut map = TestMap { sectors: vec![(0, 128), (16, 112)], ..TestMap::default() };
+        map.add_loop(&[(0, 0), (0, 512), (256, 512), (256, 256), (512, 256), (512, 0)], 0, None);
+        map.add_loop(&[(64, 64), (128, 64), (128, 128), (64, 128)], 0, None);
+        map.add_loop(&[(300, 64), (400, 64), (400, 160), (300, 160)], 0, Some(1));
+        map
+    }
+
+    // How far the point is to the left of the line from v1 to v2,
+    //  the back side, in map units.
+    fn left_of(v1: &Vertex, v2: &Vertex, x: f64, y: f64) -> f64 {
+        let (dx, dy) = (v2.x.to_f64() - v1.x.to_f64(), v2.y.to_f64() - v1.y.to_f64());
+        (dx * (y - v1.y.to_f64()) - dy * (x - v1.x.to_f64())) / dx.hypot(dy)
+    }
+
+    fn subsector_segs(level: &Level, subsector: usize) -> &[crate::level::segs::Seg] {
+        let subsector = &level.subsectors[subsector];
+        &level.segs[subsector.first_line..subsector.first_line + subsector.num_lines]
+    }
+
+    #[test]
+    fn same_tree_every_time() {
+        let first = l_shaped_room().load().unwrap();
+        let second = l_shaped_room().load().unwrap();
+
+        assert!(!first.nodes.is_empty());
+        assert_eq!(first.vertices.len(), second.vertices.len());
+        for (a, b) in first.vertices.iter().zip(&second.vertices) {
+            assert_eq!((a.x, a.y), (b.x, b.y));
+        }
+        assert_eq!(first.segs.len(), second.segs.len());
+        for (a, b) in first.segs.iter().zip(&second.segs) {
+            assert_eq!((a.v1, a.v2, a.offset, a.linedef, a.sidedef), (b.v1, b.v2, b.offset, b.linedef, b.sidedef));
+        }
+        assert_eq!(first.subsectors.len(), second.subsectors.len());
+        for (a, b) in first.subsectors.iter().zip(&second.subsectors) {
+            assert_eq!((a.first_line, a.num_lines, a.sector), (b.first_line, b.num_lines, b.sector));
+        }
+        assert_eq!(first.nodes.len(), second.nodes.len());
+        for (a, b) in first.nodes.iter().zip(&second.nodes) {
+            assert_eq!((a.x, a.y, a.dx, a.dy, a.children), (b.x, b.y, b.dx, b.dy, b.children));
+        }
+    }
+
+    #[test]
+    fn subsectors_are_convex() {
+        let level = l_shaped_room().load().unwrap();
+
+        for subsector in 0..level.subsectors.len() {
+            let segs = subsector_segs(&level, subsector);
+            for seg in segs {
+                let (v1, v2) = (&level.vertices[seg.v1], &level.vertices[seg.v2]);
+                for other in segs {
+                    for vertex in [&level.vertices[other.v1], &level.vertices[other.v2]] {
+                        assert!(left_of(v1, v2, vertex.x.to_f64(), vertex.y.to_f64()) < 0.01,
+                                "subsector {} is not convex", subsector);
+                    }
+                }
+                assert_eq!(seg.front_sector, level.subsectors[subsector].sector);
+            }
+        }
+    }
+
+    #[test]
+    fn points_in_subsectors() {
+        let level = l_shaped_room().load().unwrap();
+
+        for (x, y, sector) in [(32, 32, 0), (200, 400, 0), (96, 200, 0), (450, 200, 0), (350, 100, 1), (390, 150, 1)] {
+            assert_eq!(level.point_in_sector(real(x), real(y)), sector, "({}, {})", x, y);
+        }
+
+        // every point in the room is in front of all segs of its subsector
+        for x in (8..512).step_by(16) {
+            for y in (8..512).step_by(16) {
+                let in_pillar = (64..128).contains(&x) && (64..128).contains(&y);
+                if in_pillar || (x > 256 && y > 256) {
+                    continue;
+                }
+
+                let subsector = level.point_in_subsector(real(x), real(y));
+                for seg in subsector_segs(&level, subsector) {
+                    let (v1, v2) = (&level.vertices[seg.v1], &level.vertices[seg.v2]);
+                    assert!(left_of(v1, v2, x as f64, y as f64) <= 0.0,
+                            "({}, {}) is behind a seg of subsector {}", x, y, subsector);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn partition_out_of_range() {
+        // split across its whole width of 40000 units
+        let map = TestMap {
+            vertices: vec![(-20000, -20000), (-20000, 0), (-20000, 20000), (20000, 20000), (20000, 0), (20000, -20000)],
+            sectors: vec![(0, 128), (0, 128)],
+            lines: vec![
+                (0, 1, 0, None),
+                (1, 4, 0, Some(1)),
+                (4, 5, 0, None),
+                (5, 0, 0, None),
+                (1, 2, 1, None),
+                (2, 3, 1, None),
+                (3, 4, 1, None),
+            ],
+            ..TestMap::default()
+        };
+
+        assert!(matches!(map.load(), Err(WadError::MalformedLump { name, .. }) if name == "LINEDEFS"));
+    }
+}
@@ -1,9 +1,10 @@
-use crate::types::{DoomRealNum, real};
-use crate::wad::LumpStore;
+use crate::types::{DoomRealNum, real, fixed_mul, FRAC_BITS, RealNum};
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::ML_NODES;
+use crate::level::segs::Seg;
+use crate::level::subsectors::SubSector;
 use std::mem::size_of;
 use std::io::Cursor;
-use fixed::{FixedI32, FixedU32};
-use fixed::types::{U16F16, I16F16, U32F0};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -18,28 +19,167 @@ struct NodeRaw {
     children: [u16; 2],
 }
 
+// Indicate a leaf, in the children of the NODES lump.
+const NF_SUBSECTOR_RAW: u16 = 0x8000;
+
+// Indicate a leaf.
+// Extended nodes have 32 bit children, so a leaf is
+//  marked by the top bit of those in memory.
+pub const NF_SUBSECTOR: usize = 0x8000_0000;
+
+/// The BSP of a map: the nodes with the segs and
+/// subsectors they lead to.
+pub struct BspTree {
+    pub(crate) segs: Vec<Seg>,
+    pub(crate) subsectors: Vec<SubSector>,
+    pub(crate) nodes: Vec<Node>,
+}
+
 pub struct Node {
     // Partition line
-    x: DoomRealNum,
-    y: DoomRealNum,
-    dx: DoomRealNum,
-    dy: DoomRealNum,
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum,
+    pub(crate) dx: DoomRealNum,
+    pub(crate) dy: DoomRealNum,
 
     // Bounding box for each child
-    bbox: [[DoomRealNum; 4]; 2],
+    pub(crate) bbox: [[DoomRealNum; 4]; 2],
 
     // If NF_SUBSECTOR its a subsector.
-    children: [usize; 2],
+    pub(crate) children: [usize; 2],
+}
+
+/// The subsector a node child refers to,
+/// None if the child is another node.
+pub fn child_subsector(child: usize) -> Option<usize> {
+    if child & NF_SUBSECTOR != 0 {
+        Some(child & !NF_SUBSECTOR)
+    } else {
+        None
+    }
+}
+
+impl Node {
+    /// Returns which side of the partition line the point is on,
+    /// 0 for the front and 1 for the back. See R_PointOnSide.
+    pub fn point_on_side(&self, x: DoomRealNum, y: DoomRealNum) -> usize {
+        point_on_line_side(x, y, self.x, self.y, self.dx, self.dy)
+    }
+}
+
+/// Returns which side of the line through (line_x, line_y) along
+/// (line_dx, line_dy) the point is on, 0 for the front and 1 for the back.
+/// Shared by R_PointOnSide and R_PointOnSegSide.
+pub fn point_on_line_side(x: DoomRealNum, y: DoomRealNum, line_x: DoomRealNum, line_y: DoomRealNum,
+                          line_dx: DoomRealNum, line_dy: DoomRealNum) -> usize {
+    if line_dx == real(0) {
+        return if x <= line_x {
+            (line_dy > real(0)) as usize
+        } else {
+            (line_dy < real(0)) as usize
+        };
+    }
+
+    if line_dy == real(0) {
+        return if y <= line_y {
+            (line_dx < real(0)) as usize
+        } else {
+            (line_dx > real(0)) as usize
+        };
+    }
+
+    let dx = x.wrapping_sub(line_x);
+    let dy = y.wrapping_sub(line_y);
+
+    let negative = |value: DoomRealNum| value < real(0);
+
+    // Try to quickly decide by looking at sign bits.
+    if negative(line_dy) ^ negative(line_dx) ^ negative(dx) ^ negative(dy) {
+        // (left is negative)
+        return (negative(line_dy) ^ negative(dx)) as usize;
+    }
+
+    let left = fixed_mul(line_dy.shr(FRAC_BITS as u32), dx);
+    let right = fixed_mul(dy, line_dx.shr(FRAC_BITS as u32));
+
+    if right < left {
+        // front side
+        return 0;
+    }
+    // back side
+    1
+}
+
+// R_PointInSubsector
+// Walks the node tree down from the root, the last node,
+//  to the subsector containing the point.
+pub fn point_in_subsector(nodes: &[Node], x: DoomRealNum, y: DoomRealNum) -> usize {
+    // single subsector is a special case
+    if nodes.is_empty() {
+        return 0;
+    }
+
+    let mut node_num = nodes.len() - 1;
+    loop {
+        if let Some(subsector) = child_subsector(node_num) {
+            return subsector;
+        }
+
+        let node = &nodes[node_num];
+        node_num = node.children[node.point_on_side(x, y)];
+    }
+}
+
+/// Checks that the children of every node are nodes before it,
+/// the root being the last, or existing subsectors. Walking
+/// the tree then always ends in a subsector, which a map
+/// without nodes also needs to have.
+pub fn check_children(nodes: &[Node], num_subsectors: usize) -> Result<(), WadError> {
+    if num_subsectors == 0 {
+        return Err(WadError::MalformedLump {
+            name: "SSECTORS".to_string(),
+            reason: "Map has no subsectors".to_string(),
+        });
+    }
+
+    for (node_num, node) in nodes.iter().enumerate() {
+        for &child in &node.children {
+            let valid = match child_subsector(child) {
+                Some(subsector) => subsector < num_subsectors,
+                None => child < node_num,
+            };
+            if !valid {
+                return Err(WadError::MalformedLump {
+                    name: "NODES".to_string(),
+                    reason: format!("Node {} has an invalid child {:#x}", node_num, child),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn raw_child(child: u16) -> usize {
+    if child & NF_SUBSECTOR_RAW != 0 {
+        (child & !NF_SUBSECTOR_RAW) as usize | NF_SUBSECTOR
+    } else {
+        child as usize
+    }
+}
+
+// P_LoadNodes
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Result<Vec<Node>, WadError> {
+    load_nodes(lumps.get_lump(By::Number(map_lump.offset(ML_NODES)))?)
 }
 
-pub fn load_nodes(data: &[u8]) -> Vec<Node> {
+pub fn load_nodes(data: &[u8]) -> Result<Vec<Node>, WadError> {
     let num_nodes = data.len() / size_of::<NodeRaw>();
 
     let mut nodes = Vec::new();
     let mut nodes_cursor = Cursor::new(data);
-    for i in 0..num_nodes {
-        let raw_node: NodeRaw = bincode::deserialize_from(&mut nodes_cursor)
-            .expect("Load Nodes failed");
+    for _ in 0..num_nodes {
+        let raw_node: NodeRaw = bincode::deserialize_from(&mut nodes_cursor)?;
 
         let node = Node {
             x: real(raw_node.x),
@@ -61,11 +201,11 @@ pub fn load_nodes(data: &[u8]) -> Vec<Node> {
                 ]
             ],
             children: [
-                raw_node.children[0] as usize,
-                raw_node.children[1] as usize,
+                raw_child(raw_node.children[0]),
+                raw_child(raw_node.children[1]),
             ]
         };
         nodes.push(node);
     }
-    nodes
+    Ok(nodes)
 }
\ No newline at end of file
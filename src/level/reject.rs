@@ -0,0 +1,61 @@
+use crate::wad::{LumpStore, By, LumpNumber};
+
+// The REJECT table, one bit for every pair of sectors
+// that can not see each other at all.
+pub struct Reject {
+    sector_count: usize,
+    data: Vec<u8>,
+}
+
+impl Reject {
+    /// Whether the table says nothing in the from sector
+    /// can see anything in the to sector.
+    pub fn rejects(&self, from: usize, to: usize) -> bool {
+        let pnum = from * self.sector_count + to;
+        self.data[pnum >> 3] & (1 << (pnum & 7)) != 0
+    }
+}
+
+// P_LoadReject
+// Short or missing lumps are padded with zeros,
+//  so those sector pairs are left to the line of sight checks.
+pub fn load(lumps: &LumpStore, reject_lump: Option<LumpNumber>, sector_count: usize) -> Reject {
+    let mut data = reject_lump
+        .and_then(|lump_number| lumps.get_lump(By::Number(lump_number)).ok())
+        .map(|data| data.to_vec())
+        .unwrap_or_default();
+
+    data.resize((sector_count * sector_count).div_ceil(8), 0);
+
+    Reject {
+        sector_count,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wad::store;
+    use crate::wad::WadBuilder;
+
+    #[test]
+    fn short_lump_is_padded() {
+        let mut wad = WadBuilder::new();
+        // three sectors need two bytes
+        wad.add_lump("REJECT", vec![0b1000_0010]).unwrap();
+        let lumps = store(&[("map.wad", &wad)]);
+
+        let reject = super::load(&lumps, lumps.get_lump_number("REJECT"), 3);
+        assert!(reject.rejects(0, 1));
+        assert!(reject.rejects(2, 1));
+        assert!(!reject.rejects(0, 0));
+        assert!(!reject.rejects(2, 2));
+    }
+
+    #[test]
+    fn missing_lump_rejects_nothing() {
+        let lumps = store(&[]);
+        let reject = super::load(&lumps, None, 3);
+        assert!((0..3).all(|from| (0..3).all(|to| !reject.rejects(from, to))));
+    }
+}
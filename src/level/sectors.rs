@@ -1,5 +1,7 @@
 use crate::types::{DoomRealNum, real};
-use crate::wad::{LumpStore, By, LumpNumber};
+use crate::wad::{LumpStore, By, LumpNumber, WadError, wad_string};
+use crate::rendering::flats::FlatData;
+use crate::level::ML_SECTORS;
 use std::io::Seek;
 use std::mem::size_of;
 use serde::Deserialize;
@@ -16,36 +18,71 @@ struct SectorRaw {
 }
 
 pub struct Sector {
-    floor_height: DoomRealNum,
-    ceiling_height: DoomRealNum,
-    floor_pic: usize,
-    ceiling_pic: usize,
-    light_level: i16,
-    special: i16,
-    tag: i16,
+    pub(crate) floor_height: DoomRealNum,
+    pub(crate) ceiling_height: DoomRealNum,
+    pub(crate) floor_pic: usize,
+    pub(crate) ceiling_pic: usize,
+    pub(crate) light_level: i16,
+    pub(crate) special: i16,
+    pub(crate) tag: i16,
+
+    // mapblock bounding box for height changes
+    pub(crate) block_box: [usize; 4],
+    // bounding box in map coordinates
+    pub(crate) bbox: [DoomRealNum; 4],
+
+    // origin for any sounds played by the sector
+    pub(crate) sound_origin: (DoomRealNum, DoomRealNum),
+
+    // Indices of all lines bordering this sector
+    pub(crate) lines: Vec<usize>,
 
-    // TODO: Unfinished, look at definition in original code
+    // list of mobjs in sector, the first one's thinker
+    pub(crate) thing_list: Option<usize>,
 }
 
-pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Vec<Sector> {
-    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(8)));
+impl Sector {
+    /// A sector as described in the map,
+    /// group_lines fills in the rest.
+    pub fn new(floor_height: DoomRealNum, ceiling_height: DoomRealNum, floor_pic: usize, ceiling_pic: usize,
+               light_level: i16, special: i16, tag: i16) -> Self {
+        Sector {
+            floor_height,
+            ceiling_height,
+            floor_pic,
+            ceiling_pic,
+            light_level,
+            special,
+            tag,
 
-    let sector_count = data.stream_len().unwrap() as usize / size_of::<SectorRaw>();
+            block_box: [0; 4],
+            bbox: [real(0); 4],
+            sound_origin: (real(0), real(0)),
+            lines: Vec::new(),
+            thing_list: None,
+        }
+    }
+}
+
+// P_LoadSectors
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber, flats: &FlatData) -> Result<Vec<Sector>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_SECTORS)))?;
+
+    let sector_count = data.stream_len()? as usize / size_of::<SectorRaw>();
     let mut sectors = Vec::new();
 
     for _ in 0..sector_count {
-        let raw_sector: SectorRaw = bincode::deserialize_from(&mut data).unwrap();
-        sectors.push(Sector {
-            floor_height: real(raw_sector.floor_height),
-            ceiling_height: real(raw_sector.ceiling_height),
-
-            floor_pic: 0,
-            ceiling_pic: 0,
-            light_level: 0,
-            special: 0,
-            tag: 0
-        });
+        let raw_sector: SectorRaw = bincode::deserialize_from(&mut data)?;
+        sectors.push(Sector::new(
+            real(raw_sector.floor_height),
+            real(raw_sector.ceiling_height),
+            flats.flat_num_for_name(&wad_string(&raw_sector.floor_pic)?)?,
+            flats.flat_num_for_name(&wad_string(&raw_sector.ceiling_pic)?)?,
+            raw_sector.light_level,
+            raw_sector.special,
+            raw_sector.tag,
+        ));
     }
 
-    sectors
-}
\ No newline at end of file
+    Ok(sectors)
+}
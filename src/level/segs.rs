@@ -1,3 +1,11 @@
+use crate::types::{DoomRealNum, Angle, real};
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::{ML_SEGS, bad_reference};
+use crate::level::vertices::Vertex;
+use crate::level::linedefs::{LineDef, ML_TWOSIDED};
+use crate::level::sidedefs::SideDef;
+use std::io::Seek;
+use std::mem::size_of;
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -10,7 +18,86 @@ struct SegRaw {
     offset: i16,
 }
 
+// LineSeg, generated by splitting LineDefs
+// using partition lines selected by BSP builder.
+pub struct Seg {
+    pub(crate) v1: usize,
+    pub(crate) v2: usize,
 
+    pub(crate) offset: DoomRealNum,
 
-// LineSeg, generated by splitting LineDefs
-// using partition lines selected by BSP builder.
\ No newline at end of file
+    pub(crate) angle: Angle,
+
+    pub(crate) sidedef: usize,
+    pub(crate) linedef: usize,
+
+    // Sector references.
+    // Could be retrieved from linedef, too.
+    // back_sector is None for one sided lines
+    pub(crate) front_sector: usize,
+    pub(crate) back_sector: Option<usize>,
+}
+
+/// Looks up the sidedef a seg on the given side of a linedef
+/// is part of, with the sectors in front of and behind it.
+pub fn seg_sides(lump: &str, linedef: usize, side: usize, lines: &[LineDef], sides: &[SideDef])
+                 -> Result<(usize, usize, Option<usize>), WadError> {
+    let line = lines.get(linedef)
+        .ok_or_else(|| bad_reference(lump, "linedef", linedef))?;
+
+    let sidedef = line.sidenum[side]
+        .ok_or_else(|| bad_reference(lump, "sidedef of linedef", linedef))?;
+
+    let back_sector = if line.flags & ML_TWOSIDED != 0 {
+        line.sidenum[side ^ 1].map(|back_side| sides[back_side].sector)
+    } else {
+        None
+    };
+
+    Ok((sidedef, sides[sidedef].sector, back_sector))
+}
+
+/// The offset of a seg this far along its linedef, saturated
+/// at the largest one a seg can have, which only segs of lines
+/// longer than a vanilla map can be reach.
+pub fn seg_offset(distance: f64) -> DoomRealNum {
+    real(distance.min(i16::MAX as f64))
+}
+
+// P_LoadSegs
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber, vertices: &[Vertex], lines: &[LineDef], sides: &[SideDef]) -> Result<Vec<Seg>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_SEGS)))?;
+
+    let seg_count = data.stream_len()? as usize / size_of::<SegRaw>();
+    let mut segs = Vec::with_capacity(seg_count);
+
+    for _ in 0..seg_count {
+        let raw_seg: SegRaw = bincode::deserialize_from(&mut data)?;
+
+        let v1 = raw_seg.v1 as u16 as usize;
+        let v2 = raw_seg.v2 as u16 as usize;
+        if v1 >= vertices.len() {
+            return Err(bad_reference("SEGS", "vertex", v1));
+        }
+        if v2 >= vertices.len() {
+            return Err(bad_reference("SEGS", "vertex", v2));
+        }
+
+        let linedef = raw_seg.linedef as u16 as usize;
+        let (sidedef, front_sector, back_sector) =
+            seg_sides("SEGS", linedef, (raw_seg.side & 1) as usize, lines, sides)?;
+
+        segs.push(Seg {
+            v1,
+            v2,
+            offset: real(raw_seg.offset),
+            angle: ((raw_seg.angle as u16 as u32) << 16) as Angle,
+            sidedef,
+            linedef,
+            front_sector,
+            back_sector,
+        });
+    }
+
+    Ok(segs)
+}
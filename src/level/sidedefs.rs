@@ -0,0 +1,71 @@
+use crate::types::{DoomRealNum, real};
+use crate::wad::{LumpStore, By, LumpNumber, WadError, wad_string};
+use crate::rendering::textures::TextureData;
+use crate::level::{ML_SIDEDEFS, bad_reference};
+use std::io::Seek;
+use std::mem::size_of;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct SideDefRaw {
+    texture_offset: i16,
+    row_offset: i16,
+    top_texture: [u8; 8],
+    bottom_texture: [u8; 8],
+    mid_texture: [u8; 8],
+    // Front sector, towards viewer.
+    sector: i16,
+}
+
+pub struct SideDef {
+    // add this to the calculated texture column
+    pub(crate) texture_offset: DoomRealNum,
+    // add this to the calculated texture top
+    pub(crate) row_offset: DoomRealNum,
+
+    // Texture indices.
+    // We do not maintain names here.
+    pub(crate) top_texture: usize,
+    pub(crate) bottom_texture: usize,
+    pub(crate) mid_texture: usize,
+
+    // Sector the SideDef is facing.
+    pub(crate) sector: usize,
+}
+
+/// The texture number of a sidedef texture, 0 for "-".
+pub fn texture_num(lump: &str, textures: &TextureData, name: &str) -> Result<usize, WadError> {
+    textures.check_texture_num_for_name(name)
+        .ok_or_else(|| WadError::MalformedLump {
+            name: lump.to_string(),
+            reason: format!("Texture {} not found", name),
+        })
+}
+
+// P_LoadSideDefs
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber, textures: &TextureData, sector_count: usize) -> Result<Vec<SideDef>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_SIDEDEFS)))?;
+
+    let side_count = data.stream_len()? as usize / size_of::<SideDefRaw>();
+    let mut sides = Vec::with_capacity(side_count);
+
+    for _ in 0..side_count {
+        let raw_side: SideDefRaw = bincode::deserialize_from(&mut data)?;
+
+        let sector = raw_side.sector as u16 as usize;
+        if sector >= sector_count {
+            return Err(bad_reference("SIDEDEFS", "sector", sector));
+        }
+
+        sides.push(SideDef {
+            texture_offset: real(raw_side.texture_offset),
+            row_offset: real(raw_side.row_offset),
+            top_texture: texture_num("SIDEDEFS", textures, &wad_string(&raw_side.top_texture)?)?,
+            bottom_texture: texture_num("SIDEDEFS", textures, &wad_string(&raw_side.bottom_texture)?)?,
+            mid_texture: texture_num("SIDEDEFS", textures, &wad_string(&raw_side.mid_texture)?)?,
+            sector,
+        });
+    }
+
+    Ok(sides)
+}
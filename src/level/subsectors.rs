@@ -0,0 +1,44 @@
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::ML_SSECTORS;
+use std::io::Seek;
+use std::mem::size_of;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct SubSectorRaw {
+    num_segs: i16,
+    // Index of first one, segs are stored sequentially.
+    first_seg: i16,
+}
+
+// A SubSector.
+// References a Sector.
+// Basically, this is a list of LineSegs,
+//  indicating the visible walls that define
+//  (all or some) sides of a convex BSP leaf.
+pub struct SubSector {
+    pub(crate) sector: usize,
+    pub(crate) num_lines: usize,
+    pub(crate) first_line: usize,
+}
+
+// P_LoadSubsectors
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Result<Vec<SubSector>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_SSECTORS)))?;
+
+    let subsector_count = data.stream_len()? as usize / size_of::<SubSectorRaw>();
+    let mut subsectors = Vec::with_capacity(subsector_count);
+
+    for _ in 0..subsector_count {
+        let raw_subsector: SubSectorRaw = bincode::deserialize_from(&mut data)?;
+
+        subsectors.push(SubSector {
+            // Filled in by group_lines
+            sector: 0,
+            num_lines: raw_subsector.num_segs as u16 as usize,
+            first_line: raw_subsector.first_seg as u16 as usize,
+        });
+    }
+
+    Ok(subsectors)
+}
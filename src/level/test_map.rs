@@ -0,0 +1,135 @@
+// A map written as Doom format lumps for the tests,
+// loaded over the rendering test resources.
+
+use crate::game_context::GameMode;
+use crate::level::{self, Level};
+use crate::rendering::{init_rendering, test_resources};
+use crate::wad::{WadBuilder, WadError, store};
+
+/// The lines of a map in Doom format, with the floor
+/// and ceiling heights of the sectors.
+/// The nodes and blockmap are left to be built.
+#[derive(Default)]
+pub(crate) struct TestMap {
+    pub(crate) vertices: Vec<(i16, i16)>,
+    pub(crate) sectors: Vec<(i16, i16)>,
+    // v1, v2, front sector and back sector
+    pub(crate) lines: Vec<(usize, usize, usize, Option<usize>)>,
+    // x, y and doomednum
+    pub(crate) things: Vec<(i16, i16, i16)>,
+    pub(crate) reject: Vec<u8>,
+}
+
+impl TestMap {
+    /// Two rooms next to each other, open to each other
+    /// over a step, with a player in the west room
+    /// and an imp in the east room.
+    pub(crate) fn two_rooms() -> Self {
+        Self {
+            vertices: vec![(0, 0), (0, 256), (256, 256), (256, 0), (512, 256), (512, 0)],
+            sectors: vec![(0, 128), (24, 96)],
+            lines: vec![
+                (0, 1, 0, None),
+                (1, 2, 0, None),
+                (3, 0, 0, None),
+                (2, 3, 0, Some(1)),
+                (2, 4, 1, None),
+                (4, 5, 1, None),
+                (5, 3, 1, None),
+            ],
+            things: vec![(128, 128, 1), (400, 128, 3001)],
+            reject: Vec::new(),
+        }
+    }
+
+    /// Adds a closed loop of lines through the points, with the
+    /// front sector on the right side of each line.
+    pub(crate) fn add_loop(&mut self, points: &[(i16, i16)], front: usize, back: Option<usize>) {
+        let first = self.vertices.len();
+        self.vertices.extend_from_slice(points);
+        for index in 0..points.len() {
+            let next = (index + 1) % points.len();
+            self.lines.push((first + index, first + next, front, back));
+        }
+    }
+
+    /// Adds the map lumps after the marker lump.
+    pub(crate) fn add_to(&self, builder: &mut WadBuilder, map_name: &str) {
+        let mut things = Vec::new();
+        for &(x, y, doomed_num) in &self.things {
+            // on all skills
+            things.extend(shorts(&[x, y, 0, doomed_num, 7]));
+        }
+
+        let mut lines = Vec::new();
+        let mut sides = Vec::new();
+        for &(v1, v2, front, back) in &self.lines {
+            let (flags, back_side) = match back {
+                // ML_TWOSIDED
+                Some(_) => (4, (sides.len() / 30 + 1) as i16),
+                // ML_BLOCKING
+                None => (1, -1),
+            };
+            lines.extend(shorts(&[v1 as i16, v2 as i16, flags, 0, 0, (sides.len() / 30) as i16, back_side]));
+
+            let middle = if back.is_some() { "-" } else { "STARTAN" };
+            for (sector, middle) in [(Some(front), middle), (back, "-")] {
+                if let Some(sector) = sector {
+                    sides.extend(shorts(&[0, 0]));
+                    sides.extend(name8("STARTAN"));
+                    sides.extend(name8("STARTAN"));
+                    sides.extend(name8(middle));
+                    sides.extend(shorts(&[sector as i16]));
+                }
+            }
+        }
+
+        let vertices = self.vertices.iter()
+            .flat_map(|&(x, y)| shorts(&[x, y]))
+            .collect();
+
+        let mut sectors = Vec::new();
+        for &(floor, ceiling) in &self.sectors {
+            sectors.extend(shorts(&[floor, ceiling]));
+            sectors.extend(name8("FLOOR1"));
+            sectors.extend(name8("CEIL1"));
+            sectors.extend(shorts(&[160, 0, 0]));
+        }
+
+        builder.add_lump(map_name, Vec::new()).unwrap();
+        builder.add_lump("THINGS", things).unwrap();
+        builder.add_lump("LINEDEFS", lines).unwrap();
+        builder.add_lump("SIDEDEFS", sides).unwrap();
+        builder.add_lump("VERTEXES", vertices).unwrap();
+        builder.add_lump("SEGS", Vec::new()).unwrap();
+        builder.add_lump("SSECTORS", Vec::new()).unwrap();
+        builder.add_lump("NODES", Vec::new()).unwrap();
+        builder.add_lump("SECTORS", sectors).unwrap();
+        builder.add_lump("REJECT", self.reject.clone()).unwrap();
+        builder.add_lump("BLOCKMAP", Vec::new()).unwrap();
+    }
+
+    /// Loads the map as MAP01 of a PWAD over the resources.
+    pub(crate) fn load(&self) -> Result<Level, WadError> {
+        let mut pwad = WadBuilder::new();
+        self.add_to(&mut pwad, "MAP01");
+        load_map01(&pwad)
+    }
+}
+
+/// Loads MAP01 of the PWAD over the resources.
+pub(crate) fn load_map01(pwad: &WadBuilder) -> Result<Level, WadError> {
+    let lumps = store(&[("doom2.wad", &test_resources()), ("map.wad", pwad)]);
+    let render_data = init_rendering(&lumps)?;
+    level::load(&lumps, &render_data, &GameMode::Commercial, 1, 1)
+}
+
+fn name8(name: &str) -> [u8; 8] {
+    let mut bytes = [0u8; 8];
+    bytes[..name.len()].copy_from_slice(name.as_bytes());
+    bytes
+}
+
+fn shorts(values: &[i16]) -> Vec<u8> {
+    values.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
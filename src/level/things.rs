@@ -0,0 +1,131 @@
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::ML_THINGS;
+use std::io::Seek;
+use std::mem::size_of;
+use serde::Deserialize;
+
+// Thing options in the THINGS lump, see doomdata.h.
+// Skill flags.
+pub const MTF_EASY: i16 = 1;
+pub const MTF_NORMAL: i16 = 2;
+pub const MTF_HARD: i16 = 4;
+// Deaf monsters/do not react to sound.
+pub const MTF_AMBUSH: i16 = 8;
+// Only spawned in multiplayer games.
+pub const MTF_NOTSINGLE: i16 = 16;
+// Boom: not spawned in deathmatch or cooperative games, and friendly monsters.
+pub const MTF_NOTDM: i16 = 32;
+pub const MTF_NOTCOOP: i16 = 64;
+pub const MTF_FRIEND: i16 = 128;
+
+// Hexen thing flags that differ from the Doom ones.
+// The skill flags and MTF_AMBUSH are the same.
+pub const MTF_DORMANT: i16 = 16;
+pub const MTF_GSINGLE: i16 = 256;
+pub const MTF_GCOOP: i16 = 512;
+pub const MTF_GDEATHMATCH: i16 = 1024;
+
+#[derive(Deserialize)]
+struct ThingRaw {
+    x: i16,
+    y: i16,
+    angle: i16,
+    thing_type: i16,
+    options: i16,
+}
+
+#[derive(Deserialize)]
+struct HexenThingRaw {
+    tid: i16,
+    x: i16,
+    y: i16,
+    height: i16,
+    angle: i16,
+    thing_type: i16,
+    options: i16,
+    special: u8,
+    args: [u8; 5],
+}
+
+// Thing definition, position, orientation and type,
+// plus skill/visibility flags and attributes.
+#[derive(Copy, Clone, Default)]
+pub struct Thing {
+    pub(crate) x: i16,
+    pub(crate) y: i16,
+    pub(crate) angle: i16,
+    pub(crate) thing_type: i16,
+    pub(crate) options: i16,
+
+    // Hexen: the thing id for scripts, the height above
+    //  the floor, the special run when it dies, and
+    //  whether it does nothing until a script wakes it up.
+    pub(crate) tid: i16,
+    pub(crate) height: i16,
+    pub(crate) special: u8,
+    pub(crate) args: [u8; 5],
+    pub(crate) dormant: bool,
+}
+
+// Things are spawned in P_LoadThings, here they
+// are only read so they can be spawned later.
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Result<Vec<Thing>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_THINGS)))?;
+
+    let thing_count = data.stream_len()? as usize / size_of::<ThingRaw>();
+    let mut things = Vec::with_capacity(thing_count);
+
+    for _ in 0..thing_count {
+        let raw_thing: ThingRaw = bincode::deserialize_from(&mut data)?;
+        things.push(Thing {
+            x: raw_thing.x,
+            y: raw_thing.y,
+            angle: raw_thing.angle,
+            thing_type: raw_thing.thing_type,
+            options: raw_thing.options,
+            ..Thing::default()
+        });
+    }
+
+    Ok(things)
+}
+
+// P_LoadThings of Hexen
+// The game mode flags are turned into the Doom ones,
+//  the class flags are not used.
+pub fn load_hexen(lumps: &LumpStore, map_lump: LumpNumber) -> Result<Vec<Thing>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_THINGS)))?;
+
+    let thing_count = data.stream_len()? as usize / size_of::<HexenThingRaw>();
+    let mut things = Vec::with_capacity(thing_count);
+
+    for _ in 0..thing_count {
+        let raw_thing: HexenThingRaw = bincode::deserialize_from(&mut data)?;
+
+        let mut options = raw_thing.options & (MTF_EASY | MTF_NORMAL | MTF_HARD | MTF_AMBUSH);
+        if raw_thing.options & MTF_GSINGLE == 0 {
+            options |= MTF_NOTSINGLE;
+        }
+        if raw_thing.options & MTF_GCOOP == 0 {
+            options |= MTF_NOTCOOP;
+        }
+        if raw_thing.options & MTF_GDEATHMATCH == 0 {
+            options |= MTF_NOTDM;
+        }
+
+        things.push(Thing {
+            x: raw_thing.x,
+            y: raw_thing.y,
+            angle: raw_thing.angle,
+            thing_type: raw_thing.thing_type,
+            options,
+            tid: raw_thing.tid,
+            height: raw_thing.height,
+            special: raw_thing.special,
+            args: raw_thing.args,
+            dormant: raw_thing.options & MTF_DORMANT != 0,
+        });
+    }
+
+    Ok(things)
+}
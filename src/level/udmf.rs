@@ -0,0 +1,574 @@
+use crate::types::real;
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::rendering::RenderData;
+use crate::level::bad_reference;
+use crate::level::vertices::Vertex;
+use crate::level::sectors::Sector;
+use crate::level::sidedefs::{SideDef, texture_num};
+use crate::level::linedefs::{LineDef, ActionSpecial, ML_BLOCKING, ML_BLOCKMONSTERS, ML_TWOSIDED, ML_DONTPEGTOP,
+                             ML_DONTPEGBOTTOM, ML_SECRET, ML_SOUNDBLOCK, ML_DONTDRAW, ML_MAPPED, ML_PASSUSE,
+                             SPAC_CROSS, SPAC_USE, SPAC_MCROSS, SPAC_IMPACT, SPAC_PUSH, SPAC_PCROSS};
+use crate::level::things::{Thing, MTF_EASY, MTF_NORMAL, MTF_HARD, MTF_AMBUSH, MTF_NOTSINGLE,
+                           MTF_NOTDM, MTF_NOTCOOP, MTF_FRIEND};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+// The namespaces of the supported maps, zdoom ones
+//  have Hexen style specials with arguments.
+const NAMESPACES: [&str; 3] = ["doom", "boom", "zdoom"];
+
+// Linedef flag fields and the LINEDEFS flags they stand for.
+const LINE_FLAGS: [(&str, i16); 10] = [
+    ("blocking", ML_BLOCKING),
+    ("blockmonsters", ML_BLOCKMONSTERS),
+    ("twosided", ML_TWOSIDED),
+    ("dontpegtop", ML_DONTPEGTOP),
+    ("dontpegbottom", ML_DONTPEGBOTTOM),
+    ("secret", ML_SECRET),
+    ("blocksound", ML_SOUNDBLOCK),
+    ("dontdraw", ML_DONTDRAW),
+    ("mapped", ML_MAPPED),
+    ("passuse", ML_PASSUSE),
+];
+
+// zdoom linedef fields for how the special is activated,
+//  and the SPAC values they stand for.
+const ACTIVATION_FLAGS: [(&str, u8); 6] = [
+    ("playercross", SPAC_CROSS),
+    ("playeruse", SPAC_USE),
+    ("monstercross", SPAC_MCROSS),
+    ("impact", SPAC_IMPACT),
+    ("playerpush", SPAC_PUSH),
+    ("missilecross", SPAC_PCROSS),
+];
+
+/// The map data of a TEXTMAP lump, in the same
+/// structures as the binary map lumps load into.
+pub struct TextMap {
+    pub(crate) vertices: Vec<Vertex>,
+    pub(crate) sectors: Vec<Sector>,
+    pub(crate) sides: Vec<SideDef>,
+    pub(crate) lines: Vec<LineDef>,
+    pub(crate) things: Vec<Thing>,
+}
+
+#[derive(Clone)]
+enum Value {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Bool(bool),
+    // Unquoted words other than true and false.
+    Keyword,
+}
+
+#[derive(PartialEq)]
+enum Token {
+    Identifier(String),
+    Symbol(u8),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+// A block like "vertex { x = 0.0; y = 0.0; }", field names are lower case.
+struct Block {
+    kind: String,
+    line: usize,
+    fields: HashMap<String, Value>,
+}
+
+fn malformed(reason: String) -> WadError {
+    WadError::MalformedLump {
+        name: "TEXTMAP".to_string(),
+        reason,
+    }
+}
+
+impl Block {
+    fn field_error(&self, key: &str, expected: &str) -> WadError {
+        malformed(format!("Field {} of the {} on line {} is not {}", key, self.kind, self.line, expected))
+    }
+
+    fn int(&self, key: &str, default: i64) -> Result<i64, WadError> {
+        match self.fields.get(key) {
+            None => Ok(default),
+            Some(Value::Int(value)) => Ok(*value),
+            Some(_) => Err(self.field_error(key, "an integer")),
+        }
+    }
+
+    fn required_int(&self, key: &str) -> Result<i64, WadError> {
+        match self.fields.get(key) {
+            None => Err(malformed(format!("The {} on line {} has no {}", self.kind, self.line, key))),
+            Some(_) => self.int(key, 0),
+        }
+    }
+
+    // Integers are taken as floats too.
+    fn float(&self, key: &str, default: f64) -> Result<f64, WadError> {
+        match self.fields.get(key) {
+            None => Ok(default),
+            Some(Value::Int(value)) => Ok(*value as f64),
+            Some(Value::Float(value)) => Ok(*value),
+            Some(_) => Err(self.field_error(key, "a number")),
+        }
+    }
+
+    // Coordinates and heights, which have to fit
+    //  the shorts of the binary map lumps.
+    fn map_units(&self, key: &str, default: f64) -> Result<f64, WadError> {
+        let value = self.float(key, default)?;
+        if !(i16::MIN as f64..=i16::MAX as f64).contains(&value) {
+            return Err(malformed(format!("Field {} of the {} on line {} is out of range", key, self.kind, self.line)));
+        }
+        Ok(value)
+    }
+
+    fn required_map_units(&self, key: &str) -> Result<f64, WadError> {
+        match self.fields.get(key) {
+            None => Err(malformed(format!("The {} on line {} has no {}", self.kind, self.line, key))),
+            Some(_) => self.map_units(key, 0.0),
+        }
+    }
+
+    fn required_string(&self, key: &str) -> Result<&str, WadError> {
+        match self.fields.get(key) {
+            None => Err(malformed(format!("The {} on line {} has no {}", self.kind, self.line, key))),
+            Some(_) => self.string(key, ""),
+        }
+    }
+
+    fn string<'a>(&'a self, key: &str, default: &'a str) -> Result<&'a str, WadError> {
+        match self.fields.get(key) {
+            None => Ok(default),
+            Some(Value::String(value)) => Ok(value),
+            Some(_) => Err(self.field_error(key, "a string")),
+        }
+    }
+
+    fn flag(&self, key: &str) -> Result<bool, WadError> {
+        match self.fields.get(key) {
+            None => Ok(false),
+            Some(Value::Bool(value)) => Ok(*value),
+            Some(_) => Err(self.field_error(key, "true or false")),
+        }
+    }
+}
+
+// Splits the text into tokens with the line they are on,
+//  skipping white space and comments.
+fn tokenize(text: &[u8]) -> Result<Vec<(Token, usize)>, WadError> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let c = text[pos];
+        let start = pos;
+
+        if c == b'\n' {
+            line += 1;
+            pos += 1;
+        } else if c.is_ascii_whitespace() {
+            pos += 1;
+        } else if text[pos..].starts_with(b"//") {
+            while pos < text.len() && text[pos] != b'\n' {
+                pos += 1;
+            }
+        } else if text[pos..].starts_with(b"/*") {
+            let end = text[pos + 2..].windows(2).position(|w| w == b"*/")
+                .ok_or_else(|| malformed(format!("Comment on line {} is not closed", line)))?;
+            line += text[pos..pos + 2 + end].iter().filter(|&&c| c == b'\n').count();
+            pos += end + 4;
+        } else if c == b'"' {
+            let mut value = Vec::new();
+            pos += 1;
+            loop {
+                match text.get(pos) {
+                    None => return Err(malformed(format!("String on line {} is not closed", line))),
+                    Some(b'"') => break,
+                    Some(b'\\') if pos + 1 < text.len() => {
+                        value.push(text[pos + 1]);
+                        pos += 2;
+                    }
+                    Some(&c) => {
+                        if c == b'\n' {
+                            line += 1;
+                        }
+                        value.push(c);
+                        pos += 1;
+                    }
+                }
+            }
+            pos += 1;
+            tokens.push((Token::String(String::from_utf8_lossy(&value).into_owned()), line));
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while pos < text.len() && (text[pos].is_ascii_alphanumeric() || text[pos] == b'_') {
+                pos += 1;
+            }
+            let word = String::from_utf8_lossy(&text[start..pos]).to_ascii_lowercase();
+            tokens.push((Token::Identifier(word), line));
+        } else if c.is_ascii_digit() || ((c == b'-' || c == b'+' || c == b'.') && pos + 1 < text.len()) {
+            pos += 1;
+            while pos < text.len() && (text[pos].is_ascii_alphanumeric() || text[pos] == b'.'
+                || ((text[pos] == b'-' || text[pos] == b'+') && matches!(text[pos - 1], b'e' | b'E'))) {
+                pos += 1;
+            }
+            let number = std::str::from_utf8(&text[start..pos]).unwrap_or_default();
+            tokens.push((parse_number(number)
+                .ok_or_else(|| malformed(format!("Bad number {} on line {}", number, line)))?, line));
+        } else {
+            tokens.push((Token::Symbol(c), line));
+            pos += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+// Integers are decimal, octal with a leading 0 or hexadecimal with 0x.
+fn parse_number(number: &str) -> Option<Token> {
+    let (negative, digits) = match number.as_bytes()[0] {
+        b'-' => (true, &number[1..]),
+        b'+' => (false, &number[1..]),
+        _ => (false, number),
+    };
+
+    let value = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16).ok()
+    } else if digits.contains(['.', 'e', 'E']) {
+        return number.parse().ok().map(Token::Float);
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8).ok()
+    } else {
+        digits.parse().ok()
+    }?;
+
+    Some(Token::Int(if negative { -value } else { value }))
+}
+
+// Returns the namespace and the blocks in the order they are defined.
+fn parse(text: &[u8]) -> Result<(String, Vec<Block>), WadError> {
+    let tokens = tokenize(text)?;
+    let mut tokens = tokens.into_iter().peekable();
+
+    let mut namespace = None;
+    let mut blocks = Vec::new();
+
+    while let Some((token, line)) = tokens.next() {
+        let name = match token {
+            Token::Identifier(name) => name,
+            _ => return Err(malformed(format!("Expected a field or block name on line {}", line))),
+        };
+
+        match tokens.next() {
+            Some((Token::Symbol(b'='), _)) => {
+                let value = parse_value(&mut tokens, line)?;
+                if name == "namespace" {
+                    match value {
+                        Value::String(value) => namespace = Some(value.to_ascii_lowercase()),
+                        _ => return Err(malformed(format!("Namespace on line {} is not a string", line))),
+                    }
+                }
+            }
+            Some((Token::Symbol(b'{'), _)) => {
+                let mut block = Block { kind: name, line, fields: HashMap::new() };
+                loop {
+                    match tokens.next() {
+                        Some((Token::Symbol(b'}'), _)) => break,
+                        Some((Token::Identifier(key), key_line)) => {
+                            if tokens.next().map(|(token, _)| token) != Some(Token::Symbol(b'=')) {
+                                return Err(malformed(format!("Expected = after {} on line {}", key, key_line)));
+                            }
+                            let value = parse_value(&mut tokens, key_line)?;
+                            block.fields.insert(key, value);
+                        }
+                        _ => return Err(malformed(format!("The {} on line {} is not closed", block.kind, block.line))),
+                    }
+                }
+                blocks.push(block);
+            }
+            _ => return Err(malformed(format!("Expected = or {{ after {} on line {}", name, line))),
+        }
+    }
+
+    let namespace = namespace.ok_or_else(|| malformed("No namespace".to_string()))?;
+    Ok((namespace, blocks))
+}
+
+fn parse_value(tokens: &mut impl Iterator<Item = (Token, usize)>, line: usize) -> Result<Value, WadError> {
+    let value = match tokens.next() {
+        Some((Token::Int(value), _)) => Value::Int(value),
+        Some((Token::Float(value), _)) => Value::Float(value),
+        Some((Token::String(value), _)) => Value::String(value),
+        Some((Token::Identifier(word), _)) => match word.as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => Value::Keyword,
+        },
+        _ => return Err(malformed(format!("Expected a value on line {}", line))),
+    };
+
+    match tokens.next() {
+        Some((Token::Symbol(b';'), _)) => Ok(value),
+        _ => Err(malformed(format!("Expected ; after the value on line {}", line))),
+    }
+}
+
+// The arguments of a special, arg0 to arg4.
+fn args(block: &Block) -> Result<[u8; 5], WadError> {
+    let mut args = [0; 5];
+    for (index, arg) in args.iter_mut().enumerate() {
+        *arg = block.int(&format!("arg{}", index), 0)? as u8;
+    }
+    Ok(args)
+}
+
+// The special of a zdoom line, like a Hexen one. Specials that
+//  nothing activates are only run by scripts, those past 255
+//  have no Hexen equivalent, neither are kept.
+fn action_special(block: &Block) -> Result<Option<ActionSpecial>, WadError> {
+    let special = match u8::try_from(block.int("special", 0)?) {
+        Ok(0) | Err(_) => return Ok(None),
+        Ok(special) => special,
+    };
+
+    let mut activation = None;
+    for (key, spac) in ACTIVATION_FLAGS {
+        if block.flag(key)? {
+            activation = activation.or(Some(spac));
+        }
+    }
+
+    Ok(match activation {
+        Some(activation) => Some(ActionSpecial {
+            special,
+            args: args(block)?,
+            activation,
+            repeat: block.flag("repeatspecial")?,
+        }),
+        None => None,
+    })
+}
+
+fn index(block: &Block, key: &str, count: usize, what: &str) -> Result<usize, WadError> {
+    let index = block.required_int(key)?;
+    if index < 0 || index as usize >= count {
+        return Err(bad_reference("TEXTMAP", what, index as usize));
+    }
+    Ok(index as usize)
+}
+
+/// Returns the lump of a UDMF map with the given name,
+/// if it is between the map marker and ENDMAP.
+pub fn find_lump(lumps: &LumpStore, map_lump: LumpNumber, name: &str) -> Option<LumpNumber> {
+    (1..).map(|offset| map_lump.offset(offset))
+        .map(|lump_number| (lump_number, lumps.get_lump_name(lump_number)))
+        .take_while(|(_, lump_name)| matches!(lump_name, Ok(lump_name) if *lump_name != "ENDMAP"))
+        .find(|(_, lump_name)| matches!(lump_name, Ok(lump_name) if *lump_name == name))
+        .map(|(lump_number, _)| lump_number)
+}
+
+// P_LoadTextmap
+// Fields that are not used here, like those of
+//  other ports, are skipped.
+pub fn load(lumps: &LumpStore, textmap_lump: LumpNumber, render_data: &RenderData) -> Result<TextMap, WadError> {
+    let (namespace, blocks) = parse(lumps.get_lump(By::Number(textmap_lump))?)?;
+    if !NAMESPACES.contains(&namespace.as_str()) {
+        return Err(malformed(format!("Namespace {} is not supported", namespace)));
+    }
+
+    let blocks_of = |kind: &'static str| blocks.iter().filter(move |block| block.kind == kind);
+
+    let mut vertices = Vec::new();
+    for block in blocks_of("vertex") {
+        vertices.push(Vertex {
+            x: real(block.required_map_units("x")?),
+            y: real(block.required_map_units("y")?),
+        });
+    }
+
+    let mut sectors = Vec::new();
+    for block in blocks_of("sector") {
+        let flat = |key| render_data.flats.flat_num_for_name(block.required_string(key)?);
+
+        sectors.push(Sector::new(
+            real(block.map_units("heightfloor", 0.0)?),
+            real(block.map_units("heightceiling", 0.0)?),
+            flat("texturefloor")?,
+            flat("textureceiling")?,
+            block.int("lightlevel", 160)? as i16,
+            block.int("special", 0)? as i16,
+            block.int("id", 0)? as i16,
+        ));
+    }
+
+    let mut sides = Vec::new();
+    for block in blocks_of("sidedef") {
+        let texture = |key: &str| texture_num("TEXTMAP", &render_data.texture, block.string(key, "-")?);
+
+        sides.push(SideDef {
+            texture_offset: real(block.map_units("offsetx", 0.0)?),
+            row_offset: real(block.map_units("offsety", 0.0)?),
+            top_texture: texture("texturetop")?,
+            bottom_texture: texture("texturebottom")?,
+            mid_texture: texture("texturemiddle")?,
+            sector: index(block, "sector", sectors.len(), "sector")?,
+        });
+    }
+
+    let mut lines = Vec::new();
+    for block in blocks_of("linedef") {
+        let v1 = index(block, "v1", vertices.len(), "vertex")?;
+        let v2 = index(block, "v2", vertices.len(), "vertex")?;
+        let front = index(block, "sidefront", sides.len(), "sidedef")?;
+        let back = match block.int("sideback", -1)? {
+            -1 => None,
+            _ => Some(index(block, "sideback", sides.len(), "sidedef")?),
+        };
+
+        let mut flags = 0;
+        for (key, flag) in LINE_FLAGS {
+            if block.flag(key)? {
+                flags |= flag;
+            }
+        }
+
+        let line = LineDef {
+            flags,
+            ..LineDef::new(v1, v2, [Some(front), back], &vertices, &sides)
+        };
+        lines.push(match namespace.as_str() {
+            // special and tag are left at 0, like on Hexen lines
+            "zdoom" => LineDef {
+                action: action_special(block)?,
+                ..line
+            },
+            // the line id is the tag
+            _ => LineDef {
+                special: block.int("special", 0)? as i16,
+                tag: block.int("id", 0)?.max(0) as i16,
+                ..line
+            },
+        });
+    }
+
+    let mut things = Vec::new();
+    for block in blocks_of("thing") {
+        let mut options = 0;
+        if block.flag("skill1")? || block.flag("skill2")? {
+            options |= MTF_EASY;
+        }
+        if block.flag("skill3")? {
+            options |= MTF_NORMAL;
+        }
+        if block.flag("skill4")? || block.flag("skill5")? {
+            options |= MTF_HARD;
+        }
+        if block.flag("ambush")? {
+            options |= MTF_AMBUSH;
+        }
+        if !block.flag("single")? {
+            options |= MTF_NOTSINGLE;
+        }
+        if !block.flag("dm")? {
+            options |= MTF_NOTDM;
+        }
+        if !block.flag("coop")? {
+            options |= MTF_NOTCOOP;
+        }
+        if block.flag("friend")? {
+            options |= MTF_FRIEND;
+        }
+
+        let thing = Thing {
+            x: block.required_map_units("x")? as i16,
+            y: block.required_map_units("y")? as i16,
+            angle: block.int("angle", 0)? as i16,
+            thing_type: block.required_int("type")? as i16,
+            options,
+            height: block.map_units("height", 0.0)? as i16,
+            ..Thing::default()
+        };
+        things.push(match namespace.as_str() {
+            "zdoom" => Thing {
+                tid: block.int("id", 0)? as i16,
+                special: block.int("special", 0)? as u8,
+                args: args(block)?,
+                dormant: block.flag("dormant")?,
+                ..thing
+            },
+            _ => thing,
+        });
+    }
+
+    Ok(TextMap {
+        vertices,
+        sectors,
+        sides,
+        lines,
+        things,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level::test_map::load_map01;
+    use crate::level::Level;
+    use crate::level::linedefs::SPAC_USE;
+    use crate::wad::{WadBuilder, WadError};
+
+    // A square room in the namespace, with the given line
+    //  on its west wall and one thing.
+    fn load(namespace: &str, west_line: &str, thing: &str) -> Result<Level, WadError> {
+        let textmap = format!(r#"
+            namespace = "{}";
+            vertex {{ x = 0; y = 0; }}
+            vertex {{ x = 0; y = 256; }}
+            vertex {{ x = 256; y = 256; }}
+            vertex {{ x = 256; y = 0; }}
+            sector {{ texturefloor = "FLOOR1"; textureceiling = "CEIL1"; heightceiling = 128; }}
+            sidedef {{ sector = 0; texturemiddle = "STARTAN"; }}
+            linedef {{ v1 = 0; v2 = 1; sidefront = 0; {} }}
+            linedef {{ v1 = 1; v2 = 2; sidefront = 0; }}
+            linedef {{ v1 = 2; v2 = 3; sidefront = 0; }}
+            linedef {{ v1 = 3; v2 = 0; sidefront = 0; }}
+            thing {{ x = 128; y = 128; type = 1; {} }}
+        "#, namespace, west_line, thing);
+
+        let mut pwad = WadBuilder::new();
+        pwad.add_lump("MAP01", Vec::new()).unwrap();
+        pwad.add_lump("TEXTMAP", textmap.into_bytes()).unwrap();
+        pwad.add_lump("ENDMAP", Vec::new()).unwrap();
+        load_map01(&pwad)
+    }
+
+    #[test]
+    fn doom_tag_is_the_line_id() {
+        let level = load("doom", "special = 1; id = 7; arg0 = 3;", "").unwrap();
+        let line = &level.lines[0];
+        assert_eq!((line.special, line.tag), (1, 7));
+        assert!(line.action.is_none());
+    }
+
+    #[test]
+    fn zdoom_specials_take_arguments() {
+        let level = load("zdoom", "special = 12; arg0 = 3; arg1 = 16; playeruse = true; repeatspecial = true;",
+                         "id = 5; special = 80; arg0 = 2; dormant = true;").unwrap();
+        let line = &level.lines[0];
+        assert_eq!((line.special, line.tag), (0, 0));
+        let action = line.action.unwrap();
+        assert_eq!((action.special, action.args, action.activation, action.repeat), (12, [3, 16, 0, 0, 0], SPAC_USE, true));
+
+        let thing = &level.things[0];
+        assert_eq!((thing.tid, thing.special, thing.args), (5, 80, [2, 0, 0, 0, 0]));
+        assert!(thing.dormant);
+    }
+
+    #[test]
+    fn coordinates_out_of_range() {
+        let result = load("doom", "", "height = 40000.0;");
+        assert!(matches!(result, Err(WadError::MalformedLump { reason, .. }) if reason.contains("out of range")));
+    }
+}
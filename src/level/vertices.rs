@@ -1,5 +1,6 @@
 use crate::types::{DoomRealNum, real};
-use crate::wad::{LumpStore, By, LumpNumber};
+use crate::wad::{LumpStore, By, LumpNumber, WadError};
+use crate::level::ML_VERTEXES;
 use std::mem::size_of;
 use std::io::Seek;
 use serde::Deserialize;
@@ -11,18 +12,18 @@ struct VertexRaw {
 }
 
 pub struct Vertex {
-    x: DoomRealNum,
-    y: DoomRealNum
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum
 }
 
-pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Vec<Vertex> {
-    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(4)));
+pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Result<Vec<Vertex>, WadError> {
+    let mut data = lumps.get_lump_cursor(By::Number(map_lump.offset(ML_VERTEXES)))?;
 
-    let vertex_count = data.stream_len().unwrap() as usize / size_of::<VertexRaw>();
+    let vertex_count = data.stream_len()? as usize / size_of::<VertexRaw>();
     let mut vertices = Vec::new();
 
     for _ in 0..vertex_count {
-        let raw_vertex: VertexRaw = bincode::deserialize_from(&mut data).unwrap();
+        let raw_vertex: VertexRaw = bincode::deserialize_from(&mut data)?;
 
         vertices.push(Vertex {
             x: real(raw_vertex.x),
@@ -30,5 +31,5 @@ pub fn load(lumps: &LumpStore, map_lump: LumpNumber) -> Vec<Vertex> {
         });
     }
 
-    vertices
-}
\ No newline at end of file
+    Ok(vertices)
+}
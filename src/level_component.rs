@@ -1,21 +1,356 @@
+use std::collections::HashSet;
+use winit::event::VirtualKeyCode;
 use crate::events::{Event, EventConsumer};
+use crate::game_context::{GameMode, Skill};
+use crate::level;
+use crate::play::{self, World};
+use crate::play::spec::LevelExit;
+use crate::play::mobj::{MF_NOSECTOR, MF_SHADOW};
+use crate::rendering::{View, ViewPoint, RenderData, Sprites, ThingSprite, render_player_view};
+use crate::rendering::renderer::Renderer;
+use crate::rendering::palette::PaletteEffects;
+use crate::tic_cmd::{TicCmd, BT_ATTACK, BT_USE, BT_CHANGE, BT_WEAPONSHIFT, BT_SPECIAL, BTS_PAUSE};
+use crate::wad::{LumpStore, WadError};
+
+// TODO Configurable, these are the defaults from m_misc.c
+const KEY_RIGHT: VirtualKeyCode = VirtualKeyCode::Right;
+const KEY_LEFT: VirtualKeyCode = VirtualKeyCode::Left;
+const KEY_UP: VirtualKeyCode = VirtualKeyCode::Up;
+const KEY_DOWN: VirtualKeyCode = VirtualKeyCode::Down;
+const KEY_STRAFE_LEFT: VirtualKeyCode = VirtualKeyCode::Comma;
+const KEY_STRAFE_RIGHT: VirtualKeyCode = VirtualKeyCode::Period;
+const KEY_FIRE: VirtualKeyCode = VirtualKeyCode::RControl;
+const KEY_USE: VirtualKeyCode = VirtualKeyCode::Space;
+const KEY_STRAFE: VirtualKeyCode = VirtualKeyCode::RAlt;
+const KEY_SPEED: VirtualKeyCode = VirtualKeyCode::RShift;
+const KEY_PAUSE: VirtualKeyCode = VirtualKeyCode::Pause;
+
+// The keys 1 to 8, NUMWEAPONS-1 of them.
+const WEAPON_KEYS: [VirtualKeyCode; 8] = [
+    VirtualKeyCode::Key1,
+    VirtualKeyCode::Key2,
+    VirtualKeyCode::Key3,
+    VirtualKeyCode::Key4,
+    VirtualKeyCode::Key5,
+    VirtualKeyCode::Key6,
+    VirtualKeyCode::Key7,
+    VirtualKeyCode::Key8,
+];
+
+const FORWARD_MOVE: [i32; 2] = [0x19, 0x32];
+const SIDE_MOVE: [i32; 2] = [0x18, 0x28];
+const ANGLE_TURN: [i16; 3] = [640, 1280, 320]; // + slow turn
+
+const SLOW_TURN_TICS: i32 = 6;
+const MAX_PL_MOVE: i32 = FORWARD_MOVE[1];
 
 pub struct LevelComponent {
+    world: Option<World>,
+    view: View,
 
+    // The input G_BuildTiccmd samples each tic.
+    game_key_down: HashSet<VirtualKeyCode>,
+    turn_held: i32, // for accelerative turning
+    send_pause: bool, // send a pause event next tic
+
+    // The state of the player that shifts the palette,
+    //  and the palette currently shown, st_palette.
+    palette_effects: PaletteEffects,
+    palette: Option<usize>,
 }
 
 impl LevelComponent {
     pub fn new() -> Self {
-        Self{}
+        Self {
+            world: None,
+            // TODO: Should follow the screen size option
+            view: View::new(11),
+            game_key_down: HashSet::new(),
+            turn_held: 0,
+            send_pause: false,
+            palette_effects: PaletteEffects::default(),
+            palette: None,
+        }
     }
-    // G_Responder
-    pub fn handle_event(event: &Event) -> bool {
-        false
+    // The P_SetupLevel part of G_DoLoadLevel
+    pub fn setup_level(&mut self, lumps: &LumpStore, render_data: &RenderData, mode: &GameMode, episode: u32, map: u32, skill: Skill) -> Result<(), WadError> {
+        let random = self.world.take()
+            .map(|world| world.random)
+            .unwrap_or_default();
+
+        let level = level::load(lumps, render_data, mode, episode, map)?;
+        self.world = Some(play::setup(level, skill, random)?);
+
+        Ok(())
+    }
+
+    // M_ClearRandom
+    pub fn clear_random(&mut self) {
+        if let Some(world) = &mut self.world {
+            world.random.clear();
+        }
+    }
+
+    // The "clear cmd building stuff" part of G_DoLoadLevel
+    pub fn clear_input(&mut self) {
+        self.game_key_down.clear();
+        self.turn_held = 0;
+        self.send_pause = false;
+    }
+
+    // G_BuildTiccmd
+    // Builds a ticcmd from all of the available inputs
+    // or reads it from the demo buffer.
+    // If recording a demo, write it out
+    pub fn build_tic_cmd(&mut self) -> TicCmd {
+        // TODO The consistancy check for net games, and mouse and joystick input
+        let mut cmd = TicCmd::default();
+
+        let keys = &self.game_key_down;
+        let key_down = |key| keys.contains(&key);
+        let strafe = key_down(KEY_STRAFE);
+        let speed = key_down(KEY_SPEED) as usize;
+
+        let mut forward = 0;
+        let mut side = 0;
+
+        // use two stage accelerative turning
+        // on the keyboard and joystick
+        if key_down(KEY_RIGHT) || key_down(KEY_LEFT) {
+            self.turn_held += 1; // ticdup
+        } else {
+            self.turn_held = 0;
+        }
+
+        let turn_speed = if self.turn_held < SLOW_TURN_TICS {
+            2 // slow turn
+        } else {
+            speed
+        };
+
+        // let movement keys cancel each other out
+        if strafe {
+            if key_down(KEY_RIGHT) {
+                side += SIDE_MOVE[speed];
+            }
+            if key_down(KEY_LEFT) {
+                side -= SIDE_MOVE[speed];
+            }
+        } else {
+            if key_down(KEY_RIGHT) {
+                cmd.angle_turn -= ANGLE_TURN[turn_speed];
+            }
+            if key_down(KEY_LEFT) {
+                cmd.angle_turn += ANGLE_TURN[turn_speed];
+            }
+        }
+
+        if key_down(KEY_UP) {
+            forward += FORWARD_MOVE[speed];
+        }
+        if key_down(KEY_DOWN) {
+            forward -= FORWARD_MOVE[speed];
+        }
+        if key_down(KEY_STRAFE_RIGHT) {
+            side += SIDE_MOVE[speed];
+        }
+        if key_down(KEY_STRAFE_LEFT) {
+            side -= SIDE_MOVE[speed];
+        }
+
+        // buttons
+        // TODO cmd.chat_char = HU_dequeueChatChar();
+
+        if key_down(KEY_FIRE) {
+            cmd.buttons |= BT_ATTACK;
+        }
+
+        if key_down(KEY_USE) {
+            cmd.buttons |= BT_USE;
+        }
+
+        // chainsaw overrides
+        if let Some(weapon) = WEAPON_KEYS.iter().position(|&key| key_down(key)) {
+            cmd.buttons |= BT_CHANGE;
+            cmd.buttons |= (weapon as u8) << BT_WEAPONSHIFT;
+        }
+
+        cmd.forward_move = forward.clamp(-MAX_PL_MOVE, MAX_PL_MOVE) as i8;
+        cmd.side_move = side.clamp(-MAX_PL_MOVE, MAX_PL_MOVE) as i8;
+
+        // special buttons
+        if self.send_pause {
+            self.send_pause = false;
+            cmd.buttons = BT_SPECIAL | BTS_PAUSE;
+        }
+
+        // TODO Savegames through BTS_SAVEGAME
+
+        cmd
+    }
+
+    // The netcmds part of G_Ticker, only the console player exists for now.
+    pub fn set_player_cmd(&mut self, cmd: TicCmd) {
+        if let Some(world) = &mut self.world {
+            world.players[0].cmd = cmd;
+        }
+    }
+
+    // The GS_LEVEL part of D_Display
+    pub fn draw(&mut self, renderer: &mut dyn Renderer, lumps: &LumpStore, render_data: &RenderData) -> Result<(), WadError> {
+        if let Some(world) = &self.world {
+            if let Some(view_point) = view_point(world) {
+                let things = thing_sprites(world);
+                let sprites = Sprites { things: &things, player: &[] };
+                render_player_view(&self.view, &view_point, &world.level, render_data, lumps, sprites, renderer.screen_mut(0))?;
+            }
+        }
+
+        self.do_palette_stuff(renderer, render_data);
+
+        Ok(())
+    }
+
+    // The GS_LEVEL part of G_Ticker
+    pub fn ticker(&mut self) {
+        if let Some(world) = &mut self.world {
+            world.ticker();
+
+            // the ST_Ticker part of the palette, for the console player
+            let palette_effects = world.players[0].palette_effects();
+            self.set_palette_effects(palette_effects);
+        }
+    }
+
+    /// Takes the exit the player took from the level, if any.
+    pub fn take_exit(&mut self) -> Option<LevelExit> {
+        self.world.as_mut().and_then(|world| world.exit.take())
+    }
+
+    // ST_doPaletteStuff
+    // Switches to the damage, bonus or radiation suit palette
+    //  when it differs from the one shown.
+    fn do_palette_stuff(&mut self, renderer: &mut dyn Renderer, render_data: &RenderData) {
+        let palette = self.palette_effects.palette_index();
+
+        if self.palette != Some(palette) {
+            self.palette = Some(palette);
+            renderer.set_palette(render_data.palettes.palette(palette));
+        }
+    }
+
+    // Goes back to the normal palette once the level is no longer shown,
+    //  as D_Display does on a game state change.
+    pub fn restore_palette(&mut self, renderer: &mut dyn Renderer, render_data: &RenderData) {
+        if let Some(palette) = self.palette.take() {
+            if palette != 0 {
+                renderer.set_palette(render_data.palettes.palette(0));
+            }
+        }
+    }
+
+    pub fn set_palette_effects(&mut self, palette_effects: PaletteEffects) {
+        self.palette_effects = palette_effects;
     }
 }
 
+// The player part of R_SetupFrame
+fn view_point(world: &World) -> Option<ViewPoint> {
+    let player = &world.players[0]; // TODO displayplayer
+    let mobj = world.thinkers.mobj(player.mobj?);
+
+    Some(ViewPoint {
+        x: mobj.x,
+        y: mobj.y,
+        z: player.view_z,
+        angle: mobj.angle,
+        extra_light: player.extra_light,
+        fixed_colormap: match player.fixed_colormap {
+            0 => None,
+            fixed_colormap => Some(fixed_colormap),
+        },
+    })
+}
+
+// The things R_AddSprites finds in the sector thing lists,
+//  everything but the invisible MF_NOSECTOR things.
+fn thing_sprites(world: &World) -> Vec<ThingSprite> {
+    world.thinkers.mobjs()
+        .filter(|(_, mobj)| mobj.flags & MF_NOSECTOR == 0)
+        .map(|(_, mobj)| ThingSprite {
+            x: mobj.x,
+            y: mobj.y,
+            z: mobj.z,
+            angle: mobj.angle,
+            sprite: mobj.sprite,
+            frame: mobj.frame,
+            sector: world.level.subsectors[mobj.subsector].sector,
+            shadow: mobj.flags & MF_SHADOW != 0,
+        })
+        .collect()
+}
+
 impl EventConsumer for LevelComponent {
+    // G_Responder
+    // Get info needed to make ticcmd_ts for the players.
+    // TODO The demo screen part is in PageComponent, and HU_Responder,
+    //  ST_Responder, AM_Responder and F_Responder are missing.
     fn consume(&mut self, event: &Event) -> bool {
-        false
+        match event {
+            Event::KeyDown { virtual_keycode: Some(key), .. } => {
+                if *key == KEY_PAUSE {
+                    self.send_pause = true;
+                    return true;
+                }
+                self.game_key_down.insert(*key);
+                true // eat key down events
+            }
+            Event::KeyUp { virtual_keycode: Some(key), .. } => {
+                self.game_key_down.remove(key);
+                false // always let key up events filter down
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::view_point;
+    use crate::game_context::Skill;
+    use crate::level::test_map::TestMap;
+    use crate::play::{setup, World};
+    use crate::player::{PW_INFRARED, PW_INVULNERABILITY};
+    use crate::rendering::lighting::INVERSE_COLORMAP;
+
+    // The fixed colormap of the view after a tic with the power left.
+    fn fixed_colormap(world: &mut World, power: usize, tics: i32) -> Option<usize> {
+        world.players[0].powers[power] = tics;
+        world.ticker();
+        view_point(world).unwrap().fixed_colormap
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn powers_set_the_fixed_colormap() {
+        let mut world = setup(TestMap::two_rooms().load().unwrap(), Skill::Medium, Default::default()).unwrap();
+        assert_eq!(view_point(&world).unwrap().fixed_colormap, None);
+
+        assert_eq!(fixed_colormap(&mut world, PW_INVULNERABILITY, 30 * 35), Some(INVERSE_COLORMAP));
+        // blinks during the last 4 seconds, the counter is 119 after the tic
+        assert_eq!(fixed_colormap(&mut world, PW_INVULNERABILITY, 120), None);
+        assert_eq!(fixed_colormap(&mut world, PW_INVULNERABILITY, 128), Some(INVERSE_COLORMAP));
+
+        // the visor shows once invulnerability runs out
+        world.players[0].powers[PW_INFRARED] = 120 * 35;
+        assert_eq!(fixed_colormap(&mut world, PW_INVULNERABILITY, 0), Some(1));
+        assert_eq!(fixed_colormap(&mut world, PW_INFRARED, 120), None);
+        assert_eq!(fixed_colormap(&mut world, PW_INFRARED, 1), None);
+    }
+
+    #[test]
+    fn extra_light_lights_the_view() {
+        let mut world = setup(TestMap::two_rooms().load().unwrap(), Skill::Medium, Default::default()).unwrap();
+        world.players[0].extra_light = 2;
+        assert_eq!(view_point(&world).unwrap().extra_light, 2);
+    }
+}
@@ -20,7 +20,11 @@ mod rendering;
 mod player;
 mod level;
 mod types;
-mod gameloop;
+mod tables;
+mod info;
+mod sounds;
+mod tic_cmd;
+mod play;
 mod game_context;
 mod drawer;
 mod menu;
@@ -29,7 +33,7 @@ mod level_component;
 mod page_component;
 mod options;
 
-fn main() {
+fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let event_loop = EventLoop::new();
@@ -41,16 +45,16 @@ fn main() {
     let mut renderer = pollster::block_on(rendering::renderer::WGPURenderer::new(&window));
     let mut events = EventSystem::new();
     let mut lumps = LumpStore::new();
-    lumps.add_file("/Users/emilnorden/doom/plutonia.wad");
-
-    renderer.set_palette(lumps.get_lump(By::Name("PLAYPAL")));
+    lumps.add_file("/Users/emilnorden/doom/plutonia.wad")?;
 
     let mut game_context= GameContext::new();
 
 
     let system = System::new();
 
-    let render_data = init_rendering(&lumps);
+    let render_data = init_rendering(&lumps)?;
+
+    renderer.set_palette(render_data.palettes.palette(0));
 
     'game_loop: loop {
         event_loop.run(move |event, _, control_flow| {
@@ -67,7 +71,10 @@ fn main() {
                     }
                 }
                 Event::MainEventsCleared => {
-                    game_context.game_loop(&mut events, &mut renderer, &system, &lumps);
+                    if let Err(e) = game_context.game_loop(&mut events, &mut renderer, &system, &lumps, &render_data) {
+                        eprintln!("{}", e);
+                        *control_flow = ControlFlow::Exit;
+                    }
                     window.request_redraw();
                 }
                 Event::WindowEvent {
@@ -1,10 +1,12 @@
 use crate::menu::menu_item::MenuItem;
 use crate::rendering::renderer::Renderer;
+use crate::rendering::patch::Patch;
 use std::thread::current;
-use crate::wad::{LumpStore, By};
+use crate::wad::{LumpStore, By, WadError};
 use crate::events::{EventConsumer, Event};
 use winit::event::{ScanCode, VirtualKeyCode};
 use crate::options::{Options, DetailLevel};
+use crate::game_context::Skill;
 
 mod menu_item;
 mod definitions;
@@ -12,7 +14,7 @@ mod definitions;
 const LINE_HEIGHT: i32 = 16;
 const SKULL_X_OFFSET: i32 = -32;
 
-pub type DrawMenuCallback = fn(&MenuComponent, &mut dyn Renderer, lumps: &LumpStore);
+pub type DrawMenuCallback = fn(&MenuComponent, &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError>;
 
 pub struct Menu {
     menu_items: Vec<MenuItem>,
@@ -31,6 +33,12 @@ pub struct MenuComponent {
     which_skull: usize,
     skull_animation_counter: i32,
     options: Options,
+
+    // The episode picked in the episode menu, epi.
+    episode: u32,
+    // The skill and episode of a new game chosen in the menu,
+    //  for G_DeferedInitNew.
+    new_game_request: Option<(Skill, u32)>,
 }
 
 impl MenuComponent {
@@ -49,6 +57,8 @@ impl MenuComponent {
             which_skull: 0,
             skull_animation_counter: 10,
             options: Options::new(),
+            episode: 0,
+            new_game_request: None,
         }
     }
 
@@ -129,13 +139,13 @@ impl MenuComponent {
         }
     }
 
-    pub fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) {
+    pub fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError> {
         if !self.is_active {
-            return;
+            return Ok(());
         }
 
         let current_menu = &self.menus[self.current_menu_index];
-        (current_menu.draw_routine)(self, renderer, lumps);
+        (current_menu.draw_routine)(self, renderer, lumps)?;
 
         let x = current_menu.x;
         let mut y = current_menu.y;
@@ -144,7 +154,7 @@ impl MenuComponent {
         for item in &current_menu.menu_items {
             if !item.name().is_empty() {
                 renderer.draw_patch(x, y, 0,
-                                    &lumps.get_lump(By::Name(item.name())).into());
+                                    &Patch::load(lumps, By::Name(item.name()))?)?;
             }
 
             y += LINE_HEIGHT;
@@ -159,8 +169,10 @@ impl MenuComponent {
             x + SKULL_X_OFFSET,
             current_menu.y - 5 + self.item_on as i32 * LINE_HEIGHT,
             0,
-            &lumps.get_lump(By::Name(SKULL_NAMES[self.which_skull])).into(),
-        );
+            &Patch::load(lumps, By::Name(SKULL_NAMES[self.which_skull]))?,
+        )?;
+
+        Ok(())
     }
 
     fn new_game(menu_component: &mut MenuComponent, choice: i16) {
@@ -181,7 +193,13 @@ impl MenuComponent {
 
     fn quit(menu_component: &mut MenuComponent, choice: i16) {}
 
-    fn episode(menu_component: &mut MenuComponent, choice: i16) {}
+    // M_Episode
+    fn episode(menu_component: &mut MenuComponent, choice: i16) {
+        // TODO The shareware and registered checks
+        menu_component.episode = choice as u32;
+        menu_component.current_menu_index = 3;
+        menu_component.item_on = menu_component.current_menu().last_on;
+    }
 
     fn end_game(menu_component: &mut MenuComponent, choice: i16) {}
 
@@ -195,24 +213,39 @@ impl MenuComponent {
 
     fn sound(menu_component: &mut MenuComponent, choice: i16) {}
 
-    fn choose_skill(menu_component: &mut MenuComponent, choice: i16) {}
+    // M_ChooseSkill
+    fn choose_skill(menu_component: &mut MenuComponent, choice: i16) {
+        // TODO Verify nightmare with M_StartMessage
+        let skill = match choice {
+            0 => Skill::Baby,
+            1 => Skill::Easy,
+            2 => Skill::Medium,
+            3 => Skill::Hard,
+            _ => Skill::Nightmare,
+        };
+
+        menu_component.new_game_request = Some((skill, menu_component.episode + 1));
+        menu_component.hide();
+    }
 
 
-    fn draw_main_menu(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore) {
-        renderer.draw_patch(94, 2, 0, &lumps.get_lump(By::Name("M_DOOM")).into());
+    fn draw_main_menu(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError> {
+        renderer.draw_patch(94, 2, 0, &Patch::load(lumps, By::Name("M_DOOM"))?)?;
+        Ok(())
     }
 
-    fn draw_new_game_menu(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore) {
-        renderer.draw_patch(96, 14, 0, &lumps.get_lump(By::Name("M_NEWG")).into());
-        renderer.draw_patch(54, 38, 0, &lumps.get_lump(By::Name("M_SKILL")).into());
+    fn draw_new_game_menu(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError> {
+        renderer.draw_patch(96, 14, 0, &Patch::load(lumps, By::Name("M_NEWG"))?)?;
+        renderer.draw_patch(54, 38, 0, &Patch::load(lumps, By::Name("M_SKILL"))?)?;
+        Ok(())
     }
 
-    fn draw_options_menu(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore) {
+    fn draw_options_menu(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError> {
         const MESSAGES_LINE_INDEX: i32 = 1;
         const DETAIL_LINE_INDEX: i32 = 2;
         const MOUSE_SENS_LINE_INDEX: i32 = 6;
         const SCREEN_SIZE_LINE_INDEX: i32 = 4;
-        renderer.draw_patch(108, 15, 0, &lumps.get_lump(By::Name("M_OPTTTL")).into());
+        renderer.draw_patch(108, 15, 0, &Patch::load(lumps, By::Name("M_OPTTTL"))?)?;
 
         let detail_text = match menu_component.options.detail {
             DetailLevel::Low => "M_GDLOW",
@@ -220,7 +253,7 @@ impl MenuComponent {
         };
 
         renderer.draw_patch(175 + 60, 37 + LINE_HEIGHT * DETAIL_LINE_INDEX, 0,
-                            &lumps.get_lump(By::Name(detail_text)).into());
+                            &Patch::load(lumps, By::Name(detail_text))?)?;
 
         let messages_text = match menu_component.options.show_messages {
             true => "M_MSGON",
@@ -228,36 +261,38 @@ impl MenuComponent {
         };
 
         renderer.draw_patch(120 + 60, 37 + LINE_HEIGHT * MESSAGES_LINE_INDEX, 0,
-                            &lumps.get_lump(By::Name(messages_text)).into());
+                            &Patch::load(lumps, By::Name(messages_text))?)?;
 
         Self::draw_slider(menu_component, renderer, lumps,
                           60, 37 + LINE_HEIGHT * MOUSE_SENS_LINE_INDEX,
-                          10, menu_component.options.mouse_sensitivity);
+                          10, menu_component.options.mouse_sensitivity)?;
 
         Self::draw_slider(menu_component, renderer, lumps,
                           60, 37 + LINE_HEIGHT * SCREEN_SIZE_LINE_INDEX,
-                          9, menu_component.options.screen_size);
+                          9, menu_component.options.screen_size)
     }
 
     fn draw_slider(menu_component: &MenuComponent, renderer: &mut dyn Renderer, lumps: &LumpStore,
-                   x: i32, y: i32, width: i32, value: i32) {
+                   x: i32, y: i32, width: i32, value: i32) -> Result<(), WadError> {
         renderer.draw_patch(x, y, 0,
-                            &lumps.get_lump(By::Name("M_THERML")).into(),
-        );
+                            &Patch::load(lumps, By::Name("M_THERML"))?,
+        )?;
 
         for i in 0..width {
             renderer.draw_patch(x + 8 + (i * 8), y, 0,
-                                &lumps.get_lump(By::Name("M_THERMM")).into(),
-            );
+                                &Patch::load(lumps, By::Name("M_THERMM"))?,
+            )?;
         }
 
         renderer.draw_patch(x + (width+1) * 8, y, 0,
-                            &lumps.get_lump(By::Name("M_THERMR")).into(),
-        );
+                            &Patch::load(lumps, By::Name("M_THERMR"))?,
+        )?;
 
         renderer.draw_patch(x + 8 + value * 8, y, 0,
-                            &lumps.get_lump(By::Name("M_THERMO")).into(),
-        );
+                            &Patch::load(lumps, By::Name("M_THERMO"))?,
+        )?;
+
+        Ok(())
     }
 
     fn draw_episode_menu() {}
@@ -283,6 +318,10 @@ impl MenuComponent {
     pub fn show(&mut self) {
         self.is_active = true;
     }
+
+    pub fn new_game_requested(&mut self) -> Option<(Skill, u32)> {
+        self.new_game_request.take()
+    }
 }
 
 impl EventConsumer for MenuComponent {
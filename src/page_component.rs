@@ -1,7 +1,8 @@
 use crate::events::{EventConsumer, Event};
 use crate::game_context::{DemoState, GameMode};
 use crate::rendering::renderer::Renderer;
-use crate::wad::{LumpStore, By};
+use crate::rendering::patch::Patch;
+use crate::wad::{LumpStore, By, WadError};
 
 pub struct PageComponent {
     demo_state: DemoState,
@@ -26,15 +27,25 @@ impl PageComponent {
         result
     }
 
-    pub fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) {
+    pub fn draw(&self, renderer: &mut dyn Renderer, lumps: &LumpStore) -> Result<(), WadError> {
         if self.demo_state.page_name().is_none() {
-            return;
+            return Ok(());
         }
         renderer.draw_patch(
             0,
             0,
             0,
-            &lumps.get_lump(By::Name(self.demo_state.page_name().unwrap())).into())
+            &Patch::load(lumps, By::Name(self.demo_state.page_name().unwrap()))?)
+    }
+
+    // D_PageTicker
+    // Handles timing for warped projection
+    pub fn ticker(&mut self) {
+        self.demo_state.page_tic -= 1;
+        if self.demo_state.page_tic < 0 {
+            // D_AdvanceDemo
+            self.demo_state.advance_demo = true;
+        }
     }
 
     pub fn advance_demo(&mut self, mode: &GameMode) {
@@ -0,0 +1,95 @@
+pub mod mobj;
+pub mod tick;
+pub mod random;
+pub mod map;
+pub mod map_util;
+pub mod user;
+pub mod sight;
+pub mod spec;
+
+use std::collections::VecDeque;
+use crate::level::Level;
+use crate::level::things::Thing;
+use crate::game_context::Skill;
+use crate::player::Player;
+use crate::play::tick::ThinkerList;
+use crate::play::map::PositionCheck;
+use crate::play::random::Random;
+use crate::play::spec::LevelExit;
+use crate::types::{DoomRealNum, FRAC_BITS, from_fixed_bits};
+use crate::wad::WadError;
+
+// The maximum number of players, multiplayer/networking.
+pub const MAX_PLAYERS: usize = 4;
+
+pub const FLOAT_SPEED: DoomRealNum = from_fixed_bits(4 << FRAC_BITS);
+
+pub const VIEW_HEIGHT: DoomRealNum = from_fixed_bits(41 << FRAC_BITS);
+
+pub const MAX_MOVE: DoomRealNum = from_fixed_bits(30 << FRAC_BITS);
+
+pub const GRAVITY: DoomRealNum = from_fixed_bits(1 << FRAC_BITS);
+
+/// The play simulation of a level: the map, everything
+/// thinking in it and the bookkeeping around them.
+pub struct World {
+    pub(crate) level: Level,
+    pub(crate) thinkers: ThinkerList,
+    pub(crate) random: Random,
+    pub(crate) skill: Skill,
+
+    // increment every time a check is made
+    pub(crate) valid_count: i32,
+    pub(crate) check: PositionCheck,
+    // Whether the player thinking is on the ground, set by P_MovePlayer.
+    pub(crate) on_ground: bool,
+
+    // tics in game play for par
+    pub(crate) level_time: i32,
+    pub(crate) total_kills: i32,
+    pub(crate) total_items: i32,
+
+    pub(crate) players: [Player; MAX_PLAYERS],
+    pub(crate) player_in_game: [bool; MAX_PLAYERS],
+    pub(crate) player_starts: [Option<Thing>; MAX_PLAYERS],
+    pub(crate) deathmatch_starts: Vec<Thing>,
+
+    // Spawn points of picked up items with the time they were taken.
+    pub(crate) item_respawn_que: VecDeque<(Thing, i32)>,
+
+    // Set by the exit specials, for G_Ticker to complete the level.
+    pub(crate) exit: Option<LevelExit>,
+}
+
+// The thing spawning part of P_SetupLevel
+// The random number generator carries over from the previous level.
+pub fn setup(level: Level, skill: Skill, random: Random) -> Result<World, WadError> {
+    let things = level.things.clone();
+
+    let mut world = World {
+        level,
+        thinkers: ThinkerList::new(),
+        random,
+        skill,
+        valid_count: 1,
+        check: PositionCheck::new(),
+        on_ground: false,
+        level_time: 0,
+        total_kills: 0,
+        total_items: 0,
+        players: Default::default(),
+        // TODO Only the console player is in game until netgames exist
+        player_in_game: [true, false, false, false],
+        player_starts: [None; MAX_PLAYERS],
+        deathmatch_starts: Vec::new(),
+        item_respawn_que: VecDeque::new(),
+        exit: None,
+    };
+
+    // P_LoadThings
+    for thing in &things {
+        world.spawn_map_thing(thing)?;
+    }
+
+    Ok(world)
+}
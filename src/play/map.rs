@@ -0,0 +1,557 @@
+use crate::types::{DoomRealNum, real, fixed_mul, FRAC_BITS, BOX_TOP, BOX_BOTTOM, BOX_LEFT, BOX_RIGHT, from_fixed_bits};
+// Only the f32 backend needs it, I16F16 has the wrapping operations of its own
+#[allow(unused_imports)]
+use crate::types::RealNum;
+use crate::tables::{point_to_angle, fine_sine, fine_cosine, ANG180, ANGLE_TO_FINE_SHIFT};
+use crate::info::{MOBJ_INFO, MT_KNIGHT, MT_BRUISER, MT_PLAYER};
+use crate::level::blockmap::MAX_RADIUS;
+use crate::level::linedefs::{SlopeType, ML_BLOCKING, ML_BLOCKMONSTERS, ML_TWOSIDED};
+use crate::play::World;
+use crate::play::map_util::{aprox_distance, box_on_line_side, line_opening, point_on_line_side, Intercept, InterceptTarget, PT_ADDLINES};
+use crate::play::mobj::{MF_SOLID, MF_SPECIAL, MF_SHOOTABLE, MF_SKULLFLY, MF_MISSILE, MF_PICKUP, MF_NOCLIP, MF_TELEPORT, MF_DROPOFF, MF_FLOAT};
+use crate::play::tick::ThinkerId;
+
+// The highest step a thing can climb in a move.
+const MAX_STEP_UP: DoomRealNum = from_fixed_bits(24 << FRAC_BITS);
+
+// The state of the last P_CheckPosition, the tm globals.
+// The P_TryMove callers look at the resulting floor and ceiling.
+pub struct PositionCheck {
+    pub(crate) bbox: [DoomRealNum; 4],
+    pub(crate) thing: ThinkerId,
+    pub(crate) flags: u32,
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum,
+
+    // If "floatok" true, move would be ok
+    // if within "tmfloorz - tmceilingz".
+    pub(crate) float_ok: bool,
+
+    pub(crate) floor_z: DoomRealNum,
+    pub(crate) ceiling_z: DoomRealNum,
+    pub(crate) dropoff_z: DoomRealNum,
+
+    // keep track of the line that lowers the ceiling,
+    // so missiles don't explode against sky hack walls
+    pub(crate) ceiling_line: Option<usize>,
+
+    // keep track of special lines as they are hit,
+    // but don't process them until the move is proven valid
+    pub(crate) spec_hit: Vec<usize>,
+}
+
+impl PositionCheck {
+    pub fn new() -> Self {
+        Self {
+            bbox: [real(0); 4],
+            thing: 0,
+            flags: 0,
+            x: real(0),
+            y: real(0),
+            float_ok: false,
+            floor_z: real(0),
+            ceiling_z: real(0),
+            dropoff_z: real(0),
+            ceiling_line: None,
+            spec_hit: Vec::new(),
+        }
+    }
+}
+
+impl World {
+    // PIT_CheckLine
+    // Adjusts tmfloorz and tmceilingz as lines are contacted
+    fn check_line(&mut self, line_index: usize) -> bool {
+        let line = &self.level.lines[line_index];
+        let bbox = &self.check.bbox;
+
+        if bbox[BOX_RIGHT] <= line.bbox[BOX_LEFT]
+            || bbox[BOX_LEFT] >= line.bbox[BOX_RIGHT]
+            || bbox[BOX_TOP] <= line.bbox[BOX_BOTTOM]
+            || bbox[BOX_BOTTOM] >= line.bbox[BOX_TOP] {
+            return true;
+        }
+
+        if box_on_line_side(bbox, line, &self.level.vertices).is_some() {
+            return true;
+        }
+
+        // A line has been hit
+
+        // The moving thing's destination position will cross
+        // the given line.
+        // If this should not be allowed, return false.
+        // If the line is special, keep track of it
+        // to process later if the move is proven ok.
+        // NOTE: specials are NOT sorted by order,
+        // so two special lines that are only 8 pixels apart
+        // could be crossed in either order.
+
+        if line.back_sector.is_none() {
+            return false; // one sided line
+        }
+
+        let thing = self.thinkers.mobj(self.check.thing);
+        if thing.flags & MF_MISSILE == 0 {
+            if line.flags & ML_BLOCKING != 0 {
+                return false; // explicitly blocking everything
+            }
+
+            if thing.player.is_none() && line.flags & ML_BLOCKMONSTERS != 0 {
+                return false; // block monsters only
+            }
+        }
+
+        // set openrange, opentop, openbottom
+        let opening = line_opening(line, &self.level.sectors);
+        let special = line.special != 0 || line.action.is_some();
+
+        // adjust floor / ceiling heights
+        if opening.top < self.check.ceiling_z {
+            self.check.ceiling_z = opening.top;
+            self.check.ceiling_line = Some(line_index);
+        }
+
+        if opening.bottom > self.check.floor_z {
+            self.check.floor_z = opening.bottom;
+        }
+
+        if opening.low_floor < self.check.dropoff_z {
+            self.check.dropoff_z = opening.low_floor;
+        }
+
+        // if contacted a special line, add it to the list
+        if special {
+            self.check.spec_hit.push(line_index);
+        }
+
+        true
+    }
+
+    // PIT_CheckThing
+    fn check_thing(&mut self, id: ThinkerId) -> bool {
+        let thing = self.thinkers.mobj(id);
+        if thing.flags & (MF_SOLID | MF_SPECIAL | MF_SHOOTABLE) == 0 {
+            return true;
+        }
+
+        let tm_thing = self.thinkers.mobj(self.check.thing);
+        let block_dist = thing.radius + tm_thing.radius;
+
+        if (thing.x - self.check.x).abs() >= block_dist || (thing.y - self.check.y).abs() >= block_dist {
+            // didn't hit it
+            return true;
+        }
+
+        // don't clip against self
+        if id == self.check.thing {
+            return true;
+        }
+
+        // check for skulls slamming into things
+        if tm_thing.flags & MF_SKULLFLY != 0 {
+            let tm_id = self.check.thing;
+            let spawn_state = MOBJ_INFO[tm_thing.mobj_type].spawn_state;
+            let _damage = (self.random.p_random() % 8 + 1) * MOBJ_INFO[tm_thing.mobj_type].damage;
+            // TODO P_DamageMobj (thing, tmthing, tmthing, damage);
+
+            let tm_thing = self.thinkers.mobj_mut(tm_id);
+            tm_thing.flags &= !MF_SKULLFLY;
+            tm_thing.mom_x = real(0);
+            tm_thing.mom_y = real(0);
+            tm_thing.mom_z = real(0);
+
+            self.set_mobj_state(tm_id, spawn_state);
+
+            return false; // stop moving
+        }
+
+        // missiles can hit other things
+        if tm_thing.flags & MF_MISSILE != 0 {
+            // see if it went over / under
+            if tm_thing.z > thing.z + thing.height {
+                return true; // overhead
+            }
+            if tm_thing.z + tm_thing.height < thing.z {
+                return true; // underneath
+            }
+
+            if let Some(target) = tm_thing.target {
+                let target_type = self.thinkers.mobj(target).mobj_type;
+                if target_type == thing.mobj_type
+                    || (target_type == MT_KNIGHT && thing.mobj_type == MT_BRUISER)
+                    || (target_type == MT_BRUISER && thing.mobj_type == MT_KNIGHT) {
+                    // Don't hit same species as originator.
+                    if id == target {
+                        return true;
+                    }
+
+                    if thing.mobj_type != MT_PLAYER {
+                        // Explode, but do no damage.
+                        // Let players missile other players.
+                        return false;
+                    }
+                }
+            }
+
+            if thing.flags & MF_SHOOTABLE == 0 {
+                // didn't do any damage
+                return thing.flags & MF_SOLID == 0;
+            }
+
+            // damage / explode
+            let _damage = (self.random.p_random() % 8 + 1) * MOBJ_INFO[tm_thing.mobj_type].damage;
+            // TODO P_DamageMobj (thing, tmthing, tmthing->target, damage);
+
+            // don't traverse any more
+            return false;
+        }
+
+        // check for special pickup
+        if thing.flags & MF_SPECIAL != 0 {
+            let solid = thing.flags & MF_SOLID != 0;
+            if self.check.flags & MF_PICKUP != 0 {
+                // can remove thing
+                // TODO P_TouchSpecialThing (thing, tmthing);
+            }
+            return !solid;
+        }
+
+        thing.flags & MF_SOLID == 0
+    }
+
+    // P_CheckPosition
+    // This is purely informative, nothing is modified
+    // (except things picked up).
+    //
+    // in:
+    //  a mobj_t (can be valid or invalid)
+    //  a position to be checked
+    //   (doesn't need to be related to the mobj_t->x,y)
+    //
+    // during:
+    //  special things are touched if MF_PICKUP
+    //  early out on solid lines?
+    //
+    // out:
+    //  newsubsec
+    //  floorz
+    //  ceilingz
+    //  tmdropoffz
+    //   the lowest point contacted
+    //   (monsters won't move to a dropoff)
+    //  speciallines[]
+    //  numspeciallines
+    pub fn check_position(&mut self, id: ThinkerId, x: DoomRealNum, y: DoomRealNum) -> bool {
+        let thing = self.thinkers.mobj(id);
+
+        self.check.thing = id;
+        self.check.flags = thing.flags;
+        self.check.x = x;
+        self.check.y = y;
+
+        self.check.bbox[BOX_TOP] = y + thing.radius;
+        self.check.bbox[BOX_BOTTOM] = y - thing.radius;
+        self.check.bbox[BOX_RIGHT] = x + thing.radius;
+        self.check.bbox[BOX_LEFT] = x - thing.radius;
+
+        let new_subsector = self.level.point_in_subsector(x, y);
+        self.check.ceiling_line = None;
+
+        // The base floor / ceiling is from the subsector
+        // that contains the point.
+        // Any contacted lines the step closer together
+        // will adjust them.
+        let sector = &self.level.sectors[self.level.subsectors[new_subsector].sector];
+        self.check.floor_z = sector.floor_height;
+        self.check.dropoff_z = sector.floor_height;
+        self.check.ceiling_z = sector.ceiling_height;
+
+        self.valid_count += 1;
+        self.check.spec_hit.clear();
+
+        if self.check.flags & MF_NOCLIP != 0 {
+            return true;
+        }
+
+        // Check things first, possibly picking things up.
+        // The bounding box is extended by MAXRADIUS
+        // because mobj_ts are grouped into mapblocks
+        // based on their origin point, and can overlap
+        // into adjacent blocks by up to MAXRADIUS units.
+        let (xl, xh, yl, yh) = self.block_box(MAX_RADIUS);
+
+        for bx in xl..=xh {
+            for by in yl..=yh {
+                if !self.block_things_iterator(bx, by, World::check_thing) {
+                    return false;
+                }
+            }
+        }
+
+        // check lines
+        let (xl, xh, yl, yh) = self.block_box(real(0));
+
+        for bx in xl..=xh {
+            for by in yl..=yh {
+                if !self.block_lines_iterator(bx, by, World::check_line) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    // The blocks touched by the checked bounding box grown by margin,
+    //  as xl, xh, yl and yh.
+    fn block_box(&self, margin: DoomRealNum) -> (i32, i32, i32, i32) {
+        let blockmap = &self.level.blockmap;
+        let bbox = &self.check.bbox;
+
+        (
+            blockmap.block_x(bbox[BOX_LEFT].wrapping_sub(margin)),
+            blockmap.block_x(bbox[BOX_RIGHT].wrapping_add(margin)),
+            blockmap.block_y(bbox[BOX_BOTTOM].wrapping_sub(margin)),
+            blockmap.block_y(bbox[BOX_TOP].wrapping_add(margin)),
+        )
+    }
+
+    // P_TryMove
+    // Attempt to move to a new position,
+    // crossing special lines unless MF_TELEPORT is set.
+    pub fn try_move(&mut self, id: ThinkerId, x: DoomRealNum, y: DoomRealNum) -> bool {
+        self.check.float_ok = false;
+        if !self.check_position(id, x, y) {
+            return false; // solid wall or thing
+        }
+
+        let thing = self.thinkers.mobj(id);
+        if thing.flags & MF_NOCLIP == 0 {
+            if self.check.ceiling_z - self.check.floor_z < thing.height {
+                return false; // doesn't fit
+            }
+
+            self.check.float_ok = true;
+
+            if thing.flags & MF_TELEPORT == 0 && self.check.ceiling_z - thing.z < thing.height {
+                return false; // mobj must lower itself to fit
+            }
+
+            if thing.flags & MF_TELEPORT == 0 && self.check.floor_z - thing.z > MAX_STEP_UP {
+                return false; // too big a step up
+            }
+
+            if thing.flags & (MF_DROPOFF | MF_FLOAT) == 0 && self.check.floor_z - self.check.dropoff_z > MAX_STEP_UP {
+                return false; // don't stand over a dropoff
+            }
+        }
+
+        // the move is ok,
+        // so link the thing into its new position
+        self.unset_thing_position(id);
+
+        let thing = self.thinkers.mobj_mut(id);
+        let (old_x, old_y) = (thing.x, thing.y);
+        thing.floor_z = self.check.floor_z;
+        thing.ceiling_z = self.check.ceiling_z;
+        thing.x = x;
+        thing.y = y;
+        let flags = thing.flags;
+
+        self.set_thing_position(id);
+
+        // if any special lines were hit, do the effect
+        if flags & (MF_TELEPORT | MF_NOCLIP) == 0 {
+            for hit in (0..self.check.spec_hit.len()).rev() {
+                // see if the line was crossed
+                let line_index = self.check.spec_hit[hit];
+                let line = &self.level.lines[line_index];
+                let side = point_on_line_side(x, y, line, &self.level.vertices);
+                let old_side = point_on_line_side(old_x, old_y, line, &self.level.vertices);
+
+                if side != old_side && (line.special != 0 || line.action.is_some()) {
+                    self.cross_special_line(line_index, old_side, id);
+                }
+            }
+        }
+
+        true
+    }
+
+    // P_HitSlideLine
+    // Adjusts the xmove / ymove
+    // so that the next move will slide along the wall.
+    fn hit_slide_line(&self, id: ThinkerId, line_index: usize, x_move: DoomRealNum, y_move: DoomRealNum) -> (DoomRealNum, DoomRealNum) {
+        let line = &self.level.lines[line_index];
+
+        if line.slope_type == SlopeType::Horizontal {
+            return (x_move, real(0));
+        }
+
+        if line.slope_type == SlopeType::Vertical {
+            return (real(0), y_move);
+        }
+
+        let mobj = self.thinkers.mobj(id);
+        let side = point_on_line_side(mobj.x, mobj.y, line, &self.level.vertices);
+
+        let mut line_angle = point_to_angle(line.dx, line.dy);
+
+        if side == 1 {
+            line_angle = line_angle.wrapping_add(ANG180);
+        }
+
+        let move_angle = point_to_angle(x_move, y_move);
+        let mut delta_angle = move_angle.wrapping_sub(line_angle);
+
+        if delta_angle > ANG180 {
+            delta_angle = delta_angle.wrapping_add(ANG180);
+        }
+        //  I_Error ("SlideLine: ang>ANG180");
+
+        let line_angle = (line_angle >> ANGLE_TO_FINE_SHIFT) as usize;
+        let delta_angle = (delta_angle >> ANGLE_TO_FINE_SHIFT) as usize;
+
+        let move_len = aprox_distance(x_move, y_move);
+        let new_len = fixed_mul(move_len, fine_cosine(delta_angle));
+
+        (fixed_mul(new_len, fine_cosine(line_angle)), fixed_mul(new_len, fine_sine(line_angle)))
+    }
+
+    // P_SlideMove
+    // The momx / momy move is bad, so try to slide
+    // along a wall.
+    // Find the first line hit, move flush to it,
+    // and slide along it
+    //
+    // This is a kludgy mess.
+    pub fn slide_move(&mut self, id: ThinkerId) {
+        let no_hit = real(1) + from_fixed_bits(1);
+
+        for _ in 0..2 {
+            let mobj = self.thinkers.mobj(id);
+            let (x, y, radius) = (mobj.x, mobj.y, mobj.radius);
+            let (mom_x, mom_y) = (mobj.mom_x, mobj.mom_y);
+
+            // trace along the three leading corners
+            let (lead_x, trail_x) = if mom_x > real(0) {
+                (x + radius, x - radius)
+            } else {
+                (x - radius, x + radius)
+            };
+
+            let (lead_y, trail_y) = if mom_y > real(0) {
+                (y + radius, y - radius)
+            } else {
+                (y - radius, y + radius)
+            };
+
+            let mut best_slide_frac = no_hit;
+            let mut best_slide_line = None;
+
+            // PTR_SlideTraverse
+            let mut slide_traverse = |world: &mut World, intercept: &Intercept| {
+                let line_index = match intercept.target {
+                    InterceptTarget::Line(line_index) => line_index,
+                    InterceptTarget::Thing(_) => panic!("PTR_SlideTraverse: not a line?"),
+                };
+
+                let line = &world.level.lines[line_index];
+                let mobj = world.thinkers.mobj(id);
+
+                let blocking = if line.flags & ML_TWOSIDED == 0 {
+                    if point_on_line_side(mobj.x, mobj.y, line, &world.level.vertices) != 0 {
+                        // don't hit the back side
+                        return true;
+                    }
+                    true
+                } else {
+                    // set openrange, opentop, openbottom
+                    let opening = line_opening(line, &world.level.sectors);
+
+                    // doesn't fit, mobj is too high or too big a step up
+                    opening.range < mobj.height
+                        || opening.top - mobj.z < mobj.height
+                        || opening.bottom - mobj.z > MAX_STEP_UP
+                };
+
+                if !blocking {
+                    // this line doesn't block movement
+                    return true;
+                }
+
+                // the line does block movement,
+                // see if it is closer than best so far
+                if intercept.frac < best_slide_frac {
+                    best_slide_frac = intercept.frac;
+                    best_slide_line = Some(line_index);
+                }
+
+                false // stop
+            };
+
+            self.path_traverse(lead_x, lead_y, lead_x + mom_x, lead_y + mom_y, PT_ADDLINES, &mut slide_traverse);
+            self.path_traverse(trail_x, lead_y, trail_x + mom_x, lead_y + mom_y, PT_ADDLINES, &mut slide_traverse);
+            self.path_traverse(lead_x, trail_y, lead_x + mom_x, trail_y + mom_y, PT_ADDLINES, &mut slide_traverse);
+
+            // move up to the wall
+            let best_slide_line = match best_slide_line {
+                Some(line) => line,
+                None => {
+                    // the move most have hit the middle, so stairstep
+                    self.stair_step(id);
+                    return;
+                }
+            };
+
+            // fudge a bit to make sure it doesn't hit
+            let best_slide_frac = best_slide_frac - from_fixed_bits(0x800);
+            if best_slide_frac > real(0) {
+                let new_x = fixed_mul(mom_x, best_slide_frac);
+                let new_y = fixed_mul(mom_y, best_slide_frac);
+
+                if !self.try_move(id, x + new_x, y + new_y) {
+                    self.stair_step(id);
+                    return;
+                }
+            }
+
+            // Now continue along the wall.
+            // First calculate remainder.
+            let remainder = (real(1) - (best_slide_frac + from_fixed_bits(0x800))).min(real(1));
+
+            if remainder <= real(0) {
+                return;
+            }
+
+            let x_move = fixed_mul(mom_x, remainder);
+            let y_move = fixed_mul(mom_y, remainder);
+
+            // clip the moves
+            let (x_move, y_move) = self.hit_slide_line(id, best_slide_line, x_move, y_move);
+
+            let mobj = self.thinkers.mobj_mut(id);
+            mobj.mom_x = x_move;
+            mobj.mom_y = y_move;
+            let (x, y) = (mobj.x, mobj.y);
+
+            if self.try_move(id, x + x_move, y + y_move) {
+                return;
+            }
+        }
+
+        // don't loop forever
+        self.stair_step(id);
+    }
+
+    // The stairstep part of P_SlideMove, tries the y and x moves alone.
+    fn stair_step(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj(id);
+        let (x, y, mom_x, mom_y) = (mobj.x, mobj.y, mobj.mom_x, mobj.mom_y);
+
+        if !self.try_move(id, x, y + mom_y) {
+            self.try_move(id, x + mom_x, y);
+        }
+    }
+}
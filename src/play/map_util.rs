@@ -0,0 +1,451 @@
+use crate::types::{DoomRealNum, real, fixed_mul, fixed_div, FRAC_BITS, BOX_TOP, BOX_BOTTOM, BOX_LEFT, BOX_RIGHT, RealNum};
+use crate::level::blockmap::MAP_BLOCK_SHIFT;
+use crate::level::linedefs::{LineDef, SlopeType};
+use crate::level::sectors::Sector;
+use crate::level::vertices::Vertex;
+use crate::play::World;
+use crate::play::tick::ThinkerId;
+
+// Flags for P_PathTraverse.
+pub const PT_ADDLINES: u32 = 1;
+pub const PT_ADDTHINGS: u32 = 2;
+pub const PT_EARLYOUT: u32 = 4;
+
+// P_AproxDistance
+// Gives an estimation of distance (not exact)
+pub fn aprox_distance(dx: DoomRealNum, dy: DoomRealNum) -> DoomRealNum {
+    let dx = dx.abs();
+    let dy = dy.abs();
+
+    if dx < dy {
+        dx + dy - dx.div_int(2)
+    } else {
+        dx + dy - dy.div_int(2)
+    }
+}
+
+// P_PointOnLineSide
+// Returns 0 or 1
+pub fn point_on_line_side(x: DoomRealNum, y: DoomRealNum, line: &LineDef, vertices: &[Vertex]) -> usize {
+    let v1 = &vertices[line.v1];
+
+    if line.dx == real(0) {
+        return if x <= v1.x {
+            (line.dy > real(0)) as usize
+        } else {
+            (line.dy < real(0)) as usize
+        };
+    }
+
+    if line.dy == real(0) {
+        return if y <= v1.y {
+            (line.dx < real(0)) as usize
+        } else {
+            (line.dx > real(0)) as usize
+        };
+    }
+
+    let dx = x.wrapping_sub(v1.x);
+    let dy = y.wrapping_sub(v1.y);
+
+    let left = fixed_mul(line.dy.shr(FRAC_BITS as u32), dx);
+    let right = fixed_mul(dy, line.dx.shr(FRAC_BITS as u32));
+
+    if right < left {
+        0 // front side
+    } else {
+        1 // back side
+    }
+}
+
+// P_BoxOnLineSide
+// Considers the line to be infinite
+// Returns side 0 or 1, None if box crosses the line.
+pub fn box_on_line_side(bbox: &[DoomRealNum; 4], line: &LineDef, vertices: &[Vertex]) -> Option<usize> {
+    let v1 = &vertices[line.v1];
+
+    let (p1, p2) = match line.slope_type {
+        SlopeType::Horizontal => {
+            let flip = (line.dx < real(0)) as usize;
+            ((bbox[BOX_TOP] > v1.y) as usize ^ flip, (bbox[BOX_BOTTOM] > v1.y) as usize ^ flip)
+        }
+        SlopeType::Vertical => {
+            let flip = (line.dy < real(0)) as usize;
+            ((bbox[BOX_RIGHT] < v1.x) as usize ^ flip, (bbox[BOX_LEFT] < v1.x) as usize ^ flip)
+        }
+        SlopeType::Positive => (
+            point_on_line_side(bbox[BOX_LEFT], bbox[BOX_TOP], line, vertices),
+            point_on_line_side(bbox[BOX_RIGHT], bbox[BOX_BOTTOM], line, vertices),
+        ),
+        SlopeType::Negative => (
+            point_on_line_side(bbox[BOX_RIGHT], bbox[BOX_TOP], line, vertices),
+            point_on_line_side(bbox[BOX_LEFT], bbox[BOX_BOTTOM], line, vertices),
+        ),
+    };
+
+    if p1 == p2 {
+        Some(p1)
+    } else {
+        None
+    }
+}
+
+// A partition line used for tracing: divline_t
+#[derive(Copy, Clone)]
+pub struct DivLine {
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum,
+    pub(crate) dx: DoomRealNum,
+    pub(crate) dy: DoomRealNum,
+}
+
+impl DivLine {
+    // P_MakeDivline
+    pub fn from_line(line: &LineDef, vertices: &[Vertex]) -> Self {
+        let v1 = &vertices[line.v1];
+        Self { x: v1.x, y: v1.y, dx: line.dx, dy: line.dy }
+    }
+
+    // P_PointOnDivlineSide
+    // Returns 0 or 1.
+    pub fn point_on_side(&self, x: DoomRealNum, y: DoomRealNum) -> usize {
+        if self.dx == real(0) {
+            return if x <= self.x {
+                (self.dy > real(0)) as usize
+            } else {
+                (self.dy < real(0)) as usize
+            };
+        }
+
+        if self.dy == real(0) {
+            return if y <= self.y {
+                (self.dx < real(0)) as usize
+            } else {
+                (self.dx > real(0)) as usize
+            };
+        }
+
+        let dx = x.wrapping_sub(self.x);
+        let dy = y.wrapping_sub(self.y);
+        let negative = |value: DoomRealNum| value < real(0);
+
+        // try to quickly decide by looking at sign bits
+        if negative(self.dy) ^ negative(self.dx) ^ negative(dx) ^ negative(dy) {
+            return if negative(self.dy) ^ negative(dx) {
+                1 // (left is negative)
+            } else {
+                0
+            };
+        }
+
+        let left = fixed_mul(self.dy.shr(8), dx.shr(8));
+        let right = fixed_mul(dy.shr(8), self.dx.shr(8));
+
+        if right < left {
+            0 // front side
+        } else {
+            1 // back side
+        }
+    }
+}
+
+// P_InterceptVector
+// Returns the fractional intercept point
+// along the first divline.
+// This is only called by the addthings
+// and addlines traversers, and the sight check.
+pub fn intercept_vector(v2: &DivLine, v1: &DivLine) -> DoomRealNum {
+    let shifted = |value: DoomRealNum| value.shr(8);
+
+    let den = fixed_mul(shifted(v1.dy), v2.dx) - fixed_mul(shifted(v1.dx), v2.dy);
+    if den == real(0) {
+        return real(0);
+        // I_Error ("P_InterceptVector: parallel");
+    }
+
+    let num = fixed_mul(shifted(v1.x.wrapping_sub(v2.x)), v1.dy)
+        + fixed_mul(shifted(v2.y.wrapping_sub(v1.y)), v1.dx);
+
+    fixed_div(num, den)
+}
+
+/// The vertical gap through a two sided line,
+/// opentop, openbottom, openrange and lowfloor.
+pub struct Opening {
+    pub(crate) top: DoomRealNum,
+    pub(crate) bottom: DoomRealNum,
+    pub(crate) range: DoomRealNum,
+    pub(crate) low_floor: DoomRealNum,
+}
+
+// P_LineOpening
+// Sets opentop and openbottom to the window
+// through a two sided line.
+// OPTIMIZE: keep this precalculated
+pub fn line_opening(line: &LineDef, sectors: &[Sector]) -> Opening {
+    let (front, back) = match (line.front_sector, line.back_sector) {
+        (Some(front), Some(back)) if line.sidenum[1].is_some() => (&sectors[front], &sectors[back]),
+        _ => {
+            // single sided line
+            return Opening { top: real(0), bottom: real(0), range: real(0), low_floor: real(0) };
+        }
+    };
+
+    let top = front.ceiling_height.min(back.ceiling_height);
+
+    let (bottom, low_floor) = if front.floor_height > back.floor_height {
+        (front.floor_height, back.floor_height)
+    } else {
+        (back.floor_height, front.floor_height)
+    };
+
+    Opening { top, bottom, range: top - bottom, low_floor }
+}
+
+// What a trace crossed: intercept_t
+pub enum InterceptTarget {
+    Line(usize),
+    Thing(ThinkerId),
+}
+
+pub struct Intercept {
+    // along trace line
+    pub(crate) frac: DoomRealNum,
+    pub(crate) target: InterceptTarget,
+}
+
+impl World {
+    // P_BlockLinesIterator
+    // The validcount flags are used to avoid checking lines
+    // that are marked in multiple mapblocks,
+    // so increment validcount before the first call
+    // to P_BlockLinesIterator, then make one or more calls
+    // to it.
+    pub fn block_lines_iterator(&mut self, x: i32, y: i32, mut func: impl FnMut(&mut World, usize) -> bool) -> bool {
+        let block = match self.level.blockmap.block_index(x, y) {
+            Some(block) => block,
+            None => return true,
+        };
+
+        let blockmap = &self.level.blockmap;
+        for list in blockmap.block_offsets[block]..blockmap.block_offsets[block + 1] {
+            let line = self.level.blockmap.block_lines[list];
+            if self.level.lines[line].valid_count == self.valid_count {
+                continue; // line has already been checked
+            }
+
+            self.level.lines[line].valid_count = self.valid_count;
+
+            if !func(self, line) {
+                return false;
+            }
+        }
+
+        true // everything was checked
+    }
+
+    // P_BlockThingsIterator
+    pub fn block_things_iterator(&mut self, x: i32, y: i32, mut func: impl FnMut(&mut World, ThinkerId) -> bool) -> bool {
+        let block = match self.level.blockmap.block_index(x, y) {
+            Some(block) => block,
+            None => return true,
+        };
+
+        let mut mobj = self.level.blockmap.blocklinks[block];
+        while let Some(id) = mobj {
+            if !func(self, id) {
+                return false;
+            }
+            mobj = self.thinkers.mobj(id).block_next;
+        }
+
+        true
+    }
+
+    // P_PathTraverse
+    // Traces a line from x1,y1 to x2,y2,
+    // calling the traverser function for each.
+    // Returns true if the traverser function returns true
+    // for all lines.
+    pub fn path_traverse(&mut self, x1: DoomRealNum, y1: DoomRealNum, x2: DoomRealNum, y2: DoomRealNum,
+                         flags: u32, trav: impl FnMut(&mut World, &Intercept) -> bool) -> bool {
+        let early_out = flags & PT_EARLYOUT != 0;
+
+        self.valid_count += 1;
+        let mut intercepts = Vec::new();
+
+        let (origin_x, origin_y) = (self.level.blockmap.origin_x, self.level.blockmap.origin_y);
+        let (mut x1, mut y1) = (x1, y1);
+
+        // in map blocks from the blockmap origin
+        let block_x = |x: DoomRealNum| x.wrapping_sub(origin_x).shr(MAP_BLOCK_SHIFT as u32);
+        let block_y = |y: DoomRealNum| y.wrapping_sub(origin_y).shr(MAP_BLOCK_SHIFT as u32);
+        let on_block_line = |value: DoomRealNum| value.frac_part() == real(0)
+            && value.shr(MAP_BLOCK_SHIFT as u32).frac_part() == real(0);
+
+        if on_block_line(x1.wrapping_sub(origin_x)) {
+            x1 += real(1); // don't side exactly on a line
+        }
+
+        if on_block_line(y1.wrapping_sub(origin_y)) {
+            y1 += real(1); // don't side exactly on a line
+        }
+
+        let trace = DivLine { x: x1, y: y1, dx: x2.wrapping_sub(x1), dy: y2.wrapping_sub(y1) };
+
+        let (x1, y1) = (block_x(x1), block_y(y1));
+        let (x2, y2) = (block_x(x2), block_y(y2));
+
+        let (xt1, yt1) = (x1.to_int(), y1.to_int());
+        let (xt2, yt2) = (x2.to_int(), y2.to_int());
+
+        let (map_x_step, partial, y_step) = if xt2 > xt1 {
+            (1, real(1) - x1.frac_part(), fixed_div(trace.dy, trace.dx.abs()))
+        } else if xt2 < xt1 {
+            (-1, x1.frac_part(), fixed_div(trace.dy, trace.dx.abs()))
+        } else {
+            (0, real(1), real(256))
+        };
+
+        let mut y_intercept = y1.wrapping_add(fixed_mul(partial, y_step));
+
+        let (map_y_step, partial, x_step) = if yt2 > yt1 {
+            (1, real(1) - y1.frac_part(), fixed_div(trace.dx, trace.dy.abs()))
+        } else if yt2 < yt1 {
+            (-1, y1.frac_part(), fixed_div(trace.dx, trace.dy.abs()))
+        } else {
+            (0, real(1), real(256))
+        };
+
+        let mut x_intercept = x1.wrapping_add(fixed_mul(partial, x_step));
+
+        // Step through map blocks.
+        // Count is present to prevent a round off error
+        // from skipping the break.
+        let (mut map_x, mut map_y) = (xt1, yt1);
+
+        for _ in 0..64 {
+            if flags & PT_ADDLINES != 0
+                && !self.block_lines_iterator(map_x, map_y, |world, line| add_line_intercepts(world, line, &trace, early_out, &mut intercepts)) {
+                return false; // early out
+            }
+
+            if flags & PT_ADDTHINGS != 0
+                && !self.block_things_iterator(map_x, map_y, |world, thing| add_thing_intercepts(world, thing, &trace, &mut intercepts)) {
+                return false; // early out
+            }
+
+            if map_x == xt2 && map_y == yt2 {
+                break;
+            }
+
+            if y_intercept.to_int() == map_y {
+                y_intercept = y_intercept.wrapping_add(y_step);
+                map_x += map_x_step;
+            } else if x_intercept.to_int() == map_x {
+                x_intercept = x_intercept.wrapping_add(x_step);
+                map_y += map_y_step;
+            }
+        }
+
+        // go through the sorted list
+        self.traverse_intercepts(&mut intercepts, trav, real(1))
+    }
+
+    // P_TraverseIntercepts
+    // Returns true if the traverser function returns true
+    // for all lines.
+    fn traverse_intercepts(&mut self, intercepts: &mut [Intercept], mut func: impl FnMut(&mut World, &Intercept) -> bool, max_frac: DoomRealNum) -> bool {
+        for _ in 0..intercepts.len() {
+            let closest = intercepts.iter_mut()
+                .min_by(|a, b| a.frac.partial_cmp(&b.frac).unwrap());
+
+            let intercept = match closest {
+                Some(intercept) if intercept.frac <= max_frac => intercept,
+                _ => return true, // checked everything in range
+            };
+
+            if !func(self, intercept) {
+                return false; // don't bother going farther
+            }
+
+            intercept.frac = DoomRealNum::MAX;
+        }
+
+        true // everything was traversed
+    }
+}
+
+// PIT_AddLineIntercepts.
+// Looks for lines in the given block
+// that intercept the given trace
+// to add to the intercepts list.
+//
+// A line is crossed if its endpoints
+// are on opposite sides of the trace.
+// Returns true if earlyout and a solid line hit.
+fn add_line_intercepts(world: &mut World, line_index: usize, trace: &DivLine, early_out: bool, intercepts: &mut Vec<Intercept>) -> bool {
+    let line = &world.level.lines[line_index];
+    let vertices = &world.level.vertices;
+    let (v1, v2) = (&vertices[line.v1], &vertices[line.v2]);
+
+    // avoid precision problems with two routines
+    let (s1, s2) = if trace.dx > real(16) || trace.dy > real(16)
+        || trace.dx < real(-16) || trace.dy < real(-16) {
+        (trace.point_on_side(v1.x, v1.y), trace.point_on_side(v2.x, v2.y))
+    } else {
+        (point_on_line_side(trace.x, trace.y, line, vertices),
+         point_on_line_side(trace.x.wrapping_add(trace.dx), trace.y.wrapping_add(trace.dy), line, vertices))
+    };
+
+    if s1 == s2 {
+        return true; // line isn't crossed
+    }
+
+    // hit the line
+    let frac = intercept_vector(trace, &DivLine::from_line(line, vertices));
+
+    if frac < real(0) {
+        return true; // behind source
+    }
+
+    // try to early out the check
+    if early_out && frac < real(1) && line.back_sector.is_none() {
+        return false; // stop checking
+    }
+
+    intercepts.push(Intercept { frac, target: InterceptTarget::Line(line_index) });
+
+    true // continue
+}
+
+// PIT_AddThingIntercepts
+fn add_thing_intercepts(world: &mut World, thing: ThinkerId, trace: &DivLine, intercepts: &mut Vec<Intercept>) -> bool {
+    let mobj = world.thinkers.mobj(thing);
+    let trace_positive = (trace.dx < real(0)) == (trace.dy < real(0)) && trace.dx != trace.dy;
+
+    // check a corner to corner crossection for hit
+    let (x1, y1, x2, y2) = if trace_positive {
+        (mobj.x - mobj.radius, mobj.y + mobj.radius, mobj.x + mobj.radius, mobj.y - mobj.radius)
+    } else {
+        (mobj.x - mobj.radius, mobj.y - mobj.radius, mobj.x + mobj.radius, mobj.y + mobj.radius)
+    };
+
+    let s1 = trace.point_on_side(x1, y1);
+    let s2 = trace.point_on_side(x2, y2);
+
+    if s1 == s2 {
+        return true; // line isn't crossed
+    }
+
+    let divline = DivLine { x: x1, y: y1, dx: x2 - x1, dy: y2 - y1 };
+    let frac = intercept_vector(trace, &divline);
+
+    if frac < real(0) {
+        return true; // behind source
+    }
+
+    intercepts.push(Intercept { frac, target: InterceptTarget::Thing(thing) });
+
+    true // keep going
+}
@@ -0,0 +1,806 @@
+use crate::types::{DoomRealNum, Angle, real, fixed_mul, from_fixed_bits, RealNum};
+use crate::tables::ANG45;
+use crate::info::{Action, MobjType, SpriteNum, StateNum, MOBJ_INFO, STATES, S_NULL, S_PLAY, S_PLAY_RUN1, MT_PLAYER, MT_INV, MT_INS};
+use crate::level::things::{Thing, MTF_EASY, MTF_NORMAL, MTF_HARD, MTF_AMBUSH, MTF_NOTSINGLE};
+use crate::game_context::Skill;
+use crate::player::PlayerState;
+use crate::play::{World, MAX_PLAYERS, VIEW_HEIGHT, MAX_MOVE, GRAVITY, FLOAT_SPEED};
+use crate::play::map_util::aprox_distance;
+use crate::play::tick::{Thinker, ThinkerId};
+use crate::wad::WadError;
+
+// Misc. mobj flags
+
+// Call P_SpecialThing when touched.
+pub const MF_SPECIAL: u32 = 1;
+// Blocks.
+pub const MF_SOLID: u32 = 2;
+// Can be hit.
+pub const MF_SHOOTABLE: u32 = 4;
+// Don't use the sector links (invisible but touchable).
+pub const MF_NOSECTOR: u32 = 8;
+// Don't use the blocklinks (inert but displayable)
+pub const MF_NOBLOCKMAP: u32 = 16;
+
+// Not to be activated by sound, deaf monster.
+pub const MF_AMBUSH: u32 = 32;
+// Will try to attack right back.
+pub const MF_JUSTHIT: u32 = 64;
+// Will take at least one step before attacking.
+pub const MF_JUSTATTACKED: u32 = 128;
+// On level spawning (initial position),
+//  hang from ceiling instead of stand on floor.
+pub const MF_SPAWNCEILING: u32 = 256;
+// Don't apply gravity (every tic),
+//  that is, object will float, keeping current height
+//  or changing it actively.
+pub const MF_NOGRAVITY: u32 = 512;
+
+// Movement flags.
+// This allows jumps from high places.
+pub const MF_DROPOFF: u32 = 0x400;
+// For players, will pick up items.
+pub const MF_PICKUP: u32 = 0x800;
+// Player cheat. ???
+pub const MF_NOCLIP: u32 = 0x1000;
+// Player: keep info about sliding along walls.
+pub const MF_SLIDE: u32 = 0x2000;
+// Allow moves to any height, no gravity.
+// For active floaters, e.g. cacodemons, pain elementals.
+pub const MF_FLOAT: u32 = 0x4000;
+// Don't cross lines
+//   ??? or look at heights on teleport.
+pub const MF_TELEPORT: u32 = 0x8000;
+// Don't hit same species, explode on block.
+// Player missiles as well as fireballs of various kinds.
+pub const MF_MISSILE: u32 = 0x10000;
+// Dropped by a demon, not level spawned.
+// E.g. ammo clips dropped by dying former humans.
+pub const MF_DROPPED: u32 = 0x20000;
+// Use fuzzy draw (shadow demons or spectres),
+//  temporary player invisibility powerup.
+pub const MF_SHADOW: u32 = 0x40000;
+// Flag: don't bleed when shot (use puff),
+//  barrels and shootable furniture shall not bleed.
+pub const MF_NOBLOOD: u32 = 0x80000;
+// Don't stop moving halfway off a step,
+//  that is, have dead bodies slide down all the way.
+pub const MF_CORPSE: u32 = 0x100000;
+// Floating to a height for a move, ???
+//  don't auto float to target's height.
+pub const MF_INFLOAT: u32 = 0x200000;
+
+// On kill, count this enemy object
+//  towards intermission kill total.
+// Happy gathering.
+pub const MF_COUNTKILL: u32 = 0x400000;
+
+// On picking up, count this item object
+//  towards intermission item total.
+pub const MF_COUNTITEM: u32 = 0x800000;
+
+// Special handling: skull in flight.
+// Neither a cacodemon nor a missile.
+pub const MF_SKULLFLY: u32 = 0x1000000;
+
+// Don't spawn this object
+//  in death match mode (e.g. key cards).
+pub const MF_NOTDMATCH: u32 = 0x2000000;
+
+// Player sprites in multiplayer modes are modified
+//  using an internal color lookup table for re-indexing.
+// If 0x4 0x8 or 0xc,
+//  use a translation table for player colormaps
+pub const MF_TRANSLATION: u32 = 0xc000000;
+// Hmm ???.
+pub const MF_TRANSSHIFT: u32 = 26;
+
+const STOP_SPEED: DoomRealNum = from_fixed_bits(0x1000);
+const FRICTION: DoomRealNum = from_fixed_bits(0xe800);
+
+// Spawn heights that are resolved to the floor or ceiling of the sector.
+pub const ON_FLOOR_Z: DoomRealNum = DoomRealNum::MIN;
+pub const ON_CEILING_Z: DoomRealNum = DoomRealNum::MAX;
+
+// Deathmatch start thing type.
+const DEATHMATCH_START: i16 = 11;
+
+// Time interval for item respawning.
+pub const ITEM_QUE_SIZE: usize = 128;
+
+/// A thing in the level that moves, animates or can be interacted with,
+/// see mobj_t.
+pub struct MapObject {
+    // Info for drawing: position.
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum,
+    pub(crate) z: DoomRealNum,
+
+    // More list: links in sector (if needed)
+    pub(crate) sector_next: Option<ThinkerId>,
+    pub(crate) sector_prev: Option<ThinkerId>,
+
+    //More drawing info: to determine current sprite.
+    // orientation
+    pub(crate) angle: Angle,
+    // used to find patch_t and flip value
+    pub(crate) sprite: SpriteNum,
+    // might be ORed with FF_FULLBRIGHT
+    pub(crate) frame: u32,
+
+    // Interaction info, by BLOCKMAP.
+    // Links in blocks (if needed).
+    pub(crate) block_next: Option<ThinkerId>,
+    pub(crate) block_prev: Option<ThinkerId>,
+
+    pub(crate) subsector: usize,
+
+    // The closest interval over all contacted Sectors.
+    pub(crate) floor_z: DoomRealNum,
+    pub(crate) ceiling_z: DoomRealNum,
+
+    // For movement checking.
+    pub(crate) radius: DoomRealNum,
+    pub(crate) height: DoomRealNum,
+
+    // Momentums, used to update position.
+    pub(crate) mom_x: DoomRealNum,
+    pub(crate) mom_y: DoomRealNum,
+    pub(crate) mom_z: DoomRealNum,
+
+    // If == validcount, already checked.
+    pub(crate) valid_count: i32,
+
+    pub(crate) mobj_type: MobjType,
+
+    // state tic counter
+    pub(crate) tics: i32,
+    pub(crate) state: StateNum,
+    pub(crate) flags: u32,
+    pub(crate) health: i32,
+
+    // Movement direction, movement generation (zig-zagging).
+    // 0-7
+    pub(crate) move_dir: i32,
+    // when 0, select a new dir
+    pub(crate) move_count: i32,
+
+    // Thing being chased/attacked (or None),
+    // also the originator for missiles.
+    pub(crate) target: Option<ThinkerId>,
+
+    // Reaction time: if non 0, don't attack yet.
+    // Used by player to freeze a bit after teleporting.
+    pub(crate) reaction_time: i32,
+
+    // If >0, the target will be chased
+    // no matter what (even if shot)
+    pub(crate) threshold: i32,
+
+    // Index of the player, only valid if type == MT_PLAYER
+    pub(crate) player: Option<usize>,
+
+    // Player number last looked for.
+    pub(crate) last_look: i32,
+
+    // For nightmare respawn.
+    pub(crate) spawn_point: Thing,
+
+    // Thing being chased/attacked for tracers.
+    pub(crate) tracer: Option<ThinkerId>,
+}
+
+impl World {
+    // P_SetMobjState
+    // Returns true if the mobj is still present.
+    pub fn set_mobj_state(&mut self, id: ThinkerId, mut state: StateNum) -> bool {
+        loop {
+            if state == S_NULL {
+                self.thinkers.mobj_mut(id).state = S_NULL;
+                self.remove_mobj(id);
+                return false;
+            }
+
+            let st = &STATES[state];
+            let mobj = self.thinkers.mobj_mut(id);
+            mobj.state = state;
+            mobj.tics = st.tics;
+            mobj.sprite = st.sprite;
+            mobj.frame = st.frame;
+
+            // Modified handling.
+            // Call action functions when the state is set
+            if let Some(action) = st.action {
+                self.call_action(id, action);
+            }
+
+            state = st.next_state;
+            if self.thinkers.mobj(id).tics != 0 {
+                return true;
+            }
+        }
+    }
+
+    fn call_action(&mut self, id: ThinkerId, action: Action) {
+        // TODO The other codepointers come with the
+        //  monster AI, weapons and sound.
+        if let Action::Fall = action {
+            // A_Fall
+            // actor is on ground, it can be walked over
+            self.thinkers.mobj_mut(id).flags &= !MF_SOLID;
+        }
+    }
+
+    // P_ExplodeMissile
+    pub fn explode_missile(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.mom_x = real(0);
+        mobj.mom_y = real(0);
+        mobj.mom_z = real(0);
+
+        let death_state = MOBJ_INFO[mobj.mobj_type].death_state;
+        self.set_mobj_state(id, death_state);
+
+        let random = self.random.p_random() & 3;
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.tics = (mobj.tics - random).max(1);
+        mobj.flags &= !MF_MISSILE;
+
+        // TODO S_StartSound (mo, mo->info->deathsound);
+    }
+
+    // P_XYMovement
+    fn xy_movement(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj_mut(id);
+
+        if mobj.mom_x == real(0) && mobj.mom_y == real(0) {
+            if mobj.flags & MF_SKULLFLY != 0 {
+                // the skull slammed into something
+                mobj.flags &= !MF_SKULLFLY;
+                mobj.mom_x = real(0);
+                mobj.mom_y = real(0);
+                mobj.mom_z = real(0);
+
+                let spawn_state = MOBJ_INFO[mobj.mobj_type].spawn_state;
+                self.set_mobj_state(id, spawn_state);
+            }
+            return;
+        }
+
+        let player = mobj.player;
+
+        mobj.mom_x = mobj.mom_x.clamp(-MAX_MOVE, MAX_MOVE);
+        mobj.mom_y = mobj.mom_y.clamp(-MAX_MOVE, MAX_MOVE);
+
+        let mut x_move = mobj.mom_x;
+        let mut y_move = mobj.mom_y;
+
+        loop {
+            let mobj = self.thinkers.mobj(id);
+            let (try_x, try_y) = if x_move > MAX_MOVE.div_int(2) || y_move > MAX_MOVE.div_int(2) {
+                let try_move = (mobj.x + x_move.div_int(2), mobj.y + y_move.div_int(2));
+                x_move = x_move.shr(1);
+                y_move = y_move.shr(1);
+                try_move
+            } else {
+                let try_move = (mobj.x + x_move, mobj.y + y_move);
+                x_move = real(0);
+                y_move = real(0);
+                try_move
+            };
+
+            if !self.try_move(id, try_x, try_y) {
+                // blocked move
+                let flags = self.thinkers.mobj(id).flags;
+
+                if player.is_some() {
+                    // try to slide along it
+                    self.slide_move(id);
+                } else if flags & MF_MISSILE != 0 {
+                    // explode a missile
+                    let sky_hack = self.check.ceiling_line
+                        .and_then(|line| self.level.lines[line].back_sector)
+                        .is_some_and(|back| self.level.sectors[back].ceiling_pic == self.level.sky_flat);
+
+                    if sky_hack {
+                        // Hack to prevent missiles exploding
+                        // against the sky.
+                        // Does not handle sky floors.
+                        self.remove_mobj(id);
+                        return;
+                    }
+                    self.explode_missile(id);
+                } else {
+                    let mobj = self.thinkers.mobj_mut(id);
+                    mobj.mom_x = real(0);
+                    mobj.mom_y = real(0);
+                }
+            }
+
+            if x_move == real(0) && y_move == real(0) {
+                break;
+            }
+        }
+
+        // slow down
+        // TODO The CF_NOMOMENTUM cheat, a debug option for no sliding at all
+
+        let mobj = self.thinkers.mobj(id);
+        if mobj.flags & (MF_MISSILE | MF_SKULLFLY) != 0 {
+            return; // no friction for missiles ever
+        }
+
+        if mobj.z > mobj.floor_z {
+            return; // no friction when airborne
+        }
+
+        if mobj.flags & MF_CORPSE != 0 {
+            // do not stop sliding
+            //  if halfway off a step with some momentum
+            let quarter = real(1).div_int(4);
+            if (mobj.mom_x > quarter || mobj.mom_x < -quarter || mobj.mom_y > quarter || mobj.mom_y < -quarter)
+                && mobj.floor_z != self.level.sectors[self.level.subsectors[mobj.subsector].sector].floor_height {
+                return;
+            }
+        }
+
+        let standing_still = player.is_none_or(|player| {
+            let cmd = &self.players[player].cmd;
+            cmd.forward_move == 0 && cmd.side_move == 0
+        });
+
+        if mobj.mom_x > -STOP_SPEED && mobj.mom_x < STOP_SPEED
+            && mobj.mom_y > -STOP_SPEED && mobj.mom_y < STOP_SPEED
+            && standing_still {
+            // if in a walking frame, stop moving
+            if let Some(player_mobj) = player.and_then(|player| self.players[player].mobj) {
+                if (S_PLAY_RUN1..S_PLAY_RUN1 + 4).contains(&self.thinkers.mobj(player_mobj).state) {
+                    self.set_mobj_state(player_mobj, S_PLAY);
+                }
+            }
+
+            let mobj = self.thinkers.mobj_mut(id);
+            mobj.mom_x = real(0);
+            mobj.mom_y = real(0);
+        } else {
+            let mobj = self.thinkers.mobj_mut(id);
+            mobj.mom_x = fixed_mul(mobj.mom_x, FRICTION);
+            mobj.mom_y = fixed_mul(mobj.mom_y, FRICTION);
+        }
+    }
+
+    // P_ZMovement
+    fn z_movement(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj(id);
+
+        // check for smooth step up
+        if let Some(player) = mobj.player {
+            if mobj.z < mobj.floor_z {
+                let step = mobj.floor_z - mobj.z;
+                let player = &mut self.players[player];
+                player.view_height -= step;
+                player.delta_view_height = (VIEW_HEIGHT - player.view_height).shr(3);
+            }
+        }
+
+        // adjust height
+        let mobj = self.thinkers.mobj(id);
+        let mut z = mobj.z + mobj.mom_z;
+
+        if let (true, Some(target)) = (mobj.flags & MF_FLOAT != 0, mobj.target) {
+            // float down towards target if too close
+            if mobj.flags & (MF_SKULLFLY | MF_INFLOAT) == 0 {
+                let target = self.thinkers.mobj(target);
+                let dist = aprox_distance(mobj.x - target.x, mobj.y - target.y);
+                let delta = target.z + mobj.height.div_int(2) - z;
+
+                if delta < real(0) && dist < -delta.wrapping_mul_int(3) {
+                    z -= FLOAT_SPEED;
+                } else if delta > real(0) && dist < delta.wrapping_mul_int(3) {
+                    z += FLOAT_SPEED;
+                }
+            }
+        }
+
+        let player = mobj.player;
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.z = z;
+
+        // clip movement
+        if mobj.z <= mobj.floor_z {
+            // hit the floor
+
+            // Note (id):
+            //  somebody left this after the setting momz to 0,
+            //  kinda useless there.
+            if mobj.flags & MF_SKULLFLY != 0 {
+                // the skull slammed into something
+                mobj.mom_z = -mobj.mom_z;
+            }
+
+            if mobj.mom_z < real(0) {
+                if let (Some(player), true) = (player, mobj.mom_z < -GRAVITY.wrapping_mul_int(8)) {
+                    // Squat down.
+                    // Decrease viewheight for a moment
+                    // after hitting the ground (hard),
+                    // and utter appropriate sound.
+                    self.players[player].delta_view_height = mobj.mom_z.shr(3);
+                    // TODO S_StartSound (mo, sfx_oof);
+                }
+                mobj.mom_z = real(0);
+            }
+            mobj.z = mobj.floor_z;
+
+            if mobj.flags & MF_MISSILE != 0 && mobj.flags & MF_NOCLIP == 0 {
+                self.explode_missile(id);
+                return;
+            }
+        } else if mobj.flags & MF_NOGRAVITY == 0 {
+            if mobj.mom_z == real(0) {
+                mobj.mom_z = -GRAVITY.wrapping_mul_int(2);
+            } else {
+                mobj.mom_z -= GRAVITY;
+            }
+        }
+
+        if mobj.z + mobj.height > mobj.ceiling_z {
+            // hit the ceiling
+            if mobj.mom_z > real(0) {
+                mobj.mom_z = real(0);
+            }
+
+            mobj.z = mobj.ceiling_z - mobj.height;
+
+            if mobj.flags & MF_SKULLFLY != 0 {
+                // the skull slammed into something
+                mobj.mom_z = -mobj.mom_z;
+            }
+
+            if mobj.flags & MF_MISSILE != 0 && mobj.flags & MF_NOCLIP == 0 {
+                self.explode_missile(id);
+            }
+        }
+    }
+
+    // P_MobjThinker
+    pub fn mobj_thinker(&mut self, id: ThinkerId) {
+        // momentum movement
+        let mobj = self.thinkers.mobj(id);
+        if mobj.mom_x != real(0) || mobj.mom_y != real(0) || mobj.flags & MF_SKULLFLY != 0 {
+            self.xy_movement(id);
+
+            if self.thinkers.is_removed(id) {
+                return; // mobj was removed
+            }
+        }
+
+        let mobj = self.thinkers.mobj(id);
+        if mobj.z != mobj.floor_z || mobj.mom_z != real(0) {
+            self.z_movement(id);
+
+            if self.thinkers.is_removed(id) {
+                return; // mobj was removed
+            }
+        }
+
+        // cycle through states,
+        // calling action functions at transitions
+        let mobj = self.thinkers.mobj_mut(id);
+        if mobj.tics != -1 {
+            mobj.tics -= 1;
+
+            // you can cycle through multiple states in a tic
+            if mobj.tics == 0 {
+                let next_state = STATES[mobj.state].next_state;
+                self.set_mobj_state(id, next_state);
+            }
+        } else {
+            // TODO Nightmare respawn of monsters that are
+            //  MF_COUNTKILL, see P_NightmareRespawn.
+        }
+    }
+
+    // P_SpawnMobj
+    pub fn spawn_mobj(&mut self, x: DoomRealNum, y: DoomRealNum, z: DoomRealNum, mobj_type: MobjType) -> ThinkerId {
+        let info = &MOBJ_INFO[mobj_type];
+        let st = &STATES[info.spawn_state];
+
+        let reaction_time = if self.skill != Skill::Nightmare { info.reaction_time } else { 0 };
+        let last_look = self.random.p_random() % MAX_PLAYERS as i32;
+
+        // do not set the state with P_SetMobjState,
+        // because action routines can not be called yet
+        let id = self.thinkers.add(Thinker::Mobj(MapObject {
+            x,
+            y,
+            z: real(0),
+            sector_next: None,
+            sector_prev: None,
+            angle: 0,
+            sprite: st.sprite,
+            frame: st.frame,
+            block_next: None,
+            block_prev: None,
+            subsector: 0,
+            floor_z: real(0),
+            ceiling_z: real(0),
+            radius: info.radius,
+            height: info.height,
+            mom_x: real(0),
+            mom_y: real(0),
+            mom_z: real(0),
+            valid_count: 0,
+            mobj_type,
+            tics: st.tics,
+            state: info.spawn_state,
+            flags: info.flags,
+            health: info.spawn_health,
+            move_dir: 0,
+            move_count: 0,
+            target: None,
+            reaction_time,
+            threshold: 0,
+            player: None,
+            last_look,
+            spawn_point: Thing::default(),
+            tracer: None,
+        }));
+
+        // set subsector and/or block links
+        self.set_thing_position(id);
+
+        let sector = &self.level.sectors[self.level.subsectors[self.thinkers.mobj(id).subsector].sector];
+        let (floor_z, ceiling_z) = (sector.floor_height, sector.ceiling_height);
+
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.floor_z = floor_z;
+        mobj.ceiling_z = ceiling_z;
+        mobj.z = if z == ON_FLOOR_Z {
+            floor_z
+        } else if z == ON_CEILING_Z {
+            ceiling_z - info.height
+        } else {
+            z
+        };
+
+        id
+    }
+
+    // P_RemoveMobj
+    pub fn remove_mobj(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj(id);
+        if mobj.flags & MF_SPECIAL != 0 && mobj.flags & MF_DROPPED == 0
+            && mobj.mobj_type != MT_INV && mobj.mobj_type != MT_INS {
+            self.item_respawn_que.push_back((mobj.spawn_point, self.level_time));
+
+            // lose one off the end?
+            if self.item_respawn_que.len() > ITEM_QUE_SIZE {
+                self.item_respawn_que.pop_front();
+            }
+        }
+
+        // unlink from sector and block lists
+        self.unset_thing_position(id);
+
+        // TODO S_StopSound, stop any playing sound
+
+        // free block
+        self.thinkers.remove(id);
+    }
+
+    // P_SpawnPlayer
+    // Called when a player is spawned on the level.
+    // Most of the player structure stays unchanged
+    //  between levels.
+    fn spawn_player(&mut self, thing: &Thing) {
+        let player = (thing.thing_type - 1) as usize;
+
+        // not playing?
+        if !self.player_in_game[player] {
+            return;
+        }
+
+        let id = self.spawn_mobj(real(thing.x), real(thing.y), ON_FLOOR_Z, MT_PLAYER);
+
+        // TODO Set the translation for the colored multiplayer sprites,
+        //  and reset the player as in G_PlayerReborn.
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.angle = ANG45.wrapping_mul((thing.angle / 45) as u32);
+        mobj.player = Some(player);
+        let z = mobj.z;
+
+        let player = &mut self.players[player];
+        player.mobj = Some(id);
+        player.player_state = PlayerState::Live;
+        player.view_height = VIEW_HEIGHT;
+        player.view_z = z + VIEW_HEIGHT;
+    }
+
+    // P_SpawnMapThing
+    // The fields of the mapthing should
+    // already be in host byte order.
+    pub fn spawn_map_thing(&mut self, thing: &Thing) -> Result<(), WadError> {
+        // count deathmatch start positions
+        if thing.thing_type == DEATHMATCH_START {
+            self.deathmatch_starts.push(*thing);
+            return Ok(());
+        }
+
+        // check for players specially
+        if (1..=MAX_PLAYERS as i16).contains(&thing.thing_type) {
+            // save spots for respawning in network games
+            self.player_starts[(thing.thing_type - 1) as usize] = Some(*thing);
+            self.spawn_player(thing);
+            return Ok(());
+        }
+
+        // check for apropriate skill level
+        // TODO Netgames spawn the MTF_NOTSINGLE things too
+        if thing.options & MTF_NOTSINGLE != 0 {
+            return Ok(());
+        }
+
+        let bit = match self.skill {
+            Skill::Baby | Skill::Easy => MTF_EASY,
+            Skill::Medium => MTF_NORMAL,
+            Skill::Hard | Skill::Nightmare => MTF_HARD,
+        };
+        if thing.options & bit == 0 {
+            return Ok(());
+        }
+
+        // find which type to spawn
+        let mobj_type = MOBJ_INFO.iter()
+            .position(|info| info.doomed_num == Some(thing.thing_type))
+            .ok_or_else(|| WadError::MalformedLump {
+                name: "THINGS".to_string(),
+                reason: format!("Unknown type {} at ({}, {})", thing.thing_type, thing.x, thing.y),
+            })?;
+
+        // TODO Don't spawn keycards and players in deathmatch,
+        //  or any monsters with -nomonsters.
+
+        // spawn it
+        let z = if MOBJ_INFO[mobj_type].flags & MF_SPAWNCEILING != 0 {
+            ON_CEILING_Z
+        } else {
+            ON_FLOOR_Z
+        };
+
+        let id = self.spawn_mobj(real(thing.x), real(thing.y), z, mobj_type);
+
+        let mobj = self.thinkers.mobj(id);
+        let tics = if mobj.tics > 0 {
+            1 + self.random.p_random() % mobj.tics
+        } else {
+            mobj.tics
+        };
+
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.spawn_point = *thing;
+        mobj.tics = tics;
+
+        // Hexen things can be placed above the floor or below the ceiling
+        if z == ON_FLOOR_Z {
+            mobj.z += real(thing.height);
+        } else {
+            mobj.z -= real(thing.height);
+        }
+        if mobj.flags & MF_COUNTKILL != 0 {
+            self.total_kills += 1;
+        }
+        if mobj.flags & MF_COUNTITEM != 0 {
+            self.total_items += 1;
+        }
+
+        mobj.angle = ANG45.wrapping_mul((thing.angle / 45) as u32);
+        if thing.options & MTF_AMBUSH != 0 {
+            mobj.flags |= MF_AMBUSH;
+        }
+        // Hexen: dormant things stay in their spawn state
+        if thing.dormant {
+            mobj.tics = -1;
+        }
+
+        Ok(())
+    }
+
+    // P_UnsetThingPosition
+    // Unlinks a thing from block map and sectors.
+    // On each position change, BLOCKMAP and other
+    // lookups maintaining lists ot things inside
+    // these structures need to be updated.
+    pub fn unset_thing_position(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj(id);
+        let (flags, subsector) = (mobj.flags, mobj.subsector);
+        let (sector_next, sector_prev) = (mobj.sector_next, mobj.sector_prev);
+        let (block_next, block_prev) = (mobj.block_next, mobj.block_prev);
+        let (x, y) = (mobj.x, mobj.y);
+
+        if flags & MF_NOSECTOR == 0 {
+            // inert things don't need to be in blockmap?
+            // unlink from subsector
+            if let Some(next) = sector_next {
+                self.thinkers.mobj_mut(next).sector_prev = sector_prev;
+            }
+
+            match sector_prev {
+                Some(prev) => self.thinkers.mobj_mut(prev).sector_next = sector_next,
+                None => {
+                    let sector = self.level.subsectors[subsector].sector;
+                    self.level.sectors[sector].thing_list = sector_next;
+                }
+            }
+        }
+
+        if flags & MF_NOBLOCKMAP == 0 {
+            // inert things don't need to be in blockmap
+            // unlink from block map
+            if let Some(next) = block_next {
+                self.thinkers.mobj_mut(next).block_prev = block_prev;
+            }
+
+            match block_prev {
+                Some(prev) => self.thinkers.mobj_mut(prev).block_next = block_next,
+                None => {
+                    if let Some(block) = self.level.blockmap.point_block_index(x, y) {
+                        self.level.blockmap.blocklinks[block] = block_next;
+                    }
+                }
+            }
+        }
+    }
+
+    // P_SetThingPosition
+    // Links a thing into both a block and a subsector
+    // based on it's x y.
+    // Sets thing->subsector properly
+    pub fn set_thing_position(&mut self, id: ThinkerId) {
+        let mobj = self.thinkers.mobj(id);
+        let (x, y, flags) = (mobj.x, mobj.y, mobj.flags);
+
+        // link into subsector
+        let subsector = self.level.point_in_subsector(x, y);
+        self.thinkers.mobj_mut(id).subsector = subsector;
+
+        if flags & MF_NOSECTOR == 0 {
+            // invisible things don't go into the sector links
+            let sector = self.level.subsectors[subsector].sector;
+            let first = self.level.sectors[sector].thing_list;
+
+            let mobj = self.thinkers.mobj_mut(id);
+            mobj.sector_prev = None;
+            mobj.sector_next = first;
+
+            if let Some(first) = first {
+                self.thinkers.mobj_mut(first).sector_prev = Some(id);
+            }
+            self.level.sectors[sector].thing_list = Some(id);
+        }
+
+        // link into blockmap
+        if flags & MF_NOBLOCKMAP == 0 {
+            // inert things don't need to be in blockmap
+            match self.level.blockmap.point_block_index(x, y) {
+                Some(block) => {
+                    let first = self.level.blockmap.blocklinks[block];
+
+                    let mobj = self.thinkers.mobj_mut(id);
+                    mobj.block_prev = None;
+                    mobj.block_next = first;
+
+                    if let Some(first) = first {
+                        self.thinkers.mobj_mut(first).block_prev = Some(id);
+                    }
+                    self.level.blockmap.blocklinks[block] = Some(id);
+                }
+                None => {
+                    // thing is off the map
+                    let mobj = self.thinkers.mobj_mut(id);
+                    mobj.block_next = None;
+                    mobj.block_prev = None;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use crate::types::{DoomRealNum, real, fixed_div, RealNum};
+use crate::level::nodes::{child_subsector, NF_SUBSECTOR};
+use crate::level::linedefs::ML_TWOSIDED;
+use crate::play::World;
+use crate::play::map_util::{intercept_vector, DivLine};
+use crate::play::tick::ThinkerId;
+
+// The state of the sight trace between two things,
+// the sight globals of p_sight.c.
+struct SightTrace {
+    // eye z of looker
+    z_start: DoomRealNum,
+    // slopes to top and bottom of target
+    top_slope: DoomRealNum,
+    bottom_slope: DoomRealNum,
+
+    // from t1 to t2
+    trace: DivLine,
+    t2_x: DoomRealNum,
+    t2_y: DoomRealNum,
+}
+
+// P_DivlineSide
+// Returns side 0 (front), 1 (back), or 2 (on).
+fn divline_side(x: DoomRealNum, y: DoomRealNum, node: &DivLine) -> usize {
+    if node.dx == real(0) {
+        if x == node.x {
+            return 2;
+        }
+        return if x <= node.x {
+            (node.dy > real(0)) as usize
+        } else {
+            (node.dy < real(0)) as usize
+        };
+    }
+
+    if node.dy == real(0) {
+        // The original compares x here, kept for demo compatibility.
+        if x == node.y {
+            return 2;
+        }
+        return if y <= node.y {
+            (node.dx < real(0)) as usize
+        } else {
+            (node.dx > real(0)) as usize
+        };
+    }
+
+    let dx = x.wrapping_sub(node.x).to_int();
+    let dy = y.wrapping_sub(node.y).to_int();
+
+    let left = node.dy.to_int().wrapping_mul(dx);
+    let right = dy.wrapping_mul(node.dx.to_int());
+
+    if right < left {
+        0 // front side
+    } else if left == right {
+        2 // on line
+    } else {
+        1 // back side
+    }
+}
+
+impl World {
+    // P_CheckSight
+    // Returns true
+    //  if a straight line between t1 and t2 is unobstructed.
+    // Uses REJECT.
+    // Not called yet, A_Look and A_Chase come with the monster AI.
+    pub fn check_sight(&mut self, t1: ThinkerId, t2: ThinkerId) -> bool {
+        let from = self.thinkers.mobj(t1);
+        let to = self.thinkers.mobj(t2);
+
+        // First check for trivial rejection.
+        // Determine subsector entries in REJECT table.
+        let s1 = self.level.subsectors[from.subsector].sector;
+        let s2 = self.level.subsectors[to.subsector].sector;
+
+        // Check in REJECT table.
+        if self.level.reject.rejects(s1, s2) {
+            // can't possibly be connected
+            return false;
+        }
+
+        // An unobstructed LOS is possible.
+        // Now look from eyes of t1 to any part of t2.
+        self.valid_count += 1;
+
+        let z_start = from.z + from.height - from.height.shr(2);
+        let mut sight = SightTrace {
+            z_start,
+            top_slope: (to.z + to.height).wrapping_sub(z_start),
+            bottom_slope: to.z.wrapping_sub(z_start),
+            trace: DivLine {
+                x: from.x,
+                y: from.y,
+                dx: to.x.wrapping_sub(from.x),
+                dy: to.y.wrapping_sub(from.y),
+            },
+            t2_x: to.x,
+            t2_y: to.y,
+        };
+
+        // the head node is the last node output
+        // a map without nodes is a single subsector
+        let head = self.level.nodes.len().checked_sub(1).unwrap_or(NF_SUBSECTOR);
+        self.cross_bsp_node(head, &mut sight)
+    }
+
+    // P_CrossBSPNode
+    // Returns true
+    //  if strace crosses the given node successfully.
+    fn cross_bsp_node(&mut self, bsp_num: usize, sight: &mut SightTrace) -> bool {
+        if let Some(subsector) = child_subsector(bsp_num) {
+            return self.cross_subsector(subsector, sight);
+        }
+
+        let bsp = &self.level.nodes[bsp_num];
+        let partition = DivLine { x: bsp.x, y: bsp.y, dx: bsp.dx, dy: bsp.dy };
+        let children = bsp.children;
+
+        // decide which side the start point is on
+        let side = match divline_side(sight.trace.x, sight.trace.y, &partition) {
+            2 => 0, // an "on" should cross both sides
+            side => side,
+        };
+
+        // cross the starting side
+        if !self.cross_bsp_node(children[side], sight) {
+            return false;
+        }
+
+        // the partition plane is crossed here
+        if side == divline_side(sight.t2_x, sight.t2_y, &partition) {
+            // the line doesn't touch the other side
+            return true;
+        }
+
+        // cross the ending side
+        self.cross_bsp_node(children[side ^ 1], sight)
+    }
+
+    // P_CrossSubsector
+    // Returns true
+    //  if strace crosses the given subsector successfully.
+    fn cross_subsector(&mut self, num: usize, sight: &mut SightTrace) -> bool {
+        let subsector = &self.level.subsectors[num];
+        let segs = subsector.first_line..subsector.first_line + subsector.num_lines;
+
+        // check lines
+        for seg_index in segs {
+            let seg = &self.level.segs[seg_index];
+            let line = &mut self.level.lines[seg.linedef];
+
+            // allready checked other side?
+            if line.valid_count == self.valid_count {
+                continue;
+            }
+            line.valid_count = self.valid_count;
+
+            let line = &self.level.lines[seg.linedef];
+            let v1 = &self.level.vertices[line.v1];
+            let v2 = &self.level.vertices[line.v2];
+
+            // line isn't crossed?
+            if divline_side(v1.x, v1.y, &sight.trace) == divline_side(v2.x, v2.y, &sight.trace) {
+                continue;
+            }
+
+            let divl = DivLine {
+                x: v1.x,
+                y: v1.y,
+                dx: v2.x.wrapping_sub(v1.x),
+                dy: v2.y.wrapping_sub(v1.y),
+            };
+
+            // line isn't crossed?
+            if divline_side(sight.trace.x, sight.trace.y, &divl) == divline_side(sight.t2_x, sight.t2_y, &divl) {
+                continue;
+            }
+
+            // stop because it is not two sided anyway
+            // might do this after updating validcount?
+            let back = match seg.back_sector {
+                Some(back) if line.flags & ML_TWOSIDED != 0 => &self.level.sectors[back],
+                _ => return false,
+            };
+            let front = &self.level.sectors[seg.front_sector];
+
+            // no wall to block sight with?
+            if front.floor_height == back.floor_height
+                && front.ceiling_height == back.ceiling_height {
+                continue;
+            }
+
+            // possible occluder
+            // because of ceiling height differences
+            let open_top = front.ceiling_height.min(back.ceiling_height);
+
+            // because of floor height differences
+            let open_bottom = front.floor_height.max(back.floor_height);
+
+            // quick test for totally closed doors
+            if open_bottom >= open_top {
+                return false; // stop
+            }
+
+            // P_InterceptVector2 is the same as P_InterceptVector
+            let frac = intercept_vector(&sight.trace, &divl);
+
+            if front.floor_height != back.floor_height {
+                let slope = fixed_div(open_bottom.wrapping_sub(sight.z_start), frac);
+                if slope > sight.bottom_slope {
+                    sight.bottom_slope = slope;
+                }
+            }
+
+            if front.ceiling_height != back.ceiling_height {
+                let slope = fixed_div(open_top.wrapping_sub(sight.z_start), frac);
+                if slope < sight.top_slope {
+                    sight.top_slope = slope;
+                }
+            }
+
+            if sight.top_slope <= sight.bottom_slope {
+                return false; // stop
+            }
+        }
+
+        // passed the subsector ok
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level::test_map::TestMap;
+    use crate::game_context::Skill;
+    use crate::play::{setup, World};
+    use crate::play::tick::ThinkerId;
+
+    // The player and the imp of the map.
+    fn spawn(map: &TestMap) -> (World, ThinkerId, ThinkerId) {
+        let world = setup(map.load().unwrap(), Skill::Medium, Default::default()).unwrap();
+        let player = world.players[0].mobj.unwrap();
+        let imp = world.thinkers.mobjs()
+            .map(|(id, _)| id)
+            .find(|&id| id != player)
+            .unwrap();
+        (world, player, imp)
+    }
+
+    #[test]
+    fn clear_line_of_sight() {
+        let (mut world, player, imp) = spawn(&TestMap::two_rooms());
+        assert!(world.check_sight(player, imp));
+        assert!(world.check_sight(imp, player));
+    }
+
+    #[test]
+    fn short_reject_is_not_rejected() {
+        let mut map = TestMap::two_rooms();
+        // two sectors need one byte
+        map.reject = Vec::new();
+        let (mut world, player, imp) = spawn(&map);
+        assert!(world.check_sight(player, imp));
+    }
+
+    #[test]
+    fn rejected_sectors() {
+        let mut map = TestMap::two_rooms();
+        // sector 0 can not see sector 1
+        map.reject = vec![0b0010];
+        let (mut world, player, imp) = spawn(&map);
+        assert!(!world.check_sight(player, imp));
+        assert!(world.check_sight(imp, player));
+    }
+
+    #[test]
+    fn pillar_blocks_sight() {
+        let mut map = TestMap::two_rooms();
+        // counter clockwise, so the walls face out into the room
+        map.add_loop(&[(192, 112), (224, 112), (224, 144), (192, 144)], 0, None);
+        let (mut world, player, imp) = spawn(&map);
+        assert!(!world.check_sight(player, imp));
+        assert!(!world.check_sight(imp, player));
+    }
+
+    #[test]
+    fn closed_door_blocks_sight() {
+        let (mut world, player, imp) = spawn(&TestMap::two_rooms());
+        world.level.sectors[1].ceiling_height = world.level.sectors[1].floor_height;
+        assert!(!world.check_sight(player, imp));
+    }
+}
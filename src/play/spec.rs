@@ -0,0 +1,130 @@
+use crate::play::World;
+use crate::play::tick::ThinkerId;
+use crate::level::linedefs::{ActionSpecial, SPAC_CROSS};
+
+/// How the level is left, see G_ExitLevel
+/// and G_SecretExitLevel.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LevelExit {
+    Normal,
+    Secret,
+}
+
+impl World {
+    // P_CrossSpecialLine
+    // Called every time a thing origin is about
+    //  to cross a line with a non 0 special.
+    pub fn cross_special_line(&mut self, line_index: usize, _side: usize, thing: ThinkerId) {
+        if let Some(action) = self.level.lines[line_index].action {
+            self.activate_line(line_index, action, thing);
+            return;
+        }
+
+        // Triggers that other things can activate
+        if self.thinkers.mobj(thing).player.is_none() {
+            // TODO Monsters activate teleports and some doors and lifts
+            return;
+        }
+
+        // Note: could use some const's here.
+        match self.level.lines[line_index].special {
+            52 => {
+                // EXIT!
+                self.exit = Some(LevelExit::Normal);
+            }
+            124 => {
+                // Secret EXIT
+                self.exit = Some(LevelExit::Secret);
+            }
+            // TODO The doors, floors, ceilings, lifts, lights and teleports
+            _ => {}
+        }
+    }
+
+    // P_ActivateLine of Hexen, for lines that are crossed
+    // Runs the special of a Hexen style line, which is
+    //  cleared after the first time unless it repeats.
+    fn activate_line(&mut self, line_index: usize, action: ActionSpecial, thing: ThinkerId) {
+        // TODO Monsters and projectiles activate the SPAC_MCROSS and SPAC_PCROSS lines
+        if action.activation != SPAC_CROSS || self.thinkers.mobj(thing).player.is_none() {
+            return;
+        }
+
+        // P_ExecuteLineSpecial
+        let executed = match action.special {
+            // Exit_Normal
+            243 => {
+                self.exit = Some(LevelExit::Normal);
+                true
+            }
+            // Exit_Secret
+            244 => {
+                self.exit = Some(LevelExit::Secret);
+                true
+            }
+            // TODO The other specials, most of them are run by scripts too
+            _ => false,
+        };
+
+        if executed && !action.repeat {
+            self.level.lines[line_index].action = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LevelExit;
+    use crate::level::test_map::TestMap;
+    use crate::game_context::Skill;
+    use crate::level::Level;
+    use crate::level::linedefs::{ActionSpecial, SPAC_CROSS, SPAC_USE};
+    use crate::play::{setup, World};
+    use crate::tic_cmd::TicCmd;
+
+    // The line between the two rooms, which the player walks over.
+    const STEP_LINE: usize = 3;
+
+    fn action(special: u8, activation: u8) -> Option<ActionSpecial> {
+        Some(ActionSpecial { special, args: [0; 5], activation, repeat: false })
+    }
+
+    // Runs the player east until the level is left, for a second at most.
+    fn walk_east(level: Level) -> World {
+        let mut world = setup(level, Skill::Medium, Default::default()).unwrap();
+        for _ in 0..35 {
+            world.players[0].cmd = TicCmd { forward_move: 0x32, ..TicCmd::default() };
+            world.ticker();
+            if world.exit.is_some() {
+                break;
+            }
+        }
+        world
+    }
+
+    #[test]
+    fn exit_special() {
+        let mut level = TestMap::two_rooms().load().unwrap();
+        level.lines[STEP_LINE].special = 52;
+        assert_eq!(walk_east(level).exit, Some(LevelExit::Normal));
+    }
+
+    #[test]
+    fn exit_action_special() {
+        let mut level = TestMap::two_rooms().load().unwrap();
+        // Exit_Secret
+        level.lines[STEP_LINE].action = action(244, SPAC_CROSS);
+        let world = walk_east(level);
+        assert_eq!(world.exit, Some(LevelExit::Secret));
+        assert!(world.level.lines[STEP_LINE].action.is_none());
+    }
+
+    #[test]
+    fn action_special_not_crossed() {
+        let mut level = TestMap::two_rooms().load().unwrap();
+        level.lines[STEP_LINE].action = action(243, SPAC_USE);
+        let world = walk_east(level);
+        assert_eq!(world.exit, None);
+        assert!(world.level.lines[STEP_LINE].action.is_some());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::play::{World, MAX_PLAYERS};
+use crate::play::mobj::MapObject;
+
+/// Index of a thinker in the thinker list, used wherever the C code keeps
+/// a pointer to a thinker or a mobj.
+pub type ThinkerId = usize;
+
+// The cap of the circular list, it is never handed out.
+const THINKER_CAP: ThinkerId = 0;
+
+/// Everything that does something each tic.
+pub enum Thinker {
+    Mobj(MapObject),
+}
+
+struct ThinkerNode {
+    prev: ThinkerId,
+    next: ThinkerId,
+    // None once freed, the slot is then on the free list.
+    thinker: Option<Thinker>,
+    // P_RemoveThinker only marks the thinker, it is unlinked
+    //  when its turn to think comes up.
+    removed: bool,
+}
+
+/// Both the head and tail of the thinker list are the cap,
+/// thinkers run in the order they were added.
+pub struct ThinkerList {
+    nodes: Vec<ThinkerNode>,
+    free: Vec<ThinkerId>,
+}
+
+impl ThinkerList {
+    // P_InitThinkers
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![ThinkerNode { prev: THINKER_CAP, next: THINKER_CAP, thinker: None, removed: false }],
+            free: Vec::new(),
+        }
+    }
+
+    // P_AddThinker
+    // Adds a new thinker at the end of the list.
+    pub fn add(&mut self, thinker: Thinker) -> ThinkerId {
+        let last = self.nodes[THINKER_CAP].prev;
+        let node = ThinkerNode { prev: last, next: THINKER_CAP, thinker: Some(thinker), removed: false };
+
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.nodes[id] = node;
+                id
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        self.nodes[last].next = id;
+        self.nodes[THINKER_CAP].prev = id;
+        id
+    }
+
+    // P_RemoveThinker
+    // Deallocation is lazy -- it will not actually be freed
+    // until its thinking turn comes up.
+    pub fn remove(&mut self, id: ThinkerId) {
+        self.nodes[id].removed = true;
+    }
+
+    pub fn is_removed(&self, id: ThinkerId) -> bool {
+        self.nodes[id].removed
+    }
+
+    // Unlinks a removed thinker and makes its slot available again.
+    fn free(&mut self, id: ThinkerId) {
+        let (prev, next) = (self.nodes[id].prev, self.nodes[id].next);
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+
+        self.nodes[id].thinker = None;
+        self.free.push(id);
+    }
+
+    fn first(&self) -> Option<ThinkerId> {
+        self.after(THINKER_CAP)
+    }
+
+    fn after(&self, id: ThinkerId) -> Option<ThinkerId> {
+        match self.nodes[id].next {
+            THINKER_CAP => None,
+            next => Some(next),
+        }
+    }
+
+    pub fn get(&self, id: ThinkerId) -> Option<&Thinker> {
+        self.nodes.get(id).and_then(|node| node.thinker.as_ref())
+    }
+
+    pub fn mobj(&self, id: ThinkerId) -> &MapObject {
+        match self.get(id) {
+            Some(Thinker::Mobj(mobj)) => mobj,
+            _ => panic!("Thinker {} is not a mobj", id),
+        }
+    }
+
+    pub fn mobj_mut(&mut self, id: ThinkerId) -> &mut MapObject {
+        match self.nodes.get_mut(id).and_then(|node| node.thinker.as_mut()) {
+            Some(Thinker::Mobj(mobj)) => mobj,
+            _ => panic!("Thinker {} is not a mobj", id),
+        }
+    }
+
+    /// All mobjs that have not been removed, in thinking order.
+    pub fn mobjs(&self) -> impl Iterator<Item = (ThinkerId, &MapObject)> {
+        std::iter::successors(self.first(), move |&id| self.after(id))
+            .filter(move |&id| !self.nodes[id].removed)
+            .filter_map(move |id| self.nodes[id].thinker.as_ref()
+                .map(|Thinker::Mobj(mobj)| (id, mobj)))
+    }
+}
+
+impl World {
+    // P_RunThinkers
+    fn run_thinkers(&mut self) {
+        let mut current = self.thinkers.first();
+
+        while let Some(id) = current {
+            if self.thinkers.is_removed(id) {
+                // time to remove it
+                current = self.thinkers.after(id);
+                self.thinkers.free(id);
+                continue;
+            }
+
+            match self.thinkers.get(id) {
+                Some(Thinker::Mobj(_)) => self.mobj_thinker(id),
+                None => {}
+            }
+
+            current = self.thinkers.after(id);
+        }
+    }
+
+    // P_Ticker
+    pub fn ticker(&mut self) {
+        // TODO Pause if in menu and at least one tic has been run
+
+        for player in 0..MAX_PLAYERS {
+            if self.player_in_game[player] {
+                self.player_think(player);
+            }
+        }
+
+        self.run_thinkers();
+
+        // TODO P_UpdateSpecials and P_RespawnSpecials
+
+        // for par times
+        self.level_time += 1;
+    }
+}
@@ -0,0 +1,202 @@
+use crate::types::{DoomRealNum, Angle, real, fixed_mul, FRAC_BITS, from_fixed_bits, RealNum};
+use crate::tables::{fine_sine, fine_cosine, ANG90, ANGLE_TO_FINE_SHIFT, FINE_ANGLES, FINE_MASK};
+use crate::info::{S_PLAY, S_PLAY_RUN1};
+use crate::player::{PlayerState, PW_STRENGTH, PW_INVULNERABILITY, PW_INVISIBILITY, PW_INFRARED, PW_IRONFEET};
+use crate::play::{World, VIEW_HEIGHT};
+use crate::play::mobj::{MF_JUSTATTACKED, MF_SHADOW};
+use crate::rendering::lighting::INVERSE_COLORMAP;
+
+// 16 pixels of bob
+const MAX_BOB: DoomRealNum = from_fixed_bits(0x100000);
+
+impl World {
+    // P_Thrust
+    // Moves the given origin along a given angle.
+    fn thrust(&mut self, player: usize, angle: Angle, move_: DoomRealNum) {
+        let angle = (angle >> ANGLE_TO_FINE_SHIFT) as usize;
+
+        if let Some(id) = self.players[player].mobj {
+            let mobj = self.thinkers.mobj_mut(id);
+            mobj.mom_x += fixed_mul(move_, fine_cosine(angle));
+            mobj.mom_y += fixed_mul(move_, fine_sine(angle));
+        }
+    }
+
+    // P_CalcHeight
+    // Calculate the walking / running height adjustment
+    fn calc_height(&mut self, player: usize) {
+        let id = match self.players[player].mobj {
+            Some(id) => id,
+            None => return,
+        };
+        let mobj = self.thinkers.mobj(id);
+        let player = &mut self.players[player];
+
+        // Regular movement bobbing
+        // (needs to be calculated for gun swing
+        // even if not on ground)
+        // OPTIMIZE: tablify angle
+        // Note: a LUT allows for effects
+        //  like a ramp with low health.
+        let bob = fixed_mul(mobj.mom_x, mobj.mom_x) + fixed_mul(mobj.mom_y, mobj.mom_y);
+        player.bob = bob.shr(2).min(MAX_BOB);
+
+        // TODO The CF_NOMOMENTUM cheat
+        if !self.on_ground {
+            player.view_z = (mobj.z + VIEW_HEIGHT).min(mobj.ceiling_z - real(4));
+            player.view_z = mobj.z + player.view_height;
+            return;
+        }
+
+        let angle = (FINE_ANGLES / 20 * self.level_time as usize) & FINE_MASK;
+        let bob = fixed_mul(player.bob.div_int(2), fine_sine(angle));
+
+        // move viewheight
+        if player.player_state == PlayerState::Live {
+            player.view_height += player.delta_view_height;
+
+            if player.view_height > VIEW_HEIGHT {
+                player.view_height = VIEW_HEIGHT;
+                player.delta_view_height = real(0);
+            }
+
+            if player.view_height < VIEW_HEIGHT.div_int(2) {
+                player.view_height = VIEW_HEIGHT.div_int(2);
+                if player.delta_view_height <= real(0) {
+                    player.delta_view_height = from_fixed_bits(1);
+                }
+            }
+
+            if player.delta_view_height != real(0) {
+                player.delta_view_height += real(1).div_int(4);
+                if player.delta_view_height == real(0) {
+                    player.delta_view_height = from_fixed_bits(1);
+                }
+            }
+        }
+
+        player.view_z = (mobj.z + player.view_height + bob).min(mobj.ceiling_z - real(4));
+    }
+
+    // P_MovePlayer
+    fn move_player(&mut self, player: usize) {
+        let id = match self.players[player].mobj {
+            Some(id) => id,
+            None => return,
+        };
+        let cmd = self.players[player].cmd;
+
+        let mobj = self.thinkers.mobj_mut(id);
+        mobj.angle = mobj.angle.wrapping_add(((cmd.angle_turn as i32) << FRAC_BITS) as Angle);
+        let angle = mobj.angle;
+
+        // Do not let the player control movement
+        //  if not onground.
+        self.on_ground = mobj.z <= mobj.floor_z;
+
+        if cmd.forward_move != 0 && self.on_ground {
+            self.thrust(player, angle, from_fixed_bits(cmd.forward_move as i32 * 2048));
+        }
+
+        if cmd.side_move != 0 && self.on_ground {
+            self.thrust(player, angle.wrapping_sub(ANG90), from_fixed_bits(cmd.side_move as i32 * 2048));
+        }
+
+        if (cmd.forward_move != 0 || cmd.side_move != 0) && self.thinkers.mobj(id).state == S_PLAY {
+            self.set_mobj_state(id, S_PLAY_RUN1);
+        }
+    }
+
+    // P_PlayerThink
+    pub fn player_think(&mut self, player: usize) {
+        let id = match self.players[player].mobj {
+            Some(id) => id,
+            None => return,
+        };
+
+        // TODO The CF_NOCLIP cheat
+
+        // chain saw run forward
+        let mobj = self.thinkers.mobj_mut(id);
+        if mobj.flags & MF_JUSTATTACKED != 0 {
+            mobj.flags &= !MF_JUSTATTACKED;
+
+            let cmd = &mut self.players[player].cmd;
+            cmd.angle_turn = 0;
+            cmd.forward_move = (0xc800 / 512) as i8;
+            cmd.side_move = 0;
+        }
+
+        if self.players[player].player_state == PlayerState::Dead {
+            // TODO P_DeathThink (player);
+            return;
+        }
+
+        // Move around.
+        // Reactiontime is used to prevent movement
+        //  for a bit after a teleport.
+        let mobj = self.thinkers.mobj_mut(id);
+        if mobj.reaction_time != 0 {
+            mobj.reaction_time -= 1;
+        } else {
+            self.move_player(player);
+        }
+
+        self.calc_height(player);
+
+        // TODO P_PlayerInSpecialSector, using, weapon changes and psprites
+
+        // Counters, time dependend power ups.
+        let player = &mut self.players[player];
+
+        // Strength counts up to diminish fade.
+        if player.powers[PW_STRENGTH] != 0 {
+            player.powers[PW_STRENGTH] += 1;
+        }
+
+        if player.powers[PW_INVULNERABILITY] != 0 {
+            player.powers[PW_INVULNERABILITY] -= 1;
+        }
+
+        if player.powers[PW_INVISIBILITY] != 0 {
+            player.powers[PW_INVISIBILITY] -= 1;
+            if player.powers[PW_INVISIBILITY] == 0 {
+                self.thinkers.mobj_mut(id).flags &= !MF_SHADOW;
+            }
+        }
+
+        if player.powers[PW_INFRARED] != 0 {
+            player.powers[PW_INFRARED] -= 1;
+        }
+
+        if player.powers[PW_IRONFEET] != 0 {
+            player.powers[PW_IRONFEET] -= 1;
+        }
+
+        if player.damage_count != 0 {
+            player.damage_count -= 1;
+        }
+
+        if player.bonus_count != 0 {
+            player.bonus_count -= 1;
+        }
+
+        // Handling colormaps.
+        player.fixed_colormap = if player.powers[PW_INVULNERABILITY] != 0 {
+            if player.powers[PW_INVULNERABILITY] > 4 * 32 || player.powers[PW_INVULNERABILITY] & 8 != 0 {
+                INVERSE_COLORMAP
+            } else {
+                0
+            }
+        } else if player.powers[PW_INFRARED] != 0 {
+            if player.powers[PW_INFRARED] > 4 * 32 || player.powers[PW_INFRARED] & 8 != 0 {
+                // almost full bright
+                1
+            } else {
+                0
+            }
+        } else {
+            0
+        };
+    }
+}
@@ -1,3 +1,70 @@
+use crate::play::tick::ThinkerId;
+use crate::tic_cmd::TicCmd;
+use crate::types::DoomRealNum;
+use crate::rendering::palette::PaletteEffects;
+
+// Player states.
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub enum PlayerState {
+    // Playing or camping.
+    #[default]
+    Live,
+    // Dead on the ground, view follows killer.
+    Dead,
+    // Ready to restart/respawn???
+    Reborn,
+}
+
+// Power up artifacts.
+pub const PW_INVULNERABILITY: usize = 0;
+pub const PW_STRENGTH: usize = 1;
+pub const PW_INVISIBILITY: usize = 2;
+pub const PW_IRONFEET: usize = 3;
+pub const PW_ALLMAP: usize = 4;
+pub const PW_INFRARED: usize = 5;
+pub const NUM_POWERS: usize = 6;
+
+// Extended player object info: player_t
+#[derive(Default)]
 pub struct Player {
+    pub(crate) mobj: Option<ThinkerId>,
+    pub(crate) player_state: PlayerState,
+    pub(crate) cmd: TicCmd,
+
+    // Determine POV,
+    //  including viewpoint bobbing during movement.
+    // Focal origin above r.z
+    pub(crate) view_z: DoomRealNum,
+    // Base height above floor for viewz.
+    pub(crate) view_height: DoomRealNum,
+    // Bob/squat speed.
+    pub(crate) delta_view_height: DoomRealNum,
+    // bounded/scaled total momentum.
+    pub(crate) bob: DoomRealNum,
+
+    // Power ups. invinc and invis are tic counters.
+    pub(crate) powers: [i32; NUM_POWERS],
+
+    // For screen flashing (red or bright).
+    pub(crate) damage_count: i32,
+    pub(crate) bonus_count: i32,
+
+    // So gun flashes light up areas.
+    pub(crate) extra_light: i32,
+    // The colormap the view is drawn with, 0 for the lighting
+    //  of the sectors, set by the invulnerability and
+    //  light amplification visor powers.
+    pub(crate) fixed_colormap: usize,
+}
 
-}
\ No newline at end of file
+impl Player {
+    /// The counters ST_doPaletteStuff picks the palette from.
+    pub fn palette_effects(&self) -> PaletteEffects {
+        PaletteEffects {
+            damage_count: self.damage_count,
+            bonus_count: self.bonus_count,
+            strength: self.powers[PW_STRENGTH],
+            iron_feet: self.powers[PW_IRONFEET],
+        }
+    }
+}
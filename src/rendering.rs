@@ -1,35 +1,280 @@
 use crate::rendering::textures::{init_textures, TextureData};
-use crate::wad::LumpStore;
-use crate::types::DoomRealNum;
+use crate::rendering::flats::{init_flats, FlatData};
+use crate::rendering::renderer::{RENDER_WIDTH, RENDER_HEIGHT};
+use crate::wad::{LumpStore, WadError};
+use crate::types::{DoomRealNum, Angle, real, fixed_div, fixed_mul, from_fixed_bits, RealNum};
+use crate::rendering::lighting::{init_lighting, scale_light_table, LightData, LIGHT_LEVELS, MAX_LIGHT_SCALE};
+use crate::rendering::sky::SKY_FLAT_NAME;
+use crate::rendering::things::{init_sprites, SpriteData};
+use crate::rendering::palette::{init_palettes, PaletteData};
+use crate::info::SPRITE_NAMES;
+use crate::tables::{FINE_ANGLES, ANGLE_TO_FINE_SHIFT, ANG90, fine_tangent, fine_cosine};
 
-mod textures;
+pub(crate) mod textures;
 mod bsp;
-mod flats;
+mod segs;
+mod planes;
+mod draw;
+mod frame;
+pub(crate) mod flats;
+pub(crate) mod lighting;
+pub(crate) mod sky;
+pub(crate) mod things;
 pub mod renderer;
 pub(crate) mod patch;
-mod palette;
+pub(crate) mod palette;
+
+pub use bsp::render_player_view;
+pub use things::{Sprites, ThingSprite, PlayerSprite};
+#[cfg(test)]
+use patch::solid_patch;
+
+// Status bar height at bottom of screen.
+const STATUS_BAR_HEIGHT: usize = 32;
+
+// Fineangles in the SCREENWIDTH wide window.
+const FIELD_OF_VIEW: usize = 2048;
 
 pub struct View {
     width: usize,
     height: usize,
     scaled_width: usize,
+    window_x: usize,
+    window_y: usize,
     centerx: i32,
     centery: i32,
     centerxfrac: DoomRealNum,
     centeryfrac: DoomRealNum,
     projection: DoomRealNum,
+
+    // The viewangletox[viewangle + FINEANGLES/4] lookup
+    // maps the visible view angles to screen X coordinates,
+    // flattening the arc to a flat projection plane.
+    // There will be many angles mapped to the same X.
+    view_angle_to_x: Vec<i32>,
+
+    // The xtoviewangleangle[] table maps a screen pixel
+    // to the lowest viewangle that maps back to x ranges
+    // from clipangle to -clipangle.
+    x_to_view_angle: Vec<Angle>,
+    clip_angle: Angle,
+
+    // The scale weapon sprites are drawn at and its inverse,
+    //  the sky is drawn at the same scale.
+    psprite_scale: DoomRealNum,
+    psprite_iscale: DoomRealNum,
+
+    // Distance to the floor for each row at unit plane height,
+    //  and the length of the ray to each column at unit distance.
+    y_slope: Vec<DoomRealNum>,
+    dist_scale: Vec<DoomRealNum>,
+
+    // Colormap numbers for walls and sprites,
+    //  by sector light level and scale.
+    scale_light: [[usize; MAX_LIGHT_SCALE]; LIGHT_LEVELS],
+}
+
+/// Position and orientation the view is rendered from.
+#[derive(Copy, Clone)]
+pub struct ViewPoint {
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum,
+    pub(crate) z: DoomRealNum,
+    pub(crate) angle: Angle,
+
+    // Added to the sector light levels, from weapon flashes.
+    pub(crate) extra_light: i32,
+
+    // Colormap used for everything instead of light diminishing,
+    //  for the light amplification visor and invulnerability.
+    pub(crate) fixed_colormap: Option<usize>,
+}
+
+impl View {
+    // R_ExecuteSetViewSize
+    pub fn new(set_blocks: usize) -> Self {
+        let (scaled_width, height) = if set_blocks >= 11 {
+            (RENDER_WIDTH, RENDER_HEIGHT)
+        } else {
+            (set_blocks * 32, (set_blocks * (RENDER_HEIGHT - STATUS_BAR_HEIGHT) / 10) & !7)
+        };
+
+        // Only high detail is supported, so width is the scaled width
+        let width = scaled_width;
+        let centerx = width as i32 / 2;
+        let centery = height as i32 / 2;
+        let centerxfrac = real(centerx);
+        let centeryfrac = real(centery);
+
+        let window_x = (RENDER_WIDTH - scaled_width) / 2;
+        let window_y = if scaled_width == RENDER_WIDTH {
+            0
+        } else {
+            (RENDER_HEIGHT - STATUS_BAR_HEIGHT - height) / 2
+        };
+
+        let mut view = Self {
+            width,
+            height,
+            scaled_width,
+            window_x,
+            window_y,
+            centerx,
+            centery,
+            centerxfrac,
+            centeryfrac,
+            projection: centerxfrac,
+            view_angle_to_x: vec![0; FINE_ANGLES / 2],
+            x_to_view_angle: vec![0; width + 1],
+            clip_angle: 0,
+            psprite_scale: from_fixed_bits((width << 16) as i32 / RENDER_WIDTH as i32),
+            psprite_iscale: from_fixed_bits((RENDER_WIDTH << 16) as i32 / width as i32),
+            y_slope: vec![real(0); height],
+            dist_scale: vec![real(0); width],
+            scale_light: scale_light_table(width),
+        };
+
+        view.init_texture_mapping();
+
+        // planes
+        for i in 0..height {
+            let dy = from_fixed_bits(((i as i32 - height as i32 / 2) << 16) + (1 << 15)).abs();
+            view.y_slope[i] = fixed_div(real((width / 2) as i32), dy);
+        }
+
+        for i in 0..width {
+            let cos_adj = fine_cosine((view.x_to_view_angle[i] >> ANGLE_TO_FINE_SHIFT) as usize).abs();
+            view.dist_scale[i] = fixed_div(real(1), cos_adj);
+        }
+
+        view
+    }
+
+    // R_InitTextureMapping
+    fn init_texture_mapping(&mut self) {
+        // Use tangent table to generate viewangletox:
+        //  viewangletox will give the next greatest x
+        //  after the view angle.
+        //
+        // Calc focallength
+        //  so FIELDOFVIEW angles covers SCREENWIDTH.
+        let focal_length = fixed_div(self.centerxfrac, fine_tangent(FINE_ANGLES / 4 + FIELD_OF_VIEW / 2));
+        let width = self.width as i32;
+
+        for i in 0..FINE_ANGLES / 2 {
+            let tangent = fine_tangent(i);
+            self.view_angle_to_x[i] = if tangent > real(2) {
+                -1
+            } else if tangent < real(-2) {
+                width + 1
+            } else {
+                let t = fixed_mul(tangent, focal_length);
+                let t = (self.centerxfrac - t + from_fixed_bits(0xffff)).to_int();
+                t.max(-1).min(width + 1)
+            };
+        }
+
+        // Scan viewangletox[] to generate xtoviewangle[]:
+        //  xtoviewangle will give the smallest view angle
+        //  that maps to x.
+        for x in 0..=width {
+            let mut i = 0;
+            while self.view_angle_to_x[i] > x {
+                i += 1;
+            }
+            self.x_to_view_angle[x as usize] = ((i as Angle) << ANGLE_TO_FINE_SHIFT).wrapping_sub(ANG90);
+        }
+
+        // Take out the fencepost cases from viewangletox.
+        for i in 0..FINE_ANGLES / 2 {
+            if self.view_angle_to_x[i] == -1 {
+                self.view_angle_to_x[i] = 0;
+            } else if self.view_angle_to_x[i] == width + 1 {
+                self.view_angle_to_x[i] = width;
+            }
+        }
+
+        self.clip_angle = self.x_to_view_angle[0];
+    }
 }
 
 pub struct RenderData {
-    texture: TextureData
+    pub(crate) texture: TextureData,
+    pub(crate) flats: FlatData,
+    pub(crate) lighting: LightData,
+    pub(crate) sprites: SpriteData,
+    pub(crate) palettes: PaletteData,
+    pub(crate) sky_flat: usize,
 }
 
-pub fn init_rendering(lumps: &LumpStore) -> RenderData {
-    let texture = init_textures(lumps);
+pub fn init_rendering(lumps: &LumpStore) -> Result<RenderData, WadError> {
+    let texture = init_textures(lumps)?;
+    let flats = init_flats(lumps)?;
+    let lighting = init_lighting(lumps)?;
+    let sprites = init_sprites(lumps, &SPRITE_NAMES)?;
+    let palettes = init_palettes(lumps)?;
 
-    RenderData {
-        texture
+    // R_InitSkyMap
+    let sky_flat = flats.flat_num_for_name(SKY_FLAT_NAME)?;
+
+    Ok(RenderData {
+        texture,
+        flats,
+        lighting,
+        sprites,
+        palettes,
+        sky_flat,
+    })
+}
+
+/// An IWAD with a palette, colormaps, the STARTAN and SKY1 textures
+/// after the unused AASTINKY, the FLOOR1, CEIL1 and F_SKY1 flats
+/// and one sprite.
+#[cfg(test)]
+pub(crate) fn test_resources() -> crate::wad::WadBuilder {
+    let mut builder = crate::wad::WadBuilder::iwad();
+    builder.add_lump("PLAYPAL", vec![0; 14 * 768]).unwrap();
+    builder.add_lump("COLORMAP", (0..34 * 256).map(|index| index as u8).collect()).unwrap();
+    builder.add_lump("WALL", solid_patch(64, 128, 16)).unwrap();
+
+    let name8 = |name: &str| {
+        let mut bytes = [0u8; 8];
+        bytes[..name.len()].copy_from_slice(name.as_bytes());
+        bytes
+    };
+    let shorts = |values: &[i16]| values.iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<u8>>();
+
+    let mut pnames = 1i32.to_le_bytes().to_vec();
+    pnames.extend(name8("WALL"));
+    builder.add_lump("PNAMES", pnames).unwrap();
+
+    // texture 0 means no texture, so the first one is never drawn
+    let textures = ["AASTINKY", "STARTAN", "SKY1"];
+    let texture_size = 22 + 10;
+    let mut texture1 = (textures.len() as i32).to_le_bytes().to_vec();
+    for index in 0..textures.len() {
+        texture1.extend(((4 + 4 * textures.len() + index * texture_size) as i32).to_le_bytes());
+    }
+    for name in textures {
+        texture1.extend(name8(name));
+        texture1.extend(0i32.to_le_bytes());
+        texture1.extend(shorts(&[64, 128]));
+        texture1.extend(0i32.to_le_bytes());
+        texture1.extend(shorts(&[1, 0, 0, 0, 1, 0]));
     }
+    builder.add_lump("TEXTURE1", texture1).unwrap();
+
+    builder.add_lump("F_START", Vec::new()).unwrap();
+    for (name, color) in [("FLOOR1", 96), ("CEIL1", 112), ("F_SKY1", 1)] {
+        builder.add_lump(name, vec![color; 64 * 64]).unwrap();
+    }
+    builder.add_lump("F_END", Vec::new()).unwrap();
+
+    builder.add_lump("S_START", Vec::new()).unwrap();
+    builder.add_lump("BAR1A0", solid_patch(24, 32, 200)).unwrap();
+    builder.add_lump("S_END", Vec::new()).unwrap();
+
+    builder
 }
 
 /*pub struct Patch {
@@ -38,4 +283,4 @@ pub fn init_rendering(lumps: &LumpStore) -> RenderData {
     left_offset: i16,
     top_offset: i16,
     columnofs: [i32; 8],
-}*/
\ No newline at end of file
+}*/
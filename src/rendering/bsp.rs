@@ -1,12 +1,16 @@
-use crate::player::Player;
-use crate::rendering::View;
-
-const MAXSEGS:usize = 32;
+use crate::rendering::{View, ViewPoint, RenderData};
+use crate::rendering::frame::Frame;
+use crate::rendering::things::Sprites;
+use crate::level::Level;
+use crate::level::nodes::NF_SUBSECTOR;
+use crate::wad::{LumpStore, WadError};
+use crate::types::{DoomRealNum, BOX_TOP, BOX_BOTTOM, BOX_LEFT, BOX_RIGHT};
+use crate::tables::{ANG90, ANG180, ANGLE_TO_FINE_SHIFT};
 
 #[derive(Copy, Clone)]
-struct ClipRange {
-    first: i32,
-    last: i32,
+pub(crate) struct ClipRange {
+    pub(crate) first: i32,
+    pub(crate) last: i32,
 }
 
 impl Default for ClipRange {
@@ -15,14 +19,16 @@ impl Default for ClipRange {
     }
 }
 
-struct SolidSegs {
-    segs: [ClipRange; MAXSEGS],
-    valid_segs_count: usize
+/// The ranges of screen columns already covered by solid walls,
+/// sorted from left to right and bracketed by two sentinel posts.
+pub(crate) struct SolidSegs {
+    segs: Vec<ClipRange>,
 }
 
 impl SolidSegs {
+    // R_ClearClipSegs
     pub fn new(view: &View) -> Self {
-        let mut segs = [ClipRange::default(); MAXSEGS];
+        let mut segs = vec![ClipRange::default(); 2];
         segs[0].first = -0x7fffffff;
         segs[0].last = -1;
         segs[1].first = view.width as i32;
@@ -30,16 +36,391 @@ impl SolidSegs {
 
         Self {
             segs,
-            valid_segs_count: 2
         }
     }
-}
 
-pub fn render_player_view(player: &Player, view: &View) {
+    // Find the first range that touches the range
+    //  (adjacent pixels are touching).
+    fn first_touching(&self, first: i32) -> usize {
+        self.segs.iter().position(|x| x.last >= first - 1).unwrap()
+    }
+}
 
+// R_RenderPlayerView
+pub fn render_player_view(view: &View, view_point: &ViewPoint, level: &Level, render_data: &RenderData,
+                          lumps: &LumpStore, sprites: Sprites, screen: &mut [u8]) -> Result<(), WadError> {
     // R_SetupFrame
-    let mut solid_segs = SolidSegs::new(view);
+    let mut frame = Frame::new(view, view_point, level, render_data, lumps, sprites, screen);
+
+    // The head node is the last node output.
+    if level.nodes.is_empty() {
+        frame.render_subsector(0)?;
+    } else {
+        frame.render_bsp_node(level.nodes.len() - 1)?;
+    }
+
+    frame.draw_planes()?;
+    frame.project_sprites()?;
+    frame.draw_masked()
+}
+
+// checkcoord[12][4] from r_bsp.c
+const CHECK_COORD: [[usize; 4]; 12] = [
+    [3, 0, 2, 1],
+    [3, 0, 2, 0],
+    [3, 1, 2, 0],
+    [0, 0, 0, 0],
+    [2, 0, 2, 1],
+    [0, 0, 0, 0],
+    [3, 1, 3, 0],
+    [0, 0, 0, 0],
+    [2, 0, 3, 1],
+    [2, 1, 3, 1],
+    [2, 1, 3, 0],
+    [0, 0, 0, 0],
+];
+
+impl Frame<'_> {
+    // R_ClipSolidWallSegment
+    // Does handle solid walls,
+    //  e.g. single sided LineDefs (middle texture)
+    //  that entirely block the view.
+    fn clip_solid_wall_segment(&mut self, seg: usize, first: i32, last: i32) -> Result<(), WadError> {
+        let mut start = self.solid_segs.first_touching(first);
+
+        if first < self.solid_segs.segs[start].first {
+            if last < self.solid_segs.segs[start].first - 1 {
+                // Post is entirely visible (above start),
+                //  so insert a new clippost.
+                self.store_wall_range(seg, first, last)?;
+                self.solid_segs.segs.insert(start, ClipRange { first, last });
+                return Ok(());
+            }
+
+            // There is a fragment above *start.
+            let start_first = self.solid_segs.segs[start].first;
+            self.store_wall_range(seg, first, start_first - 1)?;
+            // Now adjust the clip size.
+            self.solid_segs.segs[start].first = first;
+        }
+
+        // Bottom contained in start?
+        if last <= self.solid_segs.segs[start].last {
+            return Ok(());
+        }
+
+        let mut next = start;
+        while last >= self.solid_segs.segs[next + 1].first - 1 {
+            // There is a fragment between two posts.
+            let (from, to) = (self.solid_segs.segs[next].last + 1, self.solid_segs.segs[next + 1].first - 1);
+            self.store_wall_range(seg, from, to)?;
+            next += 1;
+
+            if last <= self.solid_segs.segs[next].last {
+                // Bottom is contained in next.
+                // Adjust the clip size.
+                self.solid_segs.segs[start].last = self.solid_segs.segs[next].last;
+                break;
+            }
+        }
+
+        if last > self.solid_segs.segs[next].last {
+            // There is a fragment after *next.
+            let from = self.solid_segs.segs[next].last + 1;
+            self.store_wall_range(seg, from, last)?;
+            // Adjust the clip size.
+            self.solid_segs.segs[start].last = last;
+        }
+
+        // Remove start+1 to next from the clip list,
+        // because start now covers their area.
+        start += 1;
+        if next >= start {
+            self.solid_segs.segs.drain(start..=next);
+        }
+
+        Ok(())
+    }
+
+    // R_ClipPassWallSegment
+    // Clips the given range of columns,
+    //  but does not includes it in the clip list.
+    // Does handle windows,
+    //  e.g. LineDefs with upper and lower texture.
+    fn clip_pass_wall_segment(&mut self, seg: usize, first: i32, last: i32) -> Result<(), WadError> {
+        let mut start = self.solid_segs.first_touching(first);
 
+        if first < self.solid_segs.segs[start].first {
+            if last < self.solid_segs.segs[start].first - 1 {
+                // Post is entirely visible (above start).
+                return self.store_wall_range(seg, first, last);
+            }
 
+            // There is a fragment above *start.
+            let start_first = self.solid_segs.segs[start].first;
+            self.store_wall_range(seg, first, start_first - 1)?;
+        }
+
+        // Bottom contained in start?
+        if last <= self.solid_segs.segs[start].last {
+            return Ok(());
+        }
+
+        while last >= self.solid_segs.segs[start + 1].first - 1 {
+            // There is a fragment between two posts.
+            let (from, to) = (self.solid_segs.segs[start].last + 1, self.solid_segs.segs[start + 1].first - 1);
+            self.store_wall_range(seg, from, to)?;
+            start += 1;
+
+            if last <= self.solid_segs.segs[start].last {
+                return Ok(());
+            }
+        }
+
+        // There is a fragment after *next.
+        let from = self.solid_segs.segs[start].last + 1;
+        self.store_wall_range(seg, from, last)
+    }
+
+    // R_AddLine
+    // Clips the given segment
+    // and adds any visible pieces to the line list.
+    fn add_line(&mut self, seg_index: usize) -> Result<(), WadError> {
+        let level = self.level;
+        let seg = &level.segs[seg_index];
+        let (v1, v2) = (&level.vertices[seg.v1], &level.vertices[seg.v2]);
+
+        // OPTIMIZE: quickly reject orthogonal back sides.
+        let mut angle1 = self.point_to_angle(v1.x, v1.y);
+        let mut angle2 = self.point_to_angle(v2.x, v2.y);
+
+        // Clip to view edges.
+        // OPTIMIZE: make constant out of 2*clipangle (FIELDOFVIEW).
+        let span = angle1.wrapping_sub(angle2);
+
+        // Back side? I.e. backface culling?
+        if span >= ANG180 {
+            return Ok(());
+        }
 
-}
\ No newline at end of file
+        // Global angle needed by segcalc.
+        self.rw_angle1 = angle1;
+        angle1 = angle1.wrapping_sub(self.view_angle);
+        angle2 = angle2.wrapping_sub(self.view_angle);
+
+        if !self.clip_angles(&mut angle1, &mut angle2, span) {
+            return Ok(());
+        }
+
+        // The seg is in the view range,
+        // but not necessarily visible.
+        angle1 = angle1.wrapping_add(ANG90) >> ANGLE_TO_FINE_SHIFT;
+        angle2 = angle2.wrapping_add(ANG90) >> ANGLE_TO_FINE_SHIFT;
+        let x1 = self.view.view_angle_to_x[angle1 as usize];
+        let x2 = self.view.view_angle_to_x[angle2 as usize];
+
+        // Does not cross a pixel?
+        if x1 == x2 {
+            return Ok(());
+        }
+
+        let front_sector = &level.sectors[self.front_sector];
+        let back_sector = match seg.back_sector {
+            // Single sided line?
+            None => return self.clip_solid_wall_segment(seg_index, x1, x2 - 1),
+            Some(back_sector) => &level.sectors[back_sector],
+        };
+
+        // Closed door.
+        if back_sector.ceiling_height <= front_sector.floor_height
+            || back_sector.floor_height >= front_sector.ceiling_height {
+            return self.clip_solid_wall_segment(seg_index, x1, x2 - 1);
+        }
+
+        // Window.
+        if back_sector.ceiling_height != front_sector.ceiling_height
+            || back_sector.floor_height != front_sector.floor_height {
+            return self.clip_pass_wall_segment(seg_index, x1, x2 - 1);
+        }
+
+        // Reject empty lines used for triggers
+        //  and special events.
+        // Identical floor and ceiling on both sides,
+        // identical light levels on both sides,
+        // and no middle texture.
+        if back_sector.ceiling_pic == front_sector.ceiling_pic
+            && back_sector.floor_pic == front_sector.floor_pic
+            && back_sector.light_level == front_sector.light_level
+            && level.sides[seg.sidedef].mid_texture == 0 {
+            return Ok(());
+        }
+
+        self.clip_pass_wall_segment(seg_index, x1, x2 - 1)
+    }
+
+    // Clips the view relative angles to the field of view,
+    // returns false when the span is entirely outside of it.
+    fn clip_angles(&self, angle1: &mut u32, angle2: &mut u32, span: u32) -> bool {
+        let clip_angle = self.view.clip_angle;
+        let double_clip_angle = clip_angle.wrapping_mul(2);
+
+        let mut tspan = angle1.wrapping_add(clip_angle);
+        if tspan > double_clip_angle {
+            tspan = tspan.wrapping_sub(double_clip_angle);
+
+            // Totally off the left edge?
+            if tspan >= span {
+                return false;
+            }
+
+            *angle1 = clip_angle;
+        }
+
+        tspan = clip_angle.wrapping_sub(*angle2);
+        if tspan > double_clip_angle {
+            tspan = tspan.wrapping_sub(double_clip_angle);
+
+            // Totally off the left edge?
+            if tspan >= span {
+                return false;
+            }
+
+            *angle2 = clip_angle.wrapping_neg();
+        }
+
+        true
+    }
+
+    // R_CheckBBox
+    // Checks BSP node/subtree bounding box.
+    // Returns true
+    //  if some part of the bbox might be visible.
+    fn check_bbox(&self, bspcoord: &[DoomRealNum; 4]) -> bool {
+        // Find the corners of the box
+        // that define the edges from current viewpoint.
+        let box_x = if self.view_x <= bspcoord[BOX_LEFT] {
+            0
+        } else if self.view_x < bspcoord[BOX_RIGHT] {
+            1
+        } else {
+            2
+        };
+
+        let box_y = if self.view_y >= bspcoord[BOX_TOP] {
+            0
+        } else if self.view_y > bspcoord[BOX_BOTTOM] {
+            1
+        } else {
+            2
+        };
+
+        let box_pos = (box_y << 2) + box_x;
+        if box_pos == 5 {
+            return true;
+        }
+
+        let coord = &CHECK_COORD[box_pos];
+        let (x1, y1) = (bspcoord[coord[0]], bspcoord[coord[1]]);
+        let (x2, y2) = (bspcoord[coord[2]], bspcoord[coord[3]]);
+
+        // check clip list for an open space
+        let mut angle1 = self.point_to_angle(x1, y1).wrapping_sub(self.view_angle);
+        let mut angle2 = self.point_to_angle(x2, y2).wrapping_sub(self.view_angle);
+
+        let span = angle1.wrapping_sub(angle2);
+
+        // Sitting on a line?
+        if span >= ANG180 {
+            return true;
+        }
+
+        if !self.clip_angles(&mut angle1, &mut angle2, span) {
+            return false;
+        }
+
+        // Find the first clippost
+        //  that touches the source post
+        //  (adjacent pixels are touching).
+        angle1 = angle1.wrapping_add(ANG90) >> ANGLE_TO_FINE_SHIFT;
+        angle2 = angle2.wrapping_add(ANG90) >> ANGLE_TO_FINE_SHIFT;
+        let sx1 = self.view.view_angle_to_x[angle1 as usize];
+        let mut sx2 = self.view.view_angle_to_x[angle2 as usize];
+
+        // Does not cross a pixel.
+        if sx1 == sx2 {
+            return false;
+        }
+        sx2 -= 1;
+
+        let start = &self.solid_segs.segs[self.solid_segs.first_touching(sx2 + 1)];
+
+        // The clippost contains the new span.
+        !(sx1 >= start.first && sx2 <= start.last)
+    }
+
+    // R_Subsector
+    // Determine floor/ceiling planes.
+    // Add sprites of things in sector.
+    // Draw one or more line segments.
+    pub fn render_subsector(&mut self, num: usize) -> Result<(), WadError> {
+        let subsector = match self.level.subsectors.get(num) {
+            Some(subsector) => subsector,
+            None => return Err(WadError::MalformedLump {
+                name: "SSECTORS".to_string(),
+                reason: format!("Subsector {} out of range", num),
+            }),
+        };
+
+        self.front_sector = subsector.sector;
+        let level = self.level;
+        let front_sector = &level.sectors[subsector.sector];
+
+        self.floor_plane = if front_sector.floor_height < self.view_z {
+            Some(self.find_plane(front_sector.floor_height, front_sector.floor_pic, front_sector.light_level))
+        } else {
+            None
+        };
+
+        self.ceiling_plane = if front_sector.ceiling_height > self.view_z
+            || front_sector.ceiling_pic == self.render_data.sky_flat {
+            Some(self.find_plane(front_sector.ceiling_height, front_sector.ceiling_pic, front_sector.light_level))
+        } else {
+            None
+        };
+
+        self.add_sprites(subsector.sector);
+
+        for seg in subsector.first_line..subsector.first_line + subsector.num_lines {
+            self.add_line(seg)?;
+        }
+
+        Ok(())
+    }
+
+    // R_RenderBSPNode
+    // Renders all subsectors below a given node,
+    //  traversing subtree recursively.
+    // Just call with BSP root.
+    pub fn render_bsp_node(&mut self, bsp_num: usize) -> Result<(), WadError> {
+        // Found a subsector?
+        if bsp_num & NF_SUBSECTOR != 0 {
+            return self.render_subsector(bsp_num & !NF_SUBSECTOR);
+        }
+
+        let level = self.level;
+        let bsp = &level.nodes[bsp_num];
+
+        // Decide which side the view point is on.
+        let side = bsp.point_on_side(self.view_x, self.view_y);
+
+        // Recursively divide front space.
+        self.render_bsp_node(bsp.children[side])?;
+
+        // Possibly divide back space.
+        if self.check_bbox(&bsp.bbox[side ^ 1]) {
+            self.render_bsp_node(bsp.children[side ^ 1])?;
+        }
+
+        Ok(())
+    }
+}
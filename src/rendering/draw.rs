@@ -0,0 +1,240 @@
+use crate::rendering::frame::Frame;
+use crate::rendering::renderer::RENDER_WIDTH;
+use crate::rendering::lighting::FUZZ_COLORMAP;
+use crate::types::{DoomRealNum, real, from_fixed_bits, RealNum};
+
+/// Parameters for drawing a single vertical column, the dc_* globals.
+pub(crate) struct Column<'a> {
+    pub(crate) x: i32,
+    pub(crate) yl: i32,
+    pub(crate) yh: i32,
+    pub(crate) iscale: DoomRealNum,
+    pub(crate) texture_mid: DoomRealNum,
+    pub(crate) source: &'a [u8],
+    pub(crate) colormap: &'a [u8],
+}
+
+// Spectre/Invisibility.
+const FUZZ_TABLE: usize = 50;
+const FUZZ_OFF: isize = RENDER_WIDTH as isize;
+
+const FUZZ_OFFSET: [isize; FUZZ_TABLE] = [
+    FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF,
+    FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF,
+    FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF,
+    FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF,
+    FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF,
+    FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, FUZZ_OFF,
+    FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF, FUZZ_OFF, -FUZZ_OFF, FUZZ_OFF,
+];
+
+impl Frame<'_> {
+    // R_DrawColumn
+    // A column is a vertical slice/span from a wall texture that,
+    //  given the DOOM style restrictions on the view orientation,
+    //  will always have constant z depth.
+    // Thus a special case loop for very fast rendering can
+    //  be used. It has also been used with Wolfenstein 3D.
+    pub fn draw_column(&mut self, column: &Column) {
+        let count = column.yh - column.yl;
+
+        // Zero length, column does not exceed a pixel.
+        if count < 0 || column.source.is_empty() {
+            return;
+        }
+
+        debug_assert!(column.x >= 0 && (column.x as usize) < self.view.width
+            && column.yl >= 0 && (column.yh as usize) < self.view.height);
+
+        // Framebuffer destination address.
+        let mut dest = (self.view.window_y + column.yl as usize) * RENDER_WIDTH
+            + self.view.window_x + column.x as usize;
+
+        // Determine scaling,
+        //  which is the only mapping to be done.
+        let frac_step = column.iscale;
+        let mut frac = column.texture_mid.wrapping_add(frac_step.wrapping_mul_int(column.yl - self.view.centery));
+
+        // Inner loop that does the actual texture mapping,
+        //  e.g. a DDA-lile scaling.
+        // This is as fast as it gets.
+        let height = column.source.len() as i32;
+        for _ in 0..=count {
+            // Re-map color indices from wall texture column
+            //  using a lighting/special effects LUT.
+            self.screen[dest] = column.colormap[column.source[frac.to_int().rem_euclid(height) as usize] as usize];
+
+            dest += RENDER_WIDTH;
+            frac = frac.wrapping_add(frac_step);
+        }
+    }
+
+    // R_DrawFuzzColumn
+    // Spectre/Invisibility.
+    // Framebuffer postprocessing.
+    // Creates a fuzzy image by copying pixels
+    //  from adjacent ones to left and right.
+    // Used with an all black colormap, this
+    //  could create the SHADOW effect,
+    //  i.e. spectres and invisible players.
+    pub fn draw_fuzz_column(&mut self, column: &Column) {
+        // Adjust borders. Low...
+        let yl = column.yl.max(1);
+
+        // .. and high.
+        let yh = if column.yh == self.view.height as i32 - 1 {
+            self.view.height as i32 - 2
+        } else {
+            column.yh
+        };
+
+        // Zero length.
+        if yh < yl {
+            return;
+        }
+
+        debug_assert!(column.x >= 0 && (column.x as usize) < self.view.width
+            && (yh as usize) < self.view.height);
+
+        // Does not work with blocky mode.
+        let mut dest = (self.view.window_y + yl as usize) * RENDER_WIDTH
+            + self.view.window_x + column.x as usize;
+
+        // Looks like an attempt at dithering,
+        //  using the colormap #6 (of 0-31, a bit
+        //  brighter than average).
+        for _ in yl..=yh {
+            // Lookup framebuffer, and retrieve
+            //  a pixel that is either one column
+            //  left or right of the current one.
+            // Add index from colormap to index.
+            let source = dest.wrapping_add_signed(FUZZ_OFFSET[self.fuzz_pos]);
+            self.screen[dest] = column.colormap[self.screen[source] as usize];
+
+            // Clamp table lookup index.
+            self.fuzz_pos = (self.fuzz_pos + 1) % FUZZ_TABLE;
+
+            dest += RENDER_WIDTH;
+        }
+    }
+}
+
+/// A run of opaque pixels in a masked column, see post_t.
+pub(crate) struct MaskedPost<'a> {
+    pub(crate) top_delta: i32,
+    pub(crate) source: &'a [u8],
+}
+
+/// Parameters for drawing a column of a sprite or masked texture.
+pub(crate) struct MaskedColumn<'a> {
+    pub(crate) x: i32,
+    pub(crate) posts: &'a [MaskedPost<'a>],
+    // sprtopscreen, spryscale
+    pub(crate) top_screen: DoomRealNum,
+    pub(crate) scale: DoomRealNum,
+    pub(crate) iscale: DoomRealNum,
+    pub(crate) texture_mid: DoomRealNum,
+    // mfloorclip[x], mceilingclip[x]
+    pub(crate) floor_clip: i32,
+    pub(crate) ceiling_clip: i32,
+    // None draws the column with the shadow fuzz effect
+    pub(crate) colormap: Option<&'a [u8]>,
+}
+
+impl Frame<'_> {
+    // R_DrawMaskedColumn
+    // Used for sprites and masked mid textures.
+    // Masked means: partly transparent, i.e. stored
+    //  in posts/runs of opaque pixels.
+    pub fn draw_masked_column(&mut self, column: &MaskedColumn) {
+        for post in column.posts {
+            // calculate unclipped screen coordinates
+            //  for post
+            let top_screen = column.top_screen.wrapping_add(column.scale.wrapping_mul_int(post.top_delta));
+            let bottom_screen = top_screen.wrapping_add(column.scale.wrapping_mul_int(post.source.len() as i32));
+
+            let mut yl = top_screen.wrapping_add(from_fixed_bits(0xffff)).to_int();
+            let mut yh = bottom_screen.wrapping_sub(from_fixed_bits(1)).to_int();
+
+            if yh >= column.floor_clip {
+                yh = column.floor_clip - 1;
+            }
+
+            if yl <= column.ceiling_clip {
+                yl = column.ceiling_clip + 1;
+            }
+
+            if yl <= yh {
+                let mut draw = Column {
+                    x: column.x,
+                    yl,
+                    yh,
+                    iscale: column.iscale,
+                    texture_mid: column.texture_mid - real(post.top_delta),
+                    source: post.source,
+                    colormap: &[],
+                };
+
+                match column.colormap {
+                    Some(colormap) => {
+                        draw.colormap = colormap;
+                        self.draw_column(&draw);
+                    }
+                    None => {
+                        draw.colormap = self.render_data.lighting.colormap(FUZZ_COLORMAP);
+                        self.draw_fuzz_column(&draw);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parameters for drawing a single horizontal span, the ds_* globals.
+pub(crate) struct Span<'a> {
+    pub(crate) y: i32,
+    pub(crate) x1: i32,
+    pub(crate) x2: i32,
+    pub(crate) x_frac: DoomRealNum,
+    pub(crate) y_frac: DoomRealNum,
+    pub(crate) x_step: DoomRealNum,
+    pub(crate) y_step: DoomRealNum,
+    pub(crate) source: &'a [u8],
+    pub(crate) colormap: &'a [u8],
+}
+
+impl Frame<'_> {
+    // R_DrawSpan
+    // With DOOM style restrictions on view orientation,
+    //  the floors and ceilings consist of horizontal slices
+    //  or spans with constant z depth.
+    // However, rotation around the world z axis is possible,
+    //  thus this mapping, while simpler and faster than
+    //  perspective correct texture mapping, has to traverse
+    //  the texture at an angle in all but a few cases.
+    // In consequence, flats are not stored by column (like walls),
+    //  and the inner loop has to step in texture space u and v.
+    pub fn draw_span(&mut self, span: &Span) {
+        debug_assert!(span.x1 <= span.x2 && span.x1 >= 0 && (span.x2 as usize) < self.view.width
+            && span.y >= 0 && (span.y as usize) < self.view.height);
+
+        let mut x_frac = span.x_frac;
+        let mut y_frac = span.y_frac;
+
+        let dest = (self.view.window_y + span.y as usize) * RENDER_WIDTH
+            + self.view.window_x + span.x1 as usize;
+
+        for pixel in &mut self.screen[dest..=dest + (span.x2 - span.x1) as usize] {
+            // Current texture index in u,v.
+            let spot = ((y_frac.to_int() & 63) << 6) + (x_frac.to_int() & 63);
+
+            // Lookup pixel from flat texture tile,
+            //  re-index using light/colormap.
+            *pixel = span.colormap[span.source[spot as usize] as usize];
+
+            // Next step in u,v.
+            x_frac = x_frac.wrapping_add(span.x_step);
+            y_frac = y_frac.wrapping_add(span.y_step);
+        }
+    }
+}
@@ -1,32 +1,53 @@
-use crate::wad::LumpStore;
+use crate::wad::{LumpStore, LumpNumber, Namespace, WadError};
+use std::collections::HashMap;
 
 pub struct FlatData {
-    first_flat: usize,
-    last_flat: usize,
-    num_flats: usize,
+    // The flats of all files, PWAD flats replace
+    //  IWAD flats with the same name.
+    flat_lumps: Vec<LumpNumber>,
+    flat_numbers: HashMap<String, usize>,
     flat_translation: Vec<i32>,
 }
 
-pub fn init_flats(lumps: &LumpStore) -> FlatData {
-    let first_flat = lumps.get_lump_number("F_START")
-        .unwrap()
-        .into();
+impl FlatData {
+    // R_FlatNumForName
+    pub fn flat_num_for_name(&self, name: &str) -> Result<usize, WadError> {
+        let name = name.to_uppercase();
+        self.flat_numbers.get(&name)
+            .copied()
+            .ok_or(WadError::MissingLump(name))
+    }
+
+    /// Returns the lump holding the current (possibly animated)
+    /// picture of a flat.
+    pub fn flat_lump(&self, flat: usize) -> LumpNumber {
+        self.flat_lumps[self.flat_translation[flat] as usize]
+    }
+}
 
-    let last_flat = lumps.get_lump_number("F_END")
-        .unwrap()
-        .into();
+// R_InitFlats
+pub fn init_flats(lumps: &LumpStore) -> Result<FlatData, WadError> {
+    // the flats between F_START and F_END or FF_START
+    //  and FF_END of every file, merged by name
+    let flat_lumps = lumps.get_namespace_lumps(Namespace::Flats);
+    if flat_lumps.is_empty() {
+        return Err(WadError::MissingLump("F_START".to_string()));
+    }
 
-    let num_flats = last_flat - first_flat + 1;
+    let mut flat_numbers = HashMap::with_capacity(flat_lumps.len());
+    for (flat, &lump) in flat_lumps.iter().enumerate() {
+        flat_numbers.insert(lumps.get_lump_name(lump)?.to_string(), flat);
+    }
 
+    let num_flats = flat_lumps.len();
     let mut flat_translation = vec![0i32; num_flats+1];
     for i in 0..num_flats {
         flat_translation[i] = i as i32;
     }
 
-    FlatData {
-        first_flat,
-        last_flat,
-        num_flats,
+    Ok(FlatData {
+        flat_lumps,
+        flat_numbers,
         flat_translation
-    }
-}
\ No newline at end of file
+    })
+}
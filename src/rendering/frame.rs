@@ -0,0 +1,187 @@
+use crate::rendering::{View, ViewPoint, RenderData};
+use crate::rendering::bsp::SolidSegs;
+use crate::rendering::planes::Planes;
+use crate::rendering::segs::DrawSeg;
+use crate::rendering::things::{Sprites, VisSprite};
+use crate::level::Level;
+use crate::wad::LumpStore;
+use crate::types::{DoomRealNum, Angle, real, fixed_div, fixed_mul, from_fixed_bits, RealNum};
+use crate::rendering::lighting::{LIGHT_LEVELS, LIGHT_SEG_SHIFT, LIGHT_SCALE_SHIFT, MAX_LIGHT_SCALE};
+use crate::tables::{ANGLE_TO_FINE_SHIFT, ANG90, SLOPE_BITS, fine_sine, fine_cosine, tan_to_angle, point_to_angle};
+
+/// State shared by the renderer passes while a single view is drawn.
+/// The original code keeps all of this in globals spread over r_*.c.
+pub(crate) struct Frame<'a> {
+    pub(crate) view: &'a View,
+    pub(crate) level: &'a Level,
+    pub(crate) render_data: &'a RenderData,
+    pub(crate) lumps: &'a LumpStore,
+    pub(crate) sprites: Sprites<'a>,
+    pub(crate) screen: &'a mut [u8],
+
+    pub(crate) view_x: DoomRealNum,
+    pub(crate) view_y: DoomRealNum,
+    pub(crate) view_z: DoomRealNum,
+    pub(crate) view_angle: Angle,
+    pub(crate) view_sin: DoomRealNum,
+    pub(crate) view_cos: DoomRealNum,
+    pub(crate) extra_light: i32,
+    pub(crate) fixed_colormap: Option<usize>,
+
+    pub(crate) solid_segs: SolidSegs,
+
+    // Sector of the subsector being rendered
+    pub(crate) front_sector: usize,
+    // Angle from the view point to the start of the current seg
+    pub(crate) rw_angle1: Angle,
+
+    // Clip values are the solid pixel bounding the range.
+    //  floorclip starts out SCREENHEIGHT
+    //  ceilingclip starts out -1
+    pub(crate) floor_clip: Vec<i32>,
+    pub(crate) ceiling_clip: Vec<i32>,
+
+    pub(crate) planes: Planes,
+    // Visplanes of the subsector being rendered
+    pub(crate) floor_plane: Option<usize>,
+    pub(crate) ceiling_plane: Option<usize>,
+
+    pub(crate) draw_segs: Vec<DrawSeg>,
+
+    // Column clip lists of the drawsegs and the texture columns
+    //  of masked mid textures. Starts with the
+    //  screenheightarray and the negonearray.
+    pub(crate) openings: Vec<i32>,
+
+    pub(crate) vis_sprites: Vec<VisSprite>,
+    // Sectors whose things have been added
+    pub(crate) sectors_seen: Vec<bool>,
+
+    // Position in the fuzz offset table
+    pub(crate) fuzz_pos: usize,
+}
+
+impl<'a> Frame<'a> {
+    // R_SetupFrame
+    pub fn new(view: &'a View, view_point: &ViewPoint, level: &'a Level, render_data: &'a RenderData,
+               lumps: &'a LumpStore, sprites: Sprites<'a>, screen: &'a mut [u8]) -> Self {
+        let fine_angle = (view_point.angle >> ANGLE_TO_FINE_SHIFT) as usize;
+
+        Self {
+            view,
+            level,
+            render_data,
+            lumps,
+            sprites,
+            screen,
+            view_x: view_point.x,
+            view_y: view_point.y,
+            view_z: view_point.z,
+            view_angle: view_point.angle,
+            view_sin: fine_sine(fine_angle),
+            view_cos: fine_cosine(fine_angle),
+            extra_light: view_point.extra_light,
+            fixed_colormap: view_point.fixed_colormap,
+            solid_segs: SolidSegs::new(view),
+            front_sector: 0,
+            rw_angle1: 0,
+            floor_clip: vec![view.height as i32; view.width],
+            ceiling_clip: vec![-1; view.width],
+            planes: Planes::new(view, view_point.angle),
+            floor_plane: None,
+            ceiling_plane: None,
+            draw_segs: Vec::new(),
+            openings: [vec![view.height as i32; view.width], vec![-1; view.width]].concat(),
+            vis_sprites: Vec::new(),
+            sectors_seen: vec![false; level.sectors.len()],
+            fuzz_pos: 0,
+        }
+    }
+
+    // Row of the light tables for a sector light level,
+    //  contrast is the fake contrast of walls.
+    pub fn light_row(&self, light_level: i16, contrast: i32) -> usize {
+        let light = (light_level as i32 >> LIGHT_SEG_SHIFT) + self.extra_light + contrast;
+        light.max(0).min(LIGHT_LEVELS as i32 - 1) as usize
+    }
+
+    // Colormap for a wall or sprite column drawn at a scale,
+    //  see walllights and spritelights.
+    pub fn scale_colormap(&self, light_row: usize, scale: DoomRealNum) -> &'a [u8] {
+        let lighting = &self.render_data.lighting;
+
+        match self.fixed_colormap {
+            Some(fixed_colormap) => lighting.colormap(fixed_colormap),
+            None => {
+                let index = scale.scale_index(LIGHT_SCALE_SHIFT, MAX_LIGHT_SCALE);
+                lighting.colormap(self.view.scale_light[light_row][index])
+            }
+        }
+    }
+
+    // screenheightarray, for sprite clipping
+    pub fn screen_height_clip(&self) -> i32 {
+        0
+    }
+
+    // negonearray, for sprite clipping
+    pub fn neg_one_clip(&self) -> i32 {
+        self.view.width as i32
+    }
+
+    // R_PointToAngle
+    pub fn point_to_angle(&self, x: DoomRealNum, y: DoomRealNum) -> Angle {
+        point_to_angle(x.wrapping_sub(self.view_x), y.wrapping_sub(self.view_y))
+    }
+
+    // R_PointToDist
+    pub fn point_to_dist(&self, x: DoomRealNum, y: DoomRealNum) -> DoomRealNum {
+        let mut dx = x.wrapping_sub(self.view_x).wrapping_abs();
+        let mut dy = y.wrapping_sub(self.view_y).wrapping_abs();
+
+        if dy > dx {
+            std::mem::swap(&mut dx, &mut dy);
+        }
+
+        if dx == real(0) {
+            return dx;
+        }
+
+        let angle = tan_to_angle(fixed_div(dy, dx).frac_bits(SLOPE_BITS) as usize).wrapping_add(ANG90) >> ANGLE_TO_FINE_SHIFT;
+
+        // use as cosine
+        fixed_div(dx, fine_sine(angle as usize))
+    }
+
+    // R_ScaleFromGlobalAngle
+    // Returns the texture mapping scale
+    //  for the current line (horizontal span)
+    //  at the given angle.
+    // rw_distance must be calculated first.
+    pub fn scale_from_global_angle(&self, visual_angle: Angle, normal_angle: Angle, distance: DoomRealNum) -> DoomRealNum {
+        let angle_a = ANG90.wrapping_add(visual_angle.wrapping_sub(self.view_angle));
+        let angle_b = ANG90.wrapping_add(visual_angle.wrapping_sub(normal_angle));
+
+        // both sines are allways positive
+        let sine_a = fine_sine((angle_a >> ANGLE_TO_FINE_SHIFT) as usize);
+        let sine_b = fine_sine((angle_b >> ANGLE_TO_FINE_SHIFT) as usize);
+        let num = fixed_mul(self.view.projection, sine_b);
+        let den = fixed_mul(distance, sine_a);
+
+        let max_scale = real(64);
+        let min_scale = from_fixed_bits(256);
+        if den > num.shr(16) {
+            let scale = fixed_div(num, den);
+
+            if scale > max_scale {
+                max_scale
+            } else if scale < min_scale {
+                min_scale
+            } else {
+                scale
+            }
+        } else {
+            max_scale
+        }
+    }
+}
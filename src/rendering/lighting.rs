@@ -0,0 +1,101 @@
+use crate::wad::{LumpStore, By, WadError};
+use crate::rendering::renderer::RENDER_WIDTH;
+use crate::types::{DoomRealNum, real, fixed_div, from_fixed_bits, FRAC_BITS, RealNum};
+
+// Lighting constants.
+// Now why not 32 levels here?
+pub const LIGHT_LEVELS: usize = 16;
+pub const LIGHT_SEG_SHIFT: usize = 4;
+
+pub const MAX_LIGHT_Z: usize = 128;
+pub const LIGHT_Z_SHIFT: i32 = 20;
+
+pub const MAX_LIGHT_SCALE: usize = 48;
+pub const LIGHT_SCALE_SHIFT: i32 = 12;
+
+// Number of diminishing brightness levels.
+// There a 0-31, i.e. 32 LUT in the COLORMAP lump.
+pub const NUM_COLORMAPS: usize = 32;
+
+// Index of the special effects (INVUL inverse) map.
+pub const INVERSE_COLORMAP: usize = 32;
+
+// The colormap the spectre fuzz darkens the background with.
+pub const FUZZ_COLORMAP: usize = 6;
+
+const DISTMAP: i32 = 2;
+
+const COLORMAP_SIZE: usize = 256;
+
+pub struct LightData {
+    colormaps: Vec<u8>,
+    // Colormap numbers for the floor and ceiling spans,
+    //  by sector light level and distance.
+    z_light: [[usize; MAX_LIGHT_Z]; LIGHT_LEVELS],
+}
+
+impl LightData {
+    pub fn colormap(&self, index: usize) -> &[u8] {
+        &self.colormaps[index * COLORMAP_SIZE..(index + 1) * COLORMAP_SIZE]
+    }
+
+    // zlight[light][distance >> LIGHTZSHIFT]
+    pub fn z_light(&self, light: usize, distance: DoomRealNum) -> &[u8] {
+        let index = distance.scale_index(LIGHT_Z_SHIFT, MAX_LIGHT_Z);
+
+        self.colormap(self.z_light[light][index])
+    }
+}
+
+// R_InitColormaps and the zlight part of R_InitLightTables
+pub fn init_lighting(lumps: &LumpStore) -> Result<LightData, WadError> {
+    // Load in the light tables,
+    //  256 byte align tables.
+    let colormaps = lumps.get_lump(By::Name("COLORMAP"))?.to_vec();
+
+    if colormaps.len() < (INVERSE_COLORMAP + 1) * COLORMAP_SIZE {
+        return Err(WadError::MalformedLump {
+            name: "COLORMAP".to_string(),
+            reason: format!("Expected at least {} colormaps", INVERSE_COLORMAP + 1),
+        });
+    }
+
+    // Calculate the light levels to use
+    //  for each level / distance combination.
+    let mut z_light = [[0; MAX_LIGHT_Z]; LIGHT_LEVELS];
+    for (i, levels) in z_light.iter_mut().enumerate() {
+        let start_map = (((LIGHT_LEVELS - 1 - i) * 2) * NUM_COLORMAPS / LIGHT_LEVELS) as i32;
+
+        for (j, level) in levels.iter_mut().enumerate() {
+            let scale = fixed_div(
+                real(160),
+                from_fixed_bits(((j + 1) as i32) << LIGHT_Z_SHIFT),
+            ).frac_bits((FRAC_BITS - LIGHT_SCALE_SHIFT) as u32);
+
+            *level = (start_map - scale / DISTMAP).max(0).min(NUM_COLORMAPS as i32 - 1) as usize;
+        }
+    }
+
+    Ok(LightData {
+        colormaps,
+        z_light,
+    })
+}
+
+// The scalelight part of R_ExecuteSetViewSize
+// Calculate the light levels to use
+//  for each level / scale combination.
+pub fn scale_light_table(view_width: usize) -> [[usize; MAX_LIGHT_SCALE]; LIGHT_LEVELS] {
+    let mut scale_light = [[0; MAX_LIGHT_SCALE]; LIGHT_LEVELS];
+
+    for (i, levels) in scale_light.iter_mut().enumerate() {
+        let start_map = (((LIGHT_LEVELS - 1 - i) * 2) * NUM_COLORMAPS / LIGHT_LEVELS) as i32;
+
+        for (j, level) in levels.iter_mut().enumerate() {
+            let scaled = (j * RENDER_WIDTH / view_width) as i32 / DISTMAP;
+            *level = (start_map - scaled).max(0).min(NUM_COLORMAPS as i32 - 1) as usize;
+        }
+    }
+
+    scale_light
+}
@@ -1,3 +1,84 @@
+use crate::wad::{LumpStore, By, WadError};
+
+// Size of one palette, 256 RGB triplets.
+pub const PALETTE_SIZE: usize = 256 * 3;
+
+// Palette indices.
+// For damage/bonus red-/gold-shifts
+pub const START_RED_PALS: usize = 1;
+pub const START_BONUS_PALS: usize = 9;
+pub const NUM_RED_PALS: usize = 8;
+pub const NUM_BONUS_PALS: usize = 4;
+// Radiation suit, green shift.
+pub const RADIATION_PAL: usize = 13;
+
+// The number of palettes in PLAYPAL.
+pub const NUM_PALETTES: usize = 14;
+
+/// All palettes of the PLAYPAL lump.
+pub struct PaletteData {
+    palettes: Vec<u8>,
+}
+
+impl PaletteData {
+    pub fn palette(&self, index: usize) -> &[u8] {
+        &self.palettes[index * PALETTE_SIZE..(index + 1) * PALETTE_SIZE]
+    }
+}
+
+// The PLAYPAL part of I_InitGraphics and ST_loadGraphics
+pub fn init_palettes(lumps: &LumpStore) -> Result<PaletteData, WadError> {
+    let palettes = lumps.get_lump(By::Name("PLAYPAL"))?.to_vec();
+
+    if palettes.len() < NUM_PALETTES * PALETTE_SIZE {
+        return Err(WadError::MalformedLump {
+            name: "PLAYPAL".to_string(),
+            reason: format!("Expected {} palettes", NUM_PALETTES),
+        });
+    }
+
+    Ok(PaletteData {
+        palettes,
+    })
+}
+
+/// The parts of player_t that shift the palette.
+#[derive(Copy, Clone, Default)]
+pub struct PaletteEffects {
+    pub(crate) damage_count: i32,
+    pub(crate) bonus_count: i32,
+    // Tics left of the berserk and radiation suit powers,
+    //  powers[pw_strength] and powers[pw_ironfeet].
+    pub(crate) strength: i32,
+    pub(crate) iron_feet: i32,
+}
+
+impl PaletteEffects {
+    // The palette choice of ST_doPaletteStuff
+    pub fn palette_index(&self) -> usize {
+        let mut count = self.damage_count;
+
+        if self.strength != 0 {
+            // slowly fade the berzerk out
+            let berserk_count = 12 - (self.strength >> 6);
+
+            if berserk_count > count {
+                count = berserk_count;
+            }
+        }
+
+        if count != 0 {
+            START_RED_PALS + (((count + 7) >> 3) as usize).min(NUM_RED_PALS - 1)
+        } else if self.bonus_count != 0 {
+            START_BONUS_PALS + (((self.bonus_count + 7) >> 3) as usize).min(NUM_BONUS_PALS - 1)
+        } else if self.iron_feet > 4 * 32 || self.iron_feet & 8 != 0 {
+            RADIATION_PAL
+        } else {
+            0
+        }
+    }
+}
+
 pub struct Palette {
     pub texture: wgpu::Texture,
     pub view: wgpu::TextureView,
@@ -43,7 +124,7 @@ impl Palette {
     }
 
     pub fn update(&mut self, queue: &wgpu::Queue, data: &[u8]) {
-        assert!(data.len() > 256 * 3);
+        assert!(data.len() >= PALETTE_SIZE);
         let rgba_palette = Self::rgb_to_rgba(data);
         queue.write_texture(
             wgpu::ImageCopyTexture {
@@ -1,23 +1,54 @@
-use std::io::{Cursor, Seek, SeekFrom};
-use byteorder::{ReadBytesExt, LittleEndian};
-use std::mem::size_of;
+use crate::wad::{LumpStore, By, WadError};
 
+// The header is the width, height, left offset and top offset,
+//  followed by the offset of every column.
+const HEADER_SIZE: usize = 8;
+const COLUMN_OFFSET_SIZE: usize = 4;
+// A post is the top delta and length, a padding byte, the pixels
+//  and another padding byte.
+const POST_HEADER_SIZE: usize = 3;
+const POST_EXTRA_SIZE: usize = 4;
+const END_OF_COLUMN: u8 = 0xff;
+
+/// A picture in the patch format, checked while it is read so
+/// that a lump that is cut short or points outside of itself
+/// gives a MalformedLump error rather than a panic.
 pub struct Patch<'a> {
+    name: &'a str,
     data: &'a [u8],
 }
 
-impl<'a> From<&'a [u8]> for Patch<'a> {
-    fn from(data: &'a [u8]) -> Self {
-        Patch { data }
-    }
-}
-
 impl<'a> Patch<'a> {
-    pub fn new(data: &'a [u8]) -> Self {
-        Self {
-            data
+    /// Checks that the header and the column offsets fit in the lump.
+    pub fn new(name: &'a str, data: &'a [u8]) -> Result<Self, WadError> {
+        let patch = Self { name, data };
+
+        if data.len() < HEADER_SIZE {
+            return Err(patch.malformed(format!("Header needs {} bytes, the lump has {}", HEADER_SIZE, data.len())));
+        }
+        if patch.width() < 0 || patch.height() < 0 {
+            return Err(patch.malformed(format!("Bad size {}x{}", patch.width(), patch.height())));
         }
+
+        let columns_end = HEADER_SIZE + patch.width() as usize * COLUMN_OFFSET_SIZE;
+        if columns_end > data.len() {
+            return Err(patch.malformed(format!("{} column offsets run past the end of the lump", patch.width())));
+        }
+
+        Ok(patch)
+    }
+
+    /// Reads the patch lump by name or number.
+    pub fn load(lumps: &'a LumpStore, by: By) -> Result<Self, WadError> {
+        let number = match by {
+            By::Name(name) => lumps.get_lump_number(name)
+                .ok_or_else(|| WadError::MissingLump(name.to_uppercase()))?,
+            By::Number(number) => number,
+        };
+
+        Self::new(lumps.get_lump_name(number)?, lumps.get_lump(By::Number(number))?)
     }
+
     pub fn width(&self) -> i32 {
         self.read_i16_at_offset(0) as i32
     }
@@ -34,41 +65,50 @@ impl<'a> Patch<'a> {
         self.read_i16_at_offset(6) as i32
     }
 
-    pub fn get_column(&self, index: u64) -> PatchColumn {
-        const BASE_COLUMN_OFFSET: u64 = 8;
+    pub fn get_column(&self, index: usize) -> Result<PatchColumn<'a>, WadError> {
+        if index >= self.width() as usize {
+            return Err(self.malformed(format!("No column {} in a patch {} wide", index, self.width())));
+        }
+
+        let offset_position = HEADER_SIZE + index * COLUMN_OFFSET_SIZE;
+        let column_offset = self.read_i32_at_offset(offset_position) as u32 as usize;
+        if column_offset >= self.data.len() {
+            return Err(self.malformed(format!("Column {} starts at {}, past the end of the lump", index, column_offset)));
+        }
 
-        let column_offset = self.read_i32_at_offset(
-            BASE_COLUMN_OFFSET + (index * size_of::<i32>() as u64)) as u64;
+        Ok(PatchColumn { name: self.name, data: self.data, column_offset })
+    }
 
-        PatchColumn::new(self.data, column_offset)
+    fn read_i16_at_offset(&self, offset: usize) -> i16 {
+        i16::from_le_bytes([self.data[offset], self.data[offset + 1]])
     }
 
-    fn read_i16_at_offset(&self, offset: u64) -> i16 {
-        let mut cursor = Cursor::new(self.data);
-        cursor.seek(SeekFrom::Start(offset)).unwrap();
-        cursor.read_i16::<LittleEndian>().unwrap()
+    fn read_i32_at_offset(&self, offset: usize) -> i32 {
+        let bytes = &self.data[offset..offset + 4];
+        i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
     }
 
-    fn read_i32_at_offset(&self, offset: u64) -> i32 {
-        let mut cursor = Cursor::new(self.data);
-        cursor.seek(SeekFrom::Start(offset)).unwrap();
-        cursor.read_i32::<LittleEndian>().unwrap()
+    fn malformed(&self, reason: String) -> WadError {
+        WadError::MalformedLump { name: self.name.to_string(), reason }
     }
 }
 
+#[derive(Copy, Clone)]
 pub struct PatchColumn<'a> {
+    name: &'a str,
     data: &'a [u8],
-    column_offset: u64,
+    column_offset: usize,
 }
 
-
 impl<'a> PatchColumn<'a> {
-    pub fn new(data: &'a [u8], column_offset: u64) -> Self {
-        Self { data, column_offset }
+    /// Offset of the column from the start of the patch.
+    pub fn offset(&self) -> usize {
+        self.column_offset
     }
 
-    pub fn posts(&self) -> PostIterator {
-        PostIterator { data: self.data, current_offset: self.column_offset as usize }
+    /// The posts of the column, each one checked to fit in the lump.
+    pub fn posts(&self) -> PostIterator<'a> {
+        PostIterator { name: self.name, data: self.data, current_offset: Some(self.column_offset) }
     }
 }
 
@@ -87,33 +127,135 @@ impl Post<'_> {
     }
 
     pub fn data(&self) -> &[u8] {
-        let length = self.length() as usize;
-        &self.data[(self.offset + 3)..(self.offset + 3 + length)]
+        let start = self.offset + POST_HEADER_SIZE;
+        &self.data[start..start + self.length() as usize]
+    }
+}
+
+impl<'a> Post<'a> {
+    /// Like data, but borrows from the patch rather than the post.
+    pub fn into_data(self) -> &'a [u8] {
+        let start = self.offset + POST_HEADER_SIZE;
+        &self.data[start..start + self.length() as usize]
     }
 }
 
 pub struct PostIterator<'a> {
+    name: &'a str,
     data: &'a [u8],
-    current_offset: usize,
+    // None after the end of the column or an error.
+    current_offset: Option<usize>,
 }
 
 impl<'a> Iterator for PostIterator<'a> {
-    type Item = Post<'a>;
+    type Item = Result<Post<'a>, WadError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.data[self.current_offset] == 0xff {
-            return None;
+        let current_offset = self.current_offset.take()?;
+
+        match self.data.get(current_offset) {
+            Some(&END_OF_COLUMN) => return None,
+            Some(_) => {}
+            None => return Some(Err(WadError::MalformedLump {
+                name: self.name.to_string(),
+                reason: format!("Column runs past the end of the lump at {}", current_offset),
+            })),
         }
 
-        let data_length = self.data[self.current_offset + 1];
-        let total_length = data_length + 4; // post contains 4 bytes other than the actual data
+        // post contains 4 bytes other than the actual data
+        let post_end = match self.data.get(current_offset + 1) {
+            Some(&data_length) if current_offset + data_length as usize + POST_EXTRA_SIZE <= self.data.len() =>
+                current_offset + data_length as usize + POST_EXTRA_SIZE,
+            _ => return Some(Err(WadError::MalformedLump {
+                name: self.name.to_string(),
+                reason: format!("Post at {} runs past the end of the lump", current_offset),
+            })),
+        };
 
-        let current_offset = self.current_offset;
-        self.current_offset += total_length as usize;
+        self.current_offset = Some(post_end);
 
-        Some(Post {
+        Some(Ok(Post {
             data: self.data,
             offset: current_offset,
-        })
+        }))
     }
-}
\ No newline at end of file
+}
+
+/// A patch of one color, with a post for every column.
+#[cfg(test)]
+pub(crate) fn solid_patch(width: i16, height: u8, color: u8) -> Vec<u8> {
+    let shorts = |values: &[i16]| values.iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<u8>>();
+
+    let mut data = shorts(&[width, height as i16, width / 2, height as i16]);
+    let columns_start = data.len() + 4 * width as usize;
+    let column_size = height as usize + 5;
+    for x in 0..width as usize {
+        data.extend(((columns_start + x * column_size) as i32).to_le_bytes());
+    }
+    for _ in 0..width {
+        data.extend([0, height, 0]);
+        data.resize(data.len() + height as usize, color);
+        data.extend([0, 0xff]);
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Patch, solid_patch};
+    use crate::wad::WadError;
+
+    fn is_malformed<T>(result: Result<T, WadError>) -> bool {
+        matches!(result, Err(WadError::MalformedLump { name, .. }) if name == "WALL")
+    }
+
+    #[test]
+    fn reads_the_posts_of_a_column() {
+        let data = solid_patch(4, 8, 16);
+        let patch = Patch::new("WALL", &data).unwrap();
+        assert_eq!((patch.width(), patch.height(), patch.left_offset(), patch.top_offset()), (4, 8, 2, 8));
+
+        let posts = patch.get_column(3).unwrap().posts().collect::<Result<Vec<_>, WadError>>().unwrap();
+        assert_eq!(posts.len(), 1);
+        assert_eq!((posts[0].top_delta(), posts[0].data()), (0, &[16u8; 8][..]));
+    }
+
+    #[test]
+    fn long_posts_do_not_overflow() {
+        // A post of 252 or more pixels used to overflow the
+        //  length plus the 4 extra bytes in a u8.
+        let data = solid_patch(1, 255, 16);
+        let patch = Patch::new("WALL", &data).unwrap();
+
+        let mut posts = patch.get_column(0).unwrap().posts();
+        assert_eq!(posts.next().unwrap().unwrap().length(), 255);
+        assert!(posts.next().is_none());
+    }
+
+    #[test]
+    fn rejects_a_short_header_and_column_offsets() {
+        let data = solid_patch(4, 8, 16);
+        assert!(is_malformed(Patch::new("WALL", &data[..6])));
+        assert!(is_malformed(Patch::new("WALL", &data[..20])));
+    }
+
+    #[test]
+    fn rejects_columns_and_posts_outside_the_lump() {
+        let mut data = solid_patch(2, 8, 16);
+        let patch = Patch::new("WALL", &data).unwrap();
+        assert!(is_malformed(patch.get_column(2)));
+
+        // The second column starts past the end.
+        let length = data.len() as i32;
+        data[12..16].copy_from_slice(&length.to_le_bytes());
+        let patch = Patch::new("WALL", &data).unwrap();
+        assert!(is_malformed(patch.get_column(1)));
+
+        // The post of the last column is cut short.
+        let data = solid_patch(2, 8, 16);
+        let patch = Patch::new("WALL", &data[..data.len() - 4]).unwrap();
+        let mut posts = patch.get_column(1).unwrap().posts();
+        assert!(is_malformed(posts.next().unwrap()));
+        assert!(posts.next().is_none());
+    }
+}
@@ -0,0 +1,281 @@
+use crate::rendering::View;
+use crate::rendering::frame::Frame;
+use crate::rendering::draw::{Column, Span};
+use crate::rendering::sky::{ANGLE_TO_SKY_SHIFT, sky_texture_mid};
+use crate::wad::{By, WadError};
+use crate::types::{DoomRealNum, Angle, real, fixed_div, fixed_mul};
+// For the f32 spans, I16F16 wraps with its own methods
+#[allow(unused_imports)]
+use crate::types::RealNum;
+use crate::tables::{ANG90, ANGLE_TO_FINE_SHIFT, fine_sine, fine_cosine};
+
+// Marks a column of a visplane that has not been drawn to.
+const UNMARKED: i32 = i32::MAX;
+
+// Size of a flat, flats are always 64x64 pixels.
+const FLAT_SIZE: usize = 64 * 64;
+
+/// A horizontal surface to be drawn once all walls are done,
+/// collected as the columns of each plane are uncovered.
+pub(crate) struct Visplane {
+    height: DoomRealNum,
+    pic: usize,
+    light_level: i16,
+    min_x: i32,
+    max_x: i32,
+
+    // Here lies the rub for all
+    //  dynamic resize/change of resolution.
+    // Padded by one on both sides, so index with x + 1.
+    top: Vec<i32>,
+    bottom: Vec<i32>,
+}
+
+impl Visplane {
+    fn new(view: &View, height: DoomRealNum, pic: usize, light_level: i16, min_x: i32, max_x: i32) -> Self {
+        Self {
+            height,
+            pic,
+            light_level,
+            min_x,
+            max_x,
+            top: vec![UNMARKED; view.width + 2],
+            bottom: vec![0; view.width + 2],
+        }
+    }
+
+    pub fn top(&self, x: i32) -> i32 {
+        self.top[(x + 1) as usize]
+    }
+
+    pub fn bottom(&self, x: i32) -> i32 {
+        self.bottom[(x + 1) as usize]
+    }
+
+    pub fn mark(&mut self, x: i32, top: i32, bottom: i32) {
+        self.top[(x + 1) as usize] = top;
+        self.bottom[(x + 1) as usize] = bottom;
+    }
+}
+
+/// The visplanes of a frame and the per row span state.
+pub(crate) struct Planes {
+    pub(crate) visplanes: Vec<Visplane>,
+
+    // spanstart holds the start of a plane span
+    // initialized to 0 at start
+    span_start: Vec<i32>,
+
+    // texture mapping
+    plane_height: DoomRealNum,
+    cached_height: Vec<DoomRealNum>,
+    cached_distance: Vec<DoomRealNum>,
+    cached_x_step: Vec<DoomRealNum>,
+    cached_y_step: Vec<DoomRealNum>,
+    base_x_scale: DoomRealNum,
+    base_y_scale: DoomRealNum,
+}
+
+impl Planes {
+    // R_ClearPlanes
+    // At begining of frame.
+    pub fn new(view: &View, view_angle: Angle) -> Self {
+        // left to right mapping
+        let angle = (view_angle.wrapping_sub(ANG90) >> ANGLE_TO_FINE_SHIFT) as usize;
+
+        // scale will be unit scale at SCREENWIDTH/2 distance
+        Self {
+            visplanes: Vec::new(),
+            span_start: vec![0; view.height],
+            plane_height: real(0),
+            cached_height: vec![real(0); view.height],
+            cached_distance: vec![real(0); view.height],
+            cached_x_step: vec![real(0); view.height],
+            cached_y_step: vec![real(0); view.height],
+            base_x_scale: fixed_div(fine_cosine(angle), view.centerxfrac),
+            base_y_scale: -fixed_div(fine_sine(angle), view.centerxfrac),
+        }
+    }
+}
+
+impl Frame<'_> {
+    // R_FindPlane
+    pub fn find_plane(&mut self, mut height: DoomRealNum, pic: usize, mut light_level: i16) -> usize {
+        if pic == self.render_data.sky_flat {
+            // all skys map together
+            height = real(0);
+            light_level = 0;
+        }
+
+        let planes = &mut self.planes.visplanes;
+        if let Some(index) = planes.iter().position(|x|
+            x.height == height && x.pic == pic && x.light_level == light_level) {
+            return index;
+        }
+
+        planes.push(Visplane::new(self.view, height, pic, light_level, self.view.width as i32, -1));
+        planes.len() - 1
+    }
+
+    // R_CheckPlane
+    pub fn check_plane(&mut self, index: usize, start: i32, stop: i32) -> usize {
+        let plane = &mut self.planes.visplanes[index];
+
+        let (intersect_low, union_low) = if start < plane.min_x {
+            (plane.min_x, start)
+        } else {
+            (start, plane.min_x)
+        };
+
+        let (intersect_high, union_high) = if stop > plane.max_x {
+            (plane.max_x, stop)
+        } else {
+            (stop, plane.max_x)
+        };
+
+        if (intersect_low..=intersect_high).all(|x| plane.top(x) == UNMARKED) {
+            // use the same one
+            plane.min_x = union_low;
+            plane.max_x = union_high;
+            return index;
+        }
+
+        // make a new visplane
+        let (height, pic, light_level) = (plane.height, plane.pic, plane.light_level);
+        self.planes.visplanes.push(Visplane::new(self.view, height, pic, light_level, start, stop));
+        self.planes.visplanes.len() - 1
+    }
+
+    // R_MapPlane
+    // Uses global vars:
+    //  planeheight
+    //  ds_source
+    //  basexscale
+    //  baseyscale
+    //  viewx
+    //  viewy
+    fn map_plane(&mut self, y: i32, x1: i32, x2: i32, source: &[u8], light_row: usize) {
+        debug_assert!(x1 <= x2 && x1 >= 0 && (x2 as usize) < self.view.width && (y as usize) < self.view.height);
+
+        let planes = &mut self.planes;
+        let row = y as usize;
+
+        if planes.plane_height != planes.cached_height[row] {
+            planes.cached_height[row] = planes.plane_height;
+            planes.cached_distance[row] = fixed_mul(planes.plane_height, self.view.y_slope[row]);
+            planes.cached_x_step[row] = fixed_mul(planes.cached_distance[row], planes.base_x_scale);
+            planes.cached_y_step[row] = fixed_mul(planes.cached_distance[row], planes.base_y_scale);
+        }
+
+        let distance = planes.cached_distance[row];
+        let length = fixed_mul(distance, self.view.dist_scale[x1 as usize]);
+        let angle = (self.view_angle.wrapping_add(self.view.x_to_view_angle[x1 as usize]) >> ANGLE_TO_FINE_SHIFT) as usize;
+
+        let span = Span {
+            y,
+            x1,
+            x2,
+            x_frac: self.view_x.wrapping_add(fixed_mul(fine_cosine(angle), length)),
+            y_frac: (-self.view_y).wrapping_sub(fixed_mul(fine_sine(angle), length)),
+            x_step: planes.cached_x_step[row],
+            y_step: planes.cached_y_step[row],
+            source,
+            colormap: match self.fixed_colormap {
+                Some(fixed_colormap) => self.render_data.lighting.colormap(fixed_colormap),
+                None => self.render_data.lighting.z_light(light_row, distance),
+            },
+        };
+
+        self.draw_span(&span);
+    }
+
+    // R_MakeSpans
+    // Closes the spans ending at column x - 1 and opens the
+    //  ones starting at column x.
+    fn make_spans(&mut self, x: i32, plane: &Visplane, source: &[u8], light_row: usize) {
+        let (mut t1, mut b1) = (plane.top(x - 1), plane.bottom(x - 1));
+        let (mut t2, mut b2) = (plane.top(x), plane.bottom(x));
+
+        while t1 < t2 && t1 <= b1 {
+            self.map_plane(t1, self.planes.span_start[t1 as usize], x - 1, source, light_row);
+            t1 += 1;
+        }
+
+        while b1 > b2 && b1 >= t1 {
+            self.map_plane(b1, self.planes.span_start[b1 as usize], x - 1, source, light_row);
+            b1 -= 1;
+        }
+
+        while t2 < t1 && t2 <= b2 {
+            self.planes.span_start[t2 as usize] = x;
+            t2 += 1;
+        }
+
+        while b2 > b1 && b2 >= t2 {
+            self.planes.span_start[b2 as usize] = x;
+            b2 -= 1;
+        }
+    }
+
+    // R_DrawPlanes
+    // At the end of each frame.
+    pub fn draw_planes(&mut self) -> Result<(), WadError> {
+        let render_data = self.render_data;
+        let lumps = self.lumps;
+        let visplanes = std::mem::take(&mut self.planes.visplanes);
+
+        for plane in &visplanes {
+            if plane.min_x > plane.max_x {
+                continue;
+            }
+
+            // sky flat
+            if plane.pic == render_data.sky_flat {
+                // Sky is allways drawn full bright,
+                //  i.e. colormaps[0] is used.
+                // Because of this hack, sky is not affected
+                //  by INVUL inverse mapping.
+                for x in plane.min_x..=plane.max_x {
+                    let (yl, yh) = (plane.top(x), plane.bottom(x));
+
+                    if yl <= yh {
+                        let angle = self.view_angle.wrapping_add(self.view.x_to_view_angle[x as usize]) >> ANGLE_TO_SKY_SHIFT;
+                        let source = render_data.texture.get_column(lumps, self.level.sky_texture, angle as i32)?;
+                        self.draw_column(&Column {
+                            x,
+                            yl,
+                            yh,
+                            iscale: self.view.psprite_iscale,
+                            texture_mid: sky_texture_mid(),
+                            source,
+                            colormap: render_data.lighting.colormap(0),
+                        });
+                    }
+                }
+                continue;
+            }
+
+            // regular flat
+            let lump = render_data.flats.flat_lump(plane.pic);
+            let source = lumps.get_lump(By::Number(lump))?;
+            if source.len() < FLAT_SIZE {
+                return Err(WadError::MalformedLump {
+                    name: lumps.get_lump_name(lump)?.to_string(),
+                    reason: format!("Flat is {} bytes, expected {}", source.len(), FLAT_SIZE),
+                });
+            }
+
+            self.planes.plane_height = plane.height.wrapping_sub(self.view_z).wrapping_abs();
+            let light_row = self.light_row(plane.light_level, 0);
+
+            // The padding on both sides of the plane is left unmarked,
+            //  which closes every span still open at the edges.
+            for x in plane.min_x..=plane.max_x + 1 {
+                self.make_spans(x, plane, source, light_row);
+            }
+        }
+
+        self.planes.visplanes = visplanes;
+        Ok(())
+    }
+}
@@ -3,14 +3,16 @@ use wgpu::{RenderPipeline, Extent3d, TextureFormat};
 use wgpu::util::DeviceExt;
 use image::GenericImageView;
 use crate::rendering::patch::Patch;
+use crate::wad::WadError;
 use crate::rendering::palette::Palette;
 
-const RENDER_WIDTH: usize = 320;
-const RENDER_HEIGHT: usize = 200;
+pub const RENDER_WIDTH: usize = 320;
+pub const RENDER_HEIGHT: usize = 200;
 
 pub trait Renderer {
     fn set_palette(&mut self, palette: &[u8]);
-    fn draw_patch(&mut self, x: i32, y: i32, screen_index: usize, patch: &Patch);
+    fn draw_patch(&mut self, x: i32, y: i32, screen_index: usize, patch: &Patch) -> Result<(), WadError>;
+    fn screen_mut(&mut self, screen_index: usize) -> &mut [u8];
     fn present(&mut self);
 }
 
@@ -493,7 +495,7 @@ impl Renderer for WGPURenderer {
         self.current_palette.update(&self.queue, palette);
     }
 
-    fn draw_patch(&mut self, mut x: i32, mut y: i32, screen_index: usize, patch: &Patch) {
+    fn draw_patch(&mut self, mut x: i32, mut y: i32, screen_index: usize, patch: &Patch) -> Result<(), WadError> {
         x -= patch.left_offset() as i32;
         y -= patch.top_offset() as i32;
 
@@ -502,15 +504,16 @@ impl Renderer for WGPURenderer {
             || y < 0
             || y + patch.height() > RENDER_HEIGHT as i32
             || screen_index > 4 {
-            eprintln!("draw_patch: bad patch (ignored)");
-            return;
+            // Bad patch, ignored like V_DrawPatch does without RANGECHECK.
+            return Ok(());
         }
 
         let width = patch.width();
         for patch_column in 0..width {
-            let column = patch.get_column(patch_column as u64); // TODO: Rewrite to use iterator, ie patch.columns()
+            let column = patch.get_column(patch_column as usize)?; // TODO: Rewrite to use iterator, ie patch.columns()
 
             for post in column.posts() {
+                let post = post?;
                 let screen_x = x + patch_column;
 
                 let source = post.data();
@@ -523,6 +526,12 @@ impl Renderer for WGPURenderer {
                 }
             }
         }
+
+        Ok(())
+    }
+
+    fn screen_mut(&mut self, screen_index: usize) -> &mut [u8] {
+        &mut self.pixels[screen_index]
     }
 
     fn present(&mut self) {
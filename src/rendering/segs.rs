@@ -0,0 +1,641 @@
+use crate::rendering::frame::Frame;
+use crate::rendering::draw::{Column, MaskedColumn};
+use crate::level::linedefs::{ML_DONTPEGBOTTOM, ML_DONTPEGTOP};
+use crate::wad::WadError;
+use crate::types::{DoomRealNum, Angle, real, fixed_mul, from_fixed_bits, FRAC_BITS, RealNum};
+use crate::tables::{ANG90, ANG180, ANGLE_TO_FINE_SHIFT, fine_sine, fine_tangent};
+
+// Fixed point heights are kept in 20.12 while stepping
+// along the wall to avoid overflows.
+const HEIGHT_BITS: i32 = 12;
+const HEIGHT_UNIT: i32 = 1 << HEIGHT_BITS;
+
+// Silhouette, needed for clipping Segs (mainly)
+// and sprites representing things.
+pub(crate) const SIL_NONE: u8 = 0;
+pub(crate) const SIL_BOTTOM: u8 = 1;
+pub(crate) const SIL_TOP: u8 = 2;
+pub(crate) const SIL_BOTH: u8 = 3;
+
+// Marks a masked texture column as already drawn.
+const MASKED_DRAWN: i32 = i32::MAX;
+
+/// A drawn wall range, kept for clipping sprites and drawing
+/// masked mid textures once all walls are done, see drawseg_t.
+#[derive(Copy, Clone)]
+pub(crate) struct DrawSeg {
+    pub(crate) seg: usize,
+    pub(crate) x1: i32,
+    pub(crate) x2: i32,
+
+    pub(crate) scale1: DoomRealNum,
+    pub(crate) scale2: DoomRealNum,
+    pub(crate) scale_step: DoomRealNum,
+
+    // 0=none, 1=bottom, 2=top, 3=both
+    pub(crate) silhouette: u8,
+
+    // do not clip sprites above this
+    pub(crate) bottom_sil_height: DoomRealNum,
+
+    // do not clip sprites below this
+    pub(crate) top_sil_height: DoomRealNum,
+
+    // Pointers to lists for sprite clipping,
+    //  all three adjusted so [x1] is first value.
+    // These are the index of column 0 in the openings.
+    pub(crate) sprite_top_clip: Option<i32>,
+    pub(crate) sprite_bottom_clip: Option<i32>,
+    pub(crate) masked_texture_col: Option<i32>,
+}
+
+/// The rw_* state of the wall range currently being rendered.
+struct WallRange {
+    x: i32,
+    stop_x: i32,
+    center_angle: Angle,
+    offset: DoomRealNum,
+    distance: DoomRealNum,
+    scale: DoomRealNum,
+    scale_step: DoomRealNum,
+
+    top_texture: usize,
+    mid_texture: usize,
+    bottom_texture: usize,
+    top_texture_mid: DoomRealNum,
+    mid_texture_mid: DoomRealNum,
+    bottom_texture_mid: DoomRealNum,
+
+    seg_textured: bool,
+    // row of the wall light tables
+    light_row: usize,
+    // openings index of column 0 of the masked texture columns
+    masked_texture_col: Option<i32>,
+    mark_floor: bool,
+    mark_ceiling: bool,
+
+    top_frac: DoomRealNum,
+    top_step: DoomRealNum,
+    bottom_frac: DoomRealNum,
+    bottom_step: DoomRealNum,
+    pix_high: DoomRealNum,
+    pix_high_step: DoomRealNum,
+    pix_low: DoomRealNum,
+    pix_low_step: DoomRealNum,
+}
+
+// Shifts a height down to HEIGHTBITS precision.
+fn to_height_bits(value: DoomRealNum) -> DoomRealNum {
+    value.shr((FRAC_BITS - HEIGHT_BITS) as u32)
+}
+
+// The screen row of a height in HEIGHTBITS precision, rounded down.
+fn height_row(value: DoomRealNum) -> i32 {
+    value.frac_bits((FRAC_BITS - HEIGHT_BITS) as u32)
+}
+
+impl Frame<'_> {
+    // R_StoreWallRange
+    // A wall segment will be drawn
+    //  between start and stop pixels (inclusive).
+    pub fn store_wall_range(&mut self, seg_index: usize, start: i32, stop: i32) -> Result<(), WadError> {
+        let level = self.level;
+        let seg = &level.segs[seg_index];
+        let side = &level.sides[seg.sidedef];
+        let line = &level.lines[seg.linedef];
+        let front_sector = &level.sectors[self.front_sector];
+        let back_sector = seg.back_sector.map(|x| &level.sectors[x]);
+        let textures = &self.render_data.texture;
+
+        // calculate rw_distance for scale calculation
+        let normal_angle = seg.angle.wrapping_add(ANG90);
+        let mut offset_angle = (normal_angle.wrapping_sub(self.rw_angle1) as i32).wrapping_abs() as Angle;
+
+        if offset_angle > ANG90 {
+            offset_angle = ANG90;
+        }
+
+        let dist_angle = ANG90 - offset_angle;
+        let v1 = &level.vertices[seg.v1];
+        let hyp = self.point_to_dist(v1.x, v1.y);
+        let sine_val = fine_sine((dist_angle >> ANGLE_TO_FINE_SHIFT) as usize);
+        let distance = fixed_mul(hyp, sine_val);
+
+        // calculate scale at both ends and step
+        let scale = self.scale_from_global_angle(
+            self.view_angle.wrapping_add(self.view.x_to_view_angle[start as usize]), normal_angle, distance);
+
+        let (scale2, scale_step) = if stop > start {
+            let scale2 = self.scale_from_global_angle(
+                self.view_angle.wrapping_add(self.view.x_to_view_angle[stop as usize]), normal_angle, distance);
+            (scale2, (scale2 - scale).div_int(stop - start))
+        } else {
+            (scale, real(0))
+        };
+
+        let mut draw_seg = DrawSeg {
+            seg: seg_index,
+            x1: start,
+            x2: stop,
+            scale1: scale,
+            scale2,
+            scale_step,
+            silhouette: SIL_NONE,
+            bottom_sil_height: real(0),
+            top_sil_height: real(0),
+            sprite_top_clip: None,
+            sprite_bottom_clip: None,
+            masked_texture_col: None,
+        };
+
+        // calculate texture boundaries
+        //  and decide if floor / ceiling marks are needed
+        let mut world_top = front_sector.ceiling_height - self.view_z;
+        let mut world_bottom = front_sector.floor_height - self.view_z;
+        let mut world_high = real(0);
+        let mut world_low = real(0);
+
+        let mut wall = WallRange {
+            x: start,
+            stop_x: stop + 1,
+            center_angle: 0,
+            offset: real(0),
+            distance,
+            scale,
+            scale_step,
+            top_texture: 0,
+            mid_texture: 0,
+            bottom_texture: 0,
+            top_texture_mid: real(0),
+            mid_texture_mid: real(0),
+            bottom_texture_mid: real(0),
+            seg_textured: false,
+            light_row: 0,
+            masked_texture_col: None,
+            mark_floor: false,
+            mark_ceiling: false,
+            top_frac: real(0),
+            top_step: real(0),
+            bottom_frac: real(0),
+            bottom_step: real(0),
+            pix_high: real(0),
+            pix_high_step: real(0),
+            pix_low: real(0),
+            pix_low_step: real(0),
+        };
+
+        match back_sector {
+            None => {
+                // single sided line
+                wall.mid_texture = side.mid_texture;
+                // a single sided line is terminal, so it must mark ends
+                wall.mark_floor = true;
+                wall.mark_ceiling = true;
+
+                wall.mid_texture_mid = if line.flags & ML_DONTPEGBOTTOM != 0 {
+                    let v_top = front_sector.floor_height + textures.texture_height(side.mid_texture);
+                    // bottom of texture at bottom
+                    v_top - self.view_z
+                } else {
+                    // top of texture at top
+                    world_top
+                };
+                wall.mid_texture_mid += side.row_offset;
+
+                draw_seg.silhouette = SIL_BOTH;
+                draw_seg.sprite_top_clip = Some(self.screen_height_clip());
+                draw_seg.sprite_bottom_clip = Some(self.neg_one_clip());
+                draw_seg.bottom_sil_height = DoomRealNum::MAX;
+                draw_seg.top_sil_height = DoomRealNum::MIN;
+            }
+            Some(back_sector) => {
+                // two sided line
+                if front_sector.floor_height > back_sector.floor_height {
+                    draw_seg.silhouette = SIL_BOTTOM;
+                    draw_seg.bottom_sil_height = front_sector.floor_height;
+                } else if back_sector.floor_height > self.view_z {
+                    draw_seg.silhouette = SIL_BOTTOM;
+                    draw_seg.bottom_sil_height = DoomRealNum::MAX;
+                }
+
+                if front_sector.ceiling_height < back_sector.ceiling_height {
+                    draw_seg.silhouette |= SIL_TOP;
+                    draw_seg.top_sil_height = front_sector.ceiling_height;
+                } else if back_sector.ceiling_height < self.view_z {
+                    draw_seg.silhouette |= SIL_TOP;
+                    draw_seg.top_sil_height = DoomRealNum::MIN;
+                }
+
+                if back_sector.ceiling_height <= front_sector.floor_height {
+                    draw_seg.sprite_bottom_clip = Some(self.neg_one_clip());
+                    draw_seg.bottom_sil_height = DoomRealNum::MAX;
+                    draw_seg.silhouette |= SIL_BOTTOM;
+                }
+
+                if back_sector.floor_height >= front_sector.ceiling_height {
+                    draw_seg.sprite_top_clip = Some(self.screen_height_clip());
+                    draw_seg.top_sil_height = DoomRealNum::MIN;
+                    draw_seg.silhouette |= SIL_TOP;
+                }
+
+                world_high = back_sector.ceiling_height - self.view_z;
+                world_low = back_sector.floor_height - self.view_z;
+
+                // hack to allow height changes in outdoor areas
+                if front_sector.ceiling_pic == self.render_data.sky_flat
+                    && back_sector.ceiling_pic == self.render_data.sky_flat {
+                    world_top = world_high;
+                }
+
+                wall.mark_floor = world_low != world_bottom
+                    || back_sector.floor_pic != front_sector.floor_pic
+                    || back_sector.light_level != front_sector.light_level;
+
+                wall.mark_ceiling = world_high != world_top
+                    || back_sector.ceiling_pic != front_sector.ceiling_pic
+                    || back_sector.light_level != front_sector.light_level;
+
+                if back_sector.ceiling_height <= front_sector.floor_height
+                    || back_sector.floor_height >= front_sector.ceiling_height {
+                    // closed door
+                    wall.mark_ceiling = true;
+                    wall.mark_floor = true;
+                }
+
+                if world_high < world_top {
+                    // top texture
+                    wall.top_texture = side.top_texture;
+                    wall.top_texture_mid = if line.flags & ML_DONTPEGTOP != 0 {
+                        // top of texture at top
+                        world_top
+                    } else {
+                        let v_top = back_sector.ceiling_height + textures.texture_height(side.top_texture);
+                        // bottom of texture
+                        v_top - self.view_z
+                    };
+                }
+
+                if world_low > world_bottom {
+                    // bottom texture
+                    wall.bottom_texture = side.bottom_texture;
+                    wall.bottom_texture_mid = if line.flags & ML_DONTPEGBOTTOM != 0 {
+                        // bottom of texture at bottom
+                        // top of texture at top
+                        world_top
+                    } else {
+                        world_low
+                    };
+                }
+
+                wall.top_texture_mid += side.row_offset;
+                wall.bottom_texture_mid += side.row_offset;
+
+                // allocate space for masked texture tables
+                if side.mid_texture != 0 {
+                    // masked midtexture
+                    let base = self.openings.len() as i32 - start;
+                    self.openings.resize(self.openings.len() + (stop - start + 1) as usize, 0);
+                    wall.masked_texture_col = Some(base);
+                    draw_seg.masked_texture_col = Some(base);
+                }
+            }
+        }
+
+        // calculate rw_offset (only needed for textured lines)
+        let masked_texture = wall.masked_texture_col.is_some();
+        wall.seg_textured = wall.mid_texture != 0 || wall.top_texture != 0 || wall.bottom_texture != 0 || masked_texture;
+
+        if wall.seg_textured {
+            let mut offset_angle = normal_angle.wrapping_sub(self.rw_angle1);
+
+            if offset_angle > ANG180 {
+                offset_angle = offset_angle.wrapping_neg();
+            }
+
+            if offset_angle > ANG90 {
+                offset_angle = ANG90;
+            }
+
+            let sine_val = fine_sine((offset_angle >> ANGLE_TO_FINE_SHIFT) as usize);
+            wall.offset = fixed_mul(hyp, sine_val);
+
+            if normal_angle.wrapping_sub(self.rw_angle1) < ANG180 {
+                wall.offset = -wall.offset;
+            }
+
+            wall.offset += side.texture_offset + seg.offset;
+            wall.center_angle = ANG90.wrapping_add(self.view_angle).wrapping_sub(normal_angle);
+
+            // calculate light table
+            //  use different light tables
+            //  for horizontal / vertical / diagonal
+            wall.light_row = self.light_row(front_sector.light_level, self.fake_contrast(seg_index));
+        }
+
+        // if a floor / ceiling plane is on the wrong side
+        //  of the view plane, it is definitely invisible
+        //  and doesn't need to be marked.
+        if front_sector.floor_height >= self.view_z {
+            // above view plane
+            wall.mark_floor = false;
+        }
+
+        if front_sector.ceiling_height <= self.view_z
+            && front_sector.ceiling_pic != self.render_data.sky_flat {
+            // below view plane
+            wall.mark_ceiling = false;
+        }
+
+        // calculate incremental stepping values for texture edges
+        world_top = to_height_bits(world_top);
+        world_bottom = to_height_bits(world_bottom);
+
+        let center_y_frac = to_height_bits(self.view.centeryfrac);
+        wall.top_step = -fixed_mul(scale_step, world_top);
+        wall.top_frac = center_y_frac - fixed_mul(world_top, scale);
+
+        wall.bottom_step = -fixed_mul(scale_step, world_bottom);
+        wall.bottom_frac = center_y_frac - fixed_mul(world_bottom, scale);
+
+        if back_sector.is_some() {
+            world_high = to_height_bits(world_high);
+            world_low = to_height_bits(world_low);
+
+            if world_high < world_top {
+                wall.pix_high = center_y_frac - fixed_mul(world_high, scale);
+                wall.pix_high_step = -fixed_mul(scale_step, world_high);
+            }
+
+            if world_low > world_bottom {
+                wall.pix_low = center_y_frac - fixed_mul(world_low, scale);
+                wall.pix_low_step = -fixed_mul(scale_step, world_low);
+            }
+        }
+
+        // render it
+        if wall.mark_ceiling {
+            self.ceiling_plane = self.ceiling_plane.map(|plane| self.check_plane(plane, wall.x, wall.stop_x - 1));
+        }
+
+        if wall.mark_floor {
+            self.floor_plane = self.floor_plane.map(|plane| self.check_plane(plane, wall.x, wall.stop_x - 1));
+        }
+
+        self.render_seg_loop(&mut wall)?;
+
+        // save sprite clipping info
+        if (draw_seg.silhouette & SIL_TOP != 0 || masked_texture) && draw_seg.sprite_top_clip.is_none() {
+            draw_seg.sprite_top_clip = Some(self.openings.len() as i32 - start);
+            self.openings.extend_from_slice(&self.ceiling_clip[start as usize..=stop as usize]);
+        }
+
+        if (draw_seg.silhouette & SIL_BOTTOM != 0 || masked_texture) && draw_seg.sprite_bottom_clip.is_none() {
+            draw_seg.sprite_bottom_clip = Some(self.openings.len() as i32 - start);
+            self.openings.extend_from_slice(&self.floor_clip[start as usize..=stop as usize]);
+        }
+
+        if masked_texture && draw_seg.silhouette & SIL_TOP == 0 {
+            draw_seg.silhouette |= SIL_TOP;
+            draw_seg.top_sil_height = DoomRealNum::MIN;
+        }
+
+        if masked_texture && draw_seg.silhouette & SIL_BOTTOM == 0 {
+            draw_seg.silhouette |= SIL_BOTTOM;
+            draw_seg.bottom_sil_height = DoomRealNum::MAX;
+        }
+
+        self.draw_segs.push(draw_seg);
+        Ok(())
+    }
+
+    // Walls running along the map axes are made a little
+    //  darker or brighter for a fake contrast.
+    fn fake_contrast(&self, seg_index: usize) -> i32 {
+        let seg = &self.level.segs[seg_index];
+        let v1 = &self.level.vertices[seg.v1];
+        let v2 = &self.level.vertices[seg.v2];
+
+        if v1.y == v2.y {
+            -1
+        } else if v1.x == v2.x {
+            1
+        } else {
+            0
+        }
+    }
+
+    // R_RenderSegLoop
+    // Draws zero, one, or two textures (and possibly a masked
+    //  texture) for walls.
+    // Can draw or mark the starting pixel of floor and ceiling
+    //  textures.
+    fn render_seg_loop(&mut self, wall: &mut WallRange) -> Result<(), WadError> {
+        let textures = &self.render_data.texture;
+        let view_height = self.view.height as i32;
+
+        while wall.x < wall.stop_x {
+            let x = wall.x as usize;
+
+            // mark floor / ceiling areas
+            let mut yl = height_row(wall.top_frac + from_fixed_bits(HEIGHT_UNIT - 1));
+
+            // no space above wall?
+            if yl < self.ceiling_clip[x] + 1 {
+                yl = self.ceiling_clip[x] + 1;
+            }
+
+            if wall.mark_ceiling {
+                let top = self.ceiling_clip[x] + 1;
+                let bottom = (yl - 1).min(self.floor_clip[x] - 1);
+
+                if let Some(plane) = self.ceiling_plane.filter(|_| top <= bottom) {
+                    self.planes.visplanes[plane].mark(wall.x, top, bottom);
+                }
+            }
+
+            let mut yh = height_row(wall.bottom_frac);
+
+            if yh >= self.floor_clip[x] {
+                yh = self.floor_clip[x] - 1;
+            }
+
+            if wall.mark_floor {
+                let top = (yh + 1).max(self.ceiling_clip[x] + 1);
+                let bottom = self.floor_clip[x] - 1;
+
+                if let Some(plane) = self.floor_plane.filter(|_| top <= bottom) {
+                    self.planes.visplanes[plane].mark(wall.x, top, bottom);
+                }
+            }
+
+            // texturecolumn and lighting are independent of wall tiers
+            let mut texture_column = 0;
+            let mut iscale = real(0);
+            let mut colormap: &[u8] = &[];
+            if wall.seg_textured {
+                // calculate texture offset
+                let angle = wall.center_angle.wrapping_add(self.view.x_to_view_angle[x]) >> ANGLE_TO_FINE_SHIFT;
+                let column = wall.offset - fixed_mul(fine_tangent(angle as usize), wall.distance);
+                texture_column = column.to_int();
+
+                // calculate lighting
+                colormap = self.scale_colormap(wall.light_row, wall.scale);
+
+                iscale = wall.scale.inverse();
+
+                // save texturecol
+                //  for backdrawing of masked mid texture
+                if let Some(base) = wall.masked_texture_col {
+                    self.openings[(base + wall.x) as usize] = texture_column;
+                }
+            }
+
+            // draw the wall tiers
+            if wall.mid_texture != 0 {
+                // single sided line
+                let source = textures.get_column(self.lumps, wall.mid_texture, texture_column)?;
+                self.draw_column(&Column {
+                    x: wall.x,
+                    yl,
+                    yh,
+                    iscale,
+                    texture_mid: wall.mid_texture_mid,
+                    source,
+                    colormap,
+                });
+
+                self.ceiling_clip[x] = view_height;
+                self.floor_clip[x] = -1;
+            } else {
+                // two sided line
+                if wall.top_texture != 0 {
+                    // top wall
+                    let mut mid = height_row(wall.pix_high);
+                    wall.pix_high += wall.pix_high_step;
+
+                    if mid >= self.floor_clip[x] {
+                        mid = self.floor_clip[x] - 1;
+                    }
+
+                    if mid >= yl {
+                        let source = textures.get_column(self.lumps, wall.top_texture, texture_column)?;
+                        self.draw_column(&Column {
+                            x: wall.x,
+                            yl,
+                            yh: mid,
+                            iscale,
+                            texture_mid: wall.top_texture_mid,
+                            source,
+                            colormap,
+                        });
+                        self.ceiling_clip[x] = mid;
+                    } else {
+                        self.ceiling_clip[x] = yl - 1;
+                    }
+                } else if wall.mark_ceiling {
+                    // no top wall
+                    self.ceiling_clip[x] = yl - 1;
+                }
+
+                if wall.bottom_texture != 0 {
+                    // bottom wall
+                    let mut mid = height_row(wall.pix_low + from_fixed_bits(HEIGHT_UNIT - 1));
+                    wall.pix_low += wall.pix_low_step;
+
+                    // no space above wall?
+                    if mid <= self.ceiling_clip[x] {
+                        mid = self.ceiling_clip[x] + 1;
+                    }
+
+                    if mid <= yh {
+                        let source = textures.get_column(self.lumps, wall.bottom_texture, texture_column)?;
+                        self.draw_column(&Column {
+                            x: wall.x,
+                            yl: mid,
+                            yh,
+                            iscale,
+                            texture_mid: wall.bottom_texture_mid,
+                            source,
+                            colormap,
+                        });
+                        self.floor_clip[x] = mid;
+                    } else {
+                        self.floor_clip[x] = yh + 1;
+                    }
+                } else if wall.mark_floor {
+                    // no bottom wall
+                    self.floor_clip[x] = yh + 1;
+                }
+            }
+
+            wall.scale += wall.scale_step;
+            wall.top_frac += wall.top_step;
+            wall.bottom_frac += wall.bottom_step;
+            wall.x += 1;
+        }
+
+        Ok(())
+    }
+
+    // R_RenderMaskedSegRange
+    pub fn render_masked_seg_range(&mut self, draw_seg_index: usize, x1: i32, x2: i32) -> Result<(), WadError> {
+        let level = self.level;
+        let lumps = self.lumps;
+        let draw_seg = &self.draw_segs[draw_seg_index];
+        let (base, top_clip, bottom_clip) = match (draw_seg.masked_texture_col, draw_seg.sprite_top_clip, draw_seg.sprite_bottom_clip) {
+            (Some(base), Some(top_clip), Some(bottom_clip)) => (base, top_clip, bottom_clip),
+            _ => return Ok(()),
+        };
+
+        let seg = &level.segs[draw_seg.seg];
+        let side = &level.sides[seg.sidedef];
+        let line = &level.lines[seg.linedef];
+        let front_sector = &level.sectors[seg.front_sector];
+        let back_sector = match seg.back_sector {
+            Some(back_sector) => &level.sectors[back_sector],
+            None => return Ok(()),
+        };
+        let texture = side.mid_texture;
+        let light_row = self.light_row(front_sector.light_level, self.fake_contrast(draw_seg.seg));
+
+        let scale_step = draw_seg.scale_step;
+        let mut scale = draw_seg.scale1.wrapping_add(scale_step.wrapping_mul_int(x1 - draw_seg.x1));
+
+        // find positioning
+        let mut texture_mid = if line.flags & ML_DONTPEGBOTTOM != 0 {
+            front_sector.floor_height.max(back_sector.floor_height)
+                + self.render_data.texture.texture_height(texture) - self.view_z
+        } else {
+            front_sector.ceiling_height.min(back_sector.ceiling_height) - self.view_z
+        };
+        texture_mid += side.row_offset;
+
+        // draw the columns
+        for x in x1..=x2 {
+            let column_index = (base + x) as usize;
+
+            if self.openings[column_index] != MASKED_DRAWN {
+                let posts = self.render_data.texture.masked_column(lumps, texture, self.openings[column_index])?;
+
+                self.draw_masked_column(&MaskedColumn {
+                    x,
+                    posts: &posts,
+                    top_screen: self.view.centeryfrac - fixed_mul(texture_mid, scale),
+                    scale,
+                    iscale: scale.inverse(),
+                    texture_mid,
+                    floor_clip: self.openings[(bottom_clip + x) as usize],
+                    ceiling_clip: self.openings[(top_clip + x) as usize],
+                    // calculate lighting
+                    colormap: Some(self.scale_colormap(light_row, scale)),
+                });
+
+                self.openings[column_index] = MASKED_DRAWN;
+            }
+
+            scale = scale.wrapping_add(scale_step);
+        }
+
+        Ok(())
+    }
+}
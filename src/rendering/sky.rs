@@ -0,0 +1,36 @@
+use crate::game_context::GameMode;
+use crate::types::{DoomRealNum, real};
+
+// SKY, store the number for name.
+pub const SKY_FLAT_NAME: &str = "F_SKY1";
+
+// The sky map is 256*128*4 maps.
+pub const ANGLE_TO_SKY_SHIFT: u32 = 22;
+
+// The sky texture is drawn with its middle at the top of the screen.
+pub fn sky_texture_mid() -> DoomRealNum {
+    real(100)
+}
+
+/// Returns the name of the sky texture used by a map, as chosen
+/// in G_InitNew.
+pub fn sky_texture_name(mode: &GameMode, episode: u32, map: u32) -> &'static str {
+    match mode {
+        GameMode::Commercial => {
+            if map < 12 {
+                "SKY1"
+            } else if map < 21 {
+                "SKY2"
+            } else {
+                "SKY3"
+            }
+        }
+        _ => match episode {
+            2 => "SKY2",
+            3 => "SKY3",
+            // Special Edition sky
+            4 => "SKY4",
+            _ => "SKY1",
+        },
+    }
+}
@@ -1,8 +1,12 @@
-use crate::wad::{LumpStore, ReadWadString, By};
+use crate::wad::{LumpStore, LumpNumber, ReadWadString, By, WadError, wad_string};
+use crate::rendering::patch::{Patch, PatchColumn};
+use crate::rendering::draw::MaskedPost;
+use crate::types::{DoomRealNum, real};
 use std::io::{Cursor, Read, Seek, SeekFrom};
 use byteorder::{LittleEndian, ReadBytesExt};
 use serde::Deserialize;
 use std::mem::size_of;
+use std::sync::OnceLock;
 
 #[derive(Deserialize)]
 struct MapTexturePatchRaw {
@@ -13,6 +17,9 @@ struct MapTexturePatchRaw {
     colormap: i16,
 }
 
+// The size of MapTextureRaw in the lump, without padding.
+const MAP_TEXTURE_SIZE: u64 = 22;
+
 #[derive(Deserialize)]
 struct MapTextureRaw {
     name: [u8; 8],
@@ -27,7 +34,18 @@ struct MapTextureRaw {
 struct TexturePatch {
     originx: i32,
     originy: i32,
-    patch_index: usize,
+    patch: LumpNumber,
+}
+
+// Where the pixels of a texture column come from.
+#[derive(Copy, Clone)]
+enum TextureColumn {
+    // A single post of a patch covering the whole column,
+    //  drawn straight from the patch lump.
+    Patch { lump: LumpNumber, offset: usize },
+    // A column built from several patches,
+    //  at the offset in the composite texture.
+    Composite { offset: usize },
 }
 
 struct Texture {
@@ -35,99 +53,372 @@ struct Texture {
     name: String,
     width: u32,
     height: u32,
+    width_mask: i32,
 
     // All the patches[patchcount]
     //  are drawn back to front into the cached texture.
-
     patches: Vec<TexturePatch>,
-}
 
+    // texturecolumnlump and texturecolumnofs
+    columns: Vec<TextureColumn>,
+
+    // The composite columns, generated on first use.
+    composite_size: usize,
+    composite: OnceLock<Vec<u8>>,
+}
 
 pub struct TextureData {
-    patch_names: Vec<String>,
     textures: Vec<Texture>,
 }
 
-fn read_texture<R: Read>(mut data: R) -> Texture {
-    let map_texture_raw: MapTextureRaw = bincode::deserialize_from(&mut data).unwrap();
+impl TextureData {
+    // R_CheckTextureNumForName
+    pub fn check_texture_num_for_name(&self, name: &str) -> Option<usize> {
+        // "NoTexture" marker.
+        if name.starts_with('-') {
+            return Some(0);
+        }
+
+        self.textures.iter().position(|x| x.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn texture_height(&self, texture: usize) -> DoomRealNum {
+        real(self.textures[texture].height)
+    }
+
+    // R_GetColumn
+    pub fn get_column<'a>(&'a self, lumps: &'a LumpStore, texture: usize, column: i32) -> Result<&'a [u8], WadError> {
+        let texture = &self.textures[texture];
+        let height = texture.height as usize;
+
+        match texture.columns[(column & texture.width_mask) as usize] {
+            TextureColumn::Patch { lump, offset } => {
+                let data = lumps.get_lump(By::Number(lump))?;
+                Ok(&data[offset + 3..offset + 3 + height])
+            }
+            TextureColumn::Composite { offset } => {
+                let composite = match texture.composite.get() {
+                    Some(composite) => composite,
+                    None => {
+                        let composite = generate_composite(lumps, texture)?;
+                        texture.composite.get_or_init(|| composite)
+                    }
+                };
+
+                Ok(&composite[offset..offset + height])
+            }
+        }
+    }
+
+    /// Returns the posts of every patch covering a column of a texture
+    /// with transparent parts, for masked mid textures. The original
+    /// hands the patch column of single patch textures to
+    /// R_DrawMaskedColumn instead.
+    pub fn masked_column<'a>(&self, lumps: &'a LumpStore, texture: usize, column: i32) -> Result<Vec<MaskedPost<'a>>, WadError> {
+        let texture = &self.textures[texture];
+        let column = column & texture.width_mask;
+
+        let mut posts = Vec::new();
+        for texture_patch in &texture.patches {
+            let patch = Patch::load(lumps, By::Number(texture_patch.patch))?;
+
+            let x1 = texture_patch.originx;
+            if column < x1 || column >= x1 + patch.width() {
+                continue;
+            }
+
+            for post in patch.get_column((column - x1) as usize)?.posts() {
+                let post = post?;
+                posts.push(MaskedPost {
+                    top_delta: texture_patch.originy + post.top_delta(),
+                    source: post.into_data(),
+                });
+            }
+        }
+
+        Ok(posts)
+    }
+}
+
+// R_DrawColumnInCache
+// Clip and draw a column
+//  from a patch into a cached post.
+fn draw_column_in_cache(column: PatchColumn, cache: &mut [u8], origin_y: i32) -> Result<(), WadError> {
+    let height = cache.len() as i32;
+
+    for post in column.posts() {
+        let post = post?;
+        let mut position = origin_y + post.top_delta();
+        let mut source = post.into_data();
+
+        if position < 0 {
+            source = &source[(-position as usize).min(source.len())..];
+            position = 0;
+        }
+
+        let count = (source.len() as i32).min(height - position);
+        if count > 0 {
+            let position = position as usize;
+            cache[position..position + count as usize].copy_from_slice(&source[..count as usize]);
+        }
+    }
+
+    Ok(())
+}
+
+// R_GenerateComposite
+// Using the texture definition,
+//  the composite texture is created from the patches,
+//  and each column is cached.
+fn generate_composite(lumps: &LumpStore, texture: &Texture) -> Result<Vec<u8>, WadError> {
+    let mut block = vec![0u8; texture.composite_size];
+    let height = texture.height as usize;
+
+    // Composite the columns together.
+    for texture_patch in &texture.patches {
+        let patch = Patch::load(lumps, By::Number(texture_patch.patch))?;
+        let x1 = texture_patch.originx;
+        let x2 = (x1 + patch.width()).min(texture.width as i32);
+
+        for x in x1.max(0)..x2 {
+            // Column does not have multiple patches?
+            if let TextureColumn::Composite { offset } = texture.columns[x as usize] {
+                draw_column_in_cache(patch.get_column((x - x1) as usize)?, &mut block[offset..offset + height], texture_patch.originy)?;
+            }
+        }
+    }
+
+    Ok(block)
+}
+
+// R_GenerateLookup
+fn generate_lookup(lumps: &LumpStore, texture: &mut Texture) -> Result<(), WadError> {
+    let width = texture.width as usize;
+
+    // Count the number of columns
+    //  that are covered by more than one patch.
+    // Fill in the lump / offset, so columns
+    //  with only a single patch are all done.
+    let mut patch_count = vec![0u32; width];
+    let mut single = vec![None; width];
+
+    for texture_patch in &texture.patches {
+        let patch = Patch::load(lumps, By::Number(texture_patch.patch))?;
+        let x1 = texture_patch.originx;
+        let x2 = (x1 + patch.width()).min(width as i32);
+
+        for x in x1.max(0)..x2 {
+            patch_count[x as usize] += 1;
+            single[x as usize] = Some((texture_patch, patch.get_column((x - x1) as usize)?));
+        }
+    }
+
+    texture.columns = Vec::with_capacity(width);
+    texture.composite_size = 0;
+
+    for x in 0..width {
+        // A column drawn straight from a patch must be one post
+        //  of at least the texture height, otherwise it is
+        //  composited like a multi patch column.
+        let direct = match single[x] {
+            Some((texture_patch, column)) if patch_count[x] == 1 && texture_patch.originy == 0 => {
+                let mut posts = column.posts();
+                match (posts.next().transpose()?, posts.next().transpose()?) {
+                    (Some(post), None) if post.top_delta() == 0 && post.length() as u32 >= texture.height =>
+                        Some(TextureColumn::Patch { lump: texture_patch.patch, offset: column.offset() }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        texture.columns.push(match direct {
+            Some(column) => column,
+            None => {
+                // Use the cached block.
+                let column = TextureColumn::Composite { offset: texture.composite_size };
+                texture.composite_size += texture.height as usize;
+                column
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn read_texture<R: Read>(mut data: R, patch_lookup: &[Option<LumpNumber>]) -> Result<Texture, WadError> {
+    let map_texture_raw: MapTextureRaw = bincode::deserialize_from(&mut data)?;
     let mut patches = Vec::<MapTexturePatchRaw>::new();
     for _ in 0..map_texture_raw.patch_count {
-        patches.push(bincode::deserialize_from(&mut data).unwrap());
+        patches.push(bincode::deserialize_from(&mut data)?);
     }
 
-    let texture_name =
-        String::from_utf8(map_texture_raw.name.into()).unwrap().trim_matches(char::from(0)).to_string();
+    let texture_name = wad_string(&map_texture_raw.name)?;
 
-    Texture {
-        name: texture_name,
-        width: map_texture_raw.width as u32,
-        height: map_texture_raw.height as u32,
-        patches: patches.iter().map(|x| TexturePatch {
+    let patches = patches.iter().map(|x| {
+        let patch = patch_lookup.get(x.patch_index as usize).copied().flatten()
+            .ok_or_else(|| WadError::MalformedLump {
+                name: texture_name.clone(),
+                reason: format!("Missing patch {} in texture", x.patch_index),
+            })?;
+
+        Ok(TexturePatch {
             originx: x.originx as i32,
             originy: x.originy as i32,
-            patch_index: x.patch_index as usize,
-        }).collect(),
+            patch,
+        })
+    }).collect::<Result<Vec<_>, WadError>>()?;
+
+    // R_GetColumn masks the column with the width,
+    //  so a texture needs at least one column.
+    if map_texture_raw.width <= 0 {
+        return Err(WadError::MalformedLump {
+            name: texture_name,
+            reason: format!("Bad texture width {}", map_texture_raw.width),
+        });
     }
+
+    let width = map_texture_raw.width as u32;
+    let height = map_texture_raw.height.max(0) as u32;
+
+    let mut width_mask = 1;
+    while width_mask * 2 <= width {
+        width_mask <<= 1;
+    }
+
+    Ok(Texture {
+        name: texture_name,
+        width,
+        height,
+        width_mask: width_mask as i32 - 1,
+        patches,
+        columns: Vec::new(),
+        composite_size: 0,
+        composite: OnceLock::new(),
+    })
 }
 
-pub fn init_textures(lumps: &LumpStore) -> TextureData {
-    let mut names = lumps.get_lump_cursor(By::Name("PNAMES"));
+// R_InitTextures
+// Initializes the texture list
+//  with the textures from the world map.
+pub fn init_textures(lumps: &LumpStore) -> Result<TextureData, WadError> {
+    // Load the patch names from pnames.lmp.
+    let mut names = lumps.get_lump_cursor(By::Name("PNAMES"))?;
 
-    let map_patch_count = names.read_u32::<LittleEndian>().unwrap();
+    let map_patch_count = names.read_u32::<LittleEndian>()?;
 
-    let mut patch_names = Vec::new();
+    let mut patch_lookup = Vec::new();
     for _ in 0..map_patch_count {
-        patch_names.push(names.read_wad_string().unwrap());
+        patch_lookup.push(lumps.get_patch_number(&names.read_wad_string()?));
     }
 
     // Load the map texture definitions from textures.lmp.
     // The data is contained in one or two lumps,
     //  TEXTURE1 for shareware, plus TEXTURE2 for commercial.
-    let mut map_textures = lumps.get_lump_cursor(By::Name("TEXTURE1"));
+    let mut textures = Vec::new();
+    for lump_name in ["TEXTURE1", "TEXTURE2"] {
+        if lump_name == "TEXTURE2" && lumps.get_lump_number(lump_name).is_none() {
+            break;
+        }
 
-    let numtextures1 = map_textures.read_u32::<LittleEndian>().unwrap() as usize;
-    let max_offset = map_textures.stream_len().unwrap();
+        let mut map_textures = lumps.get_lump_cursor(By::Name(lump_name))?;
 
-    // TODO: Add support for TEXTURE2 (check r_data.c:468)
+        let max_offset = map_textures.stream_len()?;
+        let num_textures = map_textures.read_u32::<LittleEndian>()? as u64;
 
-    let numtextures = numtextures1;
+        // The count is checked against the lump before the
+        //  offsets are read, so a bad count cannot allocate
+        //  or read past the end.
+        let directory_size = size_of::<u32>() as u64 * (num_textures + 1);
+        if directory_size > max_offset {
+            return Err(WadError::MalformedLump {
+                name: lump_name.to_string(),
+                reason: format!("{} texture offsets do not fit in {} bytes", num_textures, max_offset),
+            });
+        }
 
-    let mut textures = Vec::new();
-    let mut texturecolumnlump = Vec::new();
-    let mut texturecolumnofs = Vec::new();
-    let mut texturewidthmask = vec![0u32; numtextures];
-    let mut textureheight = vec![0u32; numtextures];
+        let mut texture_offsets = vec![0u32; num_textures as usize];
+        map_textures.read_u32_into::<LittleEndian>(&mut texture_offsets)?;
+
+        for offset in texture_offsets {
+            let offset = offset as u64;
 
-    let mut texture_offsets = vec![0u32; numtextures];
-    map_textures.read_u32_into::<LittleEndian>(&mut texture_offsets).unwrap();
+            if offset < directory_size || offset + MAP_TEXTURE_SIZE > max_offset {
+                return Err(WadError::MalformedLump {
+                    name: lump_name.to_string(),
+                    reason: format!("Bad texture directory offset {}", offset),
+                });
+            }
 
-    for i in 0..numtextures {
-        let offset = texture_offsets[i] as u64;
+            map_textures.seek(SeekFrom::Start(offset))?;
 
-        if offset > max_offset {
-            panic!("Bad texture directory");
+            let mut texture = read_texture(&mut map_textures, &patch_lookup)?;
+            generate_lookup(lumps, &mut texture)?;
+
+            textures.push(texture);
         }
+    }
+
+    Ok(TextureData {
+        textures
+    })
+}
 
-        map_textures.seek(SeekFrom::Start(offset)).unwrap();
+#[cfg(test)]
+mod tests {
+    use crate::rendering::test_resources;
+    use crate::wad::{store, WadBuilder, WadError};
+    use super::{init_textures, TextureData};
 
-        let texture = read_texture(&mut map_textures);
+    // One 64x128 texture with the WALL patch, at the offset.
+    fn texture1(count: u32, offset: u32, width: i16) -> Vec<u8> {
+        let mut data = count.to_le_bytes().to_vec();
+        data.extend(offset.to_le_bytes());
+        data.extend(b"BADTEX\0\0");
+        data.extend(0i32.to_le_bytes());
+        for value in [width, 128] {
+            data.extend(value.to_le_bytes());
+        }
+        data.extend(0i32.to_le_bytes());
+        for value in [1i16, 0, 0, 0, 1, 0] {
+            data.extend(value.to_le_bytes());
+        }
+        data
+    }
 
-        texturecolumnlump.push(Vec::<i16>::with_capacity(texture.width as usize));
-        texturecolumnofs.push(Vec::<u16>::with_capacity(texture.width as usize));
-        textureheight[i] = texture.height << 16;
+    fn load(texture1: Vec<u8>) -> Result<TextureData, WadError> {
+        let mut pwad = WadBuilder::new();
+        pwad.add_lump("TEXTURE1", texture1).unwrap();
+        init_textures(&store(&[("doom2.wad", &test_resources()), ("bad.wad", &pwad)]))
+    }
 
-        let mut j = 1u32;
-        while j * 2 <= texture.width {
-            j <<= 1;
+    fn malformed(result: Result<TextureData, WadError>) -> Option<String> {
+        match result {
+            Err(WadError::MalformedLump { name, .. }) => Some(name),
+            _ => None,
         }
+    }
 
-        texturewidthmask[i] = j - 1;
+    #[test]
+    fn loads_a_texture() {
+        let textures = load(texture1(1, 8, 64)).unwrap();
+        assert_eq!(textures.check_texture_num_for_name("BADTEX"), Some(0));
+    }
 
-        textures.push(texture);
+    #[test]
+    fn rejects_a_count_larger_than_the_lump() {
+        assert_eq!(malformed(load(texture1(u32::MAX, 8, 64))).as_deref(), Some("TEXTURE1"));
+        assert_eq!(malformed(load(texture1(10, 8, 64))).as_deref(), Some("TEXTURE1"));
     }
 
-    TextureData {
-        patch_names,
-        textures
+    #[test]
+    fn rejects_offsets_outside_the_lump() {
+        assert_eq!(malformed(load(texture1(1, 30, 64))).as_deref(), Some("TEXTURE1"));
+        assert_eq!(malformed(load(texture1(1, 0, 64))).as_deref(), Some("TEXTURE1"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn rejects_a_texture_without_width() {
+        assert_eq!(malformed(load(texture1(1, 8, 0))).as_deref(), Some("BADTEX"));
+    }
+}
@@ -0,0 +1,819 @@
+use crate::rendering::frame::Frame;
+use crate::rendering::draw::{MaskedColumn, MaskedPost};
+use crate::rendering::patch::Patch;
+use crate::rendering::segs::{SIL_BOTTOM, SIL_TOP};
+use crate::rendering::lighting::{LIGHT_SCALE_SHIFT, MAX_LIGHT_SCALE};
+use crate::level::nodes::point_on_line_side;
+use crate::wad::{LumpStore, LumpNumber, Namespace, By, WadError};
+use crate::types::{DoomRealNum, Angle, real, fixed_div, fixed_mul, from_fixed_bits, RealNum};
+use crate::tables::ANG45;
+use std::path::Path;
+
+// Sprite frames are numbered A to \ in the lump names.
+const MAX_SPRITE_FRAMES: usize = 29;
+
+// The low bits of a state frame select the sprite frame,
+//  the high bit makes it full bright.
+pub const FF_FRAMEMASK: u32 = 0x7fff;
+pub const FF_FULLBRIGHT: u32 = 0x8000;
+
+// Sprites closer than this are not drawn.
+const MIN_Z: DoomRealNum = from_fixed_bits(4 << 16);
+
+const BASE_Y_CENTER: i32 = 100;
+
+// Sprites are patches with a special naming convention
+//  so they can be recognized by R_InitSprites.
+// The base name is NNNNFx or NNNNFxFx, with
+//  x indicating the rotation, x = 0, 1-7.
+// The sprite and frame specified by a thing_t
+//  is range checked at run time.
+// A sprite is a patch_t that is assumed to represent
+//  a three dimensional object and may have multiple
+//  rotations pre drawn.
+// Horizontal flipping is used to save space,
+//  thus NNNNF2F5 defines a mirrored patch.
+// Some sprites will only have one picture used
+// for all views: NNNNF0
+#[derive(Copy, Clone)]
+struct SpriteFrame {
+    // If false use 0 for any position.
+    // Note: as eight entries are available,
+    //  we might as well insert the same name eight times.
+    rotate: bool,
+
+    // Lump to use for view angles 0-7.
+    lump: [usize; 8],
+
+    // Flip bit to use for view angles 0-7.
+    flip: [bool; 8],
+}
+
+// A sprite definition:
+//  a number of animation frames.
+struct SpriteDef {
+    frames: Vec<SpriteFrame>,
+}
+
+pub struct SpriteData {
+    // The sprite lumps of all files, PWAD sprites replace
+    //  IWAD sprites with the same name.
+    sprite_lumps: Vec<LumpNumber>,
+    names: Vec<String>,
+    sprites: Vec<SpriteDef>,
+
+    // needed for pre rendering
+    sprite_width: Vec<DoomRealNum>,
+    sprite_offset: Vec<DoomRealNum>,
+    sprite_top_offset: Vec<DoomRealNum>,
+}
+
+impl SpriteData {
+    fn frame(&self, sprite: usize, frame: u32) -> Result<&SpriteFrame, WadError> {
+        let frame = (frame & FF_FRAMEMASK) as usize;
+
+        self.sprites.get(sprite)
+            .and_then(|x| x.frames.get(frame))
+            .ok_or_else(|| WadError::MalformedLump {
+                name: self.names.get(sprite).cloned().unwrap_or_else(|| format!("#{}", sprite)),
+                reason: format!("Invalid sprite frame {}", frame),
+            })
+    }
+}
+
+/// A map object as seen by the renderer, the parts of mobj_t
+/// used by R_ProjectSprite.
+#[derive(Copy, Clone)]
+pub struct ThingSprite {
+    pub(crate) x: DoomRealNum,
+    pub(crate) y: DoomRealNum,
+    pub(crate) z: DoomRealNum,
+    pub(crate) angle: Angle,
+    pub(crate) sprite: usize,
+    pub(crate) frame: u32,
+    pub(crate) sector: usize,
+    // MF_SHADOW, drawn with the fuzz effect
+    pub(crate) shadow: bool,
+}
+
+/// A weapon sprite drawn over the view, see pspdef_t.
+#[derive(Copy, Clone)]
+pub struct PlayerSprite {
+    pub(crate) sprite: usize,
+    pub(crate) frame: u32,
+    pub(crate) sx: DoomRealNum,
+    pub(crate) sy: DoomRealNum,
+    // the player is (flickering) invisible
+    pub(crate) shadow: bool,
+}
+
+/// The sprites to draw in a view.
+#[derive(Copy, Clone, Default)]
+pub struct Sprites<'a> {
+    pub(crate) things: &'a [ThingSprite],
+    pub(crate) player: &'a [PlayerSprite],
+}
+
+// A vissprite_t is a thing
+//  that will be drawn during a refresh.
+// I.e. a sprite object that is partly visible.
+pub(crate) struct VisSprite {
+    x1: i32,
+    x2: i32,
+
+    // for line side calculation
+    gx: DoomRealNum,
+    gy: DoomRealNum,
+
+    // global bottom / top for silhouette clipping
+    gz: DoomRealNum,
+    gzt: DoomRealNum,
+
+    // horizontal position of x1
+    start_frac: DoomRealNum,
+
+    scale: DoomRealNum,
+
+    // negative if flipped
+    x_iscale: DoomRealNum,
+
+    texture_mid: DoomRealNum,
+    patch: usize,
+
+    // for color translation and shadow draw,
+    //  maxbright frames as well
+    // None draws the sprite as a shadow.
+    colormap: Option<usize>,
+}
+
+// R_InstallSpriteLump
+// Local function for R_InitSprites.
+fn install_sprite_lump<'a>(frames: &mut [Option<TempFrame<'a>>], name: &str, lump: usize, file: &'a Path,
+                         frame: u8, rotation: u8, flipped: bool) -> Result<(), WadError> {
+    let bad_frame = |reason: String| WadError::MalformedLump { name: name.to_string(), reason };
+
+    let frame = frame.wrapping_sub(b'A') as usize;
+    let rotation = rotation.wrapping_sub(b'0') as usize;
+
+    if frame >= MAX_SPRITE_FRAMES || rotation > 8 {
+        return Err(bad_frame("Bad frame characters in lump".to_string()));
+    }
+
+    let letter = (b'A' + frame as u8) as char;
+    let temp = frames[frame].get_or_insert(TempFrame::new());
+
+    // A later file that switches the frame between rot=0
+    //  and rotations replaces all of it.
+    if temp.rotate == Some(rotation == 0) && !temp.file.contains(&Some(file)) {
+        *temp = TempFrame::new();
+    }
+
+    if rotation == 0 {
+        // the lump should be used for all rotations
+        match temp.rotate {
+            Some(false) if temp.file[0] == Some(file) => {
+                return Err(bad_frame(format!("Sprite frame {} has multiple rot=0 lumps", letter)));
+            }
+            Some(true) => return Err(bad_frame(format!("Sprite frame {} has rotations and a rot=0 lump", letter))),
+            _ => {}
+        }
+
+        temp.rotate = Some(false);
+        temp.lump = [Some(lump); 8];
+        temp.flip = [flipped; 8];
+        temp.file = [Some(file); 8];
+        return Ok(());
+    }
+
+    // the lump is only used for one rotation
+    if temp.rotate == Some(false) {
+        return Err(bad_frame(format!("Sprite frame {} has rotations and a rot=0 lump", letter)));
+    }
+
+    temp.rotate = Some(true);
+
+    // make 0 based
+    let rotation = rotation - 1;
+
+    // A lump from a later file replaces the one
+    //  it clashes with, instead of being an error.
+    if temp.lump[rotation].is_some() && temp.file[rotation] == Some(file) {
+        return Err(bad_frame(format!("Sprite frame {} rotation {} has two lumps mapped to it", letter, rotation + 1)));
+    }
+
+    temp.lump[rotation] = Some(lump);
+    temp.flip[rotation] = flipped;
+    temp.file[rotation] = Some(file);
+    Ok(())
+}
+
+// sprtemp, the frames of a sprite while its lumps are collected.
+#[derive(Copy, Clone)]
+struct TempFrame<'a> {
+    rotate: Option<bool>,
+    lump: [Option<usize>; 8],
+    flip: [bool; 8],
+    // The file of each lump.
+    file: [Option<&'a Path>; 8],
+}
+
+impl<'a> TempFrame<'a> {
+    fn new() -> Self {
+        Self { rotate: None, lump: [None; 8], flip: [false; 8], file: [None; 8] }
+    }
+}
+
+// R_InitSprites
+// Pass a null terminated list of sprite names
+//  (4 chars exactly) to be used.
+// Builds the sprite rotation matrixes to account
+//  for horizontally flipped sprites.
+// Will report an error if the lumps are inconsistant.
+// Only called at startup.
+//
+// Sprite lump names are 4 characters for the actor,
+//  a letter for the frame, and a number for the rotation.
+// A sprite that is flippable will have an additional
+//  letter/number appended.
+// The rotation character can be 0 to signify no rotations.
+pub fn init_sprites(lumps: &LumpStore, names: &[&str]) -> Result<SpriteData, WadError> {
+    // the sprites between S_START and S_END or SS_START
+    //  and SS_END of every file, merged by name
+    let sprite_lumps = lumps.get_namespace_lumps(Namespace::Sprites);
+    if sprite_lumps.is_empty() {
+        return Err(WadError::MissingLump("S_START".to_string()));
+    }
+    let num_sprite_lumps = sprite_lumps.len();
+
+    // R_InitSpriteLumps
+    // Finds the width and hoffset of all sprites in the wad,
+    //  so the sprite does not need to be cached completely
+    //  just for having the header info ready during rendering.
+    let mut sprite_width = Vec::with_capacity(num_sprite_lumps);
+    let mut sprite_offset = Vec::with_capacity(num_sprite_lumps);
+    let mut sprite_top_offset = Vec::with_capacity(num_sprite_lumps);
+    let mut lump_names = Vec::with_capacity(num_sprite_lumps);
+    let mut lump_files = Vec::with_capacity(num_sprite_lumps);
+
+    for &lump in &sprite_lumps {
+        let data = lumps.get_lump(By::Number(lump))?;
+
+        // Nested markers have no header.
+        let (width, offset, top_offset) = if data.len() >= 8 {
+            let patch = Patch::new(lumps.get_lump_name(lump)?, data)?;
+            (patch.width(), patch.left_offset(), patch.top_offset())
+        } else {
+            (0, 0, 0)
+        };
+
+        sprite_width.push(real(width));
+        sprite_offset.push(real(offset));
+        sprite_top_offset.push(real(top_offset));
+        lump_names.push(lumps.get_lump_name(lump)?.as_bytes());
+        lump_files.push(lumps.get_lump_file(lump)?);
+    }
+
+    // the lumps in the order they were loaded,
+    //  so frames of later files replace earlier ones
+    let mut load_order: Vec<usize> = (0..num_sprite_lumps).collect();
+    load_order.sort_by_key(|&lump| Into::<usize>::into(sprite_lumps[lump]));
+
+    // R_InitSpriteDefs
+    // scan all the lump names for each of the names,
+    //  noting the highest frame letter.
+    // Just compare 4 characters as ints
+    let mut sprites = Vec::with_capacity(names.len());
+    for &name in names {
+        let mut frames = [None; MAX_SPRITE_FRAMES];
+
+        // scan the lumps,
+        //  filling in the frames for whatever is found
+        for &lump in &load_order {
+            let (lump_name, file) = (lump_names[lump], lump_files[lump]);
+            if lump_name.len() < 6 || &lump_name[..4] != name.as_bytes() {
+                continue;
+            }
+
+            install_sprite_lump(&mut frames, name, lump, file, lump_name[4], lump_name[5], false)?;
+
+            if lump_name.len() >= 8 {
+                install_sprite_lump(&mut frames, name, lump, file, lump_name[6], lump_name[7], true)?;
+            }
+        }
+
+        // check the frames that were found for completeness
+        let max_frame = frames.iter().rposition(|x| x.is_some()).map_or(0, |x| x + 1);
+
+        let mut sprite_frames = Vec::with_capacity(max_frame);
+        for (frame, temp) in frames[..max_frame].iter().enumerate() {
+            let temp = temp.ok_or_else(|| WadError::MalformedLump {
+                name: name.to_string(),
+                reason: format!("No patches found for frame {}", (b'A' + frame as u8) as char),
+            })?;
+
+            // must have all 8 frames
+            let mut lump = [0; 8];
+            for (rotation, x) in temp.lump.iter().enumerate() {
+                lump[rotation] = x.ok_or_else(|| WadError::MalformedLump {
+                    name: name.to_string(),
+                    reason: format!("Frame {} is missing rotations", (b'A' + frame as u8) as char),
+                })?;
+            }
+
+            sprite_frames.push(SpriteFrame {
+                rotate: temp.rotate == Some(true),
+                lump,
+                flip: temp.flip,
+            });
+        }
+
+        sprites.push(SpriteDef {
+            frames: sprite_frames,
+        });
+    }
+
+    Ok(SpriteData {
+        sprite_lumps,
+        names: names.iter().map(|x| x.to_string()).collect(),
+        sprites,
+        sprite_width,
+        sprite_offset,
+        sprite_top_offset,
+    })
+}
+
+impl Frame<'_> {
+    // R_AddSprites
+    // During BSP traversal, this adds sprites by sector.
+    pub fn add_sprites(&mut self, sector: usize) {
+        // BSP is traversed by subsector.
+        // A sector might have been split into several
+        //  subsectors during BSP building.
+        // Thus we check whether its already added.
+        self.sectors_seen[sector] = true;
+    }
+
+    // Picks the colormap of a vissprite, spritelights[index]
+    //  being the scale lights of the sprite's sector.
+    fn sprite_colormap(&self, shadow: bool, frame: u32, light_row: usize, index: usize) -> Option<usize> {
+        if shadow {
+            // shadow draw
+            None
+        } else if self.fixed_colormap.is_some() {
+            // fixed map
+            self.fixed_colormap
+        } else if frame & FF_FULLBRIGHT != 0 {
+            // full bright
+            Some(0)
+        } else {
+            // diminished light
+            Some(self.view.scale_light[light_row][index])
+        }
+    }
+
+    // Projects the things of every sector marked by add_sprites
+    //  once the BSP traversal is done.
+    pub fn project_sprites(&mut self) -> Result<(), WadError> {
+        let things = self.sprites.things;
+
+        for thing in things {
+            if self.sectors_seen.get(thing.sector) == Some(&true) {
+                self.project_sprite(thing)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // R_ProjectSprite
+    // Generates a vissprite for a thing
+    //  if it might be visible.
+    fn project_sprite(&mut self, thing: &ThingSprite) -> Result<(), WadError> {
+        let render_data = self.render_data;
+        let sprites = &render_data.sprites;
+
+        // transform the origin point
+        let tr_x = thing.x.wrapping_sub(self.view_x);
+        let tr_y = thing.y.wrapping_sub(self.view_y);
+
+        let gxt = fixed_mul(tr_x, self.view_cos);
+        let gyt = -fixed_mul(tr_y, self.view_sin);
+
+        let tz = gxt.wrapping_sub(gyt);
+
+        // thing is behind view plane?
+        if tz < MIN_Z {
+            return Ok(());
+        }
+
+        let x_scale = fixed_div(self.view.projection, tz);
+
+        let gxt = -fixed_mul(tr_x, self.view_sin);
+        let gyt = fixed_mul(tr_y, self.view_cos);
+        let mut tx = -(gyt.wrapping_add(gxt));
+
+        // too far off the side?
+        if tx.wrapping_abs() > tz.wrapping_mul_int(4) {
+            return Ok(());
+        }
+
+        // decide which patch to use for sprite relative to player
+        let sprite_frame = sprites.frame(thing.sprite, thing.frame)?;
+
+        let (lump, flip) = if sprite_frame.rotate {
+            // choose a different rotation based on player view
+            let angle = self.point_to_angle(thing.x, thing.y);
+            let rotation = (angle.wrapping_sub(thing.angle).wrapping_add((ANG45 / 2).wrapping_mul(9)) >> 29) as usize;
+            (sprite_frame.lump[rotation], sprite_frame.flip[rotation])
+        } else {
+            // use single rotation for all views
+            (sprite_frame.lump[0], sprite_frame.flip[0])
+        };
+
+        // calculate edges of the shape
+        tx = tx.wrapping_sub(sprites.sprite_offset[lump]);
+        let x1 = self.view.centerxfrac.wrapping_add(fixed_mul(tx, x_scale)).to_int();
+
+        // off the right side?
+        if x1 > self.view.width as i32 {
+            return Ok(());
+        }
+
+        tx = tx.wrapping_add(sprites.sprite_width[lump]);
+        let x2 = self.view.centerxfrac.wrapping_add(fixed_mul(tx, x_scale)).to_int() - 1;
+
+        // off the left side
+        if x2 < 0 {
+            return Ok(());
+        }
+
+        // store information in a vissprite
+        let gzt = thing.z + sprites.sprite_top_offset[lump];
+        let iscale = fixed_div(real(1), x_scale);
+        let mut vis = VisSprite {
+            x1: x1.max(0),
+            x2: x2.min(self.view.width as i32 - 1),
+            gx: thing.x,
+            gy: thing.y,
+            gz: thing.z,
+            gzt,
+            start_frac: real(0),
+            scale: x_scale,
+            x_iscale: iscale,
+            texture_mid: gzt - self.view_z,
+            patch: lump,
+            colormap: None,
+        };
+
+        if flip {
+            vis.start_frac = sprites.sprite_width[lump] - from_fixed_bits(1);
+            vis.x_iscale = -iscale;
+        }
+
+        if vis.x1 > x1 {
+            vis.start_frac += vis.x_iscale.wrapping_mul_int(vis.x1 - x1);
+        }
+
+        let light_row = self.light_row(self.level.sectors[thing.sector].light_level, 0);
+        let index = x_scale.scale_index(LIGHT_SCALE_SHIFT, MAX_LIGHT_SCALE);
+        vis.colormap = self.sprite_colormap(thing.shadow, thing.frame, light_row, index);
+
+        self.vis_sprites.push(vis);
+        Ok(())
+    }
+
+    // R_DrawVisSprite
+    //  mfloorclip and mceilingclip should also be set.
+    fn draw_vis_sprite(&mut self, vis: &VisSprite, floor_clip: &[i32], ceiling_clip: &[i32]) -> Result<(), WadError> {
+        let render_data = self.render_data;
+        let sprites = &render_data.sprites;
+        let lumps = self.lumps;
+        let patch = Patch::load(lumps, By::Number(sprites.sprite_lumps[vis.patch]))?;
+        let width = patch.width();
+
+        let iscale = vis.x_iscale.wrapping_abs();
+        let top_screen = self.view.centeryfrac.wrapping_sub(fixed_mul(vis.texture_mid, vis.scale));
+        let mut frac = vis.start_frac;
+
+        for x in vis.x1..=vis.x2 {
+            let texture_column = frac.to_int();
+
+            if texture_column >= 0 && texture_column < width {
+                let posts = patch.get_column(texture_column as usize)?.posts()
+                    .map(|post| post.map(|post| MaskedPost {
+                        top_delta: post.top_delta(),
+                        source: post.into_data(),
+                    }))
+                    .collect::<Result<Vec<_>, WadError>>()?;
+
+                let clip = (x - vis.x1) as usize;
+                self.draw_masked_column(&MaskedColumn {
+                    x,
+                    posts: &posts,
+                    top_screen,
+                    scale: vis.scale,
+                    iscale,
+                    texture_mid: vis.texture_mid,
+                    floor_clip: floor_clip[clip],
+                    ceiling_clip: ceiling_clip[clip],
+                    colormap: vis.colormap.map(|x| render_data.lighting.colormap(x)),
+                });
+            }
+
+            frac = frac.wrapping_add(vis.x_iscale);
+        }
+
+        Ok(())
+    }
+
+    // R_DrawPSprite
+    fn draw_player_sprite(&mut self, player_sprite: &PlayerSprite) -> Result<(), WadError> {
+        let render_data = self.render_data;
+        let sprites = &render_data.sprites;
+
+        // decide which patch to use
+        let sprite_frame = sprites.frame(player_sprite.sprite, player_sprite.frame)?;
+        let lump = sprite_frame.lump[0];
+        let flip = sprite_frame.flip[0];
+
+        // calculate edges of the shape
+        let psprite_scale = self.view.psprite_scale;
+        let mut tx = player_sprite.sx - real(160);
+
+        tx -= sprites.sprite_offset[lump];
+        let x1 = (self.view.centerxfrac + fixed_mul(tx, psprite_scale)).to_int();
+
+        // off the right side
+        if x1 > self.view.width as i32 {
+            return Ok(());
+        }
+
+        tx += sprites.sprite_width[lump];
+        let x2 = (self.view.centerxfrac + fixed_mul(tx, psprite_scale)).to_int() - 1;
+
+        // off the left side
+        if x2 < 0 {
+            return Ok(());
+        }
+
+        // store information in a vissprite
+        let mut vis = VisSprite {
+            x1: x1.max(0),
+            x2: x2.min(self.view.width as i32 - 1),
+            gx: real(0),
+            gy: real(0),
+            gz: real(0),
+            gzt: real(0),
+            start_frac: real(0),
+            scale: psprite_scale,
+            x_iscale: self.view.psprite_iscale,
+            texture_mid: real(BASE_Y_CENTER) + from_fixed_bits(1 << 15)
+                - (player_sprite.sy - sprites.sprite_top_offset[lump]),
+            patch: lump,
+            colormap: None,
+        };
+
+        if flip {
+            vis.x_iscale = -self.view.psprite_iscale;
+            vis.start_frac = sprites.sprite_width[lump] - from_fixed_bits(1);
+        }
+
+        if vis.x1 > x1 {
+            vis.start_frac += vis.x_iscale.wrapping_mul_int(vis.x1 - x1);
+        }
+
+        // get light level
+        let sector = self.level.point_in_sector(self.view_x, self.view_y);
+        let light_row = self.light_row(self.level.sectors[sector].light_level, 0);
+        vis.colormap = self.sprite_colormap(player_sprite.shadow, player_sprite.frame, light_row, MAX_LIGHT_SCALE - 1);
+
+        // clip to screen bounds
+        let floor_clip = vec![self.view.height as i32; (vis.x2 - vis.x1 + 1).max(0) as usize];
+        let ceiling_clip = vec![-1; floor_clip.len()];
+        self.draw_vis_sprite(&vis, &floor_clip, &ceiling_clip)
+    }
+
+    // R_DrawSprite
+    fn draw_sprite(&mut self, sprite: &VisSprite) -> Result<(), WadError> {
+        let level = self.level;
+        let width = (sprite.x2 - sprite.x1 + 1).max(0) as usize;
+        let mut clip_bottom = vec![-2; width];
+        let mut clip_top = vec![-2; width];
+
+        // Scan drawsegs from end to start for obscuring segs.
+        // The first drawseg that has a greater scale
+        //  is the clip seg.
+        for index in (0..self.draw_segs.len()).rev() {
+            let draw_seg = self.draw_segs[index];
+
+            // determine if the drawseg obscures the sprite
+            if draw_seg.x1 > sprite.x2 || draw_seg.x2 < sprite.x1
+                || (draw_seg.silhouette == 0 && draw_seg.masked_texture_col.is_none()) {
+                // does not cover sprite
+                continue;
+            }
+
+            let r1 = draw_seg.x1.max(sprite.x1);
+            let r2 = draw_seg.x2.min(sprite.x2);
+
+            let (low_scale, scale) = if draw_seg.scale1 > draw_seg.scale2 {
+                (draw_seg.scale2, draw_seg.scale1)
+            } else {
+                (draw_seg.scale1, draw_seg.scale2)
+            };
+
+            let seg = &level.segs[draw_seg.seg];
+            let (v1, v2) = (&level.vertices[seg.v1], &level.vertices[seg.v2]);
+            if scale < sprite.scale
+                || (low_scale < sprite.scale
+                    && point_on_line_side(sprite.gx, sprite.gy, v1.x, v1.y, v2.x - v1.x, v2.y - v1.y) == 0) {
+                // masked mid texture?
+                if draw_seg.masked_texture_col.is_some() {
+                    self.render_masked_seg_range(index, r1, r2)?;
+                }
+                // seg is behind sprite
+                continue;
+            }
+
+            // clip this piece of the sprite
+            let mut silhouette = draw_seg.silhouette;
+
+            if sprite.gz >= draw_seg.bottom_sil_height {
+                silhouette &= !SIL_BOTTOM;
+            }
+
+            if sprite.gzt <= draw_seg.top_sil_height {
+                silhouette &= !SIL_TOP;
+            }
+
+            for x in r1..=r2 {
+                let clip = (x - sprite.x1) as usize;
+
+                if silhouette & SIL_BOTTOM != 0 && clip_bottom[clip] == -2 {
+                    if let Some(base) = draw_seg.sprite_bottom_clip {
+                        clip_bottom[clip] = self.openings[(base + x) as usize];
+                    }
+                }
+
+                if silhouette & SIL_TOP != 0 && clip_top[clip] == -2 {
+                    if let Some(base) = draw_seg.sprite_top_clip {
+                        clip_top[clip] = self.openings[(base + x) as usize];
+                    }
+                }
+
+            }
+        }
+
+        // all clipping has been performed, so draw the sprite
+
+        // check for unclipped columns
+        for x in clip_bottom.iter_mut().filter(|x| **x == -2) {
+            *x = self.view.height as i32;
+        }
+
+        for x in clip_top.iter_mut().filter(|x| **x == -2) {
+            *x = -1;
+        }
+
+        self.draw_vis_sprite(sprite, &clip_bottom, &clip_top)
+    }
+
+    // R_DrawMasked
+    pub fn draw_masked(&mut self) -> Result<(), WadError> {
+        // R_SortVisSprites
+        let mut vis_sprites = std::mem::take(&mut self.vis_sprites);
+        vis_sprites.sort_by(|a, b| a.scale.partial_cmp(&b.scale).unwrap());
+
+        // draw all vissprites back to front
+        for sprite in &vis_sprites {
+            self.draw_sprite(sprite)?;
+        }
+
+        // render any remaining masked mid textures
+        for index in (0..self.draw_segs.len()).rev() {
+            let draw_seg = self.draw_segs[index];
+            if draw_seg.masked_texture_col.is_some() {
+                let (x1, x2) = (draw_seg.x1, draw_seg.x2);
+                self.render_masked_seg_range(index, x1, x2)?;
+            }
+        }
+
+        // draw the psprites on top of everything
+        let player_sprites = self.sprites.player;
+        for player_sprite in player_sprites {
+            self.draw_player_sprite(player_sprite)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rendering::patch::solid_patch;
+    use crate::wad::store;
+    use crate::types::RealNum;
+    use crate::wad::{WadBuilder, WadError};
+    use super::{init_sprites, SpriteData, Sprites, ThingSprite, PlayerSprite};
+    use crate::game_context::GameMode;
+    use crate::info::{SPR_BAR1, SPR_PISG};
+    use crate::level::{self, test_map::TestMap};
+    use crate::rendering::{View, ViewPoint, init_rendering, render_player_view, test_resources};
+    use crate::rendering::renderer::{RENDER_WIDTH, RENDER_HEIGHT};
+    use crate::types::real;
+
+    // A frame with all eight rotations, three of them flipped.
+    const ROTATIONS: [&str; 5] = ["TROOA1", "TROOA2A8", "TROOA3A7", "TROOA4A6", "TROOA5"];
+
+    // A WAD with the sprite lumps, each as wide as given.
+    fn sprites(names: &[&str], width: i16) -> WadBuilder {
+        let mut wad = WadBuilder::new();
+        wad.add_lump("S_START", Vec::new()).unwrap();
+        for name in names {
+            wad.add_lump(name, solid_patch(width, 8, 1)).unwrap();
+        }
+        wad.add_lump("S_END", Vec::new()).unwrap();
+        wad
+    }
+
+    fn load(iwad: &WadBuilder, pwad: &WadBuilder) -> Result<SpriteData, WadError> {
+        init_sprites(&store(&[("doom2.wad", iwad), ("sprites.wad", pwad)]), &["TROO"])
+    }
+
+    // The widths of the lumps of frame A for every rotation.
+    fn widths(sprites: &SpriteData) -> Vec<i32> {
+        sprites.sprites[0].frames[0].lump.iter()
+            .map(|&lump| sprites.sprite_width[lump].to_int())
+            .collect()
+    }
+
+    #[test]
+    fn later_rotation_replaces_one_rotation() {
+        let sprites = load(&sprites(&ROTATIONS, 16), &sprites(&["TROOA3"], 24)).unwrap();
+        assert!(sprites.sprites[0].frames[0].rotate);
+        assert_eq!(widths(&sprites), [16, 16, 24, 16, 16, 16, 16, 16]);
+    }
+
+    #[test]
+    fn later_rot0_replaces_rotations() {
+        let sprites = load(&sprites(&ROTATIONS, 16), &sprites(&["TROOA0"], 24)).unwrap();
+        assert!(!sprites.sprites[0].frames[0].rotate);
+        assert_eq!(widths(&sprites), [24; 8]);
+    }
+
+    #[test]
+    fn later_rotations_replace_rot0() {
+        let sprites = load(&sprites(&["TROOA0"], 16), &sprites(&ROTATIONS, 24)).unwrap();
+        assert!(sprites.sprites[0].frames[0].rotate);
+        assert_eq!(widths(&sprites), [24; 8]);
+    }
+
+    #[test]
+    fn clash_in_one_file() {
+        let iwad = sprites(&["TROOA0", "TROOA1"], 16);
+        assert!(matches!(load(&iwad, &WadBuilder::new()), Err(WadError::MalformedLump { .. })));
+
+        let pwad = sprites(&["TROOA3", "TROOA3A7"], 24);
+        assert!(matches!(load(&sprites(&ROTATIONS, 16), &pwad), Err(WadError::MalformedLump { .. })));
+    }
+
+    // The column at the center of the view, from the player in the
+    //  west room of the two rooms looking east at a barrel, with
+    //  the pistol raised in front of it.
+    fn center_column(player: &[PlayerSprite]) -> Vec<u8> {
+        let mut pwad = WadBuilder::new();
+        TestMap::two_rooms().add_to(&mut pwad, "MAP01");
+        pwad.add_lump("S_START", Vec::new()).unwrap();
+        pwad.add_lump("PISGA0", solid_patch(24, 32, 250)).unwrap();
+        pwad.add_lump("S_END", Vec::new()).unwrap();
+
+        let lumps = store(&[("doom2.wad", &test_resources()), ("map.wad", &pwad)]);
+        let render_data = init_rendering(&lumps).unwrap();
+        let level = level::load(&lumps, &render_data, &GameMode::Commercial, 1, 1).unwrap();
+
+        let view_point = ViewPoint { x: real(128), y: real(128), z: real(41), angle: 0, extra_light: 0, fixed_colormap: None };
+        let things = [ThingSprite { x: real(200), y: real(128), z: real(0), angle: 0, sprite: SPR_BAR1, frame: 0, sector: 0, shadow: false }];
+        let mut screen = vec![0; RENDER_WIDTH * RENDER_HEIGHT];
+        render_player_view(&View::new(11), &view_point, &level, &render_data, &lumps,
+                           Sprites { things: &things, player }, &mut screen).unwrap();
+
+        screen.iter().skip(RENDER_WIDTH / 2).step_by(RENDER_WIDTH).copied().collect()
+    }
+
+    #[test]
+    fn player_sprite_is_drawn_over_walls_and_things() {
+        // the far wall, the floor of the east room, then the barrel,
+        //  leaving out the rows where they meet
+        let column = center_column(&[]);
+        assert_eq!(column[100..107], [16; 7]);
+        assert_eq!(column[110..119], [96; 9]);
+        assert_eq!(column[122..132], [200; 10]);
+
+        // 32 rows from the view center down
+        let pistol = PlayerSprite { sprite: SPR_PISG, frame: 0, sx: real(160), sy: real(132), shadow: false };
+        let column = center_column(&[pistol]);
+        assert_eq!(column[99], 16);
+        assert_eq!(column[100..132], [250; 32]);
+        assert_eq!(column[132], 200);
+    }
+}
@@ -0,0 +1,130 @@
+// Sound effect numbers and lump names, see sounds.h and sounds.c.
+
+pub type SfxNum = usize;
+
+// sfxenum_t
+pub const SFX_NONE: SfxNum = 0;
+pub const SFX_PISTOL: SfxNum = 1;
+pub const SFX_SHOTGN: SfxNum = 2;
+pub const SFX_SGCOCK: SfxNum = 3;
+pub const SFX_DSHTGN: SfxNum = 4;
+pub const SFX_DBOPN: SfxNum = 5;
+pub const SFX_DBCLS: SfxNum = 6;
+pub const SFX_DBLOAD: SfxNum = 7;
+pub const SFX_PLASMA: SfxNum = 8;
+pub const SFX_BFG: SfxNum = 9;
+pub const SFX_SAWUP: SfxNum = 10;
+pub const SFX_SAWIDL: SfxNum = 11;
+pub const SFX_SAWFUL: SfxNum = 12;
+pub const SFX_SAWHIT: SfxNum = 13;
+pub const SFX_RLAUNC: SfxNum = 14;
+pub const SFX_RXPLOD: SfxNum = 15;
+pub const SFX_FIRSHT: SfxNum = 16;
+pub const SFX_FIRXPL: SfxNum = 17;
+pub const SFX_PSTART: SfxNum = 18;
+pub const SFX_PSTOP: SfxNum = 19;
+pub const SFX_DOROPN: SfxNum = 20;
+pub const SFX_DORCLS: SfxNum = 21;
+pub const SFX_STNMOV: SfxNum = 22;
+pub const SFX_SWTCHN: SfxNum = 23;
+pub const SFX_SWTCHX: SfxNum = 24;
+pub const SFX_PLPAIN: SfxNum = 25;
+pub const SFX_DMPAIN: SfxNum = 26;
+pub const SFX_POPAIN: SfxNum = 27;
+pub const SFX_VIPAIN: SfxNum = 28;
+pub const SFX_MNPAIN: SfxNum = 29;
+pub const SFX_PEPAIN: SfxNum = 30;
+pub const SFX_SLOP: SfxNum = 31;
+pub const SFX_ITEMUP: SfxNum = 32;
+pub const SFX_WPNUP: SfxNum = 33;
+pub const SFX_OOF: SfxNum = 34;
+pub const SFX_TELEPT: SfxNum = 35;
+pub const SFX_POSIT1: SfxNum = 36;
+pub const SFX_POSIT2: SfxNum = 37;
+pub const SFX_POSIT3: SfxNum = 38;
+pub const SFX_BGSIT1: SfxNum = 39;
+pub const SFX_BGSIT2: SfxNum = 40;
+pub const SFX_SGTSIT: SfxNum = 41;
+pub const SFX_CACSIT: SfxNum = 42;
+pub const SFX_BRSSIT: SfxNum = 43;
+pub const SFX_CYBSIT: SfxNum = 44;
+pub const SFX_SPISIT: SfxNum = 45;
+pub const SFX_BSPSIT: SfxNum = 46;
+pub const SFX_KNTSIT: SfxNum = 47;
+pub const SFX_VILSIT: SfxNum = 48;
+pub const SFX_MANSIT: SfxNum = 49;
+pub const SFX_PESIT: SfxNum = 50;
+pub const SFX_SKLATK: SfxNum = 51;
+pub const SFX_SGTATK: SfxNum = 52;
+pub const SFX_SKEPCH: SfxNum = 53;
+pub const SFX_VILATK: SfxNum = 54;
+pub const SFX_CLAW: SfxNum = 55;
+pub const SFX_SKESWG: SfxNum = 56;
+pub const SFX_PLDETH: SfxNum = 57;
+pub const SFX_PDIEHI: SfxNum = 58;
+pub const SFX_PODTH1: SfxNum = 59;
+pub const SFX_PODTH2: SfxNum = 60;
+pub const SFX_PODTH3: SfxNum = 61;
+pub const SFX_BGDTH1: SfxNum = 62;
+pub const SFX_BGDTH2: SfxNum = 63;
+pub const SFX_SGTDTH: SfxNum = 64;
+pub const SFX_CACDTH: SfxNum = 65;
+pub const SFX_SKLDTH: SfxNum = 66;
+pub const SFX_BRSDTH: SfxNum = 67;
+pub const SFX_CYBDTH: SfxNum = 68;
+pub const SFX_SPIDTH: SfxNum = 69;
+pub const SFX_BSPDTH: SfxNum = 70;
+pub const SFX_VILDTH: SfxNum = 71;
+pub const SFX_KNTDTH: SfxNum = 72;
+pub const SFX_PEDTH: SfxNum = 73;
+pub const SFX_SKEDTH: SfxNum = 74;
+pub const SFX_POSACT: SfxNum = 75;
+pub const SFX_BGACT: SfxNum = 76;
+pub const SFX_DMACT: SfxNum = 77;
+pub const SFX_BSPACT: SfxNum = 78;
+pub const SFX_BSPWLK: SfxNum = 79;
+pub const SFX_VILACT: SfxNum = 80;
+pub const SFX_NOWAY: SfxNum = 81;
+pub const SFX_BAREXP: SfxNum = 82;
+pub const SFX_PUNCH: SfxNum = 83;
+pub const SFX_HOOF: SfxNum = 84;
+pub const SFX_METAL: SfxNum = 85;
+pub const SFX_CHGUN: SfxNum = 86;
+pub const SFX_TINK: SfxNum = 87;
+pub const SFX_BDOPN: SfxNum = 88;
+pub const SFX_BDCLS: SfxNum = 89;
+pub const SFX_ITMBK: SfxNum = 90;
+pub const SFX_FLAME: SfxNum = 91;
+pub const SFX_FLAMST: SfxNum = 92;
+pub const SFX_GETPOW: SfxNum = 93;
+pub const SFX_BOSPIT: SfxNum = 94;
+pub const SFX_BOSCUB: SfxNum = 95;
+pub const SFX_BOSSIT: SfxNum = 96;
+pub const SFX_BOSPN: SfxNum = 97;
+pub const SFX_BOSDTH: SfxNum = 98;
+pub const SFX_MANATK: SfxNum = 99;
+pub const SFX_MANDTH: SfxNum = 100;
+pub const SFX_SSSIT: SfxNum = 101;
+pub const SFX_SSDTH: SfxNum = 102;
+pub const SFX_KEENPN: SfxNum = 103;
+pub const SFX_KEENDT: SfxNum = 104;
+pub const SFX_SKEACT: SfxNum = 105;
+pub const SFX_SKESIT: SfxNum = 106;
+pub const SFX_SKEATK: SfxNum = 107;
+pub const SFX_RADIO: SfxNum = 108;
+pub const NUM_SFX: usize = 109;
+
+// The sound lumps are named with a DS prefix, indexed by sfxenum_t.
+pub const SFX_NAMES: [&str; NUM_SFX] = [
+    "none", "pistol", "shotgn", "sgcock", "dshtgn", "dbopn", "dbcls", "dbload", "plasma", "bfg",
+    "sawup", "sawidl", "sawful", "sawhit", "rlaunc", "rxplod", "firsht", "firxpl", "pstart", "pstop",
+    "doropn", "dorcls", "stnmov", "swtchn", "swtchx", "plpain", "dmpain", "popain", "vipain", "mnpain",
+    "pepain", "slop", "itemup", "wpnup", "oof", "telept", "posit1", "posit2", "posit3", "bgsit1",
+    "bgsit2", "sgtsit", "cacsit", "brssit", "cybsit", "spisit", "bspsit", "kntsit", "vilsit", "mansit",
+    "pesit", "sklatk", "sgtatk", "skepch", "vilatk", "claw", "skeswg", "pldeth", "pdiehi", "podth1",
+    "podth2", "podth3", "bgdth1", "bgdth2", "sgtdth", "cacdth", "skldth", "brsdth", "cybdth", "spidth",
+    "bspdth", "vildth", "kntdth", "pedth", "skedth", "posact", "bgact", "dmact", "bspact", "bspwlk",
+    "vilact", "noway", "barexp", "punch", "hoof", "metal", "chgun", "tink", "bdopn", "bdcls",
+    "itmbk", "flame", "flamst", "getpow", "bospit", "boscub", "bossit", "bospn", "bosdth", "manatk",
+    "mandth", "sssit", "ssdth", "keenpn", "keendt", "skeact", "skesit", "skeatk", "radio",
+];
@@ -0,0 +1,154 @@
+use std::f64::consts::PI;
+use std::sync::OnceLock;
+use crate::types::{Angle, DoomRealNum, RealNum};
+
+// Lookup tables for trigonometric functions, see tables.c.
+// The original tables are generated from the formulas below,
+// so they are computed at startup instead of being stored.
+
+pub const FINE_ANGLES: usize = 8192;
+pub const FINE_MASK: usize = FINE_ANGLES - 1;
+
+// 0x100000000 to 0x2000
+pub const ANGLE_TO_FINE_SHIFT: u32 = 19;
+
+// Binary Angle Measurement, BAM.
+pub const ANG45: Angle = 0x20000000;
+pub const ANG90: Angle = 0x40000000;
+pub const ANG180: Angle = 0x80000000;
+pub const ANG270: Angle = 0xc0000000;
+
+pub const SLOPE_RANGE: usize = 2048;
+pub const SLOPE_BITS: u32 = 11;
+
+struct Tables {
+    fine_sine: Vec<DoomRealNum>,
+    fine_tangent: Vec<DoomRealNum>,
+    tan_to_angle: Vec<Angle>,
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+
+    TABLES.get_or_init(|| {
+        // The sine table is extended with a quarter turn
+        // so that it can be used as a cosine table too.
+        let fine_sine = (0..5 * FINE_ANGLES / 4)
+            .map(|i| {
+                let angle = (i as f64 + 0.5) * PI * 2.0 / FINE_ANGLES as f64;
+                DoomRealNum::from_sin(angle)
+            })
+            .collect();
+
+        let fine_tangent = (0..FINE_ANGLES / 2)
+            .map(|i| {
+                let angle = (i as f64 - (FINE_ANGLES / 4) as f64 + 0.5) * PI * 2.0 / FINE_ANGLES as f64;
+                DoomRealNum::from_tan(angle)
+            })
+            .collect();
+
+        // An individual function for tan to angle
+        // would be a lot slower than the lookup.
+        let tan_to_angle = (0..=SLOPE_RANGE)
+            .map(|i| {
+                let angle = (i as f64 / SLOPE_RANGE as f64).atan();
+                (angle / (PI * 2.0) * 4294967296.0) as Angle
+            })
+            .collect();
+
+        Tables {
+            fine_sine,
+            fine_tangent,
+            tan_to_angle,
+        }
+    })
+}
+
+pub fn fine_sine(index: usize) -> DoomRealNum {
+    tables().fine_sine[index]
+}
+
+pub fn fine_cosine(index: usize) -> DoomRealNum {
+    tables().fine_sine[index + FINE_ANGLES / 4]
+}
+
+pub fn fine_tangent(index: usize) -> DoomRealNum {
+    tables().fine_tangent[index]
+}
+
+pub fn tan_to_angle(index: usize) -> Angle {
+    tables().tan_to_angle[index]
+}
+
+// SlopeDiv
+pub fn slope_div(num: u32, den: u32) -> usize {
+    if den < 512 {
+        return SLOPE_RANGE;
+    }
+
+    let ans = (num << 3) / (den >> 8);
+    (ans as usize).min(SLOPE_RANGE)
+}
+
+/// Returns the angle of the vector (x, y), see R_PointToAngle.
+pub fn point_to_angle(x: DoomRealNum, y: DoomRealNum) -> Angle {
+    DoomRealNum::point_to_angle(x, y)
+}
+
+// R_PointToAngle on the fixed_t bits of the vector.
+pub fn point_to_angle_bits(mut x: i32, mut y: i32) -> Angle {
+
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    if x >= 0 {
+        // x >=0
+        if y >= 0 {
+            // y>= 0
+            if x > y {
+                // octant 0
+                tan_to_angle(slope_div(y as u32, x as u32))
+            } else {
+                // octant 1
+                (ANG90 - 1).wrapping_sub(tan_to_angle(slope_div(x as u32, y as u32)))
+            }
+        } else {
+            // y<0
+            y = y.wrapping_neg();
+
+            if x > y {
+                // octant 8
+                tan_to_angle(slope_div(y as u32, x as u32)).wrapping_neg()
+            } else {
+                // octant 7
+                ANG270.wrapping_add(tan_to_angle(slope_div(x as u32, y as u32)))
+            }
+        }
+    } else {
+        // x<0
+        x = x.wrapping_neg();
+
+        if y >= 0 {
+            // y>= 0
+            if x > y {
+                // octant 3
+                (ANG180 - 1).wrapping_sub(tan_to_angle(slope_div(y as u32, x as u32)))
+            } else {
+                // octant 2
+                ANG90.wrapping_add(tan_to_angle(slope_div(x as u32, y as u32)))
+            }
+        } else {
+            // y<0
+            y = y.wrapping_neg();
+
+            if x > y {
+                // octant 4
+                ANG180.wrapping_add(tan_to_angle(slope_div(y as u32, x as u32)))
+            } else {
+                // octant 5
+                (ANG270 - 1).wrapping_sub(tan_to_angle(slope_div(x as u32, y as u32)))
+            }
+        }
+    }
+}
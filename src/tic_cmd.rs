@@ -0,0 +1,42 @@
+// The data sampled per tick (single player)
+// and transmitted to other peers (multiplayer).
+// Mainly movements/button commands per game tick,
+// plus a checksum for internal state consistency.
+// ticcmd_t
+#[derive(Copy, Clone, Default)]
+pub struct TicCmd {
+    pub(crate) forward_move: i8, // *2048 for move
+    pub(crate) side_move: i8,    // *2048 for move
+    pub(crate) angle_turn: i16,  // <<16 for angle delta
+    pub(crate) consistancy: i16, // checks for net game
+    pub(crate) chat_char: u8,
+    pub(crate) buttons: u8,
+}
+
+// Button/action code definitions: buttoncode_t
+
+// Press "Fire".
+pub const BT_ATTACK: u8 = 1;
+// Use button, to open doors, activate switches.
+pub const BT_USE: u8 = 2;
+
+// Flag: game events, not really buttons.
+pub const BT_SPECIAL: u8 = 128;
+pub const BT_SPECIALMASK: u8 = 3;
+
+// Flag, weapon change pending.
+// If true, the next 3 bits hold weapon num.
+pub const BT_CHANGE: u8 = 4;
+// The 3bit weapon mask and shift, convenience.
+pub const BT_WEAPONMASK: u8 = 8 + 16 + 32;
+pub const BT_WEAPONSHIFT: u8 = 3;
+
+// Pause the game.
+pub const BTS_PAUSE: u8 = 1;
+// Save the game at each console.
+pub const BTS_SAVEGAME: u8 = 2;
+
+// Savegame slot numbers
+//  occupy the second byte of buttons.
+pub const BTS_SAVEMASK: u8 = 4 + 8 + 16;
+pub const BTS_SAVESHIFT: u8 = 2;
@@ -1,4 +1,6 @@
 use fixed::types::I16F16;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign};
 
 #[cfg(feature = "floating-point-arithmetics")]
 pub type DoomRealNum = f32;
@@ -6,13 +8,340 @@ pub type DoomRealNum = f32;
 #[cfg(not(feature = "floating-point-arithmetics"))]
 pub type DoomRealNum = I16F16;
 
+pub const FRAC_BITS: i32 = 16;
+
+/// Binary angle measurement, a full turn wraps around at 2^32.
+pub type Angle = u32;
+
+// Indices into a bounding box, see m_bbox.h
+pub const BOX_TOP: usize = 0;
+pub const BOX_BOTTOM: usize = 1;
+pub const BOX_LEFT: usize = 2;
+pub const BOX_RIGHT: usize = 3;
+
+/// The numeric backend behind `DoomRealNum`.
+///
+/// `I16F16` is bit for bit the fixed_t of the original, which demos
+/// need to stay in sync. `f32` trades that for precision, so the
+/// renderer does not wobble at high resolutions. Game code only uses
+/// these operations and the arithmetic operators, so that it builds
+/// with either of them.
+pub trait RealNum: Copy + Default + PartialOrd + Debug + Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign {
+    /// With I16F16, whole numbers outside of the fixed_t range
+    /// wrap around like `value << FRACBITS` in the original.
+    fn from_int(value: i32) -> Self;
+    /// With I16F16, values outside of the fixed_t range saturate.
+    /// NaN gives 0 with either backend.
+    fn from_f64(value: f64) -> Self;
+
+    /// Rounds down to a whole number.
+    fn to_int(self) -> i32;
+    fn to_f64(self) -> f64;
+
+    /// The value as the bits of a 16.16 fixed_t, rounded down,
+    /// for the code that works on those bits directly.
+    fn to_fixed_bits(self) -> i32;
+
+    // FixedMul
+    fn fixed_mul(self, other: Self) -> Self;
+    // FixedDiv, saturates instead of overflowing
+    fn fixed_div(self, other: Self) -> Self;
+
+    // fixed_t arithmetic wraps around on overflow.
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_neg(self) -> Self;
+    fn wrapping_abs(self) -> Self;
+    fn wrapping_mul_int(self, other: i32) -> Self;
+    /// Divides by a whole number, rounding towards zero.
+    fn div_int(self, other: i32) -> Self;
+
+    /// Divides by 2^shift, rounding down like the shift of a fixed_t.
+    fn shr(self, shift: u32) -> Self;
+
+    /// The value with `bits` fraction bits as a whole number, rounded
+    /// down like a fixed_t shifted right by FRAC_BITS - bits.
+    fn frac_bits(self, bits: u32) -> i32;
+    /// The fraction above the value rounded down.
+    fn frac_part(self) -> Self;
+
+    /// Index of a scale or distance into a light table of `count`
+    /// entries, the fixed_t shifted right by `shift`.
+    fn scale_index(self, shift: i32, count: usize) -> usize;
+
+    /// The inverse of a scale for the column drawers, 0xffffffffu / scale.
+    fn inverse(self) -> Self;
+
+    /// The finesine and finetangent table entries for an angle in radians.
+    fn from_sin(angle: f64) -> Self;
+    fn from_tan(angle: f64) -> Self;
+
+    /// Returns the angle of the vector (x, y), see R_PointToAngle.
+    fn point_to_angle(x: Self, y: Self) -> Angle;
+}
+
+impl RealNum for I16F16 {
+    fn from_int(value: i32) -> Self {
+        I16F16::wrapping_from_num(value)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            I16F16::ZERO
+        } else {
+            I16F16::saturating_from_num(value)
+        }
+    }
+
+    fn to_int(self) -> i32 {
+        self.to_bits() >> FRAC_BITS
+    }
+
+    fn to_f64(self) -> f64 {
+        self.to_num()
+    }
+
+    fn to_fixed_bits(self) -> i32 {
+        self.to_bits()
+    }
+
+    fn fixed_mul(self, other: Self) -> Self {
+        I16F16::from_bits(((self.to_bits() as i64 * other.to_bits() as i64) >> FRAC_BITS) as i32)
+    }
+
+    fn fixed_div(self, other: Self) -> Self {
+        let (a, b) = (self.to_bits(), other.to_bits());
+        if (a.wrapping_abs() >> 14) >= b.wrapping_abs() {
+            return if (a ^ b) < 0 { I16F16::MIN } else { I16F16::MAX };
+        }
+
+        I16F16::from_bits((((a as i64) << FRAC_BITS) / b as i64) as i32)
+    }
+
+    fn wrapping_add(self, other: Self) -> Self {
+        I16F16::wrapping_add(self, other)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        I16F16::wrapping_sub(self, other)
+    }
+
+    fn wrapping_neg(self) -> Self {
+        I16F16::wrapping_neg(self)
+    }
+
+    fn wrapping_abs(self) -> Self {
+        I16F16::wrapping_abs(self)
+    }
+
+    fn wrapping_mul_int(self, other: i32) -> Self {
+        I16F16::wrapping_mul_int(self, other)
+    }
+
+    fn div_int(self, other: i32) -> Self {
+        self / other
+    }
+
+    fn shr(self, shift: u32) -> Self {
+        I16F16::from_bits(self.to_bits() >> shift)
+    }
+
+    fn frac_bits(self, bits: u32) -> i32 {
+        self.to_bits() >> (FRAC_BITS as u32 - bits)
+    }
+
+    fn frac_part(self) -> Self {
+        I16F16::from_bits(self.to_bits() & ((1 << FRAC_BITS) - 1))
+    }
+
+    fn scale_index(self, shift: i32, count: usize) -> usize {
+        ((self.to_bits() >> shift).max(0) as usize).min(count - 1)
+    }
+
+    fn inverse(self) -> Self {
+        I16F16::from_bits((0xffffffffu32 / self.to_bits() as u32) as i32)
+    }
+
+    fn from_sin(angle: f64) -> Self {
+        I16F16::from_bits((angle.sin() * (1 << FRAC_BITS) as f64) as i32)
+    }
+
+    fn from_tan(angle: f64) -> Self {
+        I16F16::from_bits((angle.tan() * (1 << FRAC_BITS) as f64) as i32)
+    }
+
+    fn point_to_angle(x: Self, y: Self) -> Angle {
+        crate::tables::point_to_angle_bits(x.to_bits(), y.to_bits())
+    }
+}
+
+impl RealNum for f32 {
+    fn from_int(value: i32) -> Self {
+        value as f32
+    }
+
+    fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            0.0
+        } else {
+            value as f32
+        }
+    }
+
+    fn to_int(self) -> i32 {
+        self.floor() as i32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn to_fixed_bits(self) -> i32 {
+        (self * (1 << FRAC_BITS) as f32).floor() as i32
+    }
+
+    fn fixed_mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn fixed_div(self, other: Self) -> Self {
+        let quotient = self / other;
+        if quotient.is_finite() {
+            quotient
+        } else if (self < 0.0) != (other < 0.0) {
+            f32::MIN
+        } else {
+            f32::MAX
+        }
+    }
+
+    fn wrapping_add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn wrapping_neg(self) -> Self {
+        -self
+    }
+
+    fn wrapping_abs(self) -> Self {
+        self.abs()
+    }
+
+    fn wrapping_mul_int(self, other: i32) -> Self {
+        self * other as f32
+    }
+
+    fn div_int(self, other: i32) -> Self {
+        self / other as f32
+    }
+
+    fn shr(self, shift: u32) -> Self {
+        self / (1u32 << shift) as f32
+    }
+
+    fn frac_bits(self, bits: u32) -> i32 {
+        (self * (1u32 << bits) as f32).floor() as i32
+    }
+
+    fn frac_part(self) -> Self {
+        self - self.floor()
+    }
+
+    fn scale_index(self, shift: i32, count: usize) -> usize {
+        ((self * 2f32.powi(FRAC_BITS - shift)).max(0.0) as usize).min(count - 1)
+    }
+
+    fn inverse(self) -> Self {
+        1.0 / self
+    }
+
+    fn from_sin(angle: f64) -> Self {
+        angle.sin() as f32
+    }
+
+    fn from_tan(angle: f64) -> Self {
+        angle.tan() as f32
+    }
+
+    fn point_to_angle(x: Self, y: Self) -> Angle {
+        if x == 0.0 && y == 0.0 {
+            return 0;
+        }
+
+        let turns = (y as f64).atan2(x as f64) / (2.0 * std::f64::consts::PI);
+        (turns.rem_euclid(1.0) * 4294967296.0) as u64 as Angle
+    }
+}
+
+/// Numbers that convert to `DoomRealNum` with `real`.
+pub trait ToReal {
+    fn to_real(self) -> DoomRealNum;
+}
+
+macro_rules! impl_to_real {
+    ($convert:ident, $as:ty, $($type:ty),*) => {
+        $(impl ToReal for $type {
+            fn to_real(self) -> DoomRealNum {
+                DoomRealNum::$convert(self as $as)
+            }
+        })*
+    };
+}
+
+impl_to_real!(from_int, i32, u8, i16, u16, i32, u32, usize);
+impl_to_real!(from_f64, f64, f32, f64);
+
+pub fn real<T: ToReal>(val: T) -> DoomRealNum {
+    val.to_real()
+}
+
+/// The number with the given fixed_t bits, for constants.
 #[cfg(feature = "floating-point-arithmetics")]
-pub fn real<T>(val: T) -> DoomRealNum {
-    panic!("not implemented!");
+pub const fn from_fixed_bits(bits: i32) -> DoomRealNum {
+    bits as f32 / (1 << FRAC_BITS) as f32
 }
 
+/// The number with the given fixed_t bits, for constants.
 #[cfg(not(feature = "floating-point-arithmetics"))]
-pub fn real<T: fixed::traits::ToFixed>(val: T) -> DoomRealNum {
-    I16F16::from_num(val)
+pub const fn from_fixed_bits(bits: i32) -> DoomRealNum {
+    I16F16::from_bits(bits)
 }
 
+// FixedMul
+pub fn fixed_mul(a: DoomRealNum, b: DoomRealNum) -> DoomRealNum {
+    a.fixed_mul(b)
+}
+
+// FixedDiv, saturates instead of overflowing
+pub fn fixed_div(a: DoomRealNum, b: DoomRealNum) -> DoomRealNum {
+    a.fixed_div(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DoomRealNum, RealNum};
+
+    #[test]
+    fn conversions_out_of_range_do_not_panic() {
+        assert!(DoomRealNum::from_f64(1e9).to_f64() >= 32767.0);
+        assert!(DoomRealNum::from_f64(-1e9).to_f64() <= -32768.0);
+        assert_eq!(DoomRealNum::from_f64(f64::NAN).to_f64(), 0.0);
+        assert_eq!(DoomRealNum::from_int(-32768).to_int(), -32768);
+    }
+
+    #[cfg(not(feature = "floating-point-arithmetics"))]
+    #[test]
+    fn from_int_wraps_like_a_shift() {
+        for value in [32768, 65537, -32769, i32::MAX] {
+            assert_eq!(DoomRealNum::from_int(value).to_fixed_bits(), value << 16);
+        }
+        assert_eq!(DoomRealNum::from_f64(f64::INFINITY).to_fixed_bits(), i32::MAX);
+        assert_eq!(DoomRealNum::from_f64(f64::NEG_INFINITY).to_fixed_bits(), i32::MIN);
+    }
+}
@@ -1,10 +1,23 @@
 use std::fs::File;
 use serde::{Serialize, Deserialize};
-use bincode::Options;
-use std::io::{SeekFrom, Seek, Read, Cursor};
+use std::io::{Read, Cursor};
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::path::Path;
-use std::fmt::Display;
+use std::collections::hash_map::Entry;
+use std::path::{Path, PathBuf};
+use std::fmt::{Display, Formatter};
+use memmap2::Mmap;
+use crate::wad::archive::ArchiveFile;
+use crate::wad::directory::ResourceDirectory;
+
+mod resources;
+mod archive;
+mod directory;
+mod builder;
+
+pub use builder::WadBuilder;
+#[cfg(test)]
+pub(crate) use builder::store;
 
 #[derive(Copy, Clone, Debug)]
 pub struct LumpNumber(usize);
@@ -30,35 +43,272 @@ impl<T: AsRef<[u8]>> ReadWadString for Cursor<T> {
     fn read_wad_string(&mut self) -> std::io::Result<String> {
         let mut buffer = [0u8; 8];
         self.read_exact(&mut buffer)?;
-        Ok(String::from_utf8(buffer.into()).unwrap()
-            .trim_matches(char::from(0)).to_string())
+        wad_string(&buffer)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// Converts a zero padded, 8 byte WAD name into a string.
+pub fn wad_string(name: &[u8; 8]) -> Result<String, WadError> {
+    let length = name.iter().position(|&x| x == 0).unwrap_or(name.len());
+    match std::str::from_utf8(&name[..length]) {
+        Ok(name) => Ok(name.to_string()),
+        Err(_) => Err(WadError::InvalidName(*name)),
+    }
+}
+
+#[derive(Debug)]
+pub enum WadError {
+    Io(std::io::Error),
+    BadIdentification([u8; 4]),
+    DirectoryOutOfBounds { offset: usize, num_lumps: usize, file_size: usize },
+    LumpOverrunsFile { name: String, offset: usize, size: usize, file_size: usize },
+    MissingLump(String),
+    InvalidName([u8; 8]),
+    NameTooLong(String),
+    MalformedLump { name: String, reason: String },
+}
+
+impl Display for WadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WadError::Io(err) => write!(f, "I/O error: {}", err),
+            WadError::BadIdentification(id) =>
+                write!(f, "Wad file does not have IWAD or PWAD id (found {:?})", id),
+            WadError::DirectoryOutOfBounds { offset, num_lumps, file_size } =>
+                write!(f, "Wad directory of {} lumps at offset {} does not fit in file of {} bytes",
+                       num_lumps, offset, file_size),
+            WadError::LumpOverrunsFile { name, offset, size, file_size } =>
+                write!(f, "Lump {} ({} bytes at offset {}) overruns file of {} bytes",
+                       name, size, offset, file_size),
+            WadError::MissingLump(name) => write!(f, "Lump {} not found", name),
+            WadError::InvalidName(name) => write!(f, "Invalid lump name {:?}", name),
+            WadError::NameTooLong(name) => write!(f, "Lump name {} is longer than 8 characters", name),
+            WadError::MalformedLump { name, reason } => write!(f, "Malformed lump {}: {}", name, reason),
+        }
+    }
+}
+
+impl std::error::Error for WadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WadError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for WadError {
+    fn from(err: std::io::Error) -> Self {
+        WadError::Io(err)
+    }
+}
+
+impl From<bincode::Error> for WadError {
+    fn from(err: bincode::Error) -> Self {
+        match *err {
+            bincode::ErrorKind::Io(err) => WadError::Io(err),
+            err => WadError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct WadHeader {
     identification: [u8; 4],
     num_lumps: i32,
     directory_offset: i32,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 struct FileLump {
     offset: i32,
     size: i32,
     name: [u8; 8],
 }
 
+/// The group of lumps a lump belongs to, given by the
+/// marker lumps around it in its file.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Namespace {
+    Global,
+    // S_START/S_END or SS_START/SS_END
+    Sprites,
+    // F_START/F_END or FF_START/FF_END
+    Flats,
+    // P_START/P_END or PP_START/PP_END
+    Patches,
+}
+
+impl Namespace {
+    // The markers put around the lumps of the namespace in a PWAD.
+    fn markers(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Namespace::Global => None,
+            Namespace::Sprites => Some(("SS_START", "SS_END")),
+            Namespace::Flats => Some(("FF_START", "FF_END")),
+            Namespace::Patches => Some(("PP_START", "PP_END")),
+        }
+    }
+
+    // The namespace started or ended by a marker lump.
+    fn from_marker(name: &str) -> Option<(Namespace, bool)> {
+        match name {
+            "S_START" | "SS_START" => Some((Namespace::Sprites, true)),
+            "S_END" | "SS_END" => Some((Namespace::Sprites, false)),
+            "F_START" | "FF_START" => Some((Namespace::Flats, true)),
+            "F_END" | "FF_END" => Some((Namespace::Flats, false)),
+            "P_START" | "PP_START" => Some((Namespace::Patches, true)),
+            "P_END" | "PP_END" => Some((Namespace::Patches, false)),
+            _ => None,
+        }
+    }
+}
+
 pub struct LumpInfo {
     name: String,
+    namespace: Namespace,
+    // Index of the file in LumpStore::files.
+    file: usize,
     offset: usize,
     size: usize,
 }
 
+/// A lump in the directory of a LumpSource,
+/// at an offset into the data of the source.
+pub struct LumpEntry {
+    pub(crate) name: String,
+    pub(crate) namespace: Namespace,
+    pub(crate) offset: usize,
+    pub(crate) size: usize,
+}
+
+/// Something lumps are loaded from: a WAD file, a PK3/ZIP
+/// archive or a directory of loose files.
+pub trait LumpSource {
+    /// The path the lumps were loaded from.
+    fn path(&self) -> &Path;
+
+    /// The lumps in the order they are loaded.
+    fn lumps(&self) -> &[LumpEntry];
+
+    /// The data the offsets of the lumps point into.
+    fn data(&self) -> &[u8];
+}
+
+// An added WAD file, mapped into memory
+// so lumps are only read when they are used.
+struct WadFile {
+    path: PathBuf,
+    data: Mmap,
+    lumps: Vec<LumpEntry>,
+}
+
+impl WadFile {
+    fn open(path: &Path) -> Result<Self, WadError> {
+        let file = File::open(path)?;
+        // Safety: the WAD files are not expected to change
+        //  while the game is running.
+        let data = unsafe { Mmap::map(&file)? };
+        let lumps = read_directory(&data)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            data,
+            lumps,
+        })
+    }
+}
+
+impl LumpSource for WadFile {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn lumps(&self) -> &[LumpEntry] {
+        &self.lumps
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Reads the directory of the WAD file in data, with
+/// the namespaces given by the marker lumps.
+pub fn read_directory(data: &[u8]) -> Result<Vec<LumpEntry>, WadError> {
+    let file_size = data.len();
+
+    let mut cursor = Cursor::new(data);
+    let header: WadHeader = bincode::deserialize_from(&mut cursor)?;
+    if header.identification != ['I' as u8, 'W' as u8, 'A' as u8, 'D' as u8] {
+        // Homebrew levels?
+        if header.identification != ['P' as u8, 'W' as u8, 'A' as u8, 'D' as u8] {
+            return Err(WadError::BadIdentification(header.identification));
+        }
+    }
+
+    const FILE_LUMP_SIZE: usize = 16;
+    let directory_offset = header.directory_offset as usize;
+    let num_lumps = header.num_lumps as usize;
+    if header.directory_offset < 0 || header.num_lumps < 0 ||
+        directory_offset + num_lumps * FILE_LUMP_SIZE > file_size {
+        return Err(WadError::DirectoryOutOfBounds {
+            offset: directory_offset,
+            num_lumps,
+            file_size,
+        });
+    }
+
+    cursor.set_position(directory_offset as u64);
+
+    let mut lumps = Vec::with_capacity(num_lumps);
+    let mut namespace = Namespace::Global;
+    for _ in 0..num_lumps {
+        let file_lump: FileLump = bincode::deserialize_from(&mut cursor)?;
+
+        let lump_name = wad_string(&file_lump.name)?.to_uppercase();
+
+        let offset = file_lump.offset as usize;
+        let size = file_lump.size as usize;
+        if file_lump.offset < 0 || file_lump.size < 0 || offset + size > file_size {
+            return Err(WadError::LumpOverrunsFile {
+                name: lump_name,
+                offset,
+                size,
+                file_size,
+            });
+        }
+
+        // the markers themselves are global
+        let lump_namespace = match Namespace::from_marker(&lump_name) {
+            Some((marked, true)) => {
+                namespace = marked;
+                Namespace::Global
+            }
+            Some((marked, false)) if marked == namespace => {
+                namespace = Namespace::Global;
+                Namespace::Global
+            }
+            _ => namespace,
+        };
+
+        lumps.push(LumpEntry {
+            name: lump_name,
+            namespace: lump_namespace,
+            offset,
+            size,
+        });
+    }
+
+    Ok(lumps)
+}
+
 pub struct LumpStore {
     lumps: Vec<LumpInfo>,
-    // offsets: HashMap<String, LumpOffset>,
-    data: Vec<u8>,
+    // The lumps with each name, in the order they were loaded.
+    names: HashMap<String, Vec<usize>>,
+    files: Vec<Box<dyn LumpSource>>,
 }
 
 pub enum By<'a> {
@@ -70,66 +320,186 @@ impl LumpStore {
     pub fn new() -> Self {
         Self {
             lumps: Vec::new(),
-            data: Vec::new(),
+            names: HashMap::new(),
+            files: Vec::new(),
         }
     }
 
-    pub fn add_file<P: AsRef<Path> + Display>(&mut self, path: P) {
-        let mut file = File::open(&path).expect(&*format!("Unable to open file {}", path));
-        let header: WadHeader =
-            bincode::deserialize_from(&file).expect(&*format!("Unable to read file {}", path));
-        if header.identification != ['I' as u8, 'W' as u8, 'A' as u8, 'D' as u8] {
-            // Homebrew levels?
-            if header.identification != ['P' as u8, 'W' as u8, 'A' as u8, 'D' as u8] {
-                panic!("Wad file {} does not have IWAD or PWAD id", path);
-            }
-        }
+    /// Adds the lumps of a WAD file, a PK3/ZIP archive
+    /// or a directory of loose files.
+    pub fn add_file<P: AsRef<Path> + Display>(&mut self, path: P) -> Result<(), WadError> {
+        let path = path.as_ref();
+        let extension = path.extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
 
-        file.seek(SeekFrom::Start(header.directory_offset as u64)).expect("Unable to read file");
-
-        let base_offset = self.data.len();
-        for _ in 0..header.num_lumps {
-            let file_lump: FileLump = bincode::deserialize_from(&file).expect("Unable to read file");
+        let source: Box<dyn LumpSource> = if path.is_dir() {
+            Box::new(ResourceDirectory::open(path)?)
+        } else if matches!(extension.as_deref(), Some("pk3" | "zip")) {
+            Box::new(ArchiveFile::open(path)?)
+        } else {
+            Box::new(WadFile::open(path)?)
+        };
+        self.add_source(source);
 
-            let lump_name =
-                String::from_utf8(Vec::from(file_lump.name)).unwrap()
-                    .trim_matches(char::from(0))
-                    .to_uppercase().to_string();
+        Ok(())
+    }
 
+    /// Adds the lumps of the source after those already loaded,
+    /// so they replace lumps with the same names.
+    pub fn add_source(&mut self, source: Box<dyn LumpSource>) {
+        let file = self.files.len();
+        for entry in source.lumps() {
+            self.names.entry(entry.name.clone())
+                .or_default()
+                .push(self.lumps.len());
             self.lumps.push(LumpInfo {
-                name: lump_name,
-                offset: base_offset + file_lump.offset as usize,
-                size: file_lump.size as usize,
+                name: entry.name.clone(),
+                namespace: entry.namespace,
+                file,
+                offset: entry.offset,
+                size: entry.size,
             });
-            /*self.offsets.insert(lump_name, LumpOffset {
-                offset: base_offset + file_lump.offset as usize,
-                size: file_lump.size as usize
-            });*/
         }
+        self.files.push(source);
+    }
 
-        file.seek(SeekFrom::Start(0)).unwrap();
-        file.read_to_end(&mut self.data).expect("Unable to read file");
+    // The loaded lumps with the name, in load order.
+    fn lumps_named(&self, name: &str) -> &[usize] {
+        let upper_case_name = if name.chars().any(char::is_lowercase) {
+            Cow::Owned(name.to_uppercase())
+        } else {
+            Cow::Borrowed(name)
+        };
+        self.names.get(upper_case_name.as_ref()).map_or(&[], Vec::as_slice)
     }
 
+    /// Returns the lump with the name that was loaded last,
+    /// so that lumps in PWADs replace those in the IWAD.
     pub fn get_lump_number(&self, name: &str) -> Option<LumpNumber> {
-        let upper_case_name = name.to_uppercase();
-        Some(LumpNumber { 0: self.lumps.iter().position(|x| x.name == upper_case_name)? })
+        self.lumps_named(name).last().map(|&index| LumpNumber(index))
+    }
+
+    /// Like get_lump_number, but only finds lumps in the namespace.
+    pub fn get_lump_number_in(&self, name: &str, namespace: Namespace) -> Option<LumpNumber> {
+        self.lumps_named(name).iter()
+            .rfind(|&&index| self.lumps[index].namespace == namespace)
+            .map(|&index| LumpNumber(index))
+    }
+
+    /// Returns the patch with the name that was loaded last, from
+    /// between patch markers or outside of any markers, so that
+    /// sprites and flats with the same name are not used as patches.
+    pub fn get_patch_number(&self, name: &str) -> Option<LumpNumber> {
+        self.lumps_named(name).iter()
+            .rfind(|&&index| matches!(self.lumps[index].namespace, Namespace::Patches | Namespace::Global))
+            .map(|&index| LumpNumber(index))
     }
 
-    pub fn get_lump(&self, by: By) -> &[u8] {
-        let lump = match by {
-            By::Name(name) => {
-                let upper_case_name = name.to_uppercase();
-                self.lumps.iter().rfind(|x| x.name == upper_case_name)
-                    .expect(&*format!("Lump {} not found", name))
+    /// Returns all lumps with the name, in the order they were loaded.
+    pub fn get_lump_numbers(&self, name: &str) -> impl Iterator<Item = LumpNumber> + '_ {
+        self.lumps_named(name).iter().map(|&index| LumpNumber(index))
+    }
+
+    /// Returns the lumps of the namespace in all files, merged like
+    /// DeuTex -merge does: a lump replaces the one loaded before it
+    /// with the same name in its place, new names are appended.
+    pub fn get_namespace_lumps(&self, namespace: Namespace) -> Vec<LumpNumber> {
+        let mut merged = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+
+        for (index, lump) in self.lumps.iter().enumerate() {
+            if lump.namespace != namespace {
+                continue;
             }
-            By::Number(number) => &self.lumps[number.0]
+
+            match positions.entry(&lump.name) {
+                Entry::Occupied(position) => merged[*position.get()] = LumpNumber(index),
+                Entry::Vacant(position) => {
+                    position.insert(merged.len());
+                    merged.push(LumpNumber(index));
+                }
+            }
+        }
+
+        merged
+    }
+
+    pub fn get_lump(&self, by: By) -> Result<&[u8], WadError> {
+        let number = match by {
+            By::Name(name) => self.get_lump_number(name)
+                .ok_or_else(|| WadError::MissingLump(name.to_uppercase()))?,
+            By::Number(number) => number,
         };
+        let lump = self.lumps.get(number.0)
+            .ok_or_else(|| WadError::MissingLump(format!("#{}", number.0)))?;
+
+        Ok(&self.files[lump.file].data()[lump.offset..(lump.offset + lump.size)])
+    }
 
-        &self.data[lump.offset..(lump.offset + lump.size)]
+    pub fn get_lump_name(&self, number: LumpNumber) -> Result<&str, WadError> {
+        self.lumps.get(number.0)
+            .map(|x| x.name.as_str())
+            .ok_or_else(|| WadError::MissingLump(format!("#{}", number.0)))
     }
 
-    pub fn get_lump_cursor(&self, by: By) -> Cursor<&[u8]> {
-        Cursor::new(self.get_lump(by))
+    pub fn get_lump_namespace(&self, number: LumpNumber) -> Result<Namespace, WadError> {
+        self.lumps.get(number.0)
+            .map(|x| x.namespace)
+            .ok_or_else(|| WadError::MissingLump(format!("#{}", number.0)))
     }
-}
\ No newline at end of file
+
+    /// Returns the path of the file the lump was loaded from.
+    pub fn get_lump_file(&self, number: LumpNumber) -> Result<&Path, WadError> {
+        self.lumps.get(number.0)
+            .map(|x| self.files[x.file].path())
+            .ok_or_else(|| WadError::MissingLump(format!("#{}", number.0)))
+    }
+
+    pub fn get_lump_cursor(&self, by: By) -> Result<Cursor<&[u8]>, WadError> {
+        Ok(Cursor::new(self.get_lump(by)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LumpStore, Namespace, By, store};
+    use crate::wad::WadBuilder;
+
+    // A flat and a patch named WALL, with different data.
+    fn flat_and_patch() -> LumpStore {
+        let mut wad = WadBuilder::new();
+        wad.add_lump("F_START", Vec::new()).unwrap();
+        wad.add_lump("WALL", vec![1]).unwrap();
+        wad.add_lump("F_END", Vec::new()).unwrap();
+        wad.add_lump("P_START", Vec::new()).unwrap();
+        wad.add_lump("WALL", vec![2]).unwrap();
+        wad.add_lump("P_END", Vec::new()).unwrap();
+        store(&[("doom2.wad", &wad)])
+    }
+
+    #[test]
+    fn flat_and_patch_resolve_to_different_lumps() {
+        let lumps = flat_and_patch();
+
+        let flat = lumps.get_lump_number_in("WALL", Namespace::Flats).unwrap();
+        let patch = lumps.get_lump_number_in("WALL", Namespace::Patches).unwrap();
+        assert_eq!(lumps.get_lump(By::Number(flat)).unwrap(), [1]);
+        assert_eq!(lumps.get_lump(By::Number(patch)).unwrap(), [2]);
+        assert_eq!(lumps.get_lump_namespace(flat).unwrap(), Namespace::Flats);
+        assert_eq!(lumps.get_lump_namespace(patch).unwrap(), Namespace::Patches);
+
+        assert_eq!(lumps.get_lump(By::Number(lumps.get_patch_number("WALL").unwrap())).unwrap(), [2]);
+        assert!(lumps.get_lump_number_in("WALL", Namespace::Sprites).is_none());
+    }
+
+    #[test]
+    fn lump_numbers_are_in_load_order() {
+        let lumps = flat_and_patch();
+
+        let data: Vec<&[u8]> = lumps.get_lump_numbers("wall")
+            .map(|number| lumps.get_lump(By::Number(number)).unwrap())
+            .collect();
+        assert_eq!(data, [[1], [2]]);
+        assert_eq!(lumps.get_lump_numbers("FLOOR").count(), 0);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::wad::{LumpEntry, LumpSource, WadError};
+use crate::wad::resources::{collect_resources, Resources};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+// An added PK3/ZIP archive, its files are
+// decompressed when it is added.
+pub struct ArchiveFile {
+    path: PathBuf,
+    resources: Resources,
+}
+
+impl ArchiveFile {
+    pub fn open(path: &Path) -> Result<Self, WadError> {
+        let mut archive = ZipArchive::new(File::open(path)?)?;
+
+        let mut files = Vec::with_capacity(archive.len());
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index)?;
+            if file.is_dir() {
+                continue;
+            }
+
+            let mut data = Vec::new();
+            file.read_to_end(&mut data)?;
+            // some archivers use \ between the folders
+            files.push((file.name().replace('\\', "/"), data));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            resources: collect_resources(files)?,
+        })
+    }
+}
+
+impl LumpSource for ArchiveFile {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn lumps(&self) -> &[LumpEntry] {
+        &self.resources.lumps
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.resources.data
+    }
+}
+
+impl From<ZipError> for WadError {
+    fn from(err: ZipError) -> Self {
+        match err {
+            ZipError::Io(err) => WadError::Io(err),
+            err => WadError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wad::{LumpSource, Namespace};
+    use super::ArchiveFile;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use zip::ZipWriter;
+    use zip::write::FileOptions;
+
+    #[test]
+    fn reads_the_files_of_the_archive() {
+        let path = std::env::temp_dir().join(format!("room-archive-{}.pk3", std::process::id()));
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        zip.add_directory("patches/", FileOptions::default()).unwrap();
+        for (name, data) in [("patches/wall.lmp", b"P"), ("flats\\floor1.lmp", b"F"), ("__MACOSX/.hidden", b"H")] {
+            zip.start_file(name, FileOptions::default()).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let archive = ArchiveFile::open(&path);
+        fs::remove_file(&path).unwrap();
+        let archive = archive.unwrap();
+
+        let lumps: Vec<_> = archive.lumps().iter()
+            .map(|lump| (lump.name.as_str(), lump.namespace, &archive.data()[lump.offset..lump.offset + lump.size]))
+            .collect();
+        assert_eq!(lumps, [
+            ("FLOOR1", Namespace::Flats, &b"F"[..]),
+            ("WALL", Namespace::Patches, b"P"),
+        ]);
+    }
+}
@@ -0,0 +1,319 @@
+use crate::wad::{LumpStore, LumpNumber, Namespace, WadHeader, FileLump, By, WadError};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+const HEADER_SIZE: usize = 12;
+
+/// Builds a WAD file out of named lumps, which are
+/// written in the order they were added.
+pub struct WadBuilder {
+    identification: [u8; 4],
+    lumps: Vec<(String, Vec<u8>)>,
+}
+
+fn too_large(name: &str) -> WadError {
+    WadError::MalformedLump {
+        name: name.to_string(),
+        reason: "Lump does not fit in a WAD file".to_string(),
+    }
+}
+
+impl WadBuilder {
+    /// Starts a PWAD.
+    pub fn new() -> Self {
+        Self {
+            identification: *b"PWAD",
+            lumps: Vec::new(),
+        }
+    }
+
+    /// Starts an IWAD.
+    pub fn iwad() -> Self {
+        Self {
+            identification: *b"IWAD",
+            ..Self::new()
+        }
+    }
+
+    /// Starts a PWAD with all lumps of the store in load order,
+    /// so that adding it to a new store loads the same lumps.
+    /// Lumps of archives and directories are put between
+    /// markers for their namespaces, their names must fit
+    /// in 8 characters.
+    pub fn from_lump_store(store: &LumpStore) -> Result<Self, WadError> {
+        let mut builder = Self::new();
+
+        // the namespace of the markers written so far
+        let mut namespace = Namespace::Global;
+
+        for (index, lump) in store.lumps.iter().enumerate() {
+            match Namespace::from_marker(&lump.name) {
+                Some((marked, true)) => namespace = marked,
+                Some((marked, false)) if marked == namespace => namespace = Namespace::Global,
+                Some(_) => {}
+                None if lump.namespace != namespace => {
+                    if let Some((_, end)) = namespace.markers() {
+                        builder.add_lump(end, Vec::new())?;
+                    }
+                    if let Some((start, _)) = lump.namespace.markers() {
+                        builder.add_lump(start, Vec::new())?;
+                    }
+                    namespace = lump.namespace;
+                }
+                None => {}
+            }
+
+            builder.add_lump(&lump.name, store.get_lump(By::Number(LumpNumber(index)))?.to_vec())?;
+        }
+
+        if let Some((_, end)) = namespace.markers() {
+            builder.add_lump(end, Vec::new())?;
+        }
+
+        Ok(builder)
+    }
+
+    /// Adds a lump after the ones added before,
+    /// the name can have up to 8 characters.
+    pub fn add_lump(&mut self, name: &str, data: Vec<u8>) -> Result<&mut Self, WadError> {
+        if name.len() > 8 {
+            return Err(WadError::NameTooLong(name.to_string()));
+        }
+
+        if name.is_empty() || !name.is_ascii() || name.contains('\0') {
+            return Err(WadError::MalformedLump {
+                name: name.to_string(),
+                reason: "Lump names have 1 to 8 ASCII characters".to_string(),
+            });
+        }
+
+        self.lumps.push((name.to_string(), data));
+        Ok(self)
+    }
+
+    /// Writes the header, the lumps and then the directory.
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), WadError> {
+        let mut directory = Vec::with_capacity(self.lumps.len());
+        let mut offset = HEADER_SIZE;
+        for (name, data) in &self.lumps {
+            let mut file_lump_name = [0u8; 8];
+            file_lump_name[..name.len()].copy_from_slice(name.as_bytes());
+
+            directory.push(FileLump {
+                offset: i32::try_from(offset).map_err(|_| too_large(name))?,
+                size: i32::try_from(data.len()).map_err(|_| too_large(name))?,
+                name: file_lump_name,
+            });
+            offset += data.len();
+        }
+
+        let header = WadHeader {
+            identification: self.identification,
+            num_lumps: self.lumps.len() as i32,
+            directory_offset: i32::try_from(offset).map_err(|_| too_large("directory"))?,
+        };
+
+        bincode::serialize_into(&mut *writer, &header)?;
+        for (_, data) in &self.lumps {
+            writer.write_all(data)?;
+        }
+        for file_lump in &directory {
+            bincode::serialize_into(&mut *writer, file_lump)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the WAD to a file, replacing it if it exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), WadError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// A built WAD held in memory, loaded like a file with the path.
+#[cfg(test)]
+struct MemoryWad {
+    path: std::path::PathBuf,
+    data: Vec<u8>,
+    lumps: Vec<crate::wad::LumpEntry>,
+}
+
+#[cfg(test)]
+impl crate::wad::LumpSource for MemoryWad {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn lumps(&self) -> &[crate::wad::LumpEntry] {
+        &self.lumps
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Loads the built WADs in order, like -file does.
+#[cfg(test)]
+pub(crate) fn store(wads: &[(&str, &WadBuilder)]) -> LumpStore {
+    let mut lumps = LumpStore::new();
+    for &(path, builder) in wads {
+        let mut data = Vec::new();
+        builder.write(&mut data).unwrap();
+        let entries = crate::wad::read_directory(&data).unwrap();
+        lumps.add_source(Box::new(MemoryWad { path: path.into(), data, lumps: entries }));
+    }
+    lumps
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use crate::rendering::test_resources;
+    use crate::wad::{LumpEntry, LumpSource, LumpStore, Namespace, By, WadError, read_directory};
+    use super::{WadBuilder, store};
+
+    fn names(lumps: &[LumpEntry]) -> Vec<&str> {
+        lumps.iter().map(|lump| lump.name.as_str()).collect()
+    }
+
+    // The lumps of a directory or archive, in namespaces
+    //  without markers.
+    struct LooseFiles {
+        path: PathBuf,
+        data: Vec<u8>,
+        lumps: Vec<LumpEntry>,
+    }
+
+    impl LooseFiles {
+        fn new(files: &[(&str, Namespace, &[u8])]) -> Self {
+            let mut data = Vec::new();
+            let mut lumps = Vec::new();
+            for &(name, namespace, file_data) in files {
+                lumps.push(LumpEntry { name: name.to_string(), namespace, offset: data.len(), size: file_data.len() });
+                data.extend_from_slice(file_data);
+            }
+
+            Self { path: PathBuf::from("files"), data, lumps }
+        }
+    }
+
+    impl LumpSource for LooseFiles {
+        fn path(&self) -> &Path {
+            &self.path
+        }
+
+        fn lumps(&self) -> &[LumpEntry] {
+            &self.lumps
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.data
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut wad = WadBuilder::iwad();
+        wad.add_lump("PLAYPAL", vec![1, 2, 3]).unwrap()
+            .add_lump("MAP01", Vec::new()).unwrap()
+            .add_lump("things", vec![4; 10]).unwrap();
+
+        let mut data = Vec::new();
+        wad.write(&mut data).unwrap();
+        assert_eq!(&data[..4], b"IWAD");
+
+        let lumps = read_directory(&data).unwrap();
+        assert_eq!(names(&lumps), ["PLAYPAL", "MAP01", "THINGS"]);
+        let sizes: Vec<usize> = lumps.iter().map(|lump| lump.size).collect();
+        assert_eq!(sizes, [3, 0, 10]);
+        assert_eq!(&data[lumps[0].offset..lumps[0].offset + 3], [1, 2, 3]);
+
+        let lumps = store(&[("doom2.wad", &wad)]);
+        assert_eq!(lumps.get_lump(By::Name("THINGS")).unwrap(), [4; 10]);
+        assert!(lumps.get_lump(By::Name("MAP01")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn bad_names() {
+        let mut wad = WadBuilder::new();
+        assert!(matches!(wad.add_lump("TEXTURES1", Vec::new()), Err(WadError::NameTooLong(name)) if name == "TEXTURES1"));
+        assert!(matches!(wad.add_lump("", Vec::new()), Err(WadError::MalformedLump { .. })));
+        assert!(matches!(wad.add_lump("A\0", Vec::new()), Err(WadError::MalformedLump { .. })));
+    }
+
+    #[test]
+    fn from_wads() {
+        let mut pwad = WadBuilder::new();
+        pwad.add_lump("P_START", Vec::new()).unwrap()
+            .add_lump("WALL", vec![1; 8]).unwrap()
+            .add_lump("P_END", Vec::new()).unwrap()
+            .add_lump("F_START", Vec::new()).unwrap()
+            .add_lump("FLOOR1", vec![2; 64 * 64]).unwrap()
+            .add_lump("F_END", Vec::new()).unwrap();
+        let original = store(&[("doom2.wad", &test_resources()), ("pwad.wad", &pwad)]);
+
+        let copy = WadBuilder::from_lump_store(&original).unwrap();
+        let copy = store(&[("copy.wad", &copy)]);
+
+        // the same lumps for every name and namespace
+        for namespace in [Namespace::Global, Namespace::Sprites, Namespace::Flats, Namespace::Patches] {
+            let lumps = |store: &LumpStore| -> Vec<(String, Vec<u8>)> {
+                store.get_namespace_lumps(namespace).into_iter()
+                    .map(|lump| (
+                        store.get_lump_name(lump).unwrap().to_string(),
+                        store.get_lump(By::Number(lump)).unwrap().to_vec(),
+                    ))
+                    .collect()
+            };
+            assert!(!lumps(&original).is_empty());
+            assert_eq!(lumps(&copy), lumps(&original));
+        }
+        assert_eq!(copy.get_lump(By::Name("FLOOR1")).unwrap(), [2; 64 * 64]);
+    }
+
+    #[test]
+    fn from_loose_files() {
+        let mut lumps = LumpStore::new();
+        lumps.add_source(Box::new(LooseFiles::new(&[
+            ("PLAYPAL", Namespace::Global, &[1, 2]),
+            ("TROOA1", Namespace::Sprites, &[3]),
+            ("TROOA2A8", Namespace::Sprites, &[4]),
+            ("FLOOR1", Namespace::Flats, &[5]),
+            ("WALL", Namespace::Patches, &[6]),
+            ("ENDOOM", Namespace::Global, &[7]),
+        ])));
+
+        let mut data = Vec::new();
+        WadBuilder::from_lump_store(&lumps).unwrap().write(&mut data).unwrap();
+
+        let directory = read_directory(&data).unwrap();
+        assert_eq!(names(&directory), [
+            "PLAYPAL",
+            "SS_START", "TROOA1", "TROOA2A8", "SS_END",
+            "FF_START", "FLOOR1", "FF_END",
+            "PP_START", "WALL", "PP_END",
+            "ENDOOM",
+        ]);
+        let namespaces: Vec<Namespace> = directory.iter().map(|lump| lump.namespace).collect();
+        assert_eq!(namespaces[2], Namespace::Sprites);
+        assert_eq!(namespaces[9], Namespace::Patches);
+        assert_eq!(namespaces[11], Namespace::Global);
+    }
+
+    #[test]
+    fn long_file_names() {
+        let mut lumps = LumpStore::new();
+        lumps.add_source(Box::new(LooseFiles::new(&[
+            ("PLAYPAL", Namespace::Global, &[1, 2]),
+            ("TITLEMUSIC", Namespace::Global, &[3]),
+        ])));
+
+        assert!(matches!(WadBuilder::from_lump_store(&lumps), Err(WadError::NameTooLong(name)) if name == "TITLEMUSIC"));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::wad::{LumpEntry, LumpSource, WadError};
+use crate::wad::resources::{collect_resources, Resources};
+use std::path::{Path, PathBuf};
+
+// An added directory of loose files, laid out like
+// a PK3 archive. The files are read when it is added.
+pub struct ResourceDirectory {
+    path: PathBuf,
+    resources: Resources,
+}
+
+impl ResourceDirectory {
+    pub fn open(path: &Path) -> Result<Self, WadError> {
+        let mut files = Vec::new();
+        read_files(path, "", &mut files)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            resources: collect_resources(files)?,
+        })
+    }
+}
+
+// Reads the files below the directory with their paths
+//  relative to it, leaving out hidden ones like .git.
+fn read_files(directory: &Path, prefix: &str, files: &mut Vec<(String, Vec<u8>)>) -> Result<(), WadError> {
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if file_name.starts_with('.') {
+            continue;
+        }
+
+        let path = format!("{}{}", prefix, file_name);
+        if entry.path().is_dir() {
+            read_files(&entry.path(), &format!("{}/", path), files)?;
+        } else {
+            files.push((path, std::fs::read(entry.path())?));
+        }
+    }
+
+    Ok(())
+}
+
+impl LumpSource for ResourceDirectory {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn lumps(&self) -> &[LumpEntry] {
+        &self.resources.lumps
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.resources.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wad::{LumpSource, Namespace};
+    use super::ResourceDirectory;
+    use std::fs;
+
+    #[test]
+    fn reads_the_files_below_the_directory() {
+        let directory = std::env::temp_dir().join(format!("room-directory-{}", std::process::id()));
+        for folder in ["flats", "sprites/troo", ".git"] {
+            fs::create_dir_all(directory.join(folder)).unwrap();
+        }
+        fs::write(directory.join("flats/floor1.lmp"), b"F").unwrap();
+        fs::write(directory.join("sprites/troo/trooa1.lmp"), b"S").unwrap();
+        fs::write(directory.join(".git/head"), b"H").unwrap();
+        fs::write(directory.join(".hidden"), b"H").unwrap();
+        fs::write(directory.join("playpal.lmp"), b"R").unwrap();
+
+        let resources = ResourceDirectory::open(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+        let resources = resources.unwrap();
+
+        let lumps: Vec<_> = resources.lumps().iter()
+            .map(|lump| (lump.name.as_str(), lump.namespace, &resources.data()[lump.offset..lump.offset + lump.size]))
+            .collect();
+        assert_eq!(lumps, [
+            ("FLOOR1", Namespace::Flats, &b"F"[..]),
+            ("PLAYPAL", Namespace::Global, b"R"),
+            ("TROOA1", Namespace::Sprites, b"S"),
+        ]);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::wad::{LumpEntry, Namespace, WadError, read_directory};
+
+// The files of a PK3/ZIP archive or a resource directory,
+//  in one buffer the lumps point into.
+pub struct Resources {
+    pub(crate) data: Vec<u8>,
+    pub(crate) lumps: Vec<LumpEntry>,
+}
+
+// The namespace of the files in a top level folder, like the
+//  markers of a WAD file. The other folders are global.
+fn folder_namespace(folder: &str) -> Namespace {
+    match folder {
+        "flats" => Namespace::Flats,
+        "sprites" => Namespace::Sprites,
+        "patches" => Namespace::Patches,
+        _ => Namespace::Global,
+    }
+}
+
+/// Makes lumps of files, given by their paths relative to the
+/// archive or directory with / between the folders.
+///
+/// A lump is named after its file without the extension, cut to
+/// 8 characters like the short names of ZDoom, and hidden files
+/// are skipped. The WAD files in maps/ hold a map
+/// each, which is named after the file like in ZDoom.
+pub fn collect_resources(mut files: Vec<(String, Vec<u8>)>) -> Result<Resources, WadError> {
+    // sorted by path, so the load order does not
+    //  depend on the archive or the file system
+    files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut data = Vec::new();
+    let mut lumps = Vec::new();
+    for (path, file_data) in files {
+        let (folder, file_name) = match path.split_once('/') {
+            Some((folder, rest)) => (folder.to_lowercase(), rest.rsplit('/').next().unwrap_or(rest)),
+            None => (String::new(), path.as_str()),
+        };
+        if file_name.starts_with('.') {
+            continue;
+        }
+
+        let (stem, extension) = file_name.split_once('.').unwrap_or((file_name, ""));
+        let name = stem.chars().take(8).collect::<String>().to_uppercase();
+        let offset = data.len();
+
+        if folder == "maps" && extension.eq_ignore_ascii_case("wad") {
+            let mut map_lumps = read_directory(&file_data)?;
+            if let Some(map_lump) = map_lumps.first_mut() {
+                map_lump.name = name;
+            }
+
+            lumps.extend(map_lumps.into_iter().map(|lump| LumpEntry {
+                offset: offset + lump.offset,
+                ..lump
+            }));
+        } else {
+            lumps.push(LumpEntry {
+                name,
+                namespace: folder_namespace(&folder),
+                offset,
+                size: file_data.len(),
+            });
+        }
+
+        data.extend_from_slice(&file_data);
+    }
+
+    Ok(Resources { data, lumps })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wad::{Namespace, WadBuilder};
+    use super::{collect_resources, Resources};
+
+    fn file(path: &str, data: &[u8]) -> (String, Vec<u8>) {
+        (path.to_string(), data.to_vec())
+    }
+
+    fn lumps(resources: &Resources) -> Vec<(&str, Namespace, &[u8])> {
+        resources.lumps.iter()
+            .map(|lump| (lump.name.as_str(), lump.namespace, &resources.data[lump.offset..lump.offset + lump.size]))
+            .collect()
+    }
+
+    #[test]
+    fn folders_map_to_namespaces() {
+        let resources = collect_resources(vec![
+            file("flats/floor1.lmp", b"F"),
+            file("Sprites/troo/trooa1.lmp", b"S"),
+            file("patches/wall.lmp", b"P"),
+            file("graphics/titlepic.lmp", b"G"),
+            file("playpal.lmp", b"R"),
+        ]).unwrap();
+
+        // sorted by the bytes of the path
+        assert_eq!(lumps(&resources), [
+            ("TROOA1", Namespace::Sprites, &b"S"[..]),
+            ("FLOOR1", Namespace::Flats, b"F"),
+            ("TITLEPIC", Namespace::Global, b"G"),
+            ("WALL", Namespace::Patches, b"P"),
+            ("PLAYPAL", Namespace::Global, b"R"),
+        ]);
+    }
+
+    #[test]
+    fn hidden_files_are_skipped() {
+        let resources = collect_resources(vec![
+            file(".hidden", b"H"),
+            file("flats/.floor1.lmp", b"H"),
+            file("colormap.lmp", b"C"),
+        ]).unwrap();
+
+        assert_eq!(lumps(&resources), [("COLORMAP", Namespace::Global, &b"C"[..])]);
+    }
+
+    #[test]
+    fn map_wads_are_named_after_the_file() {
+        let mut map = WadBuilder::new();
+        map.add_lump("MAP01", Vec::new()).unwrap();
+        map.add_lump("THINGS", vec![1, 2]).unwrap();
+        let mut data = Vec::new();
+        map.write(&mut data).unwrap();
+
+        let resources = collect_resources(vec![file("maps/e1m1.wad", &data)]).unwrap();
+
+        assert_eq!(lumps(&resources), [
+            ("E1M1", Namespace::Global, &b""[..]),
+            ("THINGS", Namespace::Global, &[1, 2]),
+        ]);
+    }
+
+    #[test]
+    fn order_does_not_depend_on_the_file_order() {
+        let files = vec![file("b.lmp", b"B"), file("flats/c.lmp", b"C"), file("a.lmp", b"A")];
+        let mut reversed = files.clone();
+        reversed.reverse();
+
+        let resources = collect_resources(files).unwrap();
+        let reversed = collect_resources(reversed).unwrap();
+
+        assert_eq!(lumps(&resources), lumps(&reversed));
+        assert_eq!(lumps(&resources).iter().map(|lump| lump.0).collect::<Vec<_>>(), ["A", "B", "C"]);
+    }
+
+    #[test]
+    fn long_names_are_cut_to_eight_characters() {
+        let mut map = WadBuilder::new();
+        map.add_lump("MAP01", Vec::new()).unwrap();
+        let mut data = Vec::new();
+        map.write(&mut data).unwrap();
+
+        let resources = collect_resources(vec![
+            file("music/titlemusic.ogg", b"M"),
+            file("maps/longmapname.wad", &data),
+        ]).unwrap();
+
+        assert_eq!(lumps(&resources).iter().map(|lump| lump.0).collect::<Vec<_>>(), ["LONGMAPN", "TITLEMUS"]);
+    }
+}